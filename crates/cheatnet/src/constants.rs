@@ -21,6 +21,9 @@ use starknet_rust::core::utils::get_selector_from_name;
 // Mocked class hashes, those are not checked anywhere
 pub const TEST_ERC20_CONTRACT_CLASS_HASH: &str = "0x1010";
 
+// Address of the system contract storing hashes of past blocks under their numbers
+pub const BLOCK_HASH_CONTRACT_ADDRESS: &str = "0x1";
+
 fn contract_class_no_entrypoints() -> ContractClass {
     let raw_contract_class = indoc!(
         r#"{
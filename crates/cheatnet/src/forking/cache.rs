@@ -1,4 +1,5 @@
 use anyhow::{Context, Result};
+use blockifier::execution::contract_class::RunnableCompiledClass;
 use camino::{Utf8Path, Utf8PathBuf};
 use fs2::FileExt;
 use regex::Regex;
@@ -14,6 +15,7 @@ use std::fs;
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Seek, Write};
 use std::string::ToString;
+use std::sync::{Arc, LazyLock, Mutex, PoisonError, Weak};
 use url::Url;
 
 #[must_use]
@@ -29,6 +31,9 @@ pub struct ForkCacheContent {
     class_hash_at: HashMap<ContractAddress, ClassHash>,
     compiled_contract_class: HashMap<ClassHash, ContractClass>,
     block_info: Option<SerializableBlockInfo>,
+    // Missing in cache files written before block hashes were cached
+    #[serde(default)]
+    block_hash: HashMap<u64, Felt>,
 }
 
 impl Default for ForkCacheContent {
@@ -40,6 +45,7 @@ impl Default for ForkCacheContent {
             class_hash_at: HashMap::default(),
             compiled_contract_class: HashMap::default(),
             block_info: Option::default(),
+            block_hash: HashMap::default(),
         }
     }
 }
@@ -74,6 +80,7 @@ impl ForkCacheContent {
         self.class_hash_at.extend(other.class_hash_at.clone());
         self.compiled_contract_class
             .extend(other.compiled_contract_class.clone());
+        self.block_hash.extend(other.block_hash.clone());
         if other.block_info.is_some() {
             self.block_info.clone_from(&other.block_info);
         }
@@ -95,6 +102,8 @@ impl ToString for ForkCacheContent {
 pub struct ForkCache {
     fork_cache_content: ForkCacheContent,
     cache_file: Utf8PathBuf,
+    // Compiled classes are kept only in memory, recompiling them is cheaper than storing casm
+    runnable_compiled_classes: HashMap<ClassHash, RunnableCompiledClass>,
 }
 
 impl Drop for ForkCache {
@@ -116,12 +125,7 @@ impl FileTruncateExtension for File {
 }
 
 impl ForkCache {
-    pub(crate) fn load_or_new(
-        url: &Url,
-        block_number: BlockNumber,
-        cache_dir: &Utf8Path,
-    ) -> Result<Self> {
-        let cache_file = cache_file_path_from_fork_config(url, block_number, cache_dir)?;
+    fn load_or_new(cache_file: Utf8PathBuf) -> Result<Self> {
         let mut file = OpenOptions::new()
             .write(true)
             .read(true)
@@ -144,6 +148,7 @@ impl ForkCache {
         Ok(ForkCache {
             fork_cache_content,
             cache_file,
+            runnable_compiled_classes: HashMap::default(),
         })
     }
 
@@ -255,6 +260,35 @@ impl ForkCache {
             .or_insert(contract_class)
     }
 
+    pub(crate) fn get_runnable_compiled_class(
+        &self,
+        class_hash: &ClassHash,
+    ) -> Option<RunnableCompiledClass> {
+        self.runnable_compiled_classes.get(class_hash).cloned()
+    }
+
+    pub(crate) fn cache_runnable_compiled_class(
+        &mut self,
+        class_hash: ClassHash,
+        compiled_class: RunnableCompiledClass,
+    ) {
+        self.runnable_compiled_classes
+            .insert(class_hash, compiled_class);
+    }
+
+    pub(crate) fn get_block_hash(&self, block_number: u64) -> Option<Felt> {
+        self.fork_cache_content
+            .block_hash
+            .get(&block_number)
+            .copied()
+    }
+
+    pub(crate) fn cache_get_block_hash(&mut self, block_number: u64, block_hash: Felt) {
+        self.fork_cache_content
+            .block_hash
+            .insert(block_number, block_hash);
+    }
+
     pub(crate) fn get_block_info(&self) -> Option<BlockInfo> {
         Some(self.fork_cache_content.block_info.clone()?.into())
     }
@@ -264,6 +298,107 @@ impl ForkCache {
     }
 }
 
+/// Kind of data requested from the fork, used to deduplicate concurrent requests.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum ForkRequest {
+    StorageAt(ContractAddress, StorageKey),
+    ContractState(ContractAddress),
+    CompiledClass(ClassHash),
+    BlockInfo,
+    BlockHash(u64),
+}
+
+/// [`ForkCache`] shared by all test threads forking at the same url and block.
+///
+/// The cache is saved to the file once the last reader using it is dropped.
+#[derive(Debug)]
+pub struct SharedForkCache {
+    cache: Mutex<ForkCache>,
+    in_flight_requests: Mutex<HashMap<ForkRequest, Arc<Mutex<()>>>>,
+}
+
+static SHARED_FORK_CACHES: LazyLock<Mutex<HashMap<Utf8PathBuf, Weak<SharedForkCache>>>> =
+    LazyLock::new(Mutex::default);
+
+impl SharedForkCache {
+    pub(crate) fn load_or_get(
+        url: &Url,
+        block_number: BlockNumber,
+        cache_dir: &Utf8Path,
+    ) -> Result<Arc<Self>> {
+        let cache_file = cache_file_path_from_fork_config(url, block_number, cache_dir)?;
+        let mut shared_caches = SHARED_FORK_CACHES.lock().unwrap();
+
+        if let Some(shared_cache) = shared_caches.get(&cache_file).and_then(Weak::upgrade) {
+            return Ok(shared_cache);
+        }
+
+        let shared_cache = Arc::new(SharedForkCache {
+            cache: Mutex::new(ForkCache::load_or_new(cache_file.clone())?),
+            in_flight_requests: Mutex::default(),
+        });
+
+        shared_caches.retain(|_, cache| cache.strong_count() > 0);
+        shared_caches.insert(cache_file, Arc::downgrade(&shared_cache));
+
+        Ok(shared_cache)
+    }
+
+    pub(crate) fn cache(&self) -> std::sync::MutexGuard<'_, ForkCache> {
+        self.cache.lock().unwrap()
+    }
+
+    /// Returns value found by `read_cache` or runs `fetch` if there is none.
+    /// Only one thread runs `fetch` for a given `request`, others wait for it and read its result from the cache.
+    pub(crate) fn read_or_fetch<T, E>(
+        &self,
+        request: ForkRequest,
+        read_cache: impl Fn(&ForkCache) -> Option<T>,
+        fetch: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, E> {
+        if let Some(cache_hit) = read_cache(&self.cache()) {
+            return Ok(cache_hit);
+        }
+
+        let request_lock = self
+            .in_flight_requests
+            .lock()
+            .unwrap()
+            .entry(request.clone())
+            .or_default()
+            .clone();
+        // The lock is poisoned if `fetch` of another thread panicked, it can be fetched again then
+        let _guard = request_lock.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Another thread could have fetched the value while we were waiting
+        if let Some(cache_hit) = read_cache(&self.cache()) {
+            return Ok(cache_hit);
+        }
+
+        let _in_flight = InFlightRequest {
+            in_flight_requests: &self.in_flight_requests,
+            request,
+        };
+
+        fetch()
+    }
+}
+
+/// Removes the request from in-flight requests when dropped, even if fetching it panicked.
+struct InFlightRequest<'a> {
+    in_flight_requests: &'a Mutex<HashMap<ForkRequest, Arc<Mutex<()>>>>,
+    request: ForkRequest,
+}
+
+impl Drop for InFlightRequest<'_> {
+    fn drop(&mut self) {
+        self.in_flight_requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.request);
+    }
+}
+
 fn cache_file_path_from_fork_config(
     url: &Url,
     BlockNumber(block_number): BlockNumber,
@@ -284,3 +419,57 @@ fn cache_file_path_from_fork_config(
 
     Ok(cache_file_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+    use tempfile::TempDir;
+
+    #[test]
+    fn cache_without_block_hashes_can_be_read() {
+        let serialized = format!(
+            r#"{{
+                "cache_version": "{}",
+                "storage_at": {{}},
+                "nonce_at": {{}},
+                "class_hash_at": {{}},
+                "compiled_contract_class": {{}},
+                "block_info": null
+            }}"#,
+            cache_version()
+        );
+
+        let cache = ForkCacheContent::from_str(&serialized);
+
+        assert!(cache.block_hash.is_empty());
+    }
+
+    #[test]
+    fn request_can_be_fetched_after_fetch_panicked() {
+        let cache_dir = TempDir::new().unwrap();
+        let shared_cache = SharedForkCache::load_or_get(
+            &Url::parse("http://localhost:5050").unwrap(),
+            BlockNumber(1),
+            Utf8Path::from_path(cache_dir.path()).unwrap(),
+        )
+        .unwrap();
+
+        let panicked = catch_unwind(AssertUnwindSafe(|| {
+            shared_cache.read_or_fetch(
+                ForkRequest::BlockHash(0),
+                |cache| cache.get_block_hash(0),
+                || -> Result<Felt, ()> { panic!("fetch panicked") },
+            )
+        }));
+        assert!(panicked.is_err());
+        assert!(shared_cache.in_flight_requests.lock().unwrap().is_empty());
+
+        let block_hash = shared_cache.read_or_fetch(
+            ForkRequest::BlockHash(0),
+            |cache| cache.get_block_hash(0),
+            || Ok::<_, ()>(Felt::ONE),
+        );
+        assert_eq!(block_hash, Ok(Felt::ONE));
+    }
+}
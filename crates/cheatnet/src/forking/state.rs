@@ -1,19 +1,25 @@
-use crate::forking::cache::ForkCache;
+use crate::constants::BLOCK_HASH_CONTRACT_ADDRESS;
+use crate::forking::cache::{ForkCache, ForkRequest, SharedForkCache};
+use crate::forking::data::ForkData;
 use crate::state::BlockInfoReader;
-use crate::sync_client::SyncClient;
+use crate::sync_client::{ContractStateResponse, SyncClient};
 use anyhow::{Context, Result};
+use blockifier::abi::constants::STORED_BLOCK_HASH_BUFFER;
 use blockifier::execution::contract_class::{
     CompiledClassV0, CompiledClassV0Inner, CompiledClassV1, RunnableCompiledClass,
 };
-use blockifier::state::errors::StateError::{self, StateReadError, UndeclaredClassHash};
+use blockifier::state::errors::StateError::{StateReadError, UndeclaredClassHash};
 use blockifier::state::state_api::{StateReader, StateResult};
 use cairo_lang_utils::bigint::BigUintAsHex;
 use cairo_vm::types::program::Program;
 use camino::Utf8Path;
+use conversions::string::TryFromHexStr;
 use conversions::{FromConv, IntoConv};
 use flate2::read::GzDecoder;
 use num_bigint::BigUint;
+use num_traits::ToPrimitive;
 use runtime::starknet::context::SerializableGasPrices;
+use runtime::starknet::deadline::Deadline;
 use starknet_api::block::{BlockInfo, BlockNumber, BlockTimestamp, StarknetVersion};
 use starknet_api::contract_class::SierraVersion;
 use starknet_api::core::{ChainId, ClassHash, CompiledClassHash, ContractAddress, Nonce};
@@ -24,64 +30,156 @@ use starknet_rust::core::types::{
 use starknet_rust::core::utils::parse_cairo_short_string;
 use starknet_rust::providers::ProviderError;
 use starknet_types_core::felt::Felt;
-use std::cell::{Ref, RefCell};
-use std::collections::HashMap;
 use std::io::Read;
 use std::sync::Arc;
 use universal_sierra_compiler_api::compile_contract_sierra;
 use url::Url;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ForkStateReader {
-    client: SyncClient,
-    cache: RefCell<ForkCache>,
+    client: Arc<SyncClient>,
+    shared_cache: Arc<SharedForkCache>,
 }
 
 impl ForkStateReader {
-    pub fn new(url: Url, block_number: BlockNumber, cache_dir: &Utf8Path) -> Result<Self> {
+    /// Requests to the node are not sent after the `deadline` and are stopped once it passes.
+    pub fn new(
+        url: Url,
+        block_number: BlockNumber,
+        cache_dir: &Utf8Path,
+        deadline: Option<Deadline>,
+    ) -> Result<Self> {
         Ok(ForkStateReader {
-            cache: RefCell::new(
-                ForkCache::load_or_new(&url, block_number, cache_dir)
-                    .context("Could not create fork cache")?,
-            ),
-            client: SyncClient::new(url, block_number),
+            shared_cache: SharedForkCache::load_or_get(&url, block_number, cache_dir)
+                .context("Could not create fork cache")?,
+            client: Arc::new(SyncClient::new(url, block_number, deadline)),
         })
     }
 
     pub fn chain_id(&self) -> Result<ChainId> {
-        let id = self.client.chain_id()?;
+        let id = match self.client.chain_id() {
+            Ok(id) => id,
+            Err(ProviderError::Other(boxed)) => other_provider_error(boxed)?,
+            Err(x) => Err(StateReadError(format!(
+                "Unable to get chain id from fork ({x})"
+            )))?,
+        };
         let id = parse_cairo_short_string(&id)?;
         Ok(ChainId::from(id))
     }
 
-    pub fn compiled_contract_class_map(
+    /// Client used to read the state, shared with cheatcodes sending their own requests to the fork
+    #[must_use]
+    pub fn client(&self) -> Arc<SyncClient> {
+        self.client.clone()
+    }
+
+    /// Hash computed from the Sierra class declared on the fork under the `class_hash`.
+    /// Fails if the class is not declared on the fork or is a Cairo 0 class.
+    pub fn get_sierra_class_hash(&self, class_hash: ClassHash) -> StateResult<ClassHash> {
+        match self.fetch_contract_class(class_hash)? {
+            ContractClassStarknet::Sierra(sierra_class) => Ok(sierra_class.class_hash().into_()),
+            ContractClassStarknet::Legacy(_) => Err(StateReadError(format!(
+                "Class {class_hash} declared on the fork is a Cairo 0 class"
+            ))),
+        }
+    }
+
+    /// Whether reading the state failed because of an error of a request to the node
+    #[must_use]
+    pub fn rpc_failed(&self) -> bool {
+        self.client.request_failed()
+    }
+
+    #[must_use]
+    pub fn fork_data(&self) -> ForkData {
+        ForkData::new(self.shared_cache.cache().compiled_contract_class_map())
+    }
+
+    /// Returns the block number if the read targets the block hash contract
+    /// and the block is old enough to have its hash stored at the fork block.
+    fn historical_block_number(
         &self,
-    ) -> Ref<'_, HashMap<ClassHash, ContractClassStarknet>> {
-        Ref::map(self.cache.borrow(), |cache| {
-            cache.compiled_contract_class_map()
-        })
+        contract_address: ContractAddress,
+        key: StorageKey,
+    ) -> Option<u64> {
+        let block_hash_contract_address =
+            ContractAddress::try_from_hex_str(BLOCK_HASH_CONTRACT_ADDRESS).unwrap();
+        if contract_address != block_hash_contract_address {
+            return None;
+        }
+
+        let block_number = key.0.key().to_u64()?;
+        let BlockNumber(fork_block_number) = self.client.block_number();
+
+        (block_number.checked_add(STORED_BLOCK_HASH_BUFFER)? <= fork_block_number)
+            .then_some(block_number)
     }
-}
 
-#[expect(clippy::needless_pass_by_value)]
-fn other_provider_error<T>(boxed: impl ToString) -> Result<T, StateError> {
-    let err_str = boxed.to_string();
+    fn get_block_hash(&self, block_number: u64) -> StateResult<Felt> {
+        self.shared_cache.read_or_fetch(
+            ForkRequest::BlockHash(block_number),
+            |cache| cache.get_block_hash(block_number),
+            || match self.client.get_block_hash(block_number) {
+                Ok(block_hash) => {
+                    self.shared_cache
+                        .cache()
+                        .cache_get_block_hash(block_number, block_hash);
+                    Ok(block_hash)
+                }
+                Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
+                Err(x) => Err(StateReadError(format!(
+                    "Unable to get hash of block {block_number} from fork ({x})"
+                ))),
+            },
+        )
+    }
 
-    Err(StateReadError(
-        if err_str.contains("error sending request") {
-            "Unable to reach the node. Check your internet connection and node url".to_string()
-        } else {
-            format!("JsonRpc provider error: {err_str}")
-        },
-    ))
+    /// Fetches class hash and nonce of the contract at once, together with its class if it is not
+    /// cached yet, as they are almost always needed together when the contract is called.
+    fn fetch_contract_state(
+        &self,
+        contract_address: ContractAddress,
+    ) -> StateResult<(ClassHash, Nonce)> {
+        let (class_hash, nonce) = match self
+            .client
+            .get_contract_state(Felt::from_(contract_address))
+        {
+            Ok(ContractStateResponse { class_hash, nonce }) => (class_hash.into_(), nonce.into_()),
+            Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {
+                (ClassHash::default(), Nonce::default())
+            }
+            Err(ProviderError::Other(boxed)) => return other_provider_error(boxed),
+            Err(x) => {
+                return Err(StateReadError(format!(
+                    "Unable to get state of contract {contract_address:?} from fork ({x})"
+                )));
+            }
+        };
+
+        if class_hash != ClassHash::default() {
+            self.fetch_contract_class(class_hash)?;
+        }
+
+        let mut cache = self.shared_cache.cache();
+        cache.cache_get_class_hash_at(contract_address, class_hash);
+        cache.cache_get_nonce_at(contract_address, nonce);
+
+        Ok((class_hash, nonce))
+    }
 }
 
 impl BlockInfoReader for ForkStateReader {
     fn get_block_info(&mut self) -> StateResult<BlockInfo> {
-        if let Some(cache_hit) = self.cache.borrow().get_block_info() {
-            return Ok(cache_hit);
-        }
+        self.shared_cache
+            .read_or_fetch(ForkRequest::BlockInfo, ForkCache::get_block_info, || {
+                self.fetch_block_info()
+            })
+    }
+}
 
+impl ForkStateReader {
+    fn fetch_block_info(&self) -> StateResult<BlockInfo> {
         match self.client.get_block_with_tx_hashes() {
             Ok(MaybePreConfirmedBlockWithTxHashes::Block(block)) => {
                 let block_info = BlockInfo {
@@ -96,8 +194,8 @@ impl BlockInfoReader for ForkStateReader {
                         .unwrap_or(StarknetVersion::LATEST),
                 };
 
-                self.cache
-                    .borrow_mut()
+                self.shared_cache
+                    .cache()
                     .cache_get_block_info(block_info.clone());
 
                 Ok(block_info)
@@ -119,24 +217,80 @@ impl StateReader for ForkStateReader {
         contract_address: ContractAddress,
         key: StorageKey,
     ) -> StateResult<Felt> {
-        if let Some(cache_hit) = self.cache.borrow().get_storage_at(&contract_address, &key) {
-            return Ok(cache_hit);
+        if let Some(block_number) = self.historical_block_number(contract_address, key) {
+            return self.get_block_hash(block_number);
         }
 
+        self.shared_cache.read_or_fetch(
+            ForkRequest::StorageAt(contract_address, key),
+            |cache| cache.get_storage_at(&contract_address, &key),
+            || self.fetch_storage_at(contract_address, key),
+        )
+    }
+
+    fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
+        self.shared_cache.read_or_fetch(
+            ForkRequest::ContractState(contract_address),
+            |cache| cache.get_nonce_at(&contract_address),
+            || {
+                self.fetch_contract_state(contract_address)
+                    .map(|(_, nonce)| nonce)
+            },
+        )
+    }
+
+    fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
+        self.shared_cache.read_or_fetch(
+            ForkRequest::ContractState(contract_address),
+            |cache| cache.get_class_hash_at(&contract_address),
+            || {
+                self.fetch_contract_state(contract_address)
+                    .map(|(class_hash, _)| class_hash)
+            },
+        )
+    }
+
+    fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        self.shared_cache.read_or_fetch(
+            ForkRequest::CompiledClass(class_hash),
+            |cache| cache.get_runnable_compiled_class(&class_hash),
+            || {
+                let compiled_class = self.fetch_compiled_class(class_hash)?;
+                self.shared_cache
+                    .cache()
+                    .cache_runnable_compiled_class(class_hash, compiled_class.clone());
+                Ok(compiled_class)
+            },
+        )
+    }
+
+    fn get_compiled_class_hash(&self, _class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+        Err(StateReadError(
+            "Unable to get compiled class hash from the fork".to_string(),
+        ))
+    }
+}
+
+impl ForkStateReader {
+    fn fetch_storage_at(
+        &self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+    ) -> StateResult<Felt> {
         match self
             .client
             .get_storage_at(Felt::from_(contract_address), Felt::from_(*key.0.key()))
         {
             Ok(value) => {
                 let value_sf = value.into_();
-                self.cache
-                    .borrow_mut()
+                self.shared_cache
+                    .cache()
                     .cache_get_storage_at(contract_address, key, value_sf);
                 Ok(value_sf)
             }
             Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
             Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {
-                self.cache.borrow_mut().cache_get_storage_at(
+                self.shared_cache.cache().cache_get_storage_at(
                     contract_address,
                     key,
                     Felt::default(),
@@ -149,81 +303,35 @@ impl StateReader for ForkStateReader {
         }
     }
 
-    fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
-        if let Some(cache_hit) = self.cache.borrow().get_nonce_at(&contract_address) {
-            return Ok(cache_hit);
-        }
-
-        match self.client.get_nonce(Felt::from_(contract_address)) {
-            Ok(nonce) => {
-                let nonce = nonce.into_();
-                self.cache
-                    .borrow_mut()
-                    .cache_get_nonce_at(contract_address, nonce);
-                Ok(nonce)
-            }
-            Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
-            Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {
-                self.cache
-                    .borrow_mut()
-                    .cache_get_nonce_at(contract_address, Nonce::default());
-                Ok(Nonce::default())
-            }
-            Err(x) => Err(StateReadError(format!(
-                "Unable to get nonce at {contract_address:?} from fork ({x})"
-            ))),
-        }
-    }
+    fn fetch_contract_class(&self, class_hash: ClassHash) -> StateResult<ContractClassStarknet> {
+        let cached_class = self
+            .shared_cache
+            .cache()
+            .get_compiled_contract_class(&class_hash)
+            .cloned();
 
-    fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
-        if let Some(cache_hit) = self.cache.borrow().get_class_hash_at(&contract_address) {
+        if let Some(cache_hit) = cached_class {
             return Ok(cache_hit);
         }
 
-        match self.client.get_class_hash_at(Felt::from_(contract_address)) {
-            Ok(class_hash) => {
-                let class_hash = class_hash.into_();
-                self.cache
-                    .borrow_mut()
-                    .cache_get_class_hash_at(contract_address, class_hash);
-                Ok(class_hash)
-            }
-            Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {
-                self.cache
-                    .borrow_mut()
-                    .cache_get_class_hash_at(contract_address, ClassHash::default());
-                Ok(ClassHash::default())
+        match self.client.get_class(Felt::from_(class_hash)) {
+            Ok(contract_class) => Ok(self
+                .shared_cache
+                .cache()
+                .insert_compiled_contract_class(class_hash, contract_class)
+                .clone()),
+            Err(ProviderError::StarknetError(StarknetError::ClassHashNotFound)) => {
+                Err(UndeclaredClassHash(class_hash))
             }
             Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
             Err(x) => Err(StateReadError(format!(
-                "Unable to get class hash at {contract_address:?} from fork ({x})"
+                "Unable to get compiled class at {class_hash} from fork ({x})"
             ))),
         }
     }
 
-    fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
-        let mut cache = self.cache.borrow_mut();
-
-        let contract_class = {
-            if let Some(cache_hit) = cache.get_compiled_contract_class(&class_hash) {
-                Ok(cache_hit)
-            } else {
-                match self.client.get_class(Felt::from_(class_hash)) {
-                    Ok(contract_class) => {
-                        Ok(cache.insert_compiled_contract_class(class_hash, contract_class))
-                    }
-                    Err(ProviderError::StarknetError(StarknetError::ClassHashNotFound)) => {
-                        Err(UndeclaredClassHash(class_hash))
-                    }
-                    Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
-                    Err(x) => Err(StateReadError(format!(
-                        "Unable to get compiled class at {class_hash} from fork ({x})"
-                    ))),
-                }
-            }
-        };
-
-        match contract_class? {
+    fn fetch_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        match self.fetch_contract_class(class_hash)? {
             ContractClassStarknet::Sierra(flattened_class) => {
                 let converted_sierra_program: Vec<BigUintAsHex> = flattened_class
                     .sierra_program
@@ -271,10 +379,18 @@ impl StateReader for ForkStateReader {
             }
         }
     }
+}
 
-    fn get_compiled_class_hash(&self, _class_hash: ClassHash) -> StateResult<CompiledClassHash> {
-        Err(StateReadError(
-            "Unable to get compiled class hash from the fork".to_string(),
-        ))
-    }
+/// Fails reading the state because of an error of the provider itself.
+#[expect(clippy::needless_pass_by_value)]
+fn other_provider_error<T>(boxed: impl ToString) -> StateResult<T> {
+    let err_str = boxed.to_string();
+
+    Err(StateReadError(
+        if err_str.contains("error sending request") {
+            "Unable to reach the node. Check your internet connection and node url".to_string()
+        } else {
+            format!("JsonRpc provider error: {err_str}")
+        },
+    ))
 }
@@ -0,0 +1,84 @@
+use serde::Deserialize;
+use starknet_types_core::felt::Felt;
+
+/// Contracts predeployed before running each test, configured in `[tool.snforge.predeployment]`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PredeploymentConfig {
+    /// Predeploy STRK and ETH tokens
+    pub tokens: bool,
+    /// Name of the Universal Deployer Contract, predeployed at `UDC_ADDRESS`
+    pub udc: Option<String>,
+    /// Accounts predeployed with known keys and funded with STRK
+    pub accounts: Vec<PredeployedAccountsConfig>,
+    /// Contracts predeployed at fixed addresses
+    pub contracts: Vec<PredeployedContractConfig>,
+}
+
+impl Default for PredeploymentConfig {
+    fn default() -> Self {
+        Self {
+            tokens: true,
+            udc: None,
+            accounts: Vec::new(),
+            contracts: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PredeployedAccountsConfig {
+    /// Name of the account contract
+    pub contract: String,
+    /// Determines the constructor calldata of the account
+    pub kind: AccountKind,
+    /// Number of accounts to predeploy
+    #[serde(default = "default_accounts_count")]
+    pub count: u8,
+    /// STRK balance of each account (in fri)
+    #[serde(default = "default_account_balance")]
+    pub balance: Felt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AccountKind {
+    /// `constructor(public_key: felt252)`
+    OpenZeppelin,
+    /// `constructor(owner: Signer, guardian: Option<Signer>)` with a Starknet signer and no guardian
+    Argent,
+    /// `constructor(stark_pub_key: felt252)`
+    Braavos,
+}
+
+impl AccountKind {
+    #[must_use]
+    pub fn constructor_calldata(self, public_key: Felt) -> Vec<Felt> {
+        match self {
+            AccountKind::OpenZeppelin | AccountKind::Braavos => vec![public_key],
+            // `Signer::Starknet(public_key)`, `Option::None`
+            AccountKind::Argent => vec![Felt::ZERO, public_key, Felt::ONE],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PredeployedContractConfig {
+    /// Name of the contract
+    pub contract: String,
+    /// Address the contract is deployed at
+    pub address: Felt,
+    #[serde(default)]
+    pub constructor_calldata: Vec<Felt>,
+}
+
+fn default_accounts_count() -> u8 {
+    1
+}
+
+fn default_account_balance() -> Felt {
+    // 1000 STRK
+    Felt::from(1_000_000_000_000_000_000_000_u128)
+}
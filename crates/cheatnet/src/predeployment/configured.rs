@@ -0,0 +1,431 @@
+use crate::constants::build_testing_state;
+use crate::predeployment::config::{AccountKind, PredeployedAccountsConfig, PredeploymentConfig};
+use crate::predeployment::erc20::strk::STRK_CONTRACT_ADDRESS;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::declare::{
+    DeclareResult, declare, resolve_contract,
+};
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage::{
+    map_entry_address, storage_key, variable_address,
+};
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use crate::runtime_extensions::outer_call_runtime_extension::execution::cheated_syscalls::execute_deployment;
+use crate::state::{BlockInfoReader, CheatnetState, ExtendedStateReader};
+use anyhow::{Context, Result, anyhow, ensure};
+use blockifier::execution::contract_class::{RunnableCompiledClass, TrackedResource};
+use blockifier::execution::entry_point::{ConstructorContext, EntryPointExecutionContext};
+use blockifier::state::cached_state::{CachedState, StateMaps};
+use blockifier::state::errors::StateError;
+use blockifier::state::state_api::{State, StateReader, StateResult};
+use conversions::serde::serialize::CairoSerialize;
+use conversions::string::TryFromHexStr;
+use num_bigint::BigUint;
+use num_traits::CheckedSub;
+use runtime::starknet::context::build_context;
+use starknet_api::core::{
+    ClassHash, CompiledClassHash, ContractAddress, calculate_contract_address,
+};
+use starknet_api::state::StorageKey;
+use starknet_api::transaction::fields::{Calldata, ContractAddressSalt};
+use starknet_rust::signers::SigningKey;
+use starknet_types_core::felt::Felt;
+use starknet_types_core::hash::{Pedersen, StarkHash};
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use std::sync::{Arc, OnceLock};
+
+pub const UDC_ADDRESS: &str = "0x02ceed65a4bd731034c01113685c831b01c15d7d432f71afb1cf1634b53a2125";
+
+#[derive(Debug, Clone, PartialEq, Eq, CairoSerialize)]
+pub struct PredeployedAccount {
+    pub contract_address: ContractAddress,
+    pub class_hash: ClassHash,
+    pub private_key: Felt,
+    pub public_key: Felt,
+}
+
+/// State with the contracts from the configuration, declared and deployed once per run
+/// and copied to the state of each test.
+#[derive(Debug)]
+pub struct PredeployedState {
+    state_maps: StateMaps,
+    classes: HashMap<ClassHash, RunnableCompiledClass>,
+    /// Predeployed accounts with their STRK balances
+    accounts: Vec<(PredeployedAccount, Felt)>,
+}
+
+impl PredeployedState {
+    /// Declares and deploys contracts from the `config` on an empty state.
+    pub fn create(config: &PredeploymentConfig, contracts_data: &ContractsData) -> Result<Self> {
+        let mut state_reader = ExtendedStateReader {
+            dict_state_reader: build_testing_state(),
+            fork_state_reader: None,
+            fixture_snapshot: None,
+        };
+        let block_info = state_reader.get_block_info()?;
+        let mut state = CachedState::new(state_reader);
+        let mut context = build_context(&block_info, None, &TrackedResource::SierraGas);
+        let mut cheatnet_state = CheatnetState {
+            block_info,
+            ..Default::default()
+        };
+        let mut deployer = Deployer {
+            state: &mut state,
+            cheatnet_state: &mut cheatnet_state,
+            context: &mut context,
+            contracts_data,
+        };
+
+        if let Some(udc) = &config.udc {
+            let udc_address = ContractAddress::try_from_hex_str(UDC_ADDRESS).unwrap();
+            deployer.deploy(udc, udc_address, vec![])?;
+        }
+
+        for contract in &config.contracts {
+            let address = ContractAddress::try_from(contract.address).with_context(|| {
+                format!(
+                    "Invalid address of predeployed contract {}",
+                    contract.contract
+                )
+            })?;
+            deployer.deploy(
+                &contract.contract,
+                address,
+                contract.constructor_calldata.clone(),
+            )?;
+        }
+
+        let mut accounts = vec![];
+        let accounts_configs = config.accounts.iter().flat_map(|accounts_config| {
+            iter::repeat_n(accounts_config, accounts_config.count.into())
+        });
+        for (index, accounts_config) in (0..).zip(accounts_configs) {
+            let private_key = predeployed_account_private_key(index);
+            let account = deployer.deploy_account(accounts_config, private_key)?;
+            accounts.push((account, accounts_config.balance));
+        }
+
+        let state_maps = state.to_state_diff()?.state_maps;
+        let classes = state_maps
+            .declared_contracts
+            .keys()
+            .chain(state_maps.compiled_class_hashes.keys())
+            .map(|class_hash| Ok((*class_hash, state.get_compiled_class(*class_hash)?)))
+            .collect::<StateResult<_>>()?;
+
+        Ok(Self {
+            state_maps,
+            classes,
+            accounts,
+        })
+    }
+
+    /// Copies the contracts to the `state`, skipping addresses that are already occupied
+    /// (e.g. because the test is forked) and funds the accounts that were predeployed.
+    /// Returns all the configured accounts.
+    pub fn predeploy(&self, state: &mut dyn State) -> Result<Vec<PredeployedAccount>> {
+        for (class_hash, class) in &self.classes {
+            match state.get_compiled_class(*class_hash) {
+                Err(StateError::UndeclaredClassHash(_)) => {
+                    state.set_contract_class(*class_hash, class.clone())?;
+                    state.set_compiled_class_hash(*class_hash, CompiledClassHash::default())?;
+                }
+                Err(error) => return Err(error.into()),
+                Ok(_) => {}
+            }
+        }
+
+        let mut deployed = HashSet::new();
+        for (&address, &class_hash) in &self.state_maps.class_hashes {
+            if state.get_class_hash_at(address)? == ClassHash::default() {
+                state.set_class_hash_at(address, class_hash)?;
+                deployed.insert(address);
+            }
+        }
+        for (&(address, key), &value) in &self.state_maps.storage {
+            if deployed.contains(&address) {
+                state.set_storage_at(address, key, value)?;
+            }
+        }
+
+        for (account, balance) in &self.accounts {
+            if deployed.contains(&account.contract_address) {
+                fund(state, account.contract_address, *balance)?;
+            }
+        }
+
+        Ok(self
+            .accounts
+            .iter()
+            .map(|(account, _)| account.clone())
+            .collect())
+    }
+}
+
+/// [`PredeployedState`] shared by all tests of a run, created by the first test using it.
+#[derive(Debug, Default)]
+pub struct PredeployedStateCache(OnceLock<Result<Arc<PredeployedState>, String>>);
+
+impl PredeployedStateCache {
+    pub fn get_or_create(
+        &self,
+        config: &PredeploymentConfig,
+        contracts_data: &ContractsData,
+    ) -> Result<Arc<PredeployedState>> {
+        self.0
+            .get_or_init(|| {
+                PredeployedState::create(config, contracts_data)
+                    .map(Arc::new)
+                    .map_err(|error| error.to_string())
+            })
+            .clone()
+            .map_err(|error| anyhow!(error))
+    }
+}
+
+// Cache does not affect the configuration of the run
+impl PartialEq for PredeployedStateCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+struct Deployer<'a> {
+    state: &'a mut dyn State,
+    cheatnet_state: &'a mut CheatnetState,
+    context: &'a mut EntryPointExecutionContext,
+    contracts_data: &'a ContractsData,
+}
+
+impl Deployer<'_> {
+    fn declare(&mut self, contract_name: &str) -> Result<ClassHash> {
+        resolve_contract(contract_name, self.contracts_data)
+            .with_context(|| format!("Failed to declare predeployed contract {contract_name}"))?;
+
+        match declare(self.state, contract_name, self.contracts_data) {
+            Ok(DeclareResult::Success(class_hash) | DeclareResult::AlreadyDeclared(class_hash)) => {
+                Ok(class_hash)
+            }
+            Err(error) => Err(anyhow!(
+                "Failed to declare predeployed contract {contract_name}: {error:?}"
+            )),
+        }
+    }
+
+    /// Deploys the contract, unless the `address` is already occupied.
+    fn deploy(
+        &mut self,
+        contract_name: &str,
+        address: ContractAddress,
+        constructor_calldata: Vec<Felt>,
+    ) -> Result<ClassHash> {
+        let class_hash = self.declare(contract_name)?;
+        if self.state.get_class_hash_at(address)? != ClassHash::default() {
+            return Ok(class_hash);
+        }
+
+        let ctor_context = ConstructorContext {
+            class_hash,
+            code_address: Some(address),
+            storage_address: address,
+            caller_address: ContractAddress::default(),
+        };
+        let call_info = execute_deployment(
+            self.state,
+            self.cheatnet_state,
+            self.context,
+            &ctor_context,
+            Calldata(Arc::new(constructor_calldata)),
+            &mut (i64::MAX as u64),
+        )
+        .map_err(|error| {
+            anyhow!("Failed to deploy predeployed contract {contract_name}: {error}")
+        })?;
+        if call_info.execution.failed {
+            return Err(anyhow!(
+                "Constructor of predeployed contract {contract_name} failed with {:?}",
+                call_info.execution.retdata.0
+            ));
+        }
+
+        Ok(class_hash)
+    }
+
+    fn deploy_account(
+        &mut self,
+        config: &PredeployedAccountsConfig,
+        private_key: Felt,
+    ) -> Result<PredeployedAccount> {
+        let public_key = SigningKey::from_secret_scalar(private_key)
+            .verifying_key()
+            .scalar();
+
+        let class_hash = self.declare(&config.contract)?;
+        let contract_address = account_address(class_hash, config.kind, public_key)?;
+        self.deploy(
+            &config.contract,
+            contract_address,
+            config.kind.constructor_calldata(public_key),
+        )?;
+
+        Ok(PredeployedAccount {
+            contract_address,
+            class_hash,
+            private_key,
+            public_key,
+        })
+    }
+}
+
+/// Private key of the predeployed account with the given `index`.
+/// Keys are derived from the index with a hash, so they are the same in every test run and release.
+fn predeployed_account_private_key(index: u64) -> Felt {
+    let hash = Pedersen::hash(
+        &Felt::from_bytes_be_slice(b"predeployed_account"),
+        &Felt::from(index),
+    );
+    // Keys fitting in 128 bits are always valid scalars of the STARK curve
+    Felt::from_bytes_be_slice(&hash.to_bytes_be()[16..])
+}
+
+/// Address of the account deployed with its public key as the salt.
+fn account_address(
+    class_hash: ClassHash,
+    kind: AccountKind,
+    public_key: Felt,
+) -> Result<ContractAddress> {
+    Ok(calculate_contract_address(
+        ContractAddressSalt(public_key),
+        class_hash,
+        &Calldata(Arc::new(kind.constructor_calldata(public_key))),
+        ContractAddress::default(),
+    )?)
+}
+
+/// Sets the STRK balance of the `account`, changing the total supply of STRK by the difference.
+fn fund(state: &mut dyn State, account: ContractAddress, balance: Felt) -> Result<()> {
+    let strk_address = ContractAddress::try_from_hex_str(STRK_CONTRACT_ADDRESS).unwrap();
+    if state.get_class_hash_at(strk_address)? == ClassHash::default() {
+        ensure!(
+            balance == Felt::ZERO,
+            "Predeployed accounts cannot be funded, as STRK token is not predeployed (`tokens = false`)"
+        );
+        return Ok(());
+    }
+    let balance = u128::try_from(balance)
+        .map_err(|_| anyhow!("Balance of predeployed account has to fit in u128"))?;
+
+    let balance_key = storage_key(map_entry_address("ERC20_balances", &[**account]))?;
+    let total_supply_key = storage_key(variable_address("ERC20_total_supply"))?;
+    let previous_balance = read_u256(state, strk_address, balance_key)?;
+    let total_supply = read_u256(state, strk_address, total_supply_key)?;
+
+    let total_supply = (total_supply + balance)
+        .checked_sub(&previous_balance)
+        .context("Total supply of STRK is lower than the balance of a predeployed account")?;
+    write_u256(state, strk_address, balance_key, &BigUint::from(balance))?;
+    write_u256(state, strk_address, total_supply_key, &total_supply)?;
+
+    Ok(())
+}
+
+/// Reads `u256` stored at the `low_key` and the key following it.
+fn read_u256(
+    state: &mut dyn State,
+    address: ContractAddress,
+    low_key: StorageKey,
+) -> Result<BigUint> {
+    let low = state.get_storage_at(address, low_key)?;
+    let high = state.get_storage_at(address, low_key.next_storage_key()?)?;
+
+    Ok((BigUint::from_bytes_be(&high.to_bytes_be()) << 128)
+        + BigUint::from_bytes_be(&low.to_bytes_be()))
+}
+
+/// Stores `value` as `u256` at the `low_key` and the key following it.
+fn write_u256(
+    state: &mut dyn State,
+    address: ContractAddress,
+    low_key: StorageKey,
+    value: &BigUint,
+) -> Result<()> {
+    let low = u128::try_from(value & BigUint::from(u128::MAX)).expect("value is masked to u128");
+    let high = u128::try_from(value >> 128).context("Value does not fit in u256")?;
+    state.set_storage_at(address, low_key, Felt::from(low))?;
+    state.set_storage_at(address, low_key.next_storage_key()?, Felt::from(high))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runtime::starknet::state::DictStateReader;
+
+    #[test]
+    fn predeployed_account_keys_and_addresses_are_fixed() {
+        let private_key = predeployed_account_private_key(0);
+        let public_key = SigningKey::from_secret_scalar(private_key)
+            .verifying_key()
+            .scalar();
+        // Class hash of the account contract depends on its compilation, any fixed one is used
+        let address = account_address(
+            ClassHash(Felt::from_hex_unchecked("0x1234")),
+            AccountKind::OpenZeppelin,
+            public_key,
+        )
+        .unwrap();
+
+        assert_eq!(
+            private_key,
+            Felt::from_hex_unchecked("0xa37542b39440682f93757c9862cbfb06")
+        );
+        assert_eq!(
+            public_key,
+            Felt::from_hex_unchecked(
+                "0x2092213d5fe8dd71c8e234dba84c71141304729659308d3e99389d7d26f443b"
+            )
+        );
+        assert_eq!(
+            address,
+            ContractAddress::try_from_hex_str(
+                "0x72311d76c32c3511aa0066994da9b07f43778b2f562c2923107e21b7f72fbc6"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            predeployed_account_private_key(1),
+            Felt::from_hex_unchecked("0x19283f969ca97ba27f01418225682612")
+        );
+    }
+
+    #[test]
+    fn funding_account_updates_total_supply() {
+        let strk_address = ContractAddress::try_from_hex_str(STRK_CONTRACT_ADDRESS).unwrap();
+        let account = ContractAddress::try_from_hex_str("0x1234").unwrap();
+        let balance_key = storage_key(map_entry_address("ERC20_balances", &[**account])).unwrap();
+        let total_supply_key = storage_key(variable_address("ERC20_total_supply")).unwrap();
+
+        let mut state = CachedState::new(DictStateReader {
+            address_to_class_hash: HashMap::from([(
+                strk_address,
+                ClassHash(Felt::from_hex_unchecked("0x5678")),
+            )]),
+            storage_view: HashMap::from([
+                ((strk_address, balance_key), Felt::from(10)),
+                ((strk_address, total_supply_key), Felt::from(100)),
+            ]),
+            ..Default::default()
+        });
+
+        fund(&mut state, account, Felt::from(50)).unwrap();
+
+        assert_eq!(
+            read_u256(&mut state, strk_address, balance_key).unwrap(),
+            BigUint::from(50_u8)
+        );
+        assert_eq!(
+            read_u256(&mut state, strk_address, total_supply_key).unwrap(),
+            BigUint::from(140_u8)
+        );
+    }
+}
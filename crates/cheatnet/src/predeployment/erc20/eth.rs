@@ -1,4 +1,5 @@
 use conversions::string::TryFromHexStr;
+use rand::Rng;
 use starknet_api::core::ContractAddress;
 
 use crate::predeployment::predeployed_contract::PredeployedContract;
@@ -11,7 +12,7 @@ pub const ERC20MINTABLE_SIERRA_CLASS_HASH: &str =
     "0x00b45dbc3714180381c5680e41931172d67194d77d504413465390e0bef194ec";
 
 #[must_use]
-pub fn eth_predeployed_contract() -> PredeployedContract {
+pub fn eth_predeployed_contract(rng: &mut impl Rng) -> PredeployedContract {
     // starkgate-contracts v3.0.0
     // Link to Cairo contract: https://github.com/starknet-io/starkgate-contracts/blob/07e11c39119a10d5742735be5b1d51894ebf5311/packages/sg_token/src/erc20_mintable.cairo
     let raw_casm = include_str!("../../data/predeployed_contracts/ERC20Mintable/casm.json");
@@ -36,5 +37,11 @@ pub fn eth_predeployed_contract() -> PredeployedContract {
         upgrade_delay: 0,
     };
 
-    PredeployedContract::erc20(contract_address, class_hash, raw_casm, constructor_data)
+    PredeployedContract::erc20(
+        contract_address,
+        class_hash,
+        raw_casm,
+        constructor_data,
+        rng,
+    )
 }
@@ -6,6 +6,7 @@ use crate::{
     },
 };
 use conversions::felt::FromShortString;
+use rand::Rng;
 use starknet_api::{
     core::{ClassHash, ContractAddress},
     state::StorageKey,
@@ -21,6 +22,7 @@ impl PredeployedContract {
         class_hash: ClassHash,
         raw_casm: &str,
         constructor_data: ERC20ConstructorData,
+        rng: &mut impl Rng,
     ) -> Self {
         let ERC20ConstructorData {
             name,
@@ -31,7 +33,7 @@ impl PredeployedContract {
             upgrade_delay,
         } = constructor_data;
 
-        let recipient = generate_random_felt();
+        let recipient = generate_random_felt(rng);
         let recipient_balance_low_address = map_entry_address("ERC20_balances", &[recipient]);
         let recipient_balance_high_address =
             StorageKey(recipient_balance_low_address.try_into().unwrap())
@@ -1,4 +1,5 @@
 use conversions::string::TryFromHexStr;
+use rand::Rng;
 use starknet_api::core::ContractAddress;
 
 use crate::predeployment::predeployed_contract::PredeployedContract;
@@ -11,7 +12,7 @@ pub const ERC20LOCKABLE_SIERRA_CLASS_HASH: &str =
     "0x02e77ee61d4df3d988ee1f42ea5442e913862cc82c2584d212ecda76666498fc";
 
 #[must_use]
-pub fn strk_predeployed_contract() -> PredeployedContract {
+pub fn strk_predeployed_contract(rng: &mut impl Rng) -> PredeployedContract {
     // starkgate-contracts v3.0.0
     // Link to Cairo contract: https://github.com/starknet-io/starkgate-contracts/blob/07e11c39119a10d5742735be5b1d51894ebf5311/packages/strk/src/erc20_lockable.cairo
     let raw_casm = include_str!("../../data/predeployed_contracts/ERC20Lockable/casm.json");
@@ -36,5 +37,11 @@ pub fn strk_predeployed_contract() -> PredeployedContract {
         upgrade_delay: 0,
     };
 
-    PredeployedContract::erc20(contract_address, class_hash, raw_casm, constructor_data)
+    PredeployedContract::erc20(
+        contract_address,
+        class_hash,
+        raw_casm,
+        constructor_data,
+        rng,
+    )
 }
@@ -1,3 +1,5 @@
 pub mod abi;
+pub mod config;
+pub mod configured;
 pub mod erc20;
 pub mod predeployed_contract;
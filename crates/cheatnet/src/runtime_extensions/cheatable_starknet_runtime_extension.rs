@@ -107,6 +107,14 @@ impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
                     SyscallSelector::MetaTxV0,
                 )
                 .map(|()| SyscallHandlingResult::Handled)?),
+            SyscallSelector::ReplaceClass => Ok(self
+                .execute_syscall(
+                    syscall_handler,
+                    vm,
+                    cheated_syscalls::replace_class_syscall,
+                    SyscallSelector::ReplaceClass,
+                )
+                .map(|()| SyscallHandlingResult::Handled)?),
             SyscallSelector::DelegateCall
             | SyscallSelector::DelegateL1Handler
             | SyscallSelector::EmitEvent
@@ -123,7 +131,6 @@ impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
             | SyscallSelector::Sha256ProcessBlock
             | SyscallSelector::Sha512ProcessBlock
             | SyscallSelector::LibraryCallL1Handler
-            | SyscallSelector::ReplaceClass
             | SyscallSelector::Secp256k1Add
             | SyscallSelector::Secp256k1GetPointFromX
             | SyscallSelector::Secp256k1GetXy
@@ -35,6 +35,12 @@ impl<'a> ExtensionLogic for ForgeConfigExtension<'a> {
             "set_config_disable_contracts" => config_cheatcode!(disable_predeployed_contracts),
             "set_config_should_panic" => config_cheatcode!(should_panic),
             "set_config_fuzzer" => config_cheatcode!(fuzzer),
+            "set_config_seed" => config_cheatcode!(seed),
+            "set_config_setup" => config_cheatcode!(setup),
+            "set_config_timeout" => config_cheatcode!(timeout),
+            "set_config_max_steps" => config_cheatcode!(max_steps),
+            "set_config_retry" => config_cheatcode!(retry),
+            "set_config_tags" => config_cheatcode!(tags),
             "is_config_mode" => Ok(CheatcodeHandlingResult::from_serializable(true)),
             _ => Ok(CheatcodeHandlingResult::Forwarded),
         }
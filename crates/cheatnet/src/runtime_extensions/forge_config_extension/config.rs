@@ -146,11 +146,16 @@ pub enum Expected {
     ByteArray(ByteArray),
     Array(Vec<ExpectedTupleItem>),
     Any,
+    Serialized(Vec<Felt>),
+    Variant(Vec<Felt>),
+    Regex(ByteArray),
 }
 
 #[derive(Debug, Clone, CairoDeserialize)]
 pub struct RawShouldPanicConfig {
-    pub expected: Expected,
+    pub expected: Vec<Expected>,
+    pub contract: Option<ByteArray>,
+    pub function: Option<ByteArray>,
 }
 
 // ignore
@@ -167,6 +172,48 @@ pub struct RawPredeployedContractsConfig {
     pub is_disabled: bool,
 }
 
+// seed
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawSeedConfig {
+    pub seed: u64,
+}
+
+// setup
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawSetupConfig {
+    pub fixture: ByteArray,
+}
+
+// timeout
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawTimeoutConfig {
+    pub seconds: u64,
+}
+
+// max steps
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawMaxStepsConfig {
+    pub max_steps: u32,
+}
+
+// retry
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawRetryConfig {
+    pub retries: u32,
+}
+
+// tags
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawTagsConfig {
+    pub tags: Vec<ByteArray>,
+}
+
 // config
 
 #[derive(Debug, Default, Clone)]
@@ -177,4 +224,10 @@ pub struct RawForgeConfig {
     pub should_panic: Option<RawShouldPanicConfig>,
     pub fuzzer: Option<RawFuzzerConfig>,
     pub disable_predeployed_contracts: Option<RawPredeployedContractsConfig>,
+    pub seed: Option<RawSeedConfig>,
+    pub setup: Option<RawSetupConfig>,
+    pub timeout: Option<RawTimeoutConfig>,
+    pub max_steps: Option<RawMaxStepsConfig>,
+    pub retry: Option<RawRetryConfig>,
+    pub tags: Option<RawTagsConfig>,
 }
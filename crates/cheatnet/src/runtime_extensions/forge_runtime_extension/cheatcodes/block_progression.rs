@@ -0,0 +1,156 @@
+use crate::CheatnetState;
+use anyhow::{Context, Result, ensure};
+use blockifier::abi::constants::STORED_BLOCK_HASH_BUFFER;
+use conversions::serde::serialize::CairoSerialize;
+use starknet_types_core::felt::Felt;
+use starknet_types_core::hash::{Poseidon, StarkHash};
+use std::ops::Range;
+
+/// Default number of seconds between consecutive blocks produced with `roll` and `warp`
+pub const DEFAULT_BLOCK_TIME: u64 = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockProgression {
+    /// Number of seconds between consecutive blocks
+    pub block_time: u64,
+    /// Number of seconds passed since the start of the current block
+    pub time_in_block: u64,
+    /// Blocks left behind by advancing, their hashes are returned by `get_block_hash`
+    pub produced_blocks: Range<u64>,
+}
+
+impl Default for BlockProgression {
+    fn default() -> Self {
+        Self {
+            block_time: DEFAULT_BLOCK_TIME,
+            time_in_block: 0,
+            produced_blocks: 0..0,
+        }
+    }
+}
+
+#[derive(CairoSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub block_number: u64,
+    pub block_timestamp: u64,
+}
+
+impl CheatnetState {
+    pub fn set_block_time(&mut self, block_time: u64) -> Result<()> {
+        ensure!(block_time > 0, "Block time has to be greater than 0");
+        self.block_progression.block_time = block_time;
+        self.block_progression.time_in_block =
+            self.block_progression.time_in_block.min(block_time - 1);
+        Ok(())
+    }
+
+    /// Advances the block number by `blocks`, moving the block timestamp to the start
+    /// of the new block.
+    pub fn roll(&mut self, blocks: u64) -> Result<Block> {
+        let current = self.current_block();
+        if blocks == 0 {
+            return Ok(current);
+        }
+
+        let BlockProgression {
+            block_time,
+            time_in_block,
+            ..
+        } = self.block_progression;
+        let new = Block {
+            block_number: current
+                .block_number
+                .checked_add(blocks)
+                .context("Block number overflowed")?,
+            block_timestamp: blocks
+                .checked_mul(block_time)
+                .and_then(|time| {
+                    current
+                        .block_timestamp
+                        .saturating_sub(time_in_block)
+                        .checked_add(time)
+                })
+                .context("Block timestamp overflowed")?,
+        };
+        self.block_progression.time_in_block = 0;
+
+        Ok(self.advance_to(current, new))
+    }
+
+    /// Advances the block timestamp by `seconds` and the block number by the number of
+    /// blocks that started in that time.
+    pub fn warp(&mut self, seconds: u64) -> Result<Block> {
+        let current = self.current_block();
+
+        let block_time = u128::from(self.block_progression.block_time);
+        let elapsed = u128::from(self.block_progression.time_in_block) + u128::from(seconds);
+        let new = Block {
+            block_number: u64::try_from(elapsed / block_time)
+                .ok()
+                .and_then(|blocks| current.block_number.checked_add(blocks))
+                .context("Block number overflowed")?,
+            block_timestamp: current
+                .block_timestamp
+                .checked_add(seconds)
+                .context("Block timestamp overflowed")?,
+        };
+        self.block_progression.time_in_block = u64::try_from(elapsed % block_time)
+            .expect("Remainder should be smaller than the block time");
+
+        Ok(self.advance_to(current, new))
+    }
+
+    /// Returns the hash of a block produced with `roll` or `warp`.
+    /// Like on Starknet, the hash is available only after `STORED_BLOCK_HASH_BUFFER` more blocks.
+    #[must_use]
+    pub fn get_produced_block_hash(&self, block_number: u64) -> Option<Felt> {
+        let produced_blocks = &self.block_progression.produced_blocks;
+        (produced_blocks.contains(&block_number) && !self.is_recent_produced_block(block_number))
+            .then(|| {
+                Poseidon::hash(
+                    &Felt::from_bytes_be_slice(b"block_hash"),
+                    &block_number.into(),
+                )
+            })
+    }
+
+    /// Whether the block was produced with `roll` or `warp` less than
+    /// `STORED_BLOCK_HASH_BUFFER` blocks ago, so its hash is not stored yet.
+    #[must_use]
+    pub fn is_recent_produced_block(&self, block_number: u64) -> bool {
+        self.block_progression
+            .produced_blocks
+            .contains(&block_number)
+            && block_number
+                .checked_add(STORED_BLOCK_HASH_BUFFER)
+                .is_none_or(|stored_at| stored_at > self.current_block().block_number)
+    }
+
+    pub(crate) fn current_block(&self) -> Block {
+        let block_info = &self.global_cheated_execution_info.block_info;
+        Block {
+            block_number: block_info
+                .block_number
+                .as_value()
+                .unwrap_or(self.block_info.block_number.0),
+            block_timestamp: block_info
+                .block_timestamp
+                .as_value()
+                .unwrap_or(self.block_info.block_timestamp.0),
+        }
+    }
+
+    fn advance_to(&mut self, current: Block, new: Block) -> Block {
+        let produced_blocks = &mut self.block_progression.produced_blocks;
+        // Block number was changed with other cheats since the last advance
+        if produced_blocks.end != current.block_number {
+            *produced_blocks = current.block_number..current.block_number;
+        }
+        produced_blocks.end = new.block_number;
+
+        self.start_cheat_block_number_global(new.block_number);
+        self.start_cheat_block_timestamp_global(new.block_timestamp);
+
+        new
+    }
+}
@@ -3,7 +3,9 @@ use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execut
     CheatArguments, Operation,
 };
 use crate::state::CheatSpan;
-use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
+use blockifier::execution::syscalls::hint_processor::{
+    BLOCK_NUMBER_OUT_OF_RANGE_ERROR, SyscallExecutionError, SyscallHintProcessor,
+};
 use blockifier::execution::syscalls::syscall_base::SyscallResult;
 use starknet_api::block::{BlockHash, BlockNumber};
 use starknet_api::core::ContractAddress;
@@ -117,7 +119,7 @@ impl CheatnetState {
             return Some(BlockHash(StarkHash::from(*block_hash)));
         }
 
-        None
+        self.get_produced_block_hash(block_number).map(BlockHash)
     }
 
     pub fn get_block_hash_for_contract(
@@ -130,6 +132,8 @@ impl CheatnetState {
             self.get_cheated_block_hash_for_contract(contract_address, block_number);
         if let Some(cheated_block_hash) = cheated_block_hash {
             Ok(cheated_block_hash)
+        } else if self.is_recent_produced_block(block_number) {
+            Err(block_number_out_of_range())
         } else {
             Ok(BlockHash(
                 syscall_handler
@@ -139,3 +143,12 @@ impl CheatnetState {
         }
     }
 }
+
+/// Error of `get_block_hash_syscall` for blocks whose hashes are not stored yet.
+pub(crate) fn block_number_out_of_range() -> SyscallExecutionError {
+    SyscallExecutionError::Revert {
+        error_data: vec![
+            Felt::from_hex(BLOCK_NUMBER_OUT_OF_RANGE_ERROR).expect("Error should be a felt"),
+        ],
+    }
+}
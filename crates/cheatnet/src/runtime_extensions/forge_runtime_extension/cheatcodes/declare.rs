@@ -1,22 +1,33 @@
 use crate::constants::get_current_sierra_version;
+use crate::forking::state::ForkStateReader;
 use crate::runtime_extensions::forge_runtime_extension::{
     cheatcodes::{CheatcodeError, EnhancedHintError},
-    contracts_data::{ContractResolutionError, ContractsData},
+    contracts_data::{ContractData, ContractResolutionError, ContractsData},
 };
 use anyhow::{Result, anyhow};
-use blockifier::execution::contract_class::{CompiledClassV1, RunnableCompiledClass};
+use blockifier::execution::contract_class::{
+    CompiledClassV0, CompiledClassV1, RunnableCompiledClass,
+};
 #[cfg(feature = "cairo-native")]
 use blockifier::execution::native::contract_class::NativeCompiledClassV1;
-use blockifier::state::{errors::StateError, state_api::State};
+use blockifier::state::{
+    errors::StateError,
+    state_api::{State, StateReader},
+};
 #[cfg(feature = "cairo-native")]
 use cairo_lang_starknet_classes::contract_class::ContractClass;
 use conversions::IntoConv;
-use conversions::serde::serialize::CairoSerialize;
+use conversions::byte_array::ByteArray;
+use conversions::serde::deserialize::{BufferReader, CairoDeserialize};
+use conversions::serde::serialize::{CairoSerialize, SerializeToFeltVec};
 use indoc::formatdoc;
 use scarb_api::StarknetContractArtifacts;
 use starknet_api::core::{ClassHash, CompiledClassHash};
 use starknet_rust::core::types::contract::SierraClass;
+use starknet_rust::core::types::contract::legacy::LegacyContractClass;
 use std::path::Path;
+use std::{fs, io};
+use thiserror::Error;
 use universal_sierra_compiler_api::compile_contract_sierra;
 
 #[derive(CairoSerialize)]
@@ -25,46 +36,82 @@ pub enum DeclareResult {
     AlreadyDeclared(ClassHash),
 }
 
+/// Typed reason of a failed declaration, returned to the test as `DeclareError`.
+#[derive(CairoSerialize, CairoDeserialize, Error, Debug, Clone, PartialEq)]
+pub enum DeclareError {
+    #[error("Failed to get contract artifact for identifier = {0}.")]
+    ContractNotFound(ByteArray),
+    #[error("{}", ambiguous_contract_name_message(identifier, candidates))]
+    AmbiguousContractName {
+        identifier: ByteArray,
+        candidates: Vec<ByteArray>,
+    },
+    #[error("{0}")]
+    InvalidClass(ByteArray),
+    #[error("{0}")]
+    CompilationFailed(ByteArray),
+}
+
+impl DeclareError {
+    fn invalid_class(message: &str) -> Self {
+        DeclareError::InvalidClass(ByteArray::from(message))
+    }
+
+    fn compilation_failed(message: &str) -> Self {
+        DeclareError::CompilationFailed(ByteArray::from(message))
+    }
+}
+
+impl From<DeclareError> for CheatcodeError {
+    fn from(error: DeclareError) -> Self {
+        CheatcodeError::Recoverable(error.serialize_to_vec())
+    }
+}
+
+fn ambiguous_contract_name_message(identifier: &ByteArray, candidates: &[ByteArray]) -> String {
+    let paths = candidates
+        .iter()
+        .map(|path| format!(" - {path}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    formatdoc! { r"
+        Multiple contracts found with identifier = {identifier}. Found contracts at the following paths:
+        {paths}
+        Use a module path to disambiguate, or rename one of the contracts so that the identifier is unique."
+    }
+}
+
 pub fn declare(
     state: &mut dyn State,
     contract_identifier: &str,
     contracts_data: &ContractsData,
 ) -> Result<DeclareResult, CheatcodeError> {
-    let contract = match contracts_data.resolve_contract(contract_identifier) {
-        Ok(contract) => contract,
-        Err(ContractResolutionError::NameNotFound) => {
-            return Err(CheatcodeError::Unrecoverable(EnhancedHintError::from(
-                anyhow!("Failed to get contract artifact for identifier = {contract_identifier}."),
-            )));
-        }
-        Err(ContractResolutionError::AmbiguousName(module_paths)) => {
-            let paths = module_paths
-                .iter()
-                .map(|path| format!(" - {path}"))
-                .collect::<Vec<_>>()
-                .join("\n");
-            return Err(CheatcodeError::Unrecoverable(EnhancedHintError::from(
-                anyhow!(formatdoc! { r"
-                    Multiple contracts found with identifier = {contract_identifier}. Found contracts at the following paths:
-                    {paths}
-                    Use a module path to disambiguate, or rename one of the contracts so that the identifier is unique."
-                }),
-            )));
-        }
-    };
+    let contract = resolve_contract(contract_identifier, contracts_data)?;
 
-    declare_contract_class(
+    Ok(declare_contract_class(
         state,
         contract.class_hash,
         get_contract_class(&contract.artifacts),
-    )
+    )?)
 }
 
-pub fn declare_from_file(
+/// Same as [`declare`], but the test fails with the message of the `DeclareError`
+/// if the contract cannot be declared.
+pub fn declare_or_fail(
+    state: &mut dyn State,
+    contract_identifier: &str,
+    contracts_data: &ContractsData,
+) -> Result<DeclareResult, EnhancedHintError> {
+    fail_on_declare_error(declare(state, contract_identifier, contracts_data))
+}
+
+/// Same as [`declare_from_file`], but the test fails with a description of the failure
+/// if the contract cannot be declared.
+pub fn declare_from_file_or_fail(
     state: &mut dyn State,
     sierra_path: &Path,
     contracts_data: &ContractsData,
-) -> Result<DeclareResult, CheatcodeError> {
+) -> Result<DeclareResult, EnhancedHintError> {
     if let Some(contract) = contracts_data.find_by_sierra_path(sierra_path) {
         return declare_contract_class(
             state,
@@ -73,59 +120,147 @@ pub fn declare_from_file(
         );
     }
 
-    let sierra = std::fs::read_to_string(sierra_path).map_err(|error| {
-        CheatcodeError::Unrecoverable(EnhancedHintError::from(anyhow!(
+    let sierra = fs::read_to_string(sierra_path).map_err(|error| {
+        EnhancedHintError::from(anyhow!(
             "Failed to read Sierra file at {}: {error}",
             sierra_path.display()
-        )))
+        ))
     })?;
-    let sierra_class: SierraClass = serde_json::from_str(&sierra).map_err(|error| {
-        CheatcodeError::Unrecoverable(EnhancedHintError::from(anyhow!(
-            "Failed to parse Sierra contract class JSON at {}: {error}",
+
+    fail_on_declare_error(declare_sierra_from(
+        state,
+        &sierra,
+        Some(sierra_path),
+        contracts_data,
+    ))
+}
+
+fn fail_on_declare_error(
+    declare_result: Result<DeclareResult, CheatcodeError>,
+) -> Result<DeclareResult, EnhancedHintError> {
+    declare_result.map_err(|error| match error {
+        CheatcodeError::Recoverable(error_data) => {
+            let declare_error = BufferReader::new(&error_data)
+                .read::<DeclareError>()
+                .expect("Recoverable declare failure should be a serialized `DeclareError`");
+            EnhancedHintError::from(anyhow!(declare_error.to_string()))
+        }
+        CheatcodeError::Unrecoverable(error) => error,
+    })
+}
+
+pub(crate) fn resolve_contract<'a>(
+    contract_identifier: &str,
+    contracts_data: &'a ContractsData,
+) -> Result<&'a ContractData, DeclareError> {
+    contracts_data
+        .resolve_contract(contract_identifier)
+        .map_err(|error| match error {
+            ContractResolutionError::NameNotFound => {
+                DeclareError::ContractNotFound(ByteArray::from(contract_identifier))
+            }
+            ContractResolutionError::AmbiguousName(module_paths) => {
+                DeclareError::AmbiguousContractName {
+                    identifier: ByteArray::from(contract_identifier),
+                    candidates: module_paths
+                        .iter()
+                        .map(|path| ByteArray::from(path.as_str()))
+                        .collect(),
+                }
+            }
+        })
+}
+
+pub fn declare_from_file(
+    state: &mut dyn State,
+    sierra_path: &Path,
+    contracts_data: &ContractsData,
+) -> Result<DeclareResult, CheatcodeError> {
+    if let Some(contract) = contracts_data.find_by_sierra_path(sierra_path) {
+        return Ok(declare_contract_class(
+            state,
+            contract.class_hash,
+            get_contract_class(&contract.artifacts),
+        )?);
+    }
+
+    let sierra = fs::read_to_string(sierra_path).map_err(|error| match error.kind() {
+        io::ErrorKind::NotFound => CheatcodeError::from(DeclareError::ContractNotFound(
+            ByteArray::from(sierra_path.display().to_string().as_str()),
+        )),
+        _ => CheatcodeError::Unrecoverable(EnhancedHintError::from(anyhow!(
+            "Failed to read Sierra file at {}: {error}",
             sierra_path.display()
-        )))
+        ))),
+    })?;
+
+    declare_sierra_from(state, &sierra, Some(sierra_path), contracts_data)
+}
+
+/// Declares a contract from the Sierra contract class JSON.
+/// The loaded compiled artifact is reused if the class hash matches a package contract.
+pub fn declare_sierra(
+    state: &mut dyn State,
+    sierra: &str,
+    contracts_data: &ContractsData,
+) -> Result<DeclareResult, CheatcodeError> {
+    declare_sierra_from(state, sierra, None, contracts_data)
+}
+
+/// Declares a contract from the Sierra contract class JSON,
+/// read from the `sierra_path` file if it is given, which is then mentioned in the errors.
+fn declare_sierra_from(
+    state: &mut dyn State,
+    sierra: &str,
+    sierra_path: Option<&Path>,
+    contracts_data: &ContractsData,
+) -> Result<DeclareResult, CheatcodeError> {
+    let (json_origin, class_origin) = match sierra_path {
+        Some(path) => (
+            format!("Sierra contract class JSON at {}", path.display()),
+            format!("Sierra file at {}", path.display()),
+        ),
+        None => (
+            "Sierra contract class JSON".to_string(),
+            "Sierra contract class".to_string(),
+        ),
+    };
+
+    let sierra_class: SierraClass = serde_json::from_str(sierra).map_err(|error| {
+        DeclareError::invalid_class(&format!("Failed to parse {json_origin}: {error}"))
     })?;
     let class_hash = get_class_hash(&sierra_class).map_err(|error| {
-        CheatcodeError::Unrecoverable(EnhancedHintError::from(anyhow!(
-            "Failed to calculate class hash for Sierra file at {}: {error}",
-            sierra_path.display()
-        )))
+        DeclareError::invalid_class(&format!(
+            "Failed to calculate class hash for {class_origin}: {error}"
+        ))
     })?;
 
     if let Some(contract) = contracts_data.get_contract_by_class_hash(&class_hash) {
-        return declare_contract_class(
+        return Ok(declare_contract_class(
             state,
             contract.class_hash,
             get_contract_class(&contract.artifacts),
-        );
+        )?);
     }
 
-    let sierra_json: serde_json::Value = serde_json::from_str(&sierra).map_err(|error| {
-        CheatcodeError::Unrecoverable(EnhancedHintError::from(anyhow!(
-            "Failed to parse Sierra contract class JSON at {}: {error}",
-            sierra_path.display()
-        )))
+    let sierra_json: serde_json::Value = serde_json::from_str(sierra).map_err(|error| {
+        DeclareError::invalid_class(&format!("Failed to parse {json_origin}: {error}"))
     })?;
     let casm = compile_contract_sierra(&sierra_json).map_err(|error| {
-        CheatcodeError::Unrecoverable(EnhancedHintError::from(anyhow!(
-            "Failed to compile Sierra file at {}: {error}",
-            sierra_path.display()
-        )))
+        DeclareError::compilation_failed(&format!("Failed to compile {class_origin}: {error}"))
     })?;
     let contract_class =
         CompiledClassV1::try_from((casm, get_current_sierra_version())).map_err(|error| {
-            CheatcodeError::Unrecoverable(EnhancedHintError::from(anyhow!(
-                "Failed to build runnable contract class from Sierra file at {}: {error}",
-                sierra_path.display()
-            )))
+            DeclareError::compilation_failed(&format!(
+                "Failed to build runnable contract class from {class_origin}: {error}"
+            ))
         })?;
     #[cfg(feature = "cairo-native")]
     let contract_class = if contracts_data.run_native {
-        let sierra_class: ContractClass = serde_json::from_str(&sierra).map_err(|error| {
-            CheatcodeError::Unrecoverable(EnhancedHintError::from(anyhow!(
-                "Failed to parse Sierra contract class JSON for native execution at {}: {error}",
-                sierra_path.display()
-            )))
+        let sierra_class: ContractClass = serde_json::from_str(sierra).map_err(|error| {
+            DeclareError::invalid_class(&format!(
+                "Failed to parse {json_origin} for native execution: {error}"
+            ))
         })?;
 
         RunnableCompiledClass::V1Native(NativeCompiledClassV1::new(
@@ -138,21 +273,47 @@ pub fn declare_from_file(
     #[cfg(not(feature = "cairo-native"))]
     let contract_class = RunnableCompiledClass::V1(contract_class);
 
-    declare_contract_class(state, class_hash, contract_class)
+    Ok(declare_contract_class(state, class_hash, contract_class)?)
+}
+
+/// Declares a Cairo 0 contract from the compiled (legacy) contract class JSON.
+pub fn declare_cairo0(
+    state: &mut dyn State,
+    compiled_class: &str,
+) -> Result<DeclareResult, CheatcodeError> {
+    let legacy_class: LegacyContractClass =
+        serde_json::from_str(compiled_class).map_err(|error| {
+            DeclareError::invalid_class(&format!(
+                "Failed to parse Cairo 0 contract class JSON: {error}"
+            ))
+        })?;
+    let class_hash = legacy_class.class_hash().map_err(|error| {
+        DeclareError::invalid_class(&format!(
+            "Failed to calculate class hash of Cairo 0 contract class: {error}"
+        ))
+    })?;
+    let contract_class =
+        CompiledClassV0::try_from_json_string(compiled_class).map_err(|error| {
+            DeclareError::invalid_class(&format!("Failed to load Cairo 0 program: {error}"))
+        })?;
+
+    Ok(declare_contract_class(
+        state,
+        class_hash.into_(),
+        RunnableCompiledClass::V0(contract_class),
+    )?)
 }
 
 fn declare_contract_class(
     state: &mut dyn State,
     class_hash: ClassHash,
     contract_class: RunnableCompiledClass,
-) -> Result<DeclareResult, CheatcodeError> {
+) -> Result<DeclareResult, EnhancedHintError> {
     match state.get_compiled_class(class_hash) {
         Err(StateError::UndeclaredClassHash(_)) => {
             // Class is undeclared; declare it.
 
-            state
-                .set_contract_class(class_hash, contract_class)
-                .map_err(EnhancedHintError::from)?;
+            state.set_contract_class(class_hash, contract_class)?;
 
             // NOTE: Compiled class hash is being set to 0 here
             // because it is currently only used in verification
@@ -162,9 +323,7 @@ fn declare_contract_class(
                 .unwrap_or_else(|err| panic!("Failed to set compiled class hash: {err:?}"));
             Ok(DeclareResult::Success(class_hash))
         }
-        Err(error) => Err(CheatcodeError::Unrecoverable(EnhancedHintError::State(
-            error,
-        ))),
+        Err(error) => Err(EnhancedHintError::State(error)),
         Ok(_) => {
             // Class is already declared, cannot redeclare
             // (i.e., make sure the leaf is uninitialized).
@@ -173,6 +332,36 @@ fn declare_contract_class(
     }
 }
 
+/// Checks whether the class declared on the fork under the class hash of a local contract
+/// is not the local class.
+///
+/// The class hash is derived from the Sierra class, so a class with the same hash has the same code,
+/// even if the fork class is compiled to a different CASM, e.g. by another compiler version.
+/// Only a fork class that can't be fetched or compiled, or whose Sierra class has a different hash
+/// is a collision. The class is read through the fork state, so it is fetched only once.
+#[must_use]
+pub fn is_class_hash_collision(
+    fork_state_reader: &ForkStateReader,
+    class_hash: ClassHash,
+    contracts_data: &ContractsData,
+) -> bool {
+    if contracts_data
+        .get_contract_by_class_hash(&class_hash)
+        .is_none()
+    {
+        return false;
+    }
+
+    match fork_state_reader.get_compiled_class(class_hash) {
+        // Declared only locally
+        Err(StateError::UndeclaredClassHash(_)) => false,
+        Err(_) => true,
+        Ok(_) => !fork_state_reader
+            .get_sierra_class_hash(class_hash)
+            .is_ok_and(|fork_class_hash| fork_class_hash == class_hash),
+    }
+}
+
 pub fn get_class_hash(sierra_class: &SierraClass) -> Result<ClassHash> {
     Ok(sierra_class.class_hash()?.into_())
 }
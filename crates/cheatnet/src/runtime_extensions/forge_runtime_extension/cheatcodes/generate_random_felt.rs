@@ -1,10 +1,9 @@
 use num_bigint::{BigUint, RandBigInt};
+use rand::Rng;
 use starknet_types_core::felt::Felt;
 
 #[must_use]
-pub fn generate_random_felt() -> Felt {
-    let mut rng = rand::thread_rng();
-
+pub fn generate_random_felt(rng: &mut impl Rng) -> Felt {
     let random_number: BigUint = rng.gen_biguint(251);
     Felt::from(random_number)
 }
@@ -0,0 +1,251 @@
+use crate::CheatnetState;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use anyhow::{Context, Result, bail, ensure};
+use conversions::serde::serialize::CairoSerialize;
+use data_transformer::cairo_types::CairoU256;
+use num_bigint::BigUint;
+use sha3::{Digest, Keccak256};
+use starknet_api::core::{ContractAddress, EntryPointSelector, EthAddress};
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+
+/// Default number of seconds after which a started cancellation of a message to L2 can be
+/// completed, the same as in the Starknet core contract on mainnet
+pub const DEFAULT_MESSAGE_CANCELLATION_DELAY: u64 = 5 * 24 * 60 * 60;
+
+/// In-memory counterpart of the Starknet core contract messaging on L1
+#[derive(Debug, Clone)]
+pub struct L1Messaging {
+    /// Number of seconds between starting and completing a cancellation of a message to L2
+    pub cancellation_delay: u64,
+    /// Nonce of the next message sent to L2
+    pub next_nonce: u64,
+    /// Messages sent to L2, by their hashes
+    pub messages_to_l2: HashMap<[u8; 32], SentMessageToL2>,
+    /// Number of consumed messages to L1, by their hashes
+    pub consumed_messages_to_l1: HashMap<[u8; 32], usize>,
+}
+
+impl Default for L1Messaging {
+    fn default() -> Self {
+        Self {
+            cancellation_delay: DEFAULT_MESSAGE_CANCELLATION_DELAY,
+            next_nonce: 0,
+            messages_to_l2: HashMap::default(),
+            consumed_messages_to_l1: HashMap::default(),
+        }
+    }
+}
+
+#[derive(CairoSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct MessageToL2 {
+    pub from_address: EthAddress,
+    pub to_address: ContractAddress,
+    pub selector: EntryPointSelector,
+    pub payload: Vec<Felt>,
+    pub nonce: Felt,
+    pub fee: u128,
+    pub hash: CairoU256,
+}
+
+#[derive(CairoSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageToL2Status {
+    Pending,
+    Consumed,
+    /// Carries the timestamp the cancellation was started at
+    CancellationStarted(u64),
+    Cancelled,
+}
+
+#[derive(Debug, Clone)]
+pub struct SentMessageToL2 {
+    pub message: MessageToL2,
+    pub status: MessageToL2Status,
+}
+
+impl CheatnetState {
+    pub fn set_message_cancellation_delay(&mut self, cancellation_delay: u64) {
+        self.l1_messaging.cancellation_delay = cancellation_delay;
+    }
+
+    /// Sends a message from L1 to L2 the way the Starknet core contract does,
+    /// the message is pending until it is executed with `execute_message_to_l2`.
+    pub fn send_message_to_l2(
+        &mut self,
+        from_address: EthAddress,
+        to_address: ContractAddress,
+        selector: EntryPointSelector,
+        payload: Vec<Felt>,
+        fee: u128,
+    ) -> Result<MessageToL2> {
+        ensure!(fee > 0, "Message fee has to be greater than 0");
+
+        let nonce = Felt::from(self.l1_messaging.next_nonce);
+        let hash = keccak_hash(
+            [
+                Felt::from(from_address),
+                *to_address.0.key(),
+                nonce,
+                selector.0,
+                Felt::from(payload.len()),
+            ]
+            .iter()
+            .chain(&payload),
+        );
+        let message = MessageToL2 {
+            from_address,
+            to_address,
+            selector,
+            payload,
+            nonce,
+            fee,
+            hash: CairoU256::from_bytes(&hash),
+        };
+
+        self.l1_messaging.next_nonce += 1;
+        self.l1_messaging.messages_to_l2.insert(
+            hash,
+            SentMessageToL2 {
+                message: message.clone(),
+                status: MessageToL2Status::Pending,
+            },
+        );
+
+        Ok(message)
+    }
+
+    pub fn get_message_to_l2_status(&self, hash: CairoU256) -> Result<MessageToL2Status> {
+        Ok(self.sent_message_to_l2(hash)?.status)
+    }
+
+    /// Returns the message to L2 if it can still be executed.
+    pub fn get_executable_message_to_l2(&self, hash: CairoU256) -> Result<MessageToL2> {
+        let sent_message = self.sent_message_to_l2(hash)?;
+        match sent_message.status {
+            MessageToL2Status::Pending | MessageToL2Status::CancellationStarted(_) => {
+                Ok(sent_message.message.clone())
+            }
+            MessageToL2Status::Consumed => {
+                bail!("Message to L2 with hash {} was already consumed", hex(hash))
+            }
+            MessageToL2Status::Cancelled => {
+                bail!("Message to L2 with hash {} was cancelled", hex(hash))
+            }
+        }
+    }
+
+    pub fn consume_message_to_l2(&mut self, hash: CairoU256) -> Result<()> {
+        self.sent_message_to_l2_mut(hash)?.status = MessageToL2Status::Consumed;
+        Ok(())
+    }
+
+    pub fn start_message_to_l2_cancellation(&mut self, hash: CairoU256) -> Result<()> {
+        let block_timestamp = self.current_block().block_timestamp;
+        let sent_message = self.sent_message_to_l2_mut(hash)?;
+        ensure!(
+            sent_message.status == MessageToL2Status::Pending,
+            "Cancellation can only be started for a pending message to L2, message with hash {} is {:?}",
+            hex(hash),
+            sent_message.status
+        );
+
+        sent_message.status = MessageToL2Status::CancellationStarted(block_timestamp);
+        Ok(())
+    }
+
+    pub fn cancel_message_to_l2(&mut self, hash: CairoU256) -> Result<()> {
+        let block_timestamp = self.current_block().block_timestamp;
+        let cancellation_delay = self.l1_messaging.cancellation_delay;
+        let sent_message = self.sent_message_to_l2_mut(hash)?;
+        let MessageToL2Status::CancellationStarted(started_at) = sent_message.status else {
+            bail!(
+                "Cancellation of message to L2 with hash {} was not started",
+                hex(hash)
+            );
+        };
+
+        let cancellable_at = started_at.saturating_add(cancellation_delay);
+        ensure!(
+            block_timestamp >= cancellable_at,
+            "Message to L2 with hash {} cannot be cancelled before timestamp {cancellable_at}",
+            hex(hash)
+        );
+
+        sent_message.status = MessageToL2Status::Cancelled;
+        Ok(())
+    }
+
+    /// Consumes a message sent from L2 to L1 the way the Starknet core contract does.
+    /// Returns the message hash, or `None` if there is no such message left to consume.
+    pub fn consume_message_from_l2(
+        &mut self,
+        from_address: ContractAddress,
+        to_address: EthAddress,
+        payload: &[Felt],
+    ) -> Option<CairoU256> {
+        let hash = message_to_l1_hash(from_address, to_address, payload);
+        let sent = self
+            .detected_messages_to_l1
+            .iter()
+            .filter(|message| {
+                message_to_l1_hash(message.from_address, message.to_address, &message.payload)
+                    == hash
+            })
+            .count();
+
+        let consumed = self
+            .l1_messaging
+            .consumed_messages_to_l1
+            .entry(hash)
+            .or_default();
+        if *consumed >= sent {
+            return None;
+        }
+        *consumed += 1;
+
+        Some(CairoU256::from_bytes(&hash))
+    }
+
+    fn sent_message_to_l2(&self, hash: CairoU256) -> Result<&SentMessageToL2> {
+        self.l1_messaging
+            .messages_to_l2
+            .get(&hash.to_be_bytes())
+            .with_context(|| format!("Message to L2 with hash {} was not sent", hex(hash)))
+    }
+
+    fn sent_message_to_l2_mut(&mut self, hash: CairoU256) -> Result<&mut SentMessageToL2> {
+        self.l1_messaging
+            .messages_to_l2
+            .get_mut(&hash.to_be_bytes())
+            .with_context(|| format!("Message to L2 with hash {} was not sent", hex(hash)))
+    }
+}
+
+fn message_to_l1_hash(
+    from_address: ContractAddress,
+    to_address: EthAddress,
+    payload: &[Felt],
+) -> [u8; 32] {
+    keccak_hash(
+        [
+            *from_address.0.key(),
+            Felt::from(to_address),
+            Felt::from(payload.len()),
+        ]
+        .iter()
+        .chain(payload),
+    )
+}
+
+/// Keccak hash of the values encoded as `uint256` words, the same as `abi.encodePacked` in Solidity
+fn keccak_hash<'a>(values: impl Iterator<Item = &'a Felt>) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    for value in values {
+        hasher.update(value.to_bytes_be());
+    }
+    hasher.finalize().into()
+}
+
+fn hex(hash: CairoU256) -> String {
+    format!("{:#x}", BigUint::from_bytes_be(&hash.to_be_bytes()))
+}
@@ -1,9 +1,63 @@
 use crate::CheatnetState;
 use crate::state::{CheatSpan, CheatStatus};
+use conversions::serde::deserialize::CairoDeserialize;
 use starknet_api::core::{ContractAddress, EntryPointSelector};
 use starknet_types_core::felt::Felt;
+use std::collections::VecDeque;
 use std::collections::hash_map::Entry;
 
+/// Decides which calls are affected by a calldata-aware mock.
+#[derive(CairoDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum CalldataMatcher {
+    Any,
+    Exact(Vec<Felt>),
+    Prefix(Vec<Felt>),
+}
+
+impl CalldataMatcher {
+    #[must_use]
+    pub fn matches(&self, calldata: &[Felt]) -> bool {
+        match self {
+            CalldataMatcher::Any => true,
+            CalldataMatcher::Exact(expected) => calldata == expected.as_slice(),
+            CalldataMatcher::Prefix(prefix) => calldata.starts_with(prefix),
+        }
+    }
+
+    /// More specific matchers take precedence over less specific ones:
+    /// exact calldata, then the longest prefix, then any calldata.
+    fn specificity(&self) -> (u8, usize) {
+        match self {
+            CalldataMatcher::Any => (0, 0),
+            CalldataMatcher::Prefix(prefix) => (1, prefix.len()),
+            CalldataMatcher::Exact(expected) => (2, expected.len()),
+        }
+    }
+}
+
+#[derive(CairoDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum MockedResponse {
+    Return(Vec<Felt>),
+    Revert(Vec<Felt>),
+}
+
+/// Responses are consumed in order, the last one is returned for all the following calls.
+#[derive(Clone, Debug)]
+pub struct CalldataMock {
+    pub matcher: CalldataMatcher,
+    pub responses: VecDeque<MockedResponse>,
+}
+
+impl CalldataMock {
+    fn next_response(&mut self) -> Option<MockedResponse> {
+        if self.responses.len() > 1 {
+            self.responses.pop_front()
+        } else {
+            self.responses.front().cloned()
+        }
+    }
+}
+
 impl CheatnetState {
     pub fn mock_call(
         &mut self,
@@ -34,6 +88,45 @@ impl CheatnetState {
         );
     }
 
+    /// Mocks calls with calldata matched by `matcher`, replacing a previous mock with the same
+    /// matcher. Calldata-aware mocks take precedence over the ones created with `mock_call`.
+    pub fn mock_call_when(
+        &mut self,
+        contract_address: ContractAddress,
+        function_selector: EntryPointSelector,
+        matcher: CalldataMatcher,
+        responses: Vec<MockedResponse>,
+    ) {
+        let mocks = self
+            .mocked_calls
+            .entry(contract_address)
+            .or_default()
+            .entry(function_selector)
+            .or_default();
+
+        mocks.retain(|mock| mock.matcher != matcher);
+        mocks.push(CalldataMock {
+            matcher,
+            responses: responses.into(),
+        });
+    }
+
+    /// Returns the next response of the most specific calldata-aware mock matching the call.
+    pub fn next_mocked_response(
+        &mut self,
+        contract_address: ContractAddress,
+        function_selector: EntryPointSelector,
+        calldata: &[Felt],
+    ) -> Option<MockedResponse> {
+        self.mocked_calls
+            .get_mut(&contract_address)?
+            .get_mut(&function_selector)?
+            .iter_mut()
+            .filter(|mock| mock.matcher.matches(calldata))
+            .max_by_key(|mock| mock.matcher.specificity())?
+            .next_response()
+    }
+
     pub fn stop_mock_call(
         &mut self,
         contract_address: ContractAddress,
@@ -43,5 +136,9 @@ impl CheatnetState {
             let contract_mocked_functions = e.get_mut();
             contract_mocked_functions.remove(&function_selector);
         }
+        if let Entry::Occupied(mut e) = self.mocked_calls.entry(contract_address) {
+            let contract_mocked_calls = e.get_mut();
+            contract_mocked_calls.remove(&function_selector);
+        }
     }
 }
@@ -1,6 +1,7 @@
 use runtime::EnhancedHintError;
 use starknet_types_core::felt::Felt;
 
+pub mod block_progression;
 pub mod cheat_account_contract_address;
 pub mod cheat_block_hash;
 pub mod cheat_block_number;
@@ -12,12 +13,17 @@ pub mod declare;
 pub mod generate_random_felt;
 pub mod get_class_hash;
 pub mod l1_handler_execute;
+pub mod l1_messaging;
 pub mod mock_call;
 pub mod precalculate_address;
 pub mod replace_bytecode;
+pub mod replay_transaction;
 pub mod spy_events;
 pub mod spy_messages_to_l1;
 pub mod storage;
+pub mod storage_accesses;
+pub mod submit_transaction;
+pub mod token_storage;
 
 /// A structure used for returning cheatcode errors in tests
 #[derive(Debug)]
@@ -1,6 +1,29 @@
 use crate::CheatnetState;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::EnhancedHintError;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::declare::{
+    DeclareResult, declare_or_fail,
+};
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use crate::state::{CheatSpan, CheatStatus};
+use blockifier::state::errors::StateError;
+use blockifier::state::state_api::State;
 use conversions::serde::serialize::CairoSerialize;
 use starknet_api::core::{ClassHash, ContractAddress};
+use std::collections::HashMap;
+
+/// Replacement of a contract class done with `replace_class_syscall`
+#[derive(CairoSerialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClassReplacement {
+    pub contract_address: ContractAddress,
+    pub old_class_hash: ClassHash,
+    pub new_class_hash: ClassHash,
+}
+
+/// Class replacements before a call, restored if the call is reverted.
+pub struct ClassReplacementsCheckpoint {
+    detected_class_replacements: usize,
+    replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
+}
 
 impl CheatnetState {
     pub fn replace_class_for_contract(
@@ -11,6 +34,86 @@ impl CheatnetState {
         self.replaced_bytecode_contracts
             .insert(contract_address, class_hash);
     }
+
+    pub fn cheat_class_hash(
+        &mut self,
+        contract_address: ContractAddress,
+        class_hash: ClassHash,
+        span: CheatSpan,
+    ) {
+        self.cheated_class_hashes
+            .insert(contract_address, CheatStatus::Cheated(class_hash, span));
+    }
+
+    pub fn stop_cheat_class_hash(&mut self, contract_address: ContractAddress) {
+        self.cheated_class_hashes.remove(&contract_address);
+    }
+
+    /// Returns the class the contract is executed with instead of its class from the state,
+    /// a cheated class takes precedence over the one set with `replace_bytecode`.
+    #[must_use]
+    pub fn get_replaced_class_hash(&self, contract_address: ContractAddress) -> Option<ClassHash> {
+        self.cheated_class_hashes
+            .get(&contract_address)
+            .and_then(CheatStatus::as_value)
+            .or_else(|| {
+                self.replaced_bytecode_contracts
+                    .get(&contract_address)
+                    .copied()
+            })
+    }
+
+    /// Same as `get_replaced_class_hash`, but counts the call towards the span of the cheated class.
+    pub fn replaced_class_hash_for_call(
+        &mut self,
+        contract_address: ContractAddress,
+    ) -> Option<ClassHash> {
+        let class_hash = self.get_replaced_class_hash(contract_address);
+        if let Some(cheat_status) = self.cheated_class_hashes.get_mut(&contract_address) {
+            cheat_status.decrement_cheat_span();
+        }
+        class_hash
+    }
+
+    /// Registers a class replaced with `replace_class_syscall`.
+    /// The new class takes precedence over the one set with `replace_bytecode`, so upgraded
+    /// contracts execute the code they were upgraded to.
+    pub fn register_class_replacement(&mut self, class_replacement: ClassReplacement) {
+        self.replaced_bytecode_contracts
+            .remove(&class_replacement.contract_address);
+        self.detected_class_replacements.push(class_replacement);
+    }
+
+    #[must_use]
+    pub fn class_replacements_checkpoint(&self) -> ClassReplacementsCheckpoint {
+        ClassReplacementsCheckpoint {
+            detected_class_replacements: self.detected_class_replacements.len(),
+            replaced_bytecode_contracts: self.replaced_bytecode_contracts.clone(),
+        }
+    }
+
+    /// Drops class replacements registered after the checkpoint, as the state changes of a
+    /// reverted call are dropped. Classes set with `replace_bytecode` that these replacements
+    /// took precedence over are used again.
+    pub fn revert_class_replacements(&mut self, checkpoint: ClassReplacementsCheckpoint) {
+        for replacement in self
+            .detected_class_replacements
+            .drain(checkpoint.detected_class_replacements..)
+        {
+            if let Some(class_hash) = checkpoint
+                .replaced_bytecode_contracts
+                .get(&replacement.contract_address)
+            {
+                self.replaced_bytecode_contracts
+                    .insert(replacement.contract_address, *class_hash);
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn get_class_replacements(&self, offset: usize) -> Vec<ClassReplacement> {
+        self.detected_class_replacements[offset..].to_vec()
+    }
 }
 
 #[derive(CairoSerialize)]
@@ -18,3 +121,48 @@ pub enum ReplaceBytecodeError {
     ContractNotDeployed,
     UndeclaredClassHash,
 }
+
+/// Checks that `class_hash` can be used as the class of the contract deployed at
+/// `contract_address`. Returns the current class hash of the contract.
+pub fn validate_class_replacement(
+    state: &mut dyn State,
+    contract_address: ContractAddress,
+    class_hash: ClassHash,
+) -> Result<Result<ClassHash, ReplaceBytecodeError>, StateError> {
+    let is_undeclared = match state.get_compiled_class(class_hash) {
+        Err(StateError::UndeclaredClassHash(_)) => true,
+        Err(err) => return Err(err),
+        _ => false,
+    };
+
+    let current_class_hash = state.get_class_hash_at(contract_address)?;
+
+    Ok(if current_class_hash == ClassHash::default() {
+        Err(ReplaceBytecodeError::ContractNotDeployed)
+    } else if is_undeclared {
+        Err(ReplaceBytecodeError::UndeclaredClassHash)
+    } else {
+        Ok(current_class_hash)
+    })
+}
+
+/// Declares the local contract `contract_identifier` and sets it as the class of the contract
+/// deployed at `contract_address`, the same way `replace_class_syscall` does.
+/// Failures to declare the contract fail the test instead of being returned as a declare error.
+pub fn upgrade_contract(
+    state: &mut dyn State,
+    contract_address: ContractAddress,
+    contract_identifier: &str,
+    contracts_data: &ContractsData,
+) -> Result<Result<ClassHash, ReplaceBytecodeError>, EnhancedHintError> {
+    if state.get_class_hash_at(contract_address)? == ClassHash::default() {
+        return Ok(Err(ReplaceBytecodeError::ContractNotDeployed));
+    }
+
+    let (DeclareResult::Success(class_hash) | DeclareResult::AlreadyDeclared(class_hash)) =
+        declare_or_fail(state, contract_identifier, contracts_data)?;
+
+    state.set_class_hash_at(contract_address, class_hash)?;
+
+    Ok(Ok(class_hash))
+}
@@ -0,0 +1,332 @@
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
+    BlockInfoMockOperations, ExecutionInfoMockOperations, Operation, ResourceBounds,
+    TxInfoMockOperations,
+};
+use crate::runtime_extensions::outer_call_runtime_extension::rpc::{
+    CallEntryPointResult, CallFailure, call_entry_point, call_l1_handler,
+};
+use crate::state::CheatnetState;
+use crate::sync_client::SyncClient;
+use anyhow::{Result, anyhow, bail};
+use blockifier::execution::entry_point::{CallEntryPoint, CallType};
+use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
+use conversions::IntoConv;
+use conversions::byte_array::ByteArray;
+use conversions::serde::deserialize::CairoDeserialize;
+use starknet_api::abi::abi_utils::selector_from_name;
+use starknet_api::contract_class::EntryPointType;
+use starknet_api::core::{ContractAddress, EntryPointSelector};
+use starknet_api::transaction::constants::{EXECUTE_ENTRY_POINT_NAME, VALIDATE_ENTRY_POINT_NAME};
+use starknet_api::transaction::fields::Calldata;
+use starknet_rust::core::types::{
+    DataAvailabilityMode, InvokeTransaction, MaybePreConfirmedBlockWithTxHashes,
+    ResourceBoundsMapping, Transaction,
+};
+use starknet_types_core::felt::Felt;
+use std::sync::Arc;
+
+#[derive(CairoDeserialize, Clone, Copy, Debug)]
+pub struct ReplayConfig {
+    pub replay_preceding_transactions: bool,
+    pub skip_validation: bool,
+}
+
+/// Transaction fetched from the fork, reduced to what is needed to execute it.
+enum ReplayedTransaction {
+    Invoke {
+        sender_address: ContractAddress,
+        calldata: Vec<Felt>,
+        tx_info: TxInfoMockOperations,
+    },
+    L1Handler {
+        contract_address: ContractAddress,
+        entry_point_selector: Felt,
+        calldata: Vec<Felt>,
+    },
+}
+
+/// Replays the transaction with `transaction_hash` on top of the forked state.
+///
+/// The test has to be forked at the block preceding the one the transaction was included in.
+/// Calls made by the transaction are recorded in the call trace of the test.
+pub fn replay_transaction(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    client: &SyncClient,
+    transaction_hash: Felt,
+    config: ReplayConfig,
+) -> Result<CallEntryPointResult> {
+    let block_number = client.block_number().0 + 1;
+
+    let MaybePreConfirmedBlockWithTxHashes::Block(block) =
+        client.get_block_with_tx_hashes_at(block_number)?
+    else {
+        bail!("Block {block_number} containing the replayed transaction is not accepted yet");
+    };
+
+    let transaction_index = block
+        .transactions
+        .iter()
+        .position(|hash| *hash == transaction_hash)
+        .ok_or_else(|| {
+            anyhow!(
+                "Transaction {transaction_hash:#x} is not included in block {block_number}, fork the test at the block preceding the transaction"
+            )
+        })?;
+
+    let cheated_execution_info = cheatnet_state.global_cheated_execution_info.clone();
+    let cheated_execution_info_contracts = cheatnet_state.cheated_execution_info_contracts.clone();
+
+    cheatnet_state.cheat_execution_info(ExecutionInfoMockOperations {
+        block_info: BlockInfoMockOperations {
+            block_number: Operation::StartGlobal(block.block_number),
+            block_timestamp: Operation::StartGlobal(block.timestamp),
+            sequencer_address: Operation::StartGlobal(block.sequencer_address.into_()),
+        },
+        ..Default::default()
+    });
+
+    let result = execute_block_transactions(
+        syscall_handler,
+        cheatnet_state,
+        client,
+        &block.transactions[..transaction_index],
+        transaction_hash,
+        config,
+    );
+
+    // Cheats applied for the replay must not leak into the rest of the test
+    cheatnet_state.global_cheated_execution_info = cheated_execution_info;
+    cheatnet_state.cheated_execution_info_contracts = cheated_execution_info_contracts;
+
+    result
+}
+
+/// Executes the transaction with `transaction_hash`, preceded by the transactions
+/// with `preceding_transaction_hashes` if requested in `config`.
+fn execute_block_transactions(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    client: &SyncClient,
+    preceding_transaction_hashes: &[Felt],
+    transaction_hash: Felt,
+    config: ReplayConfig,
+) -> Result<CallEntryPointResult> {
+    if config.replay_preceding_transactions {
+        for preceding_transaction_hash in preceding_transaction_hashes {
+            let transaction = match ReplayedTransaction::try_from(
+                client.get_transaction_by_hash(*preceding_transaction_hash)?,
+            ) {
+                Ok(transaction) => transaction,
+                Err(error) => {
+                    return Ok(Err(replay_failure(&format!(
+                        "Cannot replay preceding transaction {preceding_transaction_hash:#x}: {error}, set `replay_preceding_transactions` to `false` to skip preceding transactions"
+                    ))));
+                }
+            };
+
+            // Reverted transactions only increment the nonce, like on chain
+            if let Err(CallFailure::Unrecoverable(error)) = execute_transaction(
+                syscall_handler,
+                cheatnet_state,
+                transaction,
+                config.skip_validation,
+            )? {
+                return Ok(Err(CallFailure::Unrecoverable(error)));
+            }
+        }
+    }
+
+    let transaction =
+        match ReplayedTransaction::try_from(client.get_transaction_by_hash(transaction_hash)?) {
+            Ok(transaction) => transaction,
+            Err(error) => return Ok(Err(replay_failure(&error.to_string()))),
+        };
+
+    execute_transaction(
+        syscall_handler,
+        cheatnet_state,
+        transaction,
+        config.skip_validation,
+    )
+}
+
+/// Failure of the replay returned to the test, so it can be handled there.
+fn replay_failure(message: &str) -> CallFailure {
+    CallFailure::Recoverable {
+        panic_data: ByteArray::from(message).serialize_with_magic(),
+    }
+}
+
+fn execute_transaction(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    transaction: ReplayedTransaction,
+    skip_validation: bool,
+) -> Result<CallEntryPointResult> {
+    match transaction {
+        ReplayedTransaction::Invoke {
+            sender_address,
+            calldata,
+            tx_info,
+        } => {
+            cheatnet_state.cheat_execution_info(ExecutionInfoMockOperations {
+                tx_info,
+                ..Default::default()
+            });
+
+            if !skip_validation
+                && let Err(failure) = call_account_entry_point(
+                    syscall_handler,
+                    cheatnet_state,
+                    sender_address,
+                    VALIDATE_ENTRY_POINT_NAME,
+                    &calldata,
+                )
+            {
+                return Ok(Err(failure));
+            }
+
+            let result = call_account_entry_point(
+                syscall_handler,
+                cheatnet_state,
+                sender_address,
+                EXECUTE_ENTRY_POINT_NAME,
+                &calldata,
+            );
+
+            // Nonce is incremented even if the execution is reverted
+            syscall_handler.base.state.increment_nonce(sender_address)?;
+
+            Ok(result)
+        }
+        ReplayedTransaction::L1Handler {
+            contract_address,
+            entry_point_selector,
+            calldata,
+        } => Ok(call_l1_handler(
+            syscall_handler,
+            cheatnet_state,
+            &contract_address,
+            EntryPointSelector(entry_point_selector),
+            &calldata,
+        )),
+    }
+}
+
+fn call_account_entry_point(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    account_address: ContractAddress,
+    entry_point_name: &str,
+    calldata: &[Felt],
+) -> CallEntryPointResult {
+    let mut remaining_gas = i64::MAX as u64;
+    let entry_point = CallEntryPoint {
+        class_hash: None,
+        code_address: Some(account_address),
+        entry_point_type: EntryPointType::External,
+        entry_point_selector: selector_from_name(entry_point_name),
+        calldata: Calldata(Arc::new(calldata.to_vec())),
+        storage_address: account_address,
+        caller_address: ContractAddress::default(),
+        call_type: CallType::Call,
+        initial_gas: remaining_gas,
+    };
+
+    call_entry_point(
+        syscall_handler,
+        cheatnet_state,
+        entry_point,
+        &mut remaining_gas,
+    )
+}
+
+impl TryFrom<Transaction> for ReplayedTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(transaction: Transaction) -> Result<Self> {
+        match transaction {
+            Transaction::Invoke(InvokeTransaction::V1(tx)) => Ok(ReplayedTransaction::Invoke {
+                sender_address: tx.sender_address.into_(),
+                tx_info: TxInfoMockOperations {
+                    version: Operation::StartGlobal(Felt::ONE),
+                    account_contract_address: Operation::StartGlobal(tx.sender_address),
+                    max_fee: Operation::StartGlobal(tx.max_fee),
+                    signature: Operation::StartGlobal(tx.signature),
+                    transaction_hash: Operation::StartGlobal(tx.transaction_hash),
+                    nonce: Operation::StartGlobal(tx.nonce),
+                    ..Default::default()
+                },
+                calldata: tx.calldata,
+            }),
+            Transaction::Invoke(InvokeTransaction::V3(tx)) => Ok(ReplayedTransaction::Invoke {
+                sender_address: tx.sender_address.into_(),
+                tx_info: TxInfoMockOperations {
+                    version: Operation::StartGlobal(Felt::from(3_u8)),
+                    account_contract_address: Operation::StartGlobal(tx.sender_address),
+                    max_fee: Operation::StartGlobal(Felt::ZERO),
+                    signature: Operation::StartGlobal(tx.signature),
+                    transaction_hash: Operation::StartGlobal(tx.transaction_hash),
+                    nonce: Operation::StartGlobal(tx.nonce),
+                    resource_bounds: Operation::StartGlobal(resource_bounds(&tx.resource_bounds)),
+                    tip: Operation::StartGlobal(Felt::from(tx.tip)),
+                    paymaster_data: Operation::StartGlobal(tx.paymaster_data),
+                    nonce_data_availability_mode: Operation::StartGlobal(data_availability_mode(
+                        tx.nonce_data_availability_mode,
+                    )),
+                    fee_data_availability_mode: Operation::StartGlobal(data_availability_mode(
+                        tx.fee_data_availability_mode,
+                    )),
+                    account_deployment_data: Operation::StartGlobal(tx.account_deployment_data),
+                    proof_facts: Operation::StartGlobal(tx.proof_facts.unwrap_or_default()),
+                    ..Default::default()
+                },
+                calldata: tx.calldata,
+            }),
+            Transaction::L1Handler(tx) => Ok(ReplayedTransaction::L1Handler {
+                contract_address: tx.contract_address.into_(),
+                entry_point_selector: tx.entry_point_selector,
+                // The first element of calldata is the L1 sender address
+                calldata: tx.calldata,
+            }),
+            other => bail!(
+                "Replaying {} transactions is not supported",
+                transaction_type_name(&other)
+            ),
+        }
+    }
+}
+
+fn transaction_type_name(transaction: &Transaction) -> &'static str {
+    match transaction {
+        Transaction::Invoke(InvokeTransaction::V0(_)) => "INVOKE_V0",
+        Transaction::Invoke(_) => "INVOKE",
+        Transaction::L1Handler(_) => "L1_HANDLER",
+        Transaction::Declare(_) => "DECLARE",
+        Transaction::Deploy(_) => "DEPLOY",
+        Transaction::DeployAccount(_) => "DEPLOY_ACCOUNT",
+    }
+}
+
+fn resource_bounds(resource_bounds: &ResourceBoundsMapping) -> Vec<ResourceBounds> {
+    [
+        ("L1_GAS", &resource_bounds.l1_gas),
+        ("L2_GAS", &resource_bounds.l2_gas),
+        ("L1_DATA", &resource_bounds.l1_data_gas),
+    ]
+    .into_iter()
+    .map(|(resource, bounds)| ResourceBounds {
+        resource: Felt::from_bytes_be_slice(resource.as_bytes()),
+        max_amount: bounds.max_amount,
+        max_price_per_unit: bounds.max_price_per_unit,
+    })
+    .collect()
+}
+
+fn data_availability_mode(mode: DataAvailabilityMode) -> Felt {
+    match mode {
+        DataAvailabilityMode::L1 => Felt::ZERO,
+        DataAvailabilityMode::L2 => Felt::ONE,
+    }
+}
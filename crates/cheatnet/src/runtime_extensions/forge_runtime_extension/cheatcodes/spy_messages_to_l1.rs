@@ -6,9 +6,9 @@ use starknet_types_core::felt::Felt;
 
 #[derive(CairoSerialize, Clone)]
 pub struct MessageToL1 {
-    from_address: ContractAddress,
-    to_address: EthAddress,
-    payload: Vec<Felt>,
+    pub(crate) from_address: ContractAddress,
+    pub(crate) to_address: EthAddress,
+    pub(crate) payload: Vec<Felt>,
 }
 
 impl MessageToL1 {
@@ -0,0 +1,120 @@
+use crate::CheatnetState;
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractData;
+use anyhow::{Result, bail};
+use conversions::byte_array::ByteArray;
+use conversions::serde::serialize::CairoSerialize;
+use starknet_api::core::ContractAddress;
+use starknet_rust::core::types::contract::SierraClass;
+use starknet_rust::core::utils::get_selector_from_name;
+use starknet_types_core::felt::Felt;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+}
+
+/// Storage access executed while storage accesses were recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageAccess {
+    pub contract_address: ContractAddress,
+    pub kind: StorageAccessKind,
+    pub key: Felt,
+    pub value: Felt,
+}
+
+#[derive(CairoSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct StorageSlotAccess {
+    pub key: Felt,
+    /// Value read from or written to the slot
+    pub value: Felt,
+    /// Name of the storage variable, if the slot is its base address
+    pub variable_name: Option<ByteArray>,
+}
+
+#[derive(CairoSerialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct StorageAccesses {
+    pub reads: Vec<StorageSlotAccess>,
+    pub writes: Vec<StorageSlotAccess>,
+}
+
+impl CheatnetState {
+    /// Starts recording storage accesses, dropping the ones recorded so far.
+    pub fn record_storage_accesses(&mut self) {
+        self.recorded_storage_accesses = Some(vec![]);
+    }
+
+    pub fn record_storage_access(&mut self, access: StorageAccess) {
+        if let Some(accesses) = &mut self.recorded_storage_accesses {
+            accesses.push(access);
+        }
+    }
+
+    /// Returns storage accesses of `contract_address` in the order they were executed.
+    /// Slots are named using `variable_names`, keyed by the storage variable addresses.
+    pub fn get_storage_accesses(
+        &self,
+        contract_address: ContractAddress,
+        variable_names: &HashMap<Felt, String>,
+    ) -> Result<StorageAccesses> {
+        let Some(recorded_accesses) = &self.recorded_storage_accesses else {
+            bail!("Storage accesses are not recorded, use `record_storage_accesses` first");
+        };
+
+        let mut accesses = StorageAccesses::default();
+        for access in recorded_accesses
+            .iter()
+            .filter(|access| access.contract_address == contract_address)
+        {
+            let slot_access = StorageSlotAccess {
+                key: access.key,
+                value: access.value,
+                variable_name: variable_names
+                    .get(&access.key)
+                    .map(|name| ByteArray::from(name.as_str())),
+            };
+            match access.kind {
+                StorageAccessKind::Read => accesses.reads.push(slot_access),
+                StorageAccessKind::Write => accesses.writes.push(slot_access),
+            }
+        }
+
+        Ok(accesses)
+    }
+}
+
+/// Finds names of storage variables of the contract, keyed by their addresses.
+///
+/// Storage variable names are not part of the contract class, so identifiers found in the Sierra
+/// debug info are used as candidates. Only slots at the base address of a variable are named,
+/// entries of maps and nested members are not.
+#[must_use]
+pub fn storage_variable_names(contract: &ContractData) -> HashMap<Felt, String> {
+    let Ok(sierra_class) = serde_json::from_str::<SierraClass>(&contract.artifacts.sierra) else {
+        return HashMap::new();
+    };
+    let debug_info = sierra_class.sierra_program_debug_info;
+
+    let identifiers: HashSet<&str> = debug_info
+        .type_names
+        .iter()
+        .chain(&debug_info.libfunc_names)
+        .chain(&debug_info.user_func_names)
+        .flat_map(|(_, name)| name.split(|c: char| !c.is_ascii_alphanumeric() && c != '_'))
+        .filter(|identifier| {
+            identifier
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        })
+        .collect();
+
+    identifiers
+        .into_iter()
+        .filter_map(|identifier| {
+            let address = get_selector_from_name(identifier).ok()?;
+            Some((address, identifier.to_string()))
+        })
+        .collect()
+}
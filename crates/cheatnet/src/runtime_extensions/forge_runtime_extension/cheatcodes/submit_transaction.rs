@@ -0,0 +1,382 @@
+use crate::constants::get_current_sierra_version;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::declare::resolve_contract;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::{
+    ContractData, ContractsData,
+};
+use anyhow::{Context, Result};
+use blockifier::context::BlockContext;
+use blockifier::execution::call_info::CallInfo;
+use blockifier::execution::contract_class::RunnableCompiledClass;
+use blockifier::state::cached_state::{ContractClassMapping, StateMaps};
+use blockifier::state::state_api::{State, StateReader, StateResult, UpdatableState};
+use blockifier::transaction::account_transaction::AccountTransaction;
+use blockifier::transaction::objects::TransactionExecutionInfo;
+use blockifier::transaction::transactions::ExecutableTransaction;
+use conversions::byte_array::ByteArray;
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::serde::serialize::CairoSerialize;
+use starknet_api::block::{GasPrice, StarknetVersion};
+use starknet_api::contract_class::{ClassInfo, ContractClass};
+use starknet_api::core::{
+    ClassHash, CompiledClassHash, ContractAddress, Nonce, calculate_contract_address,
+};
+use starknet_api::data_availability::DataAvailabilityMode;
+use starknet_api::executable_transaction;
+use starknet_api::execution_resources::GasAmount;
+use starknet_api::state::StorageKey;
+use starknet_api::transaction::fields::{
+    AccountDeploymentData, AllResourceBounds, Calldata, ContractAddressSalt, PaymasterData,
+    ResourceBounds, Tip, TransactionSignature, ValidResourceBounds,
+};
+use starknet_api::transaction::{
+    DeclareTransaction, DeclareTransactionV3, DeployAccountTransaction, DeployAccountTransactionV3,
+    InvokeTransaction, InvokeTransactionV3,
+};
+use starknet_rust::core::types::contract::{CompiledClass, SierraClass};
+use starknet_types_core::felt::Felt;
+use std::sync::Arc;
+
+// Used when the resource bounds are not provided, generous enough for most transactions
+const DEFAULT_L1_GAS_AMOUNT: u64 = 10_000;
+const DEFAULT_L1_DATA_GAS_AMOUNT: u64 = 10_000;
+const DEFAULT_L2_GAS_AMOUNT: u64 = 100_000_000;
+
+#[derive(CairoDeserialize, Debug)]
+pub enum SubmittedTransaction {
+    Invoke(InvokeParams),
+    DeployAccount(DeployAccountParams),
+    Declare(DeclareParams),
+}
+
+#[derive(CairoDeserialize, Debug)]
+pub struct InvokeParams {
+    pub sender_address: ContractAddress,
+    pub calldata: Vec<Felt>,
+    pub account_deployment_data: Vec<Felt>,
+}
+
+#[derive(CairoDeserialize, Debug)]
+pub struct DeployAccountParams {
+    pub class_hash: ClassHash,
+    pub contract_address_salt: Felt,
+    pub constructor_calldata: Vec<Felt>,
+}
+
+#[derive(CairoDeserialize, Debug)]
+pub struct DeclareParams {
+    pub sender_address: ContractAddress,
+    pub contract_name: ByteArray,
+}
+
+#[derive(CairoDeserialize, Debug)]
+pub struct TransactionParams {
+    pub signature: Vec<Felt>,
+    pub nonce: Option<Felt>,
+    pub resource_bounds: Option<TransactionResourceBounds>,
+    pub tip: u64,
+    pub paymaster_data: Vec<Felt>,
+}
+
+#[derive(CairoDeserialize, Clone, Copy, Debug)]
+pub struct TransactionResourceBounds {
+    pub l1_gas: GasBounds,
+    pub l2_gas: GasBounds,
+    pub l1_data_gas: GasBounds,
+}
+
+#[derive(CairoDeserialize, Clone, Copy, Debug)]
+pub struct GasBounds {
+    pub max_amount: u64,
+    pub max_price_per_unit: u128,
+}
+
+impl From<GasBounds> for ResourceBounds {
+    fn from(bounds: GasBounds) -> Self {
+        ResourceBounds {
+            max_amount: GasAmount(bounds.max_amount),
+            max_price_per_unit: GasPrice(bounds.max_price_per_unit),
+        }
+    }
+}
+
+#[derive(CairoSerialize, Debug)]
+pub struct TransactionReceipt {
+    pub transaction_hash: Felt,
+    pub actual_fee: u128,
+    pub revert_reason: Option<ByteArray>,
+    pub events: Vec<Event>,
+}
+
+/// Executes an account transaction using the blockifier transaction flow, on top of the state of
+/// the test: the transaction is validated by the account, its nonce is checked and the fee is
+/// charged in STRK.
+///
+/// Returns the receipt of an executed (possibly reverted) transaction, or the reason why the
+/// transaction was rejected.
+pub fn submit_transaction(
+    state: &mut dyn State,
+    block_context: &BlockContext,
+    contracts_data: &ContractsData,
+    transaction: SubmittedTransaction,
+    params: TransactionParams,
+) -> Result<Result<TransactionReceipt, ByteArray>> {
+    let chain_id = &block_context.chain_info().chain_id;
+    let resource_bounds = ValidResourceBounds::AllResources(
+        params
+            .resource_bounds
+            .map_or_else(|| default_resource_bounds(block_context), Into::into),
+    );
+    let signature = TransactionSignature(params.signature.into());
+    let tip = Tip(params.tip);
+    let paymaster_data = PaymasterData(params.paymaster_data);
+
+    let executable_transaction = match transaction {
+        SubmittedTransaction::Invoke(invoke) => {
+            let tx = InvokeTransactionV3 {
+                resource_bounds,
+                tip,
+                signature,
+                nonce: nonce_or_current(state, params.nonce, invoke.sender_address)?,
+                sender_address: invoke.sender_address,
+                calldata: Calldata(Arc::new(invoke.calldata)),
+                nonce_data_availability_mode: DataAvailabilityMode::L1,
+                fee_data_availability_mode: DataAvailabilityMode::L1,
+                paymaster_data,
+                account_deployment_data: AccountDeploymentData(invoke.account_deployment_data),
+                ..Default::default()
+            };
+
+            executable_transaction::AccountTransaction::Invoke(
+                executable_transaction::InvokeTransaction::create(
+                    InvokeTransaction::V3(tx),
+                    chain_id,
+                )?,
+            )
+        }
+        SubmittedTransaction::DeployAccount(deploy_account) => {
+            let tx = DeployAccountTransactionV3 {
+                resource_bounds,
+                tip,
+                signature,
+                nonce: Nonce(params.nonce.unwrap_or_default()),
+                class_hash: deploy_account.class_hash,
+                contract_address_salt: ContractAddressSalt(deploy_account.contract_address_salt),
+                constructor_calldata: Calldata(Arc::new(deploy_account.constructor_calldata)),
+                nonce_data_availability_mode: DataAvailabilityMode::L1,
+                fee_data_availability_mode: DataAvailabilityMode::L1,
+                paymaster_data,
+            };
+
+            executable_transaction::AccountTransaction::DeployAccount(
+                executable_transaction::DeployAccountTransaction::create(
+                    DeployAccountTransaction::V3(tx),
+                    chain_id,
+                )?,
+            )
+        }
+        SubmittedTransaction::Declare(declare) => {
+            let contract_name = declare.contract_name.to_string();
+            let contract = resolve_contract(&contract_name, contracts_data)?;
+            let (class_info, compiled_class_hash) = class_info(contract, &contract_name)?;
+
+            let tx = DeclareTransactionV3 {
+                resource_bounds,
+                tip,
+                signature,
+                nonce: nonce_or_current(state, params.nonce, declare.sender_address)?,
+                class_hash: contract.class_hash,
+                compiled_class_hash,
+                sender_address: declare.sender_address,
+                nonce_data_availability_mode: DataAvailabilityMode::L1,
+                fee_data_availability_mode: DataAvailabilityMode::L1,
+                paymaster_data,
+                account_deployment_data: AccountDeploymentData::default(),
+            };
+
+            executable_transaction::AccountTransaction::Declare(
+                executable_transaction::DeclareTransaction::create(
+                    DeclareTransaction::V3(tx),
+                    class_info,
+                    chain_id,
+                )?,
+            )
+        }
+    };
+
+    let transaction_hash = executable_transaction.tx_hash().0;
+    let account_transaction = AccountTransaction::new_with_default_flags(executable_transaction);
+
+    let result = account_transaction
+        .execute(&mut TestState(state), block_context)
+        .map(|execution_info| TransactionReceipt {
+            transaction_hash,
+            actual_fee: execution_info.receipt.fee.0,
+            revert_reason: execution_info
+                .revert_error
+                .as_ref()
+                .map(|error| ByteArray::from(error.to_string().as_str())),
+            events: collect_events(&execution_info),
+        })
+        .map_err(|error| ByteArray::from(error.to_string().as_str()));
+
+    Ok(result)
+}
+
+/// Calculates the address of an account deployed with a `DEPLOY_ACCOUNT` transaction.
+pub fn precalculate_account_address(params: &DeployAccountParams) -> Result<ContractAddress> {
+    Ok(calculate_contract_address(
+        ContractAddressSalt(params.contract_address_salt),
+        params.class_hash,
+        &Calldata(Arc::new(params.constructor_calldata.clone())),
+        ContractAddress::default(),
+    )?)
+}
+
+fn nonce_or_current(
+    state: &dyn State,
+    nonce: Option<Felt>,
+    account_address: ContractAddress,
+) -> Result<Nonce> {
+    match nonce {
+        Some(nonce) => Ok(Nonce(nonce)),
+        None => Ok(state.get_nonce_at(account_address)?),
+    }
+}
+
+fn default_resource_bounds(block_context: &BlockContext) -> AllResourceBounds {
+    let gas_prices = &block_context.block_info().gas_prices.strk_gas_prices;
+
+    AllResourceBounds {
+        l1_gas: ResourceBounds {
+            max_amount: GasAmount(DEFAULT_L1_GAS_AMOUNT),
+            max_price_per_unit: gas_prices.l1_gas_price.get(),
+        },
+        l2_gas: ResourceBounds {
+            max_amount: GasAmount(DEFAULT_L2_GAS_AMOUNT),
+            max_price_per_unit: gas_prices.l2_gas_price.get(),
+        },
+        l1_data_gas: ResourceBounds {
+            max_amount: GasAmount(DEFAULT_L1_DATA_GAS_AMOUNT),
+            max_price_per_unit: gas_prices.l1_data_gas_price.get(),
+        },
+    }
+}
+
+impl From<TransactionResourceBounds> for AllResourceBounds {
+    fn from(bounds: TransactionResourceBounds) -> Self {
+        AllResourceBounds {
+            l1_gas: bounds.l1_gas.into(),
+            l2_gas: bounds.l2_gas.into(),
+            l1_data_gas: bounds.l1_data_gas.into(),
+        }
+    }
+}
+
+fn class_info(
+    contract: &ContractData,
+    contract_name: &str,
+) -> Result<(ClassInfo, CompiledClassHash)> {
+    let sierra_class: SierraClass = serde_json::from_str(&contract.artifacts.sierra)
+        .with_context(|| format!("Failed to parse Sierra of contract = {contract_name}"))?;
+    let flattened_class = sierra_class.flatten()?;
+
+    let sierra_version = get_current_sierra_version();
+    let class_info = ClassInfo::new(
+        &ContractClass::V1((contract.artifacts.casm.clone(), sierra_version.clone())),
+        flattened_class.sierra_program.len(),
+        flattened_class.abi.len(),
+        sierra_version,
+    )?;
+
+    let compiled_class: CompiledClass =
+        serde_json::from_value(serde_json::to_value(&contract.artifacts.casm)?)?;
+    let hash_function =
+        CompiledClass::hash_function_from_starknet_version(&StarknetVersion::LATEST.to_string())
+            .context("Unsupported Starknet version")?;
+    let compiled_class_hash = compiled_class.class_hash_with_hash_function(hash_function)?;
+
+    Ok((class_info, CompiledClassHash(compiled_class_hash)))
+}
+
+fn collect_events(execution_info: &TransactionExecutionInfo) -> Vec<Event> {
+    execution_info
+        .non_optional_call_infos()
+        .flat_map(CallInfo::iter)
+        .flat_map(|call_info| {
+            call_info
+                .execution
+                .events
+                .iter()
+                .map(|event| Event::from_ordered_event(event, call_info.call.storage_address))
+        })
+        .collect()
+}
+
+/// Allows blockifier to execute transactions directly on the state of the test.
+/// Changes of a rejected transaction are discarded by blockifier before they are applied.
+struct TestState<'a>(&'a mut dyn State);
+
+impl StateReader for TestState<'_> {
+    fn get_storage_at(
+        &self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+    ) -> StateResult<Felt> {
+        self.0.get_storage_at(contract_address, key)
+    }
+
+    fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
+        self.0.get_nonce_at(contract_address)
+    }
+
+    fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
+        self.0.get_class_hash_at(contract_address)
+    }
+
+    fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        self.0.get_compiled_class(class_hash)
+    }
+
+    fn get_compiled_class_hash(&self, class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+        self.0.get_compiled_class_hash(class_hash)
+    }
+}
+
+impl UpdatableState for TestState<'_> {
+    fn apply_writes(&mut self, writes: &StateMaps, class_hash_to_class: &ContractClassMapping) {
+        for (&(contract_address, key), &value) in &writes.storage {
+            self.0
+                .set_storage_at(contract_address, key, value)
+                .expect("Failed to write storage");
+        }
+        for (&contract_address, &class_hash) in &writes.class_hashes {
+            self.0
+                .set_class_hash_at(contract_address, class_hash)
+                .expect("Failed to set class hash");
+        }
+        for (&contract_address, &nonce) in &writes.nonces {
+            // The state of the test only allows incrementing nonces
+            while self
+                .0
+                .get_nonce_at(contract_address)
+                .expect("Failed to get nonce")
+                < nonce
+            {
+                self.0
+                    .increment_nonce(contract_address)
+                    .expect("Failed to increment nonce");
+            }
+        }
+        for (&class_hash, contract_class) in class_hash_to_class {
+            self.0
+                .set_contract_class(class_hash, contract_class.clone())
+                .expect("Failed to set contract class");
+        }
+        for (&class_hash, &compiled_class_hash) in &writes.compiled_class_hashes {
+            self.0
+                .set_compiled_class_hash(class_hash, compiled_class_hash)
+                .expect("Failed to set compiled class hash");
+        }
+    }
+}
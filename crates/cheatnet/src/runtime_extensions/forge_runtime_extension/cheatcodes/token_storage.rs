@@ -0,0 +1,225 @@
+use crate::runtime_extensions::common::create_execute_calldata;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage::storage_key;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage_accesses::StorageAccessKind;
+use crate::runtime_extensions::outer_call_runtime_extension::execution::entry_point::{
+    ExecuteCallEntryPointExtraOptions, execute_call_entry_point,
+};
+use crate::state::{CheatnetState, TestRng};
+use anyhow::{Result, bail, ensure};
+use blockifier::execution::contract_class::TrackedResource;
+use blockifier::execution::entry_point::{CallEntryPoint, CallType, EntryPointExecutionContext};
+use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
+use blockifier::state::state_api::State;
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::string::IntoHexStr;
+use rand::RngCore;
+use runtime::starknet::context::build_context;
+use starknet_api::contract_class::EntryPointType;
+use starknet_api::core::{ClassHash, ContractAddress, EntryPointSelector};
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+/// Index of the storage read determining the value returned by a getter of a token class.
+/// Shared by all tests run in the process, as the layout depends only on the class.
+static TOKEN_STORAGE_LAYOUTS: LazyLock<Mutex<HashMap<(ClassHash, EntryPointSelector), usize>>> =
+    LazyLock::new(Mutex::default);
+
+/// View function of a token returning the value stored in the storage slot being set,
+/// e.g. `balance_of(account)`.
+#[derive(CairoDeserialize, Clone, Debug)]
+pub struct TokenStorageGetter {
+    pub token: ContractAddress,
+    /// Selectors of the getter, the first one implemented by the token is used
+    /// (e.g. `balance_of` and `balanceOf`)
+    pub selectors: Vec<Felt>,
+    pub calldata: Vec<Felt>,
+}
+
+/// Sets the value returned by the `getter` to `value` by writing to the token storage.
+///
+/// The storage slot is discovered by recording storage reads of the token done by the getter
+/// and checking which of them determines the returned value.
+/// Index of the read is cached per class hash, so the discovery is done once for each token class
+/// across all tests.
+pub fn set_token_storage(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    getter: &TokenStorageGetter,
+    value: &[Felt],
+) -> Result<()> {
+    let token = getter.token;
+    let token_address = token.into_hex_string();
+    let class_hash = match cheatnet_state.get_replaced_class_hash(token) {
+        Some(class_hash) => class_hash,
+        None => syscall_handler.base.state.get_class_hash_at(token)?,
+    };
+    ensure!(
+        class_hash != ClassHash::default(),
+        "Token {token_address} is not deployed"
+    );
+
+    let chain_id = syscall_handler
+        .base
+        .context
+        .tx_context
+        .block_context
+        .chain_info()
+        .chain_id
+        .clone();
+    let mut discovery = Discovery {
+        state: &mut *syscall_handler.base.state,
+        // Cheats are not applied to discovery calls, so e.g. mocked getters do not affect it
+        cheatnet_state: CheatnetState {
+            block_info: cheatnet_state.block_info.clone(),
+            replaced_bytecode_contracts: cheatnet_state.replaced_bytecode_contracts.clone(),
+            cheated_class_hashes: cheatnet_state.cheated_class_hashes.clone(),
+            rng: TestRng::new(cheatnet_state.rng.unobserved().next_u64()),
+            ..Default::default()
+        },
+        context: build_context(
+            &cheatnet_state.block_info,
+            Some(chain_id),
+            &TrackedResource::SierraGas,
+        ),
+        token,
+    };
+
+    for selector in &getter.selectors {
+        let selector = EntryPointSelector(*selector);
+        let Some((retdata, reads)) = discovery.call_getter(selector, &getter.calldata) else {
+            continue;
+        };
+        ensure!(
+            retdata.len() == value.len(),
+            "Getter of token {token_address} returned {} felts, expected {}",
+            retdata.len(),
+            value.len()
+        );
+
+        let cached_index = TOKEN_STORAGE_LAYOUTS
+            .lock()
+            .unwrap()
+            .get(&(class_hash, selector))
+            .copied()
+            .filter(|index| *index < reads.len());
+        let indexes = cached_index
+            .into_iter()
+            .chain((0..reads.len()).filter(|index| Some(*index) != cached_index));
+
+        for index in indexes {
+            if discovery.probe(selector, &getter.calldata, reads[index], value.len())? {
+                discovery.write(reads[index], value)?;
+                TOKEN_STORAGE_LAYOUTS
+                    .lock()
+                    .unwrap()
+                    .insert((class_hash, selector), index);
+                return Ok(());
+            }
+        }
+
+        bail!("Failed to find the storage slot of token {token_address} read by the getter");
+    }
+
+    bail!("Token {token_address} does not implement the getter or it failed")
+}
+
+struct Discovery<'a> {
+    state: &'a mut dyn State,
+    cheatnet_state: CheatnetState,
+    context: EntryPointExecutionContext,
+    token: ContractAddress,
+}
+
+impl Discovery<'_> {
+    /// Calls the getter, returning its result and distinct storage keys of the token
+    /// in the order they were read, or `None` if the call failed.
+    fn call_getter(
+        &mut self,
+        selector: EntryPointSelector,
+        calldata: &[Felt],
+    ) -> Option<(Vec<Felt>, Vec<Felt>)> {
+        let mut entry_point = CallEntryPoint {
+            class_hash: None,
+            code_address: Some(self.token),
+            entry_point_type: EntryPointType::External,
+            entry_point_selector: selector,
+            calldata: create_execute_calldata(calldata),
+            storage_address: self.token,
+            caller_address: ContractAddress::default(),
+            call_type: CallType::Call,
+            initial_gas: i64::MAX as u64,
+        };
+
+        self.cheatnet_state.record_storage_accesses();
+        let result = execute_call_entry_point(
+            &mut entry_point,
+            self.state,
+            &mut self.cheatnet_state,
+            &mut self.context,
+            &mut (i64::MAX as u64),
+            &ExecuteCallEntryPointExtraOptions {
+                trace_data_handled_by_revert_call: false,
+            },
+        );
+        let recorded_accesses = self
+            .cheatnet_state
+            .recorded_storage_accesses
+            .take()
+            .unwrap_or_default();
+
+        let call_info = result
+            .ok()
+            .filter(|call_info| !call_info.execution.failed)?;
+
+        let mut reads = vec![];
+        for access in recorded_accesses {
+            if access.kind == StorageAccessKind::Read
+                && access.contract_address == self.token
+                && !reads.contains(&access.key)
+            {
+                reads.push(access.key);
+            }
+        }
+
+        Some((call_info.execution.retdata.0, reads))
+    }
+
+    /// Checks if the value returned by the getter is stored in consecutive slots starting
+    /// at `key`, by temporarily writing marker values to them.
+    fn probe(
+        &mut self,
+        selector: EntryPointSelector,
+        calldata: &[Felt],
+        key: Felt,
+        len: usize,
+    ) -> Result<bool> {
+        let original = self.read(key, len)?;
+        let markers: Vec<_> = (0..len)
+            .map(|_| Felt::from(self.cheatnet_state.rng.unobserved().next_u64()))
+            .collect();
+
+        self.write(key, &markers)?;
+        let retdata = self.call_getter(selector, calldata);
+        self.write(key, &original)?;
+
+        Ok(retdata.is_some_and(|(retdata, _)| retdata == markers))
+    }
+
+    fn read(&self, key: Felt, len: usize) -> Result<Vec<Felt>> {
+        (0..len)
+            .map(|offset| {
+                let storage_key = storage_key(key + Felt::from(offset))?;
+                Ok(self.state.get_storage_at(self.token, storage_key)?)
+            })
+            .collect()
+    }
+
+    fn write(&mut self, key: Felt, values: &[Felt]) -> Result<()> {
+        for (offset, value) in values.iter().enumerate() {
+            let storage_key = storage_key(key + Felt::from(offset))?;
+            self.state.set_storage_at(self.token, storage_key, *value)?;
+        }
+        Ok(())
+    }
+}
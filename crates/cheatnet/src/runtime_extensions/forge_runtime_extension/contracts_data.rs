@@ -97,8 +97,9 @@ impl ContractsData {
     /// Resolves a user-provided identifier to a single contract.
     ///
     /// The identifier can be either a contract name (e.g. `MyContract`), an absolute
-    /// module tree path (e.g. `my_package::module::MyContract`) or a partial module tree path
-    /// (e.g. `module::MyContract`).
+    /// module tree path (e.g. `my_package::module::MyContract`), a partial module tree path
+    /// (e.g. `module::MyContract`) or a package name followed by the contract name
+    /// (e.g. `my_dependency::MyContract`), which is useful for contracts of dependency packages.
     /// Returns an error if no contract or multiple contracts match the given identifier.
     pub fn resolve_contract(
         &self,
@@ -120,16 +121,25 @@ impl ContractsData {
             .filter(|(module_path, _)| module_path.ends_with(&module_path_suffix))
             .collect();
 
-        match matches.as_slice() {
-            [] => Err(ContractResolutionError::NameNotFound),
-            [(_, contract)] => Ok(contract),
-            _ => {
-                let mut module_paths: Vec<ModulePath> =
-                    matches.iter().map(|(path, _)| (*path).clone()).collect();
-                module_paths.sort();
-                Err(ContractResolutionError::AmbiguousName(module_paths))
-            }
+        if matches.is_empty()
+            && let Some((package_name, contract_name)) = contract_identifier.split_once("::")
+            && !contract_name.contains("::")
+        {
+            let package_prefix = format!("{package_name}::");
+
+            let matches: Vec<(&ModulePath, &ContractData)> = self
+                .contracts
+                .iter()
+                .filter(|(module_path, _)| {
+                    module_path.starts_with(&package_prefix)
+                        && contract_name_from_module_path(module_path) == contract_name
+                })
+                .collect();
+
+            return single_match(&matches);
         }
+
+        single_match(&matches)
     }
 
     #[must_use]
@@ -188,6 +198,21 @@ pub fn build_selectors_from_abi_map<S: BuildHasher>(
         .collect()
 }
 
+fn single_match<'a>(
+    matches: &[(&ModulePath, &'a ContractData)],
+) -> Result<&'a ContractData, ContractResolutionError> {
+    match matches {
+        [] => Err(ContractResolutionError::NameNotFound),
+        [(_, contract)] => Ok(contract),
+        _ => {
+            let mut module_paths: Vec<ModulePath> =
+                matches.iter().map(|(path, _)| (*path).clone()).collect();
+            module_paths.sort();
+            Err(ContractResolutionError::AmbiguousName(module_paths))
+        }
+    }
+}
+
 #[must_use]
 pub fn build_name_selector_map(abi: &[AbiEntry]) -> HashMap<EntryPointSelector, FunctionName> {
     let mut selector_map = HashMap::new();
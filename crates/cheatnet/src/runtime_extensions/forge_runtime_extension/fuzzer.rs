@@ -2,10 +2,9 @@ use anyhow::ensure;
 use num_bigint::RandBigInt;
 use rand::prelude::StdRng;
 use starknet_types_core::felt::Felt;
-use std::sync::{Arc, Mutex};
 
 pub(crate) fn generate_arg(
-    fuzzer_rng: Option<Arc<Mutex<StdRng>>>,
+    rng: &mut StdRng,
     min_value: Felt,
     max_value: Felt,
 ) -> anyhow::Result<Felt> {
@@ -26,15 +25,7 @@ pub(crate) fn generate_arg(
         )
     );
 
-    let value = if let Some(fuzzer_rng) = fuzzer_rng {
-        fuzzer_rng
-            .lock()
-            .expect("Failed to acquire lock on fuzzer_rng")
-            .gen_bigint_range(&min_big_int, &(max_big_int + 1))
-    } else {
-        // `generate_arg` cheatcode can be also used outside the fuzzer context
-        rand::thread_rng().gen_bigint_range(&min_big_int, &(max_big_int + 1))
-    };
+    let value = rng.gen_bigint_range(&min_big_int, &(max_big_int + 1));
 
     Ok(Felt::from(value))
 }
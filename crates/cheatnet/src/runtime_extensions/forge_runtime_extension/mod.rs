@@ -1,19 +1,34 @@
 use self::contracts_data::ContractsData;
+use crate::forking::state::ForkStateReader;
+use crate::predeployment::configured::PredeployedAccount;
 use crate::runtime_extensions::common::sum_syscall_usage;
-use crate::runtime_extensions::forge_runtime_extension::cheatcodes::replace_bytecode::ReplaceBytecodeError;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::replace_bytecode::{
+    upgrade_contract, validate_class_replacement,
+};
 use crate::runtime_extensions::outer_call_runtime_extension::rpc::UsedResources;
 use crate::runtime_extensions::{
     common::get_relocated_vm_trace,
     forge_runtime_extension::cheatcodes::{
         CheatcodeError,
-        declare::{declare, declare_from_file},
+        declare::{
+            DeclareResult, declare, declare_cairo0, declare_from_file, declare_from_file_or_fail,
+            declare_or_fail, declare_sierra, is_class_hash_collision,
+        },
         generate_random_felt::generate_random_felt,
         get_class_hash::get_class_hash,
         l1_handler_execute::l1_handler_execute,
+        replay_transaction::replay_transaction,
         storage::{calculate_variable_address, load, store},
+        storage_accesses::storage_variable_names,
+        submit_transaction::{precalculate_account_address, submit_transaction},
+        token_storage::set_token_storage,
+    },
+    outer_call_runtime_extension::{
+        OuterCallRuntime,
+        rpc::{CallFailure, CallSuccess},
     },
-    outer_call_runtime_extension::{OuterCallRuntime, rpc::CallFailure},
 };
+use crate::state::{CheatnetState, ForkClassConflict};
 use crate::trace_data::{CallTrace, CallTraceNode, GasReportData};
 use anyhow::{Context, Result, anyhow};
 use blockifier::blockifier_versioned_constants::VersionedConstants;
@@ -25,7 +40,6 @@ use blockifier::execution::call_info::{
 };
 use blockifier::execution::contract_class::TrackedResource;
 use blockifier::execution::syscalls::vm_syscall_utils::{SyscallSelector, SyscallUsageMap};
-use blockifier::state::errors::StateError;
 use blockifier::utils::u64_from_usize;
 use cairo_vm::vm::runners::cairo_runner::CairoRunner;
 use cairo_vm::vm::{errors::hint_errors::HintError, vm_core::VirtualMachine};
@@ -35,6 +49,7 @@ use conversions::serde::SerializedValue;
 use conversions::serde::deserialize::BufferReader;
 use conversions::serde::serialize::{CairoSerialize, SerializeToFeltVec};
 use data_transformer::cairo_types::CairoU256;
+use num_bigint::{BigUint, RandBigInt};
 use rand::prelude::StdRng;
 use runtime::{
     CheatcodeHandlingResult, EnhancedHintError, ExtendedRuntime, ExtensionLogic,
@@ -43,7 +58,7 @@ use runtime::{
 use scarb_oracle_hint_service::OracleHintService;
 use starknet_api::execution_resources::GasAmount;
 use starknet_api::versioned_constants_logic::VersionedConstantsTrait;
-use starknet_api::{contract_class::EntryPointType::L1Handler, core::ClassHash};
+use starknet_api::{contract_class::EntryPointType::L1Handler, core::EthAddress};
 use starknet_rust::signers::SigningKey;
 use starknet_types_core::felt::Felt;
 use std::cell::RefCell;
@@ -61,8 +76,10 @@ pub type ForgeRuntime<'a> = ExtendedRuntime<ForgeExtension<'a>>;
 pub struct ForgeExtension<'a> {
     pub environment_variables: &'a HashMap<String, String>,
     pub contracts_data: &'a ContractsData,
+    pub predeployed_accounts: Vec<PredeployedAccount>,
     pub fuzzer_rng: Option<Arc<Mutex<StdRng>>>,
     pub oracle_hint_service: OracleHintService,
+    pub fork_state_reader: Option<ForkStateReader>,
 }
 
 // This runtime extension provides an implementation logic for functions from snforge_std library.
@@ -122,6 +139,19 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .mock_call(contract_address, function_selector, &ret_data, span);
                 Ok(CheatcodeHandlingResult::from_serializable(()))
             }
+            "mock_call_when" => {
+                let contract_address = input_reader.read()?;
+                let function_selector = input_reader.read()?;
+                let matcher = input_reader.read()?;
+                let responses = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .mock_call_when(contract_address, function_selector, matcher, responses);
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
             "stop_mock_call" => {
                 let contract_address = input_reader.read()?;
                 let function_selector = input_reader.read()?;
@@ -137,43 +167,70 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                 let contract = input_reader.read()?;
                 let class = input_reader.read()?;
 
-                let is_undeclared = match extended_runtime
+                let state = &mut extended_runtime
                     .extended_runtime
                     .extended_runtime
                     .hint_handler
                     .base
-                    .state
-                    .get_compiled_class(class)
-                {
-                    Err(StateError::UndeclaredClassHash(_)) => true,
-                    Err(err) => return Err(err.into()),
-                    _ => false,
-                };
+                    .state;
 
-                let res = if extended_runtime
+                let res =
+                    validate_class_replacement(*state, contract, class)?.map(|current_class| {
+                        let cheatnet_state =
+                            &mut *extended_runtime.extended_runtime.extension.cheatnet_state;
+
+                        if self.fork_state_reader.is_some()
+                            && self.contracts_data.is_fork_class_hash(&current_class)
+                        {
+                            cheatnet_state.fork_class_conflicts.push(
+                                ForkClassConflict::ShadowedForkedClass {
+                                    contract_address: contract,
+                                    forked_class_hash: current_class,
+                                    class_hash: class,
+                                },
+                            );
+                        }
+                        cheatnet_state.replace_class_for_contract(contract, class);
+                    });
+
+                Ok(CheatcodeHandlingResult::from_serializable(res))
+            }
+            "cheat_class_hash" => {
+                let contract = input_reader.read()?;
+                let class = input_reader.read()?;
+                let span = input_reader.read()?;
+
+                let state = &mut extended_runtime
                     .extended_runtime
                     .extended_runtime
                     .hint_handler
                     .base
-                    .state
-                    .get_class_hash_at(contract)?
-                    == ClassHash::default()
-                {
-                    Err(ReplaceBytecodeError::ContractNotDeployed)
-                } else if is_undeclared {
-                    Err(ReplaceBytecodeError::UndeclaredClassHash)
-                } else {
+                    .state;
+
+                let res = validate_class_replacement(*state, contract, class)?.map(|_| {
                     extended_runtime
                         .extended_runtime
                         .extension
                         .cheatnet_state
-                        .replace_class_for_contract(contract, class);
-                    Ok(())
-                };
+                        .cheat_class_hash(contract, class, span);
+                });
 
                 Ok(CheatcodeHandlingResult::from_serializable(res))
             }
-            "declare" => {
+            "stop_cheat_class_hash" => {
+                let contract = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_cheat_class_hash(contract);
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "upgrade_forked_contract" => {
+                let contract = input_reader.read()?;
+                let contract_identifier: String = input_reader.read::<ByteArray>()?.to_string();
+
                 let state = &mut extended_runtime
                     .extended_runtime
                     .extended_runtime
@@ -181,11 +238,108 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .base
                     .state;
 
+                let result =
+                    upgrade_contract(*state, contract, &contract_identifier, self.contracts_data)?;
+
+                if result.is_ok() {
+                    // Upgrade takes precedence over previously replaced bytecode
+                    extended_runtime
+                        .extended_runtime
+                        .extension
+                        .cheatnet_state
+                        .replaced_bytecode_contracts
+                        .remove(&contract);
+                }
+
+                Ok(CheatcodeHandlingResult::from_serializable(result))
+            }
+            // `declare` and `declare_from_file` fail the test if the contract cannot be declared,
+            // their `try_` counterparts return the `DeclareError` instead.
+            "declare" => {
+                let contract_identifier: String = input_reader.read::<ByteArray>()?.to_string();
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let state = &mut cheatnet_runtime.extended_runtime.hint_handler.base.state;
+
+                let result = declare_or_fail(*state, &contract_identifier, self.contracts_data)?;
+                self.check_class_hash_collision(cheatnet_runtime.extension.cheatnet_state, &result);
+
+                Ok(CheatcodeHandlingResult::from_serializable(Ok::<_, ()>(
+                    result,
+                )))
+            }
+            "try_declare" => {
                 let contract_identifier: String = input_reader.read::<ByteArray>()?.to_string();
 
-                handle_declare_result(declare(*state, &contract_identifier, self.contracts_data))
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let state = &mut cheatnet_runtime.extended_runtime.hint_handler.base.state;
+
+                let result = declare(*state, &contract_identifier, self.contracts_data);
+                if let Ok(declare_result) = &result {
+                    self.check_class_hash_collision(
+                        cheatnet_runtime.extension.cheatnet_state,
+                        declare_result,
+                    );
+                }
+
+                handle_declare_result(result)
             }
             "declare_from_file" => {
+                let sierra_path = input_reader.read::<ByteArray>()?.to_string();
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let state = &mut cheatnet_runtime.extended_runtime.hint_handler.base.state;
+
+                let result = declare_from_file_or_fail(
+                    *state,
+                    std::path::Path::new(&sierra_path),
+                    self.contracts_data,
+                )?;
+                self.check_class_hash_collision(cheatnet_runtime.extension.cheatnet_state, &result);
+
+                Ok(CheatcodeHandlingResult::from_serializable(Ok::<_, ()>(
+                    result,
+                )))
+            }
+            "try_declare_from_file" => {
+                let sierra_path = input_reader.read::<ByteArray>()?.to_string();
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let state = &mut cheatnet_runtime.extended_runtime.hint_handler.base.state;
+
+                let result = declare_from_file(
+                    *state,
+                    std::path::Path::new(&sierra_path),
+                    self.contracts_data,
+                );
+                if let Ok(declare_result) = &result {
+                    self.check_class_hash_collision(
+                        cheatnet_runtime.extension.cheatnet_state,
+                        declare_result,
+                    );
+                }
+
+                handle_declare_result(result)
+            }
+            "declare_sierra" => {
+                let sierra = input_reader.read::<ByteArray>()?.to_string();
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let state = &mut cheatnet_runtime.extended_runtime.hint_handler.base.state;
+
+                let result = declare_sierra(*state, &sierra, self.contracts_data);
+                if let Ok(declare_result) = &result {
+                    self.check_class_hash_collision(
+                        cheatnet_runtime.extension.cheatnet_state,
+                        declare_result,
+                    );
+                }
+
+                handle_declare_result(result)
+            }
+            "declare_cairo0" => {
+                let compiled_class = input_reader.read::<ByteArray>()?.to_string();
+
                 let state = &mut extended_runtime
                     .extended_runtime
                     .extended_runtime
@@ -193,13 +347,7 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .base
                     .state;
 
-                let sierra_path = input_reader.read::<ByteArray>()?.to_string();
-
-                handle_declare_result(declare_from_file(
-                    *state,
-                    std::path::Path::new(&sierra_path),
-                    self.contracts_data,
-                ))
+                handle_declare_result(declare_cairo0(*state, &compiled_class))
             }
             // Internal cheatcode used to pass a contract address when calling `deploy_at`.
             "set_deploy_at_address" => {
@@ -295,6 +443,181 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     }
                 }
             }
+            "set_message_cancellation_delay" => {
+                let cancellation_delay = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .set_message_cancellation_delay(cancellation_delay);
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "send_message_to_l2" => {
+                let from_address = EthAddress::try_from(input_reader.read::<Felt>()?)
+                    .map_err(EnhancedHintError::from)?;
+                let to_address = input_reader.read()?;
+                let selector = input_reader.read()?;
+                let payload = input_reader.read()?;
+                let fee = input_reader.read()?;
+
+                let message = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .send_message_to_l2(from_address, to_address, selector, payload, fee)?;
+                Ok(CheatcodeHandlingResult::from_serializable(message))
+            }
+            "execute_message_to_l2" => {
+                let hash = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let message = cheatnet_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_executable_message_to_l2(hash)?;
+
+                let syscall_handler = &mut cheatnet_runtime.extended_runtime.hint_handler;
+                match l1_handler_execute(
+                    syscall_handler,
+                    cheatnet_runtime.extension.cheatnet_state,
+                    message.to_address,
+                    message.selector,
+                    message.from_address.into(),
+                    &message.payload,
+                ) {
+                    Ok(_) => {
+                        cheatnet_runtime
+                            .extension
+                            .cheatnet_state
+                            .consume_message_to_l2(hash)?;
+                        Ok(CheatcodeHandlingResult::from_serializable(Ok::<
+                            (),
+                            Vec<Felt>,
+                        >(
+                            ()
+                        )))
+                    }
+                    Err(CallFailure::Recoverable { panic_data }) => Ok(
+                        CheatcodeHandlingResult::from_serializable(Err::<(), _>(panic_data)),
+                    ),
+                    Err(CallFailure::Unrecoverable(error)) => {
+                        Err(EnhancedHintError::from(error.into_unannotated()))
+                    }
+                }
+            }
+            "get_message_to_l2_status" => {
+                let hash = input_reader.read()?;
+
+                let status = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_message_to_l2_status(hash)?;
+                Ok(CheatcodeHandlingResult::from_serializable(status))
+            }
+            "start_message_to_l2_cancellation" => {
+                let hash = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .start_message_to_l2_cancellation(hash)?;
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "cancel_message_to_l2" => {
+                let hash = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .cancel_message_to_l2(hash)?;
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "consume_message_from_l2" => {
+                let from_address = input_reader.read()?;
+                let to_address = EthAddress::try_from(input_reader.read::<Felt>()?)
+                    .map_err(EnhancedHintError::from)?;
+                let payload: Vec<_> = input_reader.read()?;
+
+                let hash = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .consume_message_from_l2(from_address, to_address, &payload);
+                Ok(CheatcodeHandlingResult::from_serializable(hash))
+            }
+            "replay_transaction" => {
+                let transaction_hash = input_reader.read()?;
+                let config = input_reader.read()?;
+
+                let client = self.fork_state_reader.as_ref().map(ForkStateReader::client).context(
+                    "Replaying a transaction is only possible in a forked test, use the `#[fork(...)]` attribute",
+                )?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                let syscall_handler = &mut cheatnet_runtime.extended_runtime.hint_handler;
+                match replay_transaction(
+                    syscall_handler,
+                    cheatnet_runtime.extension.cheatnet_state,
+                    &client,
+                    transaction_hash,
+                    config,
+                )? {
+                    Ok(CallSuccess { ret_data }) => Ok(CheatcodeHandlingResult::from_serializable(
+                        Ok::<_, Vec<Felt>>(ret_data),
+                    )),
+                    Err(CallFailure::Recoverable { panic_data }) => {
+                        Ok(CheatcodeHandlingResult::from_serializable(Err::<
+                            Vec<Felt>,
+                            _,
+                        >(
+                            panic_data
+                        )))
+                    }
+                    Err(CallFailure::Unrecoverable(error)) => {
+                        Err(EnhancedHintError::from(error.into_unannotated()))
+                    }
+                }
+            }
+            "submit_transaction" => {
+                let transaction = input_reader.read()?;
+                let params = input_reader.read()?;
+
+                let syscall_handler = &mut extended_runtime
+                    .extended_runtime
+                    .extended_runtime
+                    .hint_handler;
+                let block_context = syscall_handler
+                    .base
+                    .context
+                    .tx_context
+                    .block_context
+                    .clone();
+
+                let receipt = submit_transaction(
+                    syscall_handler.base.state,
+                    &block_context,
+                    self.contracts_data,
+                    transaction,
+                    params,
+                )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(receipt))
+            }
+            "precalculate_account_address" => {
+                let params = input_reader.read()?;
+
+                let contract_address = precalculate_account_address(&params)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(contract_address))
+            }
+            "predeployed_accounts" => Ok(CheatcodeHandlingResult::from_serializable(
+                self.predeployed_accounts.clone(),
+            )),
             "read_txt" => {
                 let file_path: String = input_reader.read::<ByteArray>()?.to_string();
                 let parsed_content = file_operations::read_txt(file_path)?;
@@ -328,6 +651,47 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(events))
             }
+            "record_storage_accesses" => {
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .record_storage_accesses();
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "get_storage_accesses" => {
+                let contract_address = input_reader.read()?;
+
+                let replaced_class_hash = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_replaced_class_hash(contract_address);
+                let class_hash = match replaced_class_hash {
+                    Some(class_hash) => class_hash,
+                    None => extended_runtime
+                        .extended_runtime
+                        .extended_runtime
+                        .hint_handler
+                        .base
+                        .state
+                        .get_class_hash_at(contract_address)?,
+                };
+                let variable_names = self
+                    .contracts_data
+                    .get_contract_by_class_hash(&class_hash)
+                    .map(storage_variable_names)
+                    .unwrap_or_default();
+
+                let accesses = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_storage_accesses(contract_address, &variable_names)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(accesses))
+            }
             "spy_messages_to_l1" => {
                 let messages_offset = extended_runtime
                     .extended_runtime
@@ -349,8 +713,40 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(messages))
             }
+            "spy_class_replacements" => {
+                let replacements_offset = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .detected_class_replacements
+                    .len();
+
+                Ok(CheatcodeHandlingResult::from_serializable(
+                    replacements_offset,
+                ))
+            }
+            "get_class_replacements" => {
+                let replacements_offset = input_reader.read()?;
+
+                let replacements = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_class_replacements(replacements_offset);
+
+                Ok(CheatcodeHandlingResult::from_serializable(replacements))
+            }
             "generate_stark_keys" => {
-                let key_pair = SigningKey::from_random();
+                let rng = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .rng
+                    .observed();
+                // Secret scalar has to be in range [1, curve order), the order being above 2^251
+                let secret_scalar =
+                    rng.gen_biguint_range(&BigUint::from(1_u8), &(BigUint::from(1_u8) << 251));
+                let key_pair = SigningKey::from_secret_scalar(Felt::from(secret_scalar));
 
                 Ok(CheatcodeHandlingResult::from_serializable((
                     key_pair.secret_scalar(),
@@ -380,6 +776,12 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
             "generate_ecdsa_keys" => {
                 let curve: Felt = input_reader.read()?;
                 let curve = curve.to_short_string().ok();
+                let rng = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .rng
+                    .observed();
 
                 let (signing_key_bytes, x_coordinate_bytes, y_coordinate_bytes) = {
                     let extract_coordinates_from_verifying_key = |verifying_key: Box<[u8]>| {
@@ -392,9 +794,7 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                     match curve.as_deref() {
                         Some("Secp256k1") => {
-                            let signing_key = k256::ecdsa::SigningKey::random(
-                                &mut k256::elliptic_curve::rand_core::OsRng,
-                            );
+                            let signing_key = k256::ecdsa::SigningKey::random(rng);
                             let verifying_key = signing_key
                                 .verifying_key()
                                 .to_encoded_point(false)
@@ -408,9 +808,7 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                             )
                         }
                         Some("Secp256r1") => {
-                            let signing_key = p256::ecdsa::SigningKey::random(
-                                &mut p256::elliptic_curve::rand_core::OsRng,
-                            );
+                            let signing_key = p256::ecdsa::SigningKey::random(rng);
                             let verifying_key = signing_key
                                 .verifying_key()
                                 .to_encoded_point(false)
@@ -530,6 +928,20 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(loaded))
             }
+            "set_token_storage" => {
+                let getter = input_reader.read()?;
+                let value: Vec<_> = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                set_token_storage(
+                    &mut cheatnet_runtime.extended_runtime.hint_handler,
+                    cheatnet_runtime.extension.cheatnet_state,
+                    &getter,
+                    &value,
+                )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
             "map_entry_address" => {
                 let map_selector = input_reader.read()?;
                 let keys: Vec<_> = input_reader.read()?;
@@ -540,15 +952,42 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                 ))
             }
             "generate_random_felt" => Ok(CheatcodeHandlingResult::from_serializable(
-                generate_random_felt(),
+                generate_random_felt(
+                    extended_runtime
+                        .extended_runtime
+                        .extension
+                        .cheatnet_state
+                        .rng
+                        .observed(),
+                ),
             )),
             "generate_arg" => {
                 let min_value = input_reader.read()?;
                 let max_value = input_reader.read()?;
 
-                Ok(CheatcodeHandlingResult::from_serializable(
-                    fuzzer::generate_arg(self.fuzzer_rng.clone(), min_value, max_value)?,
-                ))
+                let value = if let Some(fuzzer_rng) = &self.fuzzer_rng {
+                    fuzzer::generate_arg(
+                        &mut fuzzer_rng
+                            .lock()
+                            .expect("Failed to acquire lock on fuzzer_rng"),
+                        min_value,
+                        max_value,
+                    )?
+                } else {
+                    // `generate_arg` cheatcode can be also used outside the fuzzer context
+                    fuzzer::generate_arg(
+                        extended_runtime
+                            .extended_runtime
+                            .extension
+                            .cheatnet_state
+                            .rng
+                            .observed(),
+                        min_value,
+                        max_value,
+                    )?
+                };
+
+                Ok(CheatcodeHandlingResult::from_serializable(value))
             }
             "save_fuzzer_arg" => {
                 let arg = input_reader.read::<ByteArray>()?.to_string();
@@ -561,6 +1000,21 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(()))
             }
+            "get_fixture_result" => {
+                let key = input_reader.read::<ByteArray>()?.to_string();
+                let cheatnet_state =
+                    &mut extended_runtime.extended_runtime.extension.cheatnet_state;
+                let result = match &cheatnet_state.fixture_snapshot {
+                    Some(snapshot) => snapshot.result_of(&key).map(<[Felt]>::to_vec),
+                    None => {
+                        // Fixture being snapshotted requests its result before running its body
+                        cheatnet_state.fixture_key.get_or_insert(key);
+                        None
+                    }
+                };
+
+                Ok(CheatcodeHandlingResult::from_serializable(result))
+            }
             "set_block_hash" => {
                 let block_number = input_reader.read()?;
                 let operation = input_reader.read()?;
@@ -572,6 +1026,36 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .cheat_block_hash(block_number, operation);
                 Ok(CheatcodeHandlingResult::from_serializable(()))
             }
+            "set_block_time" => {
+                let block_time = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .set_block_time(block_time)?;
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "roll" => {
+                let blocks = input_reader.read()?;
+
+                let block = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .roll(blocks)?;
+                Ok(CheatcodeHandlingResult::from_serializable(block))
+            }
+            "warp" => {
+                let seconds = input_reader.read()?;
+
+                let block = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .warp(seconds)?;
+                Ok(CheatcodeHandlingResult::from_serializable(block))
+            }
             "get_current_vm_step" => {
                 // Each contract call is executed in separate VM, hence all VM steps
                 // are calculated as sum of steps from calls + current VM steps.
@@ -624,16 +1108,42 @@ enum SignError {
     HashOutOfRange,
 }
 
-fn handle_declare_result<T: CairoSerialize>(
-    declare_result: Result<T, CheatcodeError>,
-) -> Result<CheatcodeHandlingResult, EnhancedHintError> {
-    let result = match declare_result {
-        Ok(data) => Ok(data),
-        Err(CheatcodeError::Recoverable(panic_data)) => Err(panic_data),
-        Err(CheatcodeError::Unrecoverable(err)) => return Err(err),
-    };
+impl ForgeExtension<'_> {
+    /// Records a conflict if a local contract was declared with a class hash,
+    /// that already exists on the fork with a different Sierra class.
+    fn check_class_hash_collision(
+        &self,
+        cheatnet_state: &mut CheatnetState,
+        declare_result: &DeclareResult,
+    ) {
+        let Some(fork_state_reader) = &self.fork_state_reader else {
+            return;
+        };
+
+        if let DeclareResult::AlreadyDeclared(class_hash) = declare_result
+            && is_class_hash_collision(fork_state_reader, *class_hash, self.contracts_data)
+        {
+            cheatnet_state
+                .fork_class_conflicts
+                .push(ForkClassConflict::ClassHashCollision(*class_hash));
+        }
+    }
+}
 
-    Ok(CheatcodeHandlingResult::from_serializable(result))
+fn handle_declare_result(
+    declare_result: Result<DeclareResult, CheatcodeError>,
+) -> Result<CheatcodeHandlingResult, EnhancedHintError> {
+    match declare_result {
+        Ok(declare_result) => Ok(CheatcodeHandlingResult::from_serializable(Ok::<_, ()>(
+            declare_result,
+        ))),
+        // Recoverable error data is an already serialized `DeclareError`
+        Err(CheatcodeError::Recoverable(declare_error)) => {
+            let result: Result<DeclareResult, _> = Err(SerializedValue::new(declare_error));
+            Ok(CheatcodeHandlingResult::from_serializable(result))
+        }
+        Err(CheatcodeError::Unrecoverable(err)) => Err(err),
+    }
 }
 
 pub fn add_resources_to_top_call(
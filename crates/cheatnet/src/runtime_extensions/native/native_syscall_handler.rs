@@ -1,5 +1,10 @@
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_block_hash::block_number_out_of_range;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::replace_bytecode::ClassReplacement;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage_accesses::{
+    StorageAccess, StorageAccessKind,
+};
 use crate::runtime_extensions::native::call::execute_inner_call;
 use crate::runtime_extensions::native::deploy::deploy;
 use crate::state::CheatnetState;
@@ -197,6 +202,8 @@ impl StarknetSyscallHandler for &mut CheatableNativeSyscallHandler<'_> {
 
         if let Some(block_hash) = block_hash {
             Ok(block_hash.0)
+        } else if self.cheatnet_state.is_recent_produced_block(block_number) {
+            Err(self.handle_error(remaining_gas, block_number_out_of_range()))
         } else {
             match self
                 .native_syscall_handler
@@ -509,8 +516,23 @@ impl StarknetSyscallHandler for &mut CheatableNativeSyscallHandler<'_> {
     }
 
     fn replace_class(&mut self, class_hash: Felt, remaining_gas: &mut u64) -> SyscallResult<()> {
+        let contract_address = self.native_syscall_handler.base.call.storage_address;
+        let old_class_hash = self
+            .native_syscall_handler
+            .base
+            .state
+            .get_class_hash_at(contract_address)
+            .map_err(|err| self.handle_error(remaining_gas, err.into()))?;
+
         self.native_syscall_handler
-            .replace_class(class_hash, remaining_gas)
+            .replace_class(class_hash, remaining_gas)?;
+        self.cheatnet_state
+            .register_class_replacement(ClassReplacement {
+                contract_address,
+                old_class_hash,
+                new_class_hash: ClassHash(class_hash),
+            });
+        Ok(())
     }
 
     // Based on (blockifier 0.14.0) https://github.com/software-mansion-labs/sequencer/blob/b6d1c0b354d84225ab9c47f8ff28663d22e84d19/crates/blockifier/src/execution/native/syscall_handler.rs#L399
@@ -650,8 +672,16 @@ impl StarknetSyscallHandler for &mut CheatableNativeSyscallHandler<'_> {
         address: Felt,
         remaining_gas: &mut u64,
     ) -> SyscallResult<Felt> {
-        self.native_syscall_handler
-            .storage_read(address_domain, address, remaining_gas)
+        let value =
+            self.native_syscall_handler
+                .storage_read(address_domain, address, remaining_gas)?;
+        self.cheatnet_state.record_storage_access(StorageAccess {
+            contract_address: self.native_syscall_handler.base.call.storage_address,
+            kind: StorageAccessKind::Read,
+            key: address,
+            value,
+        });
+        Ok(value)
     }
 
     fn storage_write(
@@ -662,7 +692,14 @@ impl StarknetSyscallHandler for &mut CheatableNativeSyscallHandler<'_> {
         remaining_gas: &mut u64,
     ) -> SyscallResult<()> {
         self.native_syscall_handler
-            .storage_write(address_domain, address, value, remaining_gas)
+            .storage_write(address_domain, address, value, remaining_gas)?;
+        self.cheatnet_state.record_storage_access(StorageAccess {
+            contract_address: self.native_syscall_handler.base.call.storage_address,
+            kind: StorageAccessKind::Write,
+            key: address,
+            value,
+        });
+        Ok(())
     }
 
     fn emit_event(
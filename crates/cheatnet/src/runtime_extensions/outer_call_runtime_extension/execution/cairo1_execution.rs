@@ -68,11 +68,13 @@ pub(crate) fn execute_entry_point_call_cairo1(
 
     // region: Modified blockifier code
 
+    let deadline = cheatnet_state.deadline.clone();
     let mut cheatable_runtime = ExtendedRuntime {
         extension: CheatableStarknetRuntimeExtension { cheatnet_state },
         extended_runtime: StarknetRuntime {
             hint_handler: syscall_handler,
             panic_traceback: None,
+            deadline,
         },
     };
 
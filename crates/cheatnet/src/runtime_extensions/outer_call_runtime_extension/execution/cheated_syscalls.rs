@@ -1,6 +1,10 @@
 use super::calls::{execute_inner_call, execute_library_call};
 use super::execution_info::get_cheated_exec_info_ptr;
 use super::execution_utils::clear_events_and_messages_from_reverted_call;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::replace_bytecode::ClassReplacement;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage_accesses::{
+    StorageAccess, StorageAccessKind,
+};
 use crate::runtime_extensions::outer_call_runtime_extension::CheatnetState;
 use crate::runtime_extensions::outer_call_runtime_extension::execution::entry_point::execute_constructor_entry_point;
 use blockifier::context::TransactionContext;
@@ -19,9 +23,9 @@ use blockifier::execution::syscalls::syscall_base::SyscallResult;
 use blockifier::execution::syscalls::vm_syscall_utils::{
     CallContractRequest, CallContractResponse, DeployRequest, DeployResponse, EmptyRequest,
     GetBlockHashRequest, GetBlockHashResponse, GetExecutionInfoResponse, LibraryCallRequest,
-    LibraryCallResponse, MetaTxV0Request, MetaTxV0Response, StorageReadRequest,
-    StorageReadResponse, StorageWriteRequest, StorageWriteResponse, SyscallSelector,
-    TryExtractRevert,
+    LibraryCallResponse, MetaTxV0Request, MetaTxV0Response, ReplaceClassRequest,
+    ReplaceClassResponse, StorageReadRequest, StorageReadResponse, StorageWriteRequest,
+    StorageWriteResponse, SyscallSelector, TryExtractRevert,
 };
 use blockifier::execution::{call_info::CallInfo, entry_point::ConstructorContext};
 use blockifier::state::errors::StateError;
@@ -512,6 +516,12 @@ pub fn storage_read(
             // Restore state on error before bubbling up
             syscall_handler.base.call.storage_address = original_storage_address;
         })?;
+    cheatnet_state.record_storage_access(StorageAccess {
+        contract_address: syscall_handler.base.call.storage_address,
+        kind: StorageAccessKind::Read,
+        key: *request.address.0.key(),
+        value,
+    });
 
     // Restore the original storage_address
     syscall_handler.base.call.storage_address = original_storage_address;
@@ -537,6 +547,12 @@ pub fn storage_write(
             // Restore state on error before bubbling up
             syscall_handler.base.call.storage_address = original_storage_address;
         })?;
+    cheatnet_state.record_storage_access(StorageAccess {
+        contract_address: syscall_handler.base.call.storage_address,
+        kind: StorageAccessKind::Write,
+        key: *request.address.0.key(),
+        value: request.value,
+    });
 
     // Restore the original storage_address
     syscall_handler.base.call.storage_address = original_storage_address;
@@ -544,6 +560,30 @@ pub fn storage_write(
     Ok(StorageWriteResponse {})
 }
 
+#[expect(clippy::needless_pass_by_value)]
+pub fn replace_class_syscall(
+    request: ReplaceClassRequest,
+    _vm: &mut VirtualMachine,
+    syscall_handler: &mut SyscallHintProcessor<'_>,
+    cheatnet_state: &mut CheatnetState,
+    _remaining_gas: &mut u64,
+) -> SyscallResult<ReplaceClassResponse> {
+    let contract_address = syscall_handler.storage_address();
+    let old_class_hash = syscall_handler
+        .base
+        .state
+        .get_class_hash_at(contract_address)?;
+
+    syscall_handler.base.replace_class(request.class_hash)?;
+    cheatnet_state.register_class_replacement(ClassReplacement {
+        contract_address,
+        old_class_hash,
+        new_class_hash: request.class_hash,
+    });
+
+    Ok(ReplaceClassResponse {})
+}
+
 // This logic is used to modify the storage address to enable using `contract_state_for_testing`
 // inside `interact_with_state` closure cheatcode.
 fn maybe_modify_storage_address(
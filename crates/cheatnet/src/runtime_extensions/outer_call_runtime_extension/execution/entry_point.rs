@@ -1,9 +1,11 @@
 use super::cairo1_execution::execute_entry_point_call_cairo1;
 use crate::runtime_extensions::outer_call_runtime_extension::execution::deprecated::cairo0_execution::execute_entry_point_call_cairo0;
 use crate::runtime_extensions::outer_call_runtime_extension::execution::execution_utils::{exit_error_call, resolve_cheated_data_for_call, update_trace_data};
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::MockedResponse;
 use crate::runtime_extensions::outer_call_runtime_extension::rpc::CallSuccess;
 use crate::runtime_extensions::outer_call_runtime_extension::CheatnetState;
 use crate::runtime_extensions::common::get_relocated_vm_trace;
+use crate::trace_data::TraceDataCallFailure;
 #[cfg(feature = "cairo-native")]
 use crate::runtime_extensions::native::execution::execute_entry_point_call_native;
 use crate::state::CheatStatus;
@@ -33,7 +35,6 @@ use blockifier::{
     state::state_api::State,
 };
 use cairo_vm::vm::runners::cairo_runner::CairoRunner;
-use conversions::FromConv;
 use shared::vm::VirtualMachineExt;
 use starknet_api::execution_resources::GasAmount;
 use starknet_api::{
@@ -77,19 +78,26 @@ pub fn execute_call_entry_point(
             .enter_nested_call(entry_point.clone(), cheated_data.clone());
     }
 
-    if let Some(cheat_status) = get_mocked_function_cheat_status(entry_point, cheatnet_state)
-        && let CheatStatus::Cheated(ret_data, _) = (*cheat_status).clone()
-    {
-        cheat_status.decrement_cheat_span();
-        let ret_data_f252: Vec<Felt> = ret_data.iter().map(|datum| Felt::from_(*datum)).collect();
+    if let Some(response) = get_mocked_response(entry_point, cheatnet_state) {
+        let (ret_data, failed) = match response {
+            MockedResponse::Return(ret_data) => (ret_data, false),
+            MockedResponse::Revert(panic_data) => (panic_data, true),
+        };
+        let result = if failed {
+            Err(TraceDataCallFailure::Recoverable {
+                panic_data: ret_data.clone(),
+            })
+        } else {
+            Ok(CallSuccess {
+                ret_data: ret_data.clone(),
+            })
+        };
         cheatnet_state.trace_data.update_current_call(
             ExtendedExecutionResources::default(),
             u64::default(),
             SyscallUsageMap::default(),
             SyscallUsageMap::default(),
-            Ok(CallSuccess {
-                ret_data: ret_data_f252,
-            }),
+            result,
             &[],
             vec![],
             vec![],
@@ -106,7 +114,8 @@ pub fn execute_call_entry_point(
 
         return Ok(mocked_call_info(
             entry_point.clone(),
-            ret_data.clone(),
+            ret_data,
+            failed,
             tracked_resource,
         ));
     }
@@ -128,13 +137,9 @@ pub fn execute_call_entry_point(
     }
 
     // region: Modified blockifier code
-    let maybe_replacement_class = cheatnet_state
-        .replaced_bytecode_contracts
-        .get(&entry_point.storage_address)
-        .copied();
     let class_hash = entry_point
         .class_hash
-        .or(maybe_replacement_class)
+        .or_else(|| cheatnet_state.replaced_class_hash_for_call(entry_point.storage_address))
         .unwrap_or(storage_class_hash); // If not given, take the storage contract class hash.
     // endregion
 
@@ -164,6 +169,10 @@ pub fn execute_call_entry_point(
         EntryPointExecutionError::from(e).annotated(TrackedResource::CairoSteps, strip_vm_frames)
     })?;
 
+    // region: Modified blockifier code
+    let class_replacements_checkpoint = cheatnet_state.class_replacements_checkpoint();
+    // endregion
+
     context.revert_infos.0.push(EntryPointRevertInfo::new(
         entry_point.storage_address,
         class_hash,
@@ -218,6 +227,13 @@ pub fn execute_call_entry_point(
         .pop()
         .expect("Unexpected empty tracked resource.");
 
+    // region: Modified blockifier code
+    // Changes to the state are reverted by the caller, replacements of classes are dropped here
+    if !matches!(&result, Ok(res) if !res.call_info.execution.failed) {
+        cheatnet_state.revert_class_replacements(class_replacements_checkpoint);
+    }
+    // endregion
+
     match result {
         Ok(res) => {
             if res.call_info.execution.failed && !context.versioned_constants().enable_reverts {
@@ -403,23 +419,39 @@ pub fn execute_constructor_entry_point(
     // endregion
 }
 
-fn get_mocked_function_cheat_status<'a>(
+fn get_mocked_response(
     call: &CallEntryPoint,
-    cheatnet_state: &'a mut CheatnetState,
-) -> Option<&'a mut CheatStatus<Vec<Felt>>> {
+    cheatnet_state: &mut CheatnetState,
+) -> Option<MockedResponse> {
     if call.call_type == CallType::Delegate {
         return None;
     }
 
-    cheatnet_state
+    if let Some(response) = cheatnet_state.next_mocked_response(
+        call.storage_address,
+        call.entry_point_selector,
+        &call.calldata.0,
+    ) {
+        return Some(response);
+    }
+
+    let cheat_status = cheatnet_state
         .mocked_functions
         .get_mut(&call.storage_address)
-        .and_then(|contract_functions| contract_functions.get_mut(&call.entry_point_selector))
+        .and_then(|contract_functions| contract_functions.get_mut(&call.entry_point_selector))?;
+
+    let CheatStatus::Cheated(ret_data, _) = cheat_status.clone() else {
+        return None;
+    };
+    cheat_status.decrement_cheat_span();
+
+    Some(MockedResponse::Return(ret_data))
 }
 
 fn mocked_call_info(
     call: CallEntryPoint,
     ret_data: Vec<Felt>,
+    failed: bool,
     tracked_resource: TrackedResource,
 ) -> CallInfo {
     CallInfo {
@@ -432,7 +464,7 @@ fn mocked_call_info(
             events: vec![],
             l2_to_l1_messages: vec![],
             cairo_native: false,
-            failed: false,
+            failed,
             gas_consumed: 0,
         },
         resources: ExtendedExecutionResources::default(),
@@ -186,10 +186,8 @@ fn build_failed_call_info(
         .state
         .get_class_hash_at(entry_point.storage_address)
         .expect("There should be a class hash at the storage address");
-    let maybe_replacement_class = cheatnet_state
-        .replaced_bytecode_contracts
-        .get(&entry_point.storage_address)
-        .copied();
+    let maybe_replacement_class =
+        cheatnet_state.get_replaced_class_hash(entry_point.storage_address);
     let class_hash = entry_point
         .class_hash
         .or(maybe_replacement_class)
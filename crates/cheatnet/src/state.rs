@@ -3,21 +3,30 @@ use crate::forking::state::ForkStateReader;
 use crate::predeployment::erc20::eth::eth_predeployed_contract;
 use crate::predeployment::erc20::strk::strk_predeployed_contract;
 use crate::predeployment::predeployed_contract::PredeployedContract;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::block_progression::BlockProgression;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
     ExecutionInfoMock, ResourceBounds,
 };
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::l1_messaging::L1Messaging;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::CalldataMock;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::replace_bytecode::ClassReplacement;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage_accesses::StorageAccess;
 use crate::trace_data::{CallTrace, NotEmptyCallStack, TraceData};
 use blockifier::execution::contract_class::RunnableCompiledClass;
+use blockifier::state::cached_state::{CachedState, StateMaps};
 use blockifier::state::errors::StateError::UndeclaredClassHash;
 use blockifier::state::state_api::{StateReader, StateResult};
 use cairo_vm::Felt252;
 use conversions::serde::deserialize::CairoDeserialize;
 use conversions::string::TryFromHexStr;
 use indexmap::IndexMap;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use runtime::starknet::constants::TEST_CONTRACT_CLASS_HASH;
 use runtime::starknet::context::SerializableBlockInfo;
+use runtime::starknet::deadline::Deadline;
 use runtime::starknet::state::DictStateReader;
 use starknet_api::block::BlockInfo;
 use starknet_api::core::{ChainId, EntryPointSelector};
@@ -31,6 +40,7 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::rc::Rc;
+use std::sync::Arc;
 
 // Specifies the duration of the cheat
 #[derive(CairoDeserialize, Copy, Clone, Debug, PartialEq, Eq)]
@@ -43,14 +53,157 @@ pub enum CheatSpan {
 pub struct ExtendedStateReader {
     pub dict_state_reader: DictStateReader,
     pub fork_state_reader: Option<ForkStateReader>,
+    /// State left by the fixture the test is set up with, it takes precedence over other readers
+    pub fixture_snapshot: Option<Arc<FixtureSnapshot>>,
+}
+
+/// Snapshot of the state left by a `#[fixture]` function,
+/// shared by all tests that are set up with the fixture.
+#[derive(Debug, Default)]
+pub struct FixtureSnapshot {
+    pub name: String,
+    /// Key the fixture requested its cached result with, unique for each `#[fixture]` function
+    pub key: Option<String>,
+    /// Serialized value returned by the fixture
+    pub result: Vec<Felt>,
+    pub state_maps: StateMaps,
+    pub classes: HashMap<ClassHash, RunnableCompiledClass>,
+    /// Cheats, mocks and spied data left by the fixture
+    pub cheats: CheatsSnapshot,
+}
+
+/// Part of the `CheatnetState` carried over from a fixture to the tests set up with it.
+/// Per-run data, like the trace or the deadline, is not included.
+#[derive(Debug, Clone, Default)]
+pub struct CheatsSnapshot {
+    pub cheated_execution_info_contracts: HashMap<ContractAddress, ExecutionInfoMock>,
+    pub global_cheated_execution_info: ExecutionInfoMock,
+    pub mocked_functions:
+        HashMap<ContractAddress, HashMap<EntryPointSelector, CheatStatus<Vec<Felt>>>>,
+    pub mocked_calls: HashMap<ContractAddress, HashMap<EntryPointSelector, Vec<CalldataMock>>>,
+    pub replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
+    pub cheated_class_hashes: HashMap<ContractAddress, CheatStatus<ClassHash>>,
+    pub detected_class_replacements: Vec<ClassReplacement>,
+    pub detected_events: Vec<Event>,
+    pub detected_messages_to_l1: Vec<MessageToL1>,
+    pub deploy_salt_base: u32,
+    pub next_deploy_at_address: Option<ContractAddress>,
+    pub block_info: BlockInfo,
+    pub block_hash_contracts: HashMap<(ContractAddress, u64), (CheatSpan, Felt)>,
+    pub global_block_hash: HashMap<u64, (Felt, Vec<ContractAddress>)>,
+    pub block_progression: BlockProgression,
+    pub l1_messaging: L1Messaging,
+}
+
+impl CheatsSnapshot {
+    #[must_use]
+    pub fn capture(cheatnet_state: &CheatnetState) -> Self {
+        Self {
+            cheated_execution_info_contracts: cheatnet_state
+                .cheated_execution_info_contracts
+                .clone(),
+            global_cheated_execution_info: cheatnet_state.global_cheated_execution_info.clone(),
+            mocked_functions: cheatnet_state.mocked_functions.clone(),
+            mocked_calls: cheatnet_state.mocked_calls.clone(),
+            replaced_bytecode_contracts: cheatnet_state.replaced_bytecode_contracts.clone(),
+            cheated_class_hashes: cheatnet_state.cheated_class_hashes.clone(),
+            detected_class_replacements: cheatnet_state.detected_class_replacements.clone(),
+            detected_events: cheatnet_state.detected_events.clone(),
+            detected_messages_to_l1: cheatnet_state.detected_messages_to_l1.clone(),
+            deploy_salt_base: cheatnet_state.deploy_salt_base,
+            next_deploy_at_address: cheatnet_state.next_deploy_at_address,
+            block_info: cheatnet_state.block_info.clone(),
+            block_hash_contracts: cheatnet_state.block_hash_contracts.clone(),
+            global_block_hash: cheatnet_state.global_block_hash.clone(),
+            block_progression: cheatnet_state.block_progression.clone(),
+            l1_messaging: cheatnet_state.l1_messaging.clone(),
+        }
+    }
+
+    /// Applies the snapshot to the state of a test that has not run yet.
+    pub fn restore(&self, cheatnet_state: &mut CheatnetState) {
+        let Self {
+            cheated_execution_info_contracts,
+            global_cheated_execution_info,
+            mocked_functions,
+            mocked_calls,
+            replaced_bytecode_contracts,
+            cheated_class_hashes,
+            detected_class_replacements,
+            detected_events,
+            detected_messages_to_l1,
+            deploy_salt_base,
+            next_deploy_at_address,
+            block_info,
+            block_hash_contracts,
+            global_block_hash,
+            block_progression,
+            l1_messaging,
+        } = self.clone();
+
+        cheatnet_state.cheated_execution_info_contracts = cheated_execution_info_contracts;
+        cheatnet_state.global_cheated_execution_info = global_cheated_execution_info;
+        cheatnet_state.mocked_functions = mocked_functions;
+        cheatnet_state.mocked_calls = mocked_calls;
+        cheatnet_state.replaced_bytecode_contracts = replaced_bytecode_contracts;
+        cheatnet_state.cheated_class_hashes = cheated_class_hashes;
+        cheatnet_state.detected_class_replacements = detected_class_replacements;
+        cheatnet_state.detected_events = detected_events;
+        cheatnet_state.detected_messages_to_l1 = detected_messages_to_l1;
+        cheatnet_state.deploy_salt_base = deploy_salt_base;
+        cheatnet_state.next_deploy_at_address = next_deploy_at_address;
+        cheatnet_state.block_info = block_info;
+        cheatnet_state.block_hash_contracts = block_hash_contracts;
+        cheatnet_state.global_block_hash = global_block_hash;
+        cheatnet_state.block_progression = block_progression;
+        cheatnet_state.l1_messaging = l1_messaging;
+    }
+}
+
+impl FixtureSnapshot {
+    /// Captures all writes made to the `state`, including classes declared by the fixture,
+    /// together with the cheats set in the `cheatnet_state`.
+    pub fn capture(
+        state: &mut CachedState<ExtendedStateReader>,
+        cheatnet_state: &CheatnetState,
+        name: String,
+        result: Vec<Felt>,
+    ) -> StateResult<Self> {
+        let state_maps = state.to_state_diff()?.state_maps;
+        let classes = state_maps
+            .declared_contracts
+            .keys()
+            .chain(state_maps.compiled_class_hashes.keys())
+            .map(|class_hash| Ok((*class_hash, state.get_compiled_class(*class_hash)?)))
+            .collect::<StateResult<_>>()?;
+
+        Ok(Self {
+            name,
+            key: cheatnet_state.fixture_key.clone(),
+            result,
+            state_maps,
+            classes,
+            cheats: CheatsSnapshot::capture(cheatnet_state),
+        })
+    }
+
+    /// Returns the value returned by the fixture if it is the one identified by `key`.
+    /// Fixtures with the same name defined in different modules have different keys.
+    #[must_use]
+    pub fn result_of(&self, key: &str) -> Option<&[Felt]> {
+        (self.key.as_deref() == Some(key)).then_some(self.result.as_slice())
+    }
 }
 
 impl ExtendedStateReader {
-    pub fn predeploy_contracts(&mut self) {
+    pub fn predeploy_contracts(&mut self, rng: &mut StdRng) {
         // We consider contract as deployed solely based on the fact that the test used forking
         let is_fork = self.fork_state_reader.is_some();
         if !is_fork {
-            let contracts = vec![strk_predeployed_contract(), eth_predeployed_contract()];
+            let contracts = vec![
+                strk_predeployed_contract(rng),
+                eth_predeployed_contract(rng),
+            ];
             for contract in contracts {
                 self.predeploy_contract(contract);
             }
@@ -99,6 +252,13 @@ impl StateReader for ExtendedStateReader {
         contract_address: ContractAddress,
         key: StorageKey,
     ) -> StateResult<Felt> {
+        if let Some(value) = self
+            .fixture_snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.state_maps.storage.get(&(contract_address, key)))
+        {
+            return Ok(*value);
+        }
         self.dict_state_reader
             .get_storage_at(contract_address, key)
             .or_else(|_| {
@@ -111,6 +271,13 @@ impl StateReader for ExtendedStateReader {
     }
 
     fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
+        if let Some(nonce) = self
+            .fixture_snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.state_maps.nonces.get(&contract_address))
+        {
+            return Ok(*nonce);
+        }
         self.dict_state_reader
             .get_nonce_at(contract_address)
             .or_else(|_| {
@@ -123,6 +290,13 @@ impl StateReader for ExtendedStateReader {
     }
 
     fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
+        if let Some(class_hash) = self
+            .fixture_snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.state_maps.class_hashes.get(&contract_address))
+        {
+            return Ok(*class_hash);
+        }
         self.dict_state_reader
             .get_class_hash_at(contract_address)
             .or_else(|_| {
@@ -135,6 +309,13 @@ impl StateReader for ExtendedStateReader {
     }
 
     fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        if let Some(class) = self
+            .fixture_snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.classes.get(&class_hash))
+        {
+            return Ok(class.clone());
+        }
         self.dict_state_reader
             .get_compiled_class(class_hash)
             .or_else(|_| {
@@ -147,6 +328,13 @@ impl StateReader for ExtendedStateReader {
     }
 
     fn get_compiled_class_hash(&self, class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+        if let Some(compiled_class_hash) = self
+            .fixture_snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.state_maps.compiled_class_hashes.get(&class_hash))
+        {
+            return Ok(*compiled_class_hash);
+        }
         Ok(self
             .dict_state_reader
             .get_compiled_class_hash(class_hash)
@@ -236,7 +424,10 @@ pub struct CheatnetState {
 
     pub mocked_functions:
         HashMap<ContractAddress, HashMap<EntryPointSelector, CheatStatus<Vec<Felt>>>>,
+    pub mocked_calls: HashMap<ContractAddress, HashMap<EntryPointSelector, Vec<CalldataMock>>>,
     pub replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
+    pub cheated_class_hashes: HashMap<ContractAddress, CheatStatus<ClassHash>>,
+    pub detected_class_replacements: Vec<ClassReplacement>,
     pub detected_events: Vec<Event>,
     pub detected_messages_to_l1: Vec<MessageToL1>,
     pub deploy_salt_base: u32,
@@ -248,10 +439,89 @@ pub struct CheatnetState {
     pub fuzzer_args: Vec<String>,
     pub block_hash_contracts: HashMap<(ContractAddress, u64), (CheatSpan, Felt)>,
     pub global_block_hash: HashMap<u64, (Felt, Vec<ContractAddress>)>,
+    pub fork_class_conflicts: Vec<ForkClassConflict>,
+    /// Storage accesses in the order they were executed, recorded only if set to `Some`
+    pub recorded_storage_accesses: Option<Vec<StorageAccess>>,
+    pub block_progression: BlockProgression,
+    pub l1_messaging: L1Messaging,
+    pub rng: TestRng,
+    /// Fixture the test is set up with
+    pub fixture_snapshot: Option<Arc<FixtureSnapshot>>,
+    /// Key of the first fixture that requested its cached result in a run not set up with a fixture,
+    /// which is the fixture itself when it is run to be snapshotted
+    pub fixture_key: Option<String>,
+    /// Wall-clock limit of the test, shared with runtimes executing contract calls
+    pub deadline: Option<Deadline>,
+}
+
+/// Source of all randomness used by cheatcodes, derived from the seed of the test.
+#[derive(Debug)]
+pub struct TestRng {
+    seed: u64,
+    rng: StdRng,
+    observed: bool,
+}
+
+impl TestRng {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self::for_fuzzer_run(seed, 0)
+    }
+
+    /// Each fuzzer run gets different random values, derived from the seed of the test
+    /// and the index of the run.
+    /// The seed of the test is reported, so passing it with `--seed` reproduces every run.
+    #[must_use]
+    pub fn for_fuzzer_run(seed: u64, run: u32) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed.wrapping_add(u64::from(run))),
+            observed: false,
+        }
+    }
+
+    /// Returns the generator for values returned to the test, which makes the test result
+    /// depend on the seed.
+    pub fn observed(&mut self) -> &mut StdRng {
+        self.observed = true;
+        &mut self.rng
+    }
+
+    /// Returns the generator for values that are not returned to the test,
+    /// e.g. addresses of predeployed token holders.
+    pub fn unobserved(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    /// Returns the seed if the test received any random values.
+    #[must_use]
+    pub fn observed_seed(&self) -> Option<u64> {
+        self.observed.then_some(self.seed)
+    }
+}
+
+impl Default for TestRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
 }
 
 pub type EncounteredErrors = IndexMap<ClassHash, Vec<usize>>;
 
+/// Conflict between a locally compiled class and a class coming from the fork.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForkClassConflict {
+    /// Local contract was declared with a class hash that already exists on the fork,
+    /// but with a different Sierra class or one that can't be read. The class from the fork is used.
+    ClassHashCollision(ClassHash),
+    /// Class of a forked contract was replaced with `replace_bytecode`.
+    ShadowedForkedClass {
+        contract_address: ContractAddress,
+        forked_class_hash: ClassHash,
+        class_hash: ClassHash,
+    },
+}
+
 impl Default for CheatnetState {
     fn default() -> Self {
         let mut test_code_entry_point = build_test_entry_point();
@@ -265,7 +535,10 @@ impl Default for CheatnetState {
             cheated_execution_info_contracts: HashMap::default(),
             global_cheated_execution_info: ExecutionInfoMock::default(),
             mocked_functions: HashMap::default(),
+            mocked_calls: HashMap::default(),
             replaced_bytecode_contracts: HashMap::default(),
+            cheated_class_hashes: HashMap::default(),
+            detected_class_replacements: vec![],
             detected_events: vec![],
             detected_messages_to_l1: vec![],
             deploy_salt_base: 0,
@@ -280,6 +553,14 @@ impl Default for CheatnetState {
             fuzzer_args: Vec::default(),
             block_hash_contracts: HashMap::default(),
             global_block_hash: HashMap::default(),
+            fork_class_conflicts: Vec::default(),
+            recorded_storage_accesses: None,
+            block_progression: BlockProgression::default(),
+            l1_messaging: L1Messaging::default(),
+            rng: TestRng::default(),
+            fixture_snapshot: None,
+            fixture_key: None,
+            deadline: None,
         }
     }
 }
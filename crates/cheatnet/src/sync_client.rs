@@ -1,30 +1,86 @@
+use runtime::starknet::deadline::Deadline;
 use starknet_api::block::BlockNumber;
+use starknet_rust::core::types::requests::{GetClassHashAtRequest, GetNonceRequest};
 use starknet_rust::core::types::{
-    BlockId, ContractClass, GetStorageAtResult, MaybePreConfirmedBlockWithTxHashes,
+    BlockId, ContractClass, GetStorageAtResult, MaybePreConfirmedBlockWithTxHashes, Transaction,
+};
+use starknet_rust::providers::jsonrpc::{HttpTransport, HttpTransportError, JsonRpcClientError};
+use starknet_rust::providers::{
+    JsonRpcClient, Provider, ProviderError, ProviderImplError, ProviderRequestData,
+    ProviderResponseData,
 };
-use starknet_rust::providers::jsonrpc::HttpTransport;
-use starknet_rust::providers::{JsonRpcClient, Provider, ProviderError};
 use starknet_types_core::felt::Felt;
+use std::any::Any;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use thiserror::Error;
 use tokio::runtime::Runtime;
 use url::Url;
 
+pub struct ContractStateResponse {
+    pub class_hash: Felt,
+    pub nonce: Felt,
+}
+
 #[derive(Debug)]
 pub struct SyncClient {
     client: JsonRpcClient<HttpTransport>,
+    block_number: BlockNumber,
     block_id: BlockId,
     runtime: Runtime,
+    /// Whether any request failed in a way that may not happen again, e.g. because of a network error
+    request_failed: AtomicBool,
+    /// Deadline of the test, requests are not sent after it and are stopped once it passes
+    deadline: Option<Deadline>,
+}
+
+/// Request to the node was not sent or was stopped because the deadline of the test passed.
+#[derive(Debug, Error)]
+#[error("Test timed out after {}s while waiting for the fork node", .0.as_secs())]
+struct DeadlineReached(Duration);
+
+impl ProviderImplError for DeadlineReached {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Response of the node that does not match the request, e.g. of a different type.
+#[derive(Debug, Error)]
+#[error("Unexpected response of the fork node: {0}")]
+struct UnexpectedResponse(String);
+
+impl ProviderImplError for UnexpectedResponse {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 impl SyncClient {
     #[must_use]
-    pub fn new(url: Url, block_number: BlockNumber) -> Self {
+    pub fn new(url: Url, block_number: BlockNumber, deadline: Option<Deadline>) -> Self {
         Self {
             client: JsonRpcClient::new(HttpTransport::new(url)),
+            block_number,
             block_id: BlockId::Number(block_number.0),
             runtime: Runtime::new().expect("Could not instantiate Runtime"),
+            request_failed: AtomicBool::new(false),
+            deadline,
         }
     }
 
+    /// Whether any request failed because of the connection to the node or the node being overloaded,
+    /// so retrying it may succeed.
+    #[must_use]
+    pub fn request_failed(&self) -> bool {
+        self.request_failed.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn block_number(&self) -> BlockNumber {
+        self.block_number
+    }
+
     pub fn chain_id(&self) -> Result<Felt, ProviderError> {
         self.sync(self.client.chain_id())
     }
@@ -35,6 +91,34 @@ impl SyncClient {
         self.sync(self.client.get_block_with_tx_hashes(self.block_id))
     }
 
+    pub fn get_block_with_tx_hashes_at(
+        &self,
+        block_number: u64,
+    ) -> Result<MaybePreConfirmedBlockWithTxHashes, ProviderError> {
+        self.sync(
+            self.client
+                .get_block_with_tx_hashes(BlockId::Number(block_number)),
+        )
+    }
+
+    pub fn get_block_hash(&self, block_number: u64) -> Result<Felt, ProviderError> {
+        match self.get_block_with_tx_hashes_at(block_number)? {
+            MaybePreConfirmedBlockWithTxHashes::Block(block) => Ok(block.block_hash),
+            MaybePreConfirmedBlockWithTxHashes::PreConfirmedBlock(_) => {
+                Err(ProviderError::Other(Box::new(UnexpectedResponse(format!(
+                    "block {block_number} below the fork block is preconfirmed"
+                )))))
+            }
+        }
+    }
+
+    pub fn get_transaction_by_hash(
+        &self,
+        transaction_hash: Felt,
+    ) -> Result<Transaction, ProviderError> {
+        self.sync(self.client.get_transaction_by_hash(transaction_hash, None))
+    }
+
     pub fn get_storage_at(&self, contract_address: Felt, key: Felt) -> Result<Felt, ProviderError> {
         self.sync(
             self.client
@@ -63,7 +147,82 @@ impl SyncClient {
         self.sync(self.client.get_class(self.block_id, class_hash))
     }
 
-    fn sync<F: Future>(&self, future: F) -> F::Output {
-        self.runtime.block_on(future)
+    /// Fetches the class hash and nonce of a contract in a single batch request.
+    pub fn get_contract_state(
+        &self,
+        contract_address: Felt,
+    ) -> Result<ContractStateResponse, ProviderError> {
+        let requests = [
+            ProviderRequestData::GetClassHashAt(GetClassHashAtRequest {
+                block_id: self.block_id,
+                contract_address,
+            }),
+            ProviderRequestData::GetNonce(GetNonceRequest {
+                block_id: self.block_id,
+                contract_address,
+            }),
+        ];
+        let responses: [ProviderResponseData; 2] = self
+            .sync(self.client.batch_requests(requests))?
+            .try_into()
+            .map_err(|_| ProviderError::ArrayLengthMismatch)?;
+
+        match responses {
+            [
+                ProviderResponseData::GetClassHashAt(class_hash),
+                ProviderResponseData::GetNonce(nonce),
+            ] => Ok(ContractStateResponse { class_hash, nonce }),
+            responses => Err(ProviderError::Other(Box::new(UnexpectedResponse(format!(
+                "batch of class hash and nonce requests returned {responses:?}"
+            ))))),
+        }
+    }
+
+    fn sync<T>(
+        &self,
+        future: impl Future<Output = Result<T, ProviderError>>,
+    ) -> Result<T, ProviderError> {
+        let result = match &self.deadline {
+            // The request may take at most the time left until the deadline of the test
+            Some(deadline) => self.runtime.block_on(async {
+                let Some(remaining) = deadline.remaining() else {
+                    return Err(deadline_reached(deadline));
+                };
+                tokio::time::timeout(remaining, future)
+                    .await
+                    .unwrap_or_else(|_| {
+                        deadline.mark_reached();
+                        Err(deadline_reached(deadline))
+                    })
+            }),
+            None => self.runtime.block_on(future),
+        };
+        if let Err(error) = &result
+            && is_transient(error)
+        {
+            self.request_failed.store(true, Ordering::Relaxed);
+        }
+        result
+    }
+}
+
+fn deadline_reached(deadline: &Deadline) -> ProviderError {
+    ProviderError::Other(Box::new(DeadlineReached(deadline.timeout())))
+}
+
+/// Errors returned by the node for the request itself, e.g. `BlockNotFound`, would occur again.
+/// Only rate limiting, server errors and failures of the transport like timeouts are transient.
+fn is_transient(error: &ProviderError) -> bool {
+    match error {
+        ProviderError::RateLimited => true,
+        ProviderError::Other(error) => matches!(
+            error.as_any().downcast_ref::<JsonRpcClientError<HttpTransportError>>(),
+            Some(JsonRpcClientError::TransportError(HttpTransportError::Reqwest(error)))
+                if error.is_timeout()
+                    || error.is_connect()
+                    || error.is_request()
+                    || error.status().is_some_and(|status| status.is_server_error())
+        ),
+        _ => false,
     }
 }
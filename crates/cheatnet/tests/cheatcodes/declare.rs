@@ -1,22 +1,40 @@
 use crate::common::assertions::ClassHashAssert;
 use crate::common::{get_contracts, state::create_cached_state};
-#[cfg(feature = "cairo-native")]
 use blockifier::execution::contract_class::RunnableCompiledClass;
-#[cfg(feature = "cairo-native")]
 use blockifier::state::state_api::StateReader;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
+use cheatnet::forking::cache::cache_version;
+use cheatnet::forking::state::ForkStateReader;
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::CheatcodeError;
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::declare::{
-    DeclareResult, declare, declare_from_file,
+    DeclareError, DeclareResult, declare, declare_cairo0, declare_from_file,
+    declare_from_file_or_fail, declare_or_fail, declare_sierra, is_class_hash_collision,
 };
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
-use runtime::EnhancedHintError;
+use conversions::byte_array::ByteArray;
+use conversions::serde::deserialize::BufferReader;
+use regex::Regex;
+use serde_json::json;
 use shared::utils::contract_name_from_module_path;
+use starknet_api::block::BlockNumber;
 use starknet_api::core::ClassHash;
+use starknet_rust::core::types::ContractClass;
+use starknet_rust::core::types::contract::SierraClass;
 use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use tempfile::TempDir;
+use url::Url;
+
+fn unwrap_declare_error(output: Result<DeclareResult, CheatcodeError>) -> DeclareError {
+    match output {
+        Err(CheatcodeError::Recoverable(error_data)) => {
+            BufferReader::new(&error_data).read().unwrap()
+        }
+        _ => panic!("Expected a declare error"),
+    }
+}
 
 #[test]
 fn declare_simple() {
@@ -91,13 +109,10 @@ fn declare_non_existent() {
 
     let output = declare(&mut cached_state, contract_name, &contracts_data);
 
-    assert!(match output {
-        Err(CheatcodeError::Unrecoverable(EnhancedHintError::Anyhow(msg))) => {
-            let msg = msg.to_string();
-            msg.contains("Failed") && msg.contains(contract_name)
-        }
-        _ => false,
-    });
+    assert_eq!(
+        unwrap_declare_error(output),
+        DeclareError::ContractNotFound(ByteArray::from(contract_name))
+    );
 }
 
 #[test]
@@ -293,15 +308,21 @@ fn declare_ambiguous_name() {
 
     let output = declare(&mut cached_state, contract_name, &contracts_data);
 
-    assert!(match output {
-        Err(CheatcodeError::Unrecoverable(EnhancedHintError::Anyhow(msg))) => {
-            let msg = msg.to_string();
-            msg.contains("Multiple contracts found")
-                && msg.contains(contract_name)
-                && msg.contains(&duplicate_module_path)
-        }
-        _ => false,
-    });
+    let error = unwrap_declare_error(output);
+    let DeclareError::AmbiguousContractName {
+        identifier,
+        candidates,
+    } = &error
+    else {
+        panic!("Expected an ambiguous contract name error");
+    };
+    assert_eq!(identifier, &ByteArray::from(contract_name));
+    assert!(candidates.contains(&ByteArray::from(duplicate_module_path.as_str())));
+    assert!(
+        error
+            .to_string()
+            .starts_with("Multiple contracts found with identifier = HelloStarknet.")
+    );
 }
 
 #[test]
@@ -328,16 +349,50 @@ fn declare_ambiguous_partial_module_path() {
 
     let output = declare(&mut cached_state, "a::HelloStarknet", &contracts_data);
 
-    assert!(match output {
-        Err(CheatcodeError::Unrecoverable(EnhancedHintError::Anyhow(msg))) => {
-            let msg = msg.to_string();
-            msg.contains("Multiple contracts found")
-                && msg.contains("a::HelloStarknet")
-                && msg.contains(&nested_module_path)
-                && msg.contains(&other_module_path)
+    assert_eq!(
+        unwrap_declare_error(output),
+        DeclareError::AmbiguousContractName {
+            identifier: ByteArray::from("a::HelloStarknet"),
+            candidates: vec![
+                ByteArray::from(other_module_path.as_str()),
+                ByteArray::from(nested_module_path.as_str()),
+            ],
         }
-        _ => false,
-    });
+    );
+}
+
+#[test]
+fn declare_by_package_and_contract_name() {
+    let contract_name = "HelloStarknet";
+    let module_path = "dependency::nested::module::HelloStarknet".to_string();
+    let class_hash = ClassHash(Felt::from(123));
+
+    let mut cached_state = create_cached_state();
+
+    let mut contracts_data = get_contracts();
+    let mut contract = contracts_data
+        .contracts
+        .iter()
+        .find(|(module_path, _)| contract_name_from_module_path(module_path) == contract_name)
+        .map(|(_module_path, contract)| contract.clone())
+        .expect("HelloStarknet should be present in the test fixtures");
+    contract.class_hash = class_hash;
+    contracts_data.contracts.insert(module_path, contract);
+
+    let output = declare(
+        &mut cached_state,
+        "dependency::HelloStarknet",
+        &contracts_data,
+    )
+    .unwrap()
+    .unwrap_success();
+    assert_eq!(output, class_hash);
+
+    let output = declare(&mut cached_state, "other::HelloStarknet", &contracts_data);
+    assert_eq!(
+        unwrap_declare_error(output),
+        DeclareError::ContractNotFound(ByteArray::from("other::HelloStarknet"))
+    );
 }
 
 #[test]
@@ -491,14 +546,51 @@ fn declare_from_file_nonexistent_path() {
 
     let output = declare_from_file(&mut cached_state, sierra_path, &ContractsData::default());
 
-    assert!(match output {
-        Err(CheatcodeError::Unrecoverable(EnhancedHintError::Anyhow(msg))) => {
-            let msg = msg.to_string();
-            msg.contains("Failed to read Sierra file")
-                && msg.contains(sierra_path.to_str().unwrap())
-        }
-        _ => false,
-    });
+    assert_eq!(
+        unwrap_declare_error(output),
+        DeclareError::ContractNotFound(ByteArray::from(sierra_path.to_str().unwrap()))
+    );
+}
+
+#[test]
+fn declare_from_file_or_fail_nonexistent_path() {
+    let sierra_path = Path::new("non_existent.contract_class.json");
+
+    let mut cached_state = create_cached_state();
+
+    let error =
+        declare_from_file_or_fail(&mut cached_state, sierra_path, &ContractsData::default())
+            .unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .starts_with("Failed to read Sierra file at non_existent.contract_class.json:")
+    );
+}
+
+#[test]
+fn declare_or_fail_non_existent() {
+    let mut cached_state = create_cached_state();
+
+    let error =
+        declare_or_fail(&mut cached_state, "GoodbyeStarknet", &get_contracts()).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Failed to get contract artifact for identifier = GoodbyeStarknet."
+    );
+}
+
+#[test]
+fn declare_from_file_unreadable_path() {
+    let sierra_path = Path::new("tests/data");
+
+    let mut cached_state = create_cached_state();
+
+    let output = declare_from_file(&mut cached_state, sierra_path, &ContractsData::default());
+
+    assert!(matches!(output, Err(CheatcodeError::Unrecoverable(_))));
 }
 
 #[test]
@@ -509,12 +601,140 @@ fn declare_from_file_invalid_file() {
 
     let output = declare_from_file(&mut cached_state, sierra_path, &ContractsData::default());
 
-    assert!(match output {
-        Err(CheatcodeError::Unrecoverable(EnhancedHintError::Anyhow(msg))) => {
-            let msg = msg.to_string();
-            msg.contains("Failed to parse Sierra contract class JSON")
-                && msg.contains(sierra_path.to_str().unwrap())
-        }
-        _ => false,
+    assert!(matches!(
+        unwrap_declare_error(output),
+        DeclareError::InvalidClass(message)
+            if message.to_string().starts_with(
+                "Failed to parse Sierra contract class JSON at tests/data/invalid_contract_class.json:"
+            )
+    ));
+}
+
+#[test]
+fn declare_sierra_simple() {
+    let sierra = fs::read_to_string(
+        "tests/contracts/target/dev/cheatnet_testing_contracts_HelloStarknet.contract_class.json",
+    )
+    .unwrap();
+    let expected_class_hash = get_contracts()
+        .resolve_contract("HelloStarknet")
+        .unwrap()
+        .class_hash;
+
+    let mut cached_state = create_cached_state();
+
+    // Contract is compiled from the Sierra, as no package contracts are loaded
+    let class_hash = declare_sierra(&mut cached_state, &sierra, &ContractsData::default())
+        .unwrap()
+        .unwrap_success();
+    assert_eq!(class_hash, expected_class_hash);
+
+    let output = declare_sierra(&mut cached_state, &sierra, &ContractsData::default());
+    assert!(
+        matches!(output, Ok(DeclareResult::AlreadyDeclared(class_hash)) if class_hash == expected_class_hash)
+    );
+}
+
+#[test]
+fn declare_sierra_invalid_class() {
+    let mut cached_state = create_cached_state();
+
+    let output = declare_sierra(&mut cached_state, "{}", &ContractsData::default());
+
+    assert!(matches!(
+        unwrap_declare_error(output),
+        DeclareError::InvalidClass(message)
+            if message.to_string().contains("Failed to parse Sierra contract class JSON")
+    ));
+}
+
+#[test]
+fn declare_cairo0_invalid_class() {
+    let mut cached_state = create_cached_state();
+
+    let output = declare_cairo0(&mut cached_state, "{}");
+
+    assert!(matches!(
+        unwrap_declare_error(output),
+        DeclareError::InvalidClass(message)
+            if message.to_string().contains("Failed to parse Cairo 0 contract class JSON")
+    ));
+}
+
+#[test]
+fn declare_cairo0_simple() {
+    let compiled_class = fs::read_to_string("tests/data/cairo0_contract_class.json").unwrap();
+
+    let mut cached_state = create_cached_state();
+
+    let class_hash = declare_cairo0(&mut cached_state, &compiled_class)
+        .unwrap()
+        .unwrap_success();
+    assert!(matches!(
+        cached_state.get_compiled_class(class_hash).unwrap(),
+        RunnableCompiledClass::V0(_)
+    ));
+
+    let output = declare_cairo0(&mut cached_state, &compiled_class);
+    assert!(
+        matches!(output, Ok(DeclareResult::AlreadyDeclared(declared_class_hash)) if declared_class_hash == class_hash)
+    );
+}
+
+#[test]
+fn same_class_compiled_by_different_compiler_version_is_not_class_hash_collision() {
+    let mut contracts_data = get_contracts();
+    let class_hash = contracts_data
+        .resolve_contract("HelloStarknet")
+        .unwrap()
+        .class_hash;
+    let contract_data = contracts_data
+        .contracts
+        .values_mut()
+        .find(|contract_data| contract_data.class_hash == class_hash)
+        .unwrap();
+    // Local class compiled to a different CASM than the one compiled from the fork class
+    contract_data.artifacts.casm.compiler_version = "0.0.0".to_string();
+
+    let sierra_class: SierraClass = serde_json::from_str(&contract_data.artifacts.sierra).unwrap();
+    let fork_class = ContractClass::Sierra(sierra_class.flatten().unwrap());
+
+    // The fork is never queried, the class is read from the cache
+    let url = Url::parse("http://127.0.0.1:1/rpc").unwrap();
+    let block_number = 1;
+    let cache_dir = TempDir::new().unwrap();
+    let cache = json!({
+        "cache_version": cache_version(),
+        "storage_at": {},
+        "nonce_at": {},
+        "class_hash_at": {},
+        "compiled_contract_class": HashMap::from([(class_hash, fork_class)]),
+        "block_info": null,
     });
+    let sanitized_url = Regex::new(r"[^a-zA-Z0-9]")
+        .unwrap()
+        .replace_all(url.as_str(), "_");
+    fs::write(
+        cache_dir.path().join(format!(
+            "{sanitized_url}_{block_number}_v{}.json",
+            cache_version()
+        )),
+        cache.to_string(),
+    )
+    .unwrap();
+
+    let fork_state_reader = ForkStateReader::new(
+        url,
+        BlockNumber(block_number),
+        Utf8Path::from_path(cache_dir.path()).unwrap(),
+        None,
+    )
+    .unwrap();
+
+    assert!(!is_class_hash_collision(
+        &fork_state_reader,
+        class_hash,
+        &contracts_data
+    ));
+    assert!(!fork_state_reader.rpc_failed());
 }
@@ -2,15 +2,17 @@ use starknet_types_core::felt::Felt;
 use std::collections::HashSet;
 
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::generate_random_felt::generate_random_felt;
+use cheatnet::state::TestRng;
 
 #[test]
 fn test_generate_random_felt_range_and_uniqueness() {
+    let mut rng = TestRng::new(0);
     let mut random_values = vec![];
 
     let max_felt: Felt = Felt::MAX;
 
     for _ in 0..10 {
-        let random_value = generate_random_felt();
+        let random_value = generate_random_felt(rng.observed());
         assert!(random_value < max_felt, "Value out of range");
         random_values.push(random_value);
     }
@@ -21,3 +23,45 @@ fn test_generate_random_felt_range_and_uniqueness() {
         "Random values should not all be identical."
     );
 }
+
+#[test]
+fn test_generate_random_felt_deterministic_for_seed() {
+    let generate = |seed| {
+        let mut rng = TestRng::new(seed);
+        (0..10)
+            .map(|_| generate_random_felt(rng.observed()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(generate(1234), generate(1234));
+    assert_ne!(generate(1234), generate(4321));
+}
+
+#[test]
+fn test_seed_reported_only_when_observed() {
+    let mut rng = TestRng::new(1234);
+
+    let _ = generate_random_felt(rng.unobserved());
+    assert_eq!(rng.observed_seed(), None);
+
+    let _ = generate_random_felt(rng.observed());
+    assert_eq!(rng.observed_seed(), Some(1234));
+}
+
+#[test]
+fn test_fuzzer_runs_report_seed_of_test() {
+    let mut first_run = TestRng::for_fuzzer_run(1234, 0);
+    let mut second_run = TestRng::for_fuzzer_run(1234, 1);
+
+    assert_ne!(
+        generate_random_felt(first_run.observed()),
+        generate_random_felt(second_run.observed())
+    );
+    assert_eq!(second_run.observed_seed(), Some(1234));
+
+    // Passing the reported seed reproduces the values of the run
+    assert_eq!(
+        generate_random_felt(TestRng::for_fuzzer_run(1234, 1).observed()),
+        generate_random_felt(TestRng::for_fuzzer_run(1234, 1).observed())
+    );
+}
@@ -1,5 +1,5 @@
 use super::test_environment::TestEnvironment;
-use crate::common::assertions::ClassHashAssert;
+use crate::common::assertions::{ClassHashAssert, assert_panic};
 use crate::common::recover_data;
 use crate::common::state::create_cached_state;
 use crate::common::{call_contract, deploy};
@@ -7,7 +7,11 @@ use crate::{
     common::assertions::assert_success,
     common::{deploy_contract, get_contracts},
 };
+use blockifier::execution::syscalls::hint_processor::ENTRYPOINT_FAILED_ERROR_FELT;
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::declare::declare;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::{
+    CalldataMatcher, MockedResponse,
+};
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::storage::selector_from_name;
 use cheatnet::state::{CheatSpan, CheatnetState};
 use conversions::IntoConv;
@@ -758,3 +762,126 @@ fn mock_call_override_span() {
         &[111.into()],
     );
 }
+
+#[test]
+fn mock_call_when_matches_calldata() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = ContractAddress::from(218_u128);
+    let selector = selector_from_name("get_balance");
+
+    test_env.cheatnet_state.mock_call_when(
+        contract_address,
+        selector,
+        CalldataMatcher::Exact(vec![Felt::from(1), Felt::from(2)]),
+        vec![MockedResponse::Return(vec![Felt::from(12)])],
+    );
+    test_env.cheatnet_state.mock_call_when(
+        contract_address,
+        selector,
+        CalldataMatcher::Prefix(vec![Felt::from(1)]),
+        vec![MockedResponse::Return(vec![Felt::from(1)])],
+    );
+    test_env.cheatnet_state.mock_call_when(
+        contract_address,
+        selector,
+        CalldataMatcher::Any,
+        vec![MockedResponse::Return(vec![Felt::from(0)])],
+    );
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_balance", &[1.into(), 2.into()]),
+        &[Felt::from(12)],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_balance", &[1.into(), 3.into()]),
+        &[Felt::from(1)],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_balance", &[2.into()]),
+        &[Felt::from(0)],
+    );
+}
+
+#[test]
+fn mock_call_when_responses_queue() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("MockChecker", &[111.into()]);
+
+    test_env.cheatnet_state.mock_call_when(
+        contract_address,
+        selector_from_name("get_thing"),
+        CalldataMatcher::Any,
+        vec![
+            MockedResponse::Return(vec![Felt::from(222)]),
+            MockedResponse::Return(vec![Felt::from(333)]),
+        ],
+    );
+
+    for expected in [222, 333, 333] {
+        assert_success(
+            test_env.call_contract(&contract_address, "get_thing", &[]),
+            &[Felt::from(expected)],
+        );
+    }
+
+    test_env.stop_mock_call(&contract_address, "get_thing");
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[Felt::from(111)],
+    );
+}
+
+#[test]
+fn mock_call_when_revert() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = ContractAddress::from(218_u128);
+    let panic_data = vec![Felt::from_bytes_be_slice(b"mocked revert")];
+
+    test_env.cheatnet_state.mock_call_when(
+        contract_address,
+        selector_from_name("transfer"),
+        CalldataMatcher::Any,
+        vec![
+            MockedResponse::Revert(panic_data.clone()),
+            MockedResponse::Return(vec![Felt::from(1)]),
+        ],
+    );
+
+    assert_panic(
+        test_env.call_contract(&contract_address, "transfer", &[]),
+        &[panic_data[0], ENTRYPOINT_FAILED_ERROR_FELT],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "transfer", &[]),
+        &[Felt::from(1)],
+    );
+}
+
+#[test]
+fn mock_call_when_takes_precedence_over_mock_call() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("MockChecker", &[111.into()]);
+
+    test_env.mock_call(
+        &contract_address,
+        "get_thing",
+        &[222],
+        CheatSpan::Indefinite,
+    );
+    test_env.cheatnet_state.mock_call_when(
+        contract_address,
+        selector_from_name("get_thing"),
+        CalldataMatcher::Exact(vec![]),
+        vec![MockedResponse::Return(vec![Felt::from(333)])],
+    );
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_thing", &[]),
+        &[Felt::from(333)],
+    );
+}
@@ -9,6 +9,7 @@ pub fn create_cached_state() -> CachedState<ExtendedStateReader> {
     CachedState::new(ExtendedStateReader {
         dict_state_reader: build_testing_state(),
         fork_state_reader: None,
+        fixture_snapshot: None,
     })
 }
 
@@ -24,7 +25,9 @@ pub fn create_fork_cached_state_at(
     CachedState::new(ExtendedStateReader {
         dict_state_reader: build_testing_state(),
         fork_state_reader: Some(
-            ForkStateReader::new(node_url, BlockNumber(block_number), cache_dir.into()).unwrap(),
+            ForkStateReader::new(node_url, BlockNumber(block_number), cache_dir.into(), None)
+                .unwrap(),
         ),
+        fixture_snapshot: None,
     })
 }
@@ -4,6 +4,7 @@ use crate::common::state::{create_fork_cached_state, create_fork_cached_state_at
 use crate::common::{call_contract, deploy_contract};
 use blockifier::execution::syscalls::hint_processor::ENTRYPOINT_FAILED_ERROR_FELT;
 use blockifier::state::cached_state::CachedState;
+use blockifier::state::state_api::StateReader;
 use camino::Utf8Path;
 use cheatnet::constants::build_testing_state;
 use cheatnet::forking::cache::cache_version;
@@ -15,7 +16,8 @@ use conversions::string::TryFromHexStr;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use serde_json::Value;
 use starknet_api::block::BlockNumber;
-use starknet_api::core::ContractAddress;
+use starknet_api::core::{ClassHash, ContractAddress};
+use starknet_api::state::StorageKey;
 use starknet_types_core::felt::Felt;
 use tempfile::TempDir;
 
@@ -438,6 +440,87 @@ fn using_specified_block_nb_is_cached() {
     purge_cache(cache_dir.path().to_str().unwrap());
 }
 
+#[test]
+fn nonce_and_class_are_prefetched_with_class_hash() {
+    let cache_dir = TempDir::new().unwrap();
+    let contract_address = "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9";
+    let class_hash = "0x6a7eb29ee38b0a0b198e39ed6ad458d2e460264b463351a0acfc05822d61550";
+
+    {
+        let cached_state = create_fork_cached_state_at(53_669, cache_dir.path().to_str().unwrap());
+        let fetched_class_hash = cached_state
+            .state
+            .get_class_hash_at(ContractAddress::try_from_hex_str(contract_address).unwrap())
+            .unwrap();
+        assert_eq!(
+            fetched_class_hash,
+            ClassHash::try_from_hex_str(class_hash).unwrap()
+        );
+    }
+
+    let cache = read_cache(
+        cache_dir
+            .path()
+            .join(format!("*v{}.json", cache_version()))
+            .to_str()
+            .unwrap(),
+    );
+    assert_eq!(
+        cache["nonce_at"].as_object().unwrap()[contract_address],
+        "0x0"
+    );
+    match cache["compiled_contract_class"].as_object().unwrap()[class_hash] {
+        Value::Object(_) => {}
+        _ => panic!("The compiled_contract_class entry is not an object"),
+    }
+
+    purge_cache(cache_dir.path().to_str().unwrap());
+}
+
+#[test]
+fn block_hash_is_fetched_from_fork() {
+    let cache_dir = TempDir::new().unwrap();
+    let block_hash_contract_address = ContractAddress::from(1_u8);
+
+    let block_hash = {
+        let cached_state = create_fork_cached_state_at(54_060, cache_dir.path().to_str().unwrap());
+        let state = &cached_state.state;
+
+        let block_hash = state
+            .get_storage_at(
+                block_hash_contract_address,
+                StorageKey::try_from(Felt::from(54_000)).unwrap(),
+            )
+            .unwrap();
+        assert_ne!(block_hash, Felt::ZERO);
+
+        // Hashes of blocks within the last 10 blocks are not available
+        let recent_block_hash = state
+            .get_storage_at(
+                block_hash_contract_address,
+                StorageKey::try_from(Felt::from(54_055)).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(recent_block_hash, Felt::ZERO);
+
+        block_hash
+    };
+
+    let cache = read_cache(
+        cache_dir
+            .path()
+            .join(format!("*v{}.json", cache_version()))
+            .to_str()
+            .unwrap(),
+    );
+    assert_eq!(
+        cache["block_hash"].as_object().unwrap()["54000"],
+        block_hash.to_hex_string()
+    );
+
+    purge_cache(cache_dir.path().to_str().unwrap());
+}
+
 #[test]
 fn test_cache_merging() {
     fn run_test(cache_dir: &str, contract_address: &str, balance: u64) {
@@ -628,9 +711,11 @@ fn test_calling_nonexistent_url() {
                 nonexistent_url,
                 BlockNumber(1),
                 Utf8Path::from_path(temp_dir.path()).unwrap(),
+                None,
             )
             .unwrap(),
         ),
+        fixture_snapshot: None,
     });
 
     let mut cheatnet_state = CheatnetState::default();
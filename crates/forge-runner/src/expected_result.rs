@@ -2,6 +2,7 @@
 use cairo_lang_test_plugin::test_config::{PanicExpectation, TestExpectation};
 use serde::Deserialize;
 use starknet_types_core::felt::Felt;
+use std::fmt;
 
 /// Expectation for a panic case.
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -10,6 +11,38 @@ pub enum ExpectedPanicValue {
     Any,
     /// Accept only this specific vector of panics.
     Exact(Vec<Felt>),
+    /// Accept a serialized enum of this variant index with a payload of the same length,
+    /// whatever the values in the payload are.
+    Variant { index: Felt, length: usize },
+    /// Accept panic data containing a string that matches this regex.
+    Regex(String),
+    /// Accept panic data matching any of these expectations.
+    OneOf(Vec<ExpectedPanicValue>),
+    /// Accept panic data matching the expectation, if the panic originates from this place.
+    FromOrigin {
+        value: Box<ExpectedPanicValue>,
+        origin: PanicOrigin,
+    },
+}
+
+/// Contract and function a panic originates from, `None` matches any.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PanicOrigin {
+    pub contract: Option<String>,
+    pub function: Option<String>,
+}
+
+impl fmt::Display for PanicOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.contract, &self.function) {
+            (Some(contract), Some(function)) => {
+                write!(f, "contract `{contract}`, function `{function}`")
+            }
+            (Some(contract), None) => write!(f, "contract `{contract}`"),
+            (None, Some(function)) => write!(f, "function `{function}`"),
+            (None, None) => write!(f, "test body"),
+        }
+    }
 }
 
 impl From<PanicExpectation> for ExpectedPanicValue {
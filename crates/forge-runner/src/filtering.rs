@@ -1,4 +1,5 @@
 use crate::package_tests::TestCase;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NameFilter {
@@ -32,6 +33,147 @@ impl NameFilter {
     }
 }
 
+/// Boolean expression over tags of a test case, e.g. `fork & !slow` or `(unit | integration) & !slow`.
+///
+/// `!` binds tighter than `&`, which binds tighter than `|`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagExpression {
+    Tag(String),
+    Not(Box<TagExpression>),
+    And(Box<TagExpression>, Box<TagExpression>),
+    Or(Box<TagExpression>, Box<TagExpression>),
+}
+
+impl TagExpression {
+    #[must_use]
+    pub fn matches(&self, tags: &[String]) -> bool {
+        match self {
+            Self::Tag(tag) => tags.contains(tag),
+            Self::Not(expression) => !expression.matches(tags),
+            Self::And(left, right) => left.matches(tags) && right.matches(tags),
+            Self::Or(left, right) => left.matches(tags) || right.matches(tags),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TagToken {
+    Tag(String),
+    And,
+    Or,
+    Not,
+    OpenParen,
+    CloseParen,
+}
+
+/// Whether the character can be a part of a tag name
+#[must_use]
+pub fn is_tag_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+fn tokenize_tag_expression(s: &str) -> Result<Vec<TagToken>, String> {
+    let mut tokens = vec![];
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '&' => TagToken::And,
+            '|' => TagToken::Or,
+            '!' => TagToken::Not,
+            '(' => TagToken::OpenParen,
+            ')' => TagToken::CloseParen,
+            c if c.is_whitespace() => continue,
+            c if is_tag_char(c) => {
+                let mut tag = String::from(c);
+                while let Some(&c) = chars.peek().filter(|c| is_tag_char(**c)) {
+                    tag.push(c);
+                    chars.next();
+                }
+                TagToken::Tag(tag)
+            }
+            c => return Err(format!("Unexpected character `{c}` in tag expression")),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+struct TagExpressionParser {
+    tokens: Vec<TagToken>,
+    position: usize,
+}
+
+impl TagExpressionParser {
+    fn next_if(&mut self, token: &TagToken) -> bool {
+        let matches = self.tokens.get(self.position) == Some(token);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    fn parse_or(&mut self) -> Result<TagExpression, String> {
+        let mut expression = self.parse_and()?;
+        while self.next_if(&TagToken::Or) {
+            expression = TagExpression::Or(Box::new(expression), Box::new(self.parse_and()?));
+        }
+        Ok(expression)
+    }
+
+    fn parse_and(&mut self) -> Result<TagExpression, String> {
+        let mut expression = self.parse_not()?;
+        while self.next_if(&TagToken::And) {
+            expression = TagExpression::And(Box::new(expression), Box::new(self.parse_not()?));
+        }
+        Ok(expression)
+    }
+
+    fn parse_not(&mut self) -> Result<TagExpression, String> {
+        if self.next_if(&TagToken::Not) {
+            return Ok(TagExpression::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_atom()
+    }
+
+    fn parse_atom(&mut self) -> Result<TagExpression, String> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        match token {
+            Some(TagToken::Tag(tag)) => Ok(TagExpression::Tag(tag)),
+            Some(TagToken::OpenParen) => {
+                let expression = self.parse_or()?;
+                if self.next_if(&TagToken::CloseParen) {
+                    Ok(expression)
+                } else {
+                    Err("Unclosed parenthesis in tag expression".to_string())
+                }
+            }
+            Some(_) => Err("Expected a tag or `(` in tag expression".to_string()),
+            None => Err("Unexpected end of tag expression".to_string()),
+        }
+    }
+}
+
+impl FromStr for TagExpression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = TagExpressionParser {
+            tokens: tokenize_tag_expression(s)?,
+            position: 0,
+        };
+        let expression = parser.parse_or()?;
+
+        if parser.position < parser.tokens.len() {
+            return Err("Unexpected `)` or missing operator in tag expression".to_string());
+        }
+
+        Ok(expression)
+    }
+}
+
 /// Result of filtering a test case.
 #[derive(Debug)]
 pub enum FilterResult {
@@ -62,7 +204,7 @@ pub trait TestCaseIsIgnored {
 
 #[cfg(test)]
 mod tests {
-    use super::NameFilter;
+    use super::{NameFilter, TagExpression};
 
     #[test]
     fn name_filter_all_matches_everything() {
@@ -80,4 +222,60 @@ mod tests {
         assert!(NameFilter::ExactMatch("pkg::test".to_string()).matches("pkg::test"));
         assert!(!NameFilter::ExactMatch("pkg::test".to_string()).matches("pkg::test_case"));
     }
+
+    fn tags(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn tag_expression_single_tag() {
+        let expression: TagExpression = "slow".parse().unwrap();
+
+        assert_eq!(expression, TagExpression::Tag("slow".to_string()));
+        assert!(expression.matches(&tags(&["fork", "slow"])));
+        assert!(!expression.matches(&tags(&["fork"])));
+    }
+
+    #[test]
+    fn tag_expression_operator_precedence() {
+        let expression: TagExpression = "unit | fork & !slow".parse().unwrap();
+
+        assert!(expression.matches(&tags(&["unit", "slow"])));
+        assert!(expression.matches(&tags(&["fork"])));
+        assert!(!expression.matches(&tags(&["fork", "slow"])));
+        assert!(!expression.matches(&[]));
+    }
+
+    #[test]
+    fn tag_expression_parentheses() {
+        let expression: TagExpression = "(unit | fork) & !slow".parse().unwrap();
+
+        assert!(!expression.matches(&tags(&["unit", "slow"])));
+        assert!(expression.matches(&tags(&["unit"])));
+        assert!(!expression.matches(&tags(&["integration"])));
+    }
+
+    #[test]
+    fn tag_expression_invalid() {
+        assert_eq!(
+            "slow &".parse::<TagExpression>(),
+            Err("Unexpected end of tag expression".to_string())
+        );
+        assert_eq!(
+            "(slow | fork".parse::<TagExpression>(),
+            Err("Unclosed parenthesis in tag expression".to_string())
+        );
+        assert_eq!(
+            "slow fork".parse::<TagExpression>(),
+            Err("Unexpected `)` or missing operator in tag expression".to_string())
+        );
+        assert_eq!(
+            "slow, fork".parse::<TagExpression>(),
+            Err("Unexpected character `,` in tag expression".to_string())
+        );
+        assert_eq!(
+            "".parse::<TagExpression>(),
+            Err("Unexpected end of tag expression".to_string())
+        );
+    }
 }
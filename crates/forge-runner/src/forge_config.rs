@@ -1,6 +1,8 @@
 use crate::debugging::TraceArgs;
 use blockifier::execution::contract_class::TrackedResource;
 use camino::Utf8PathBuf;
+use cheatnet::predeployment::config::PredeploymentConfig;
+use cheatnet::predeployment::configured::PredeployedStateCache;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use clap::ValueEnum;
 use serde::Deserialize;
@@ -8,6 +10,7 @@ use std::collections::HashMap;
 use std::ffi::OsString;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Debug, PartialEq)]
 pub struct ForgeConfig {
@@ -22,13 +25,24 @@ pub struct TestRunnerConfig {
     pub deterministic_output: bool,
     pub fuzzer_runs: NonZeroU32,
     pub fuzzer_seed: u64,
+    /// Seed of the randomness used by cheatcodes, a random seed is used for each test if `None`
+    pub seed: Option<u64>,
     pub max_n_steps: Option<u32>,
+    /// Wall-clock limit of a single test, tests are not limited if `None`
+    pub timeout: Option<Duration>,
+    /// Number of times a failed test is rerun, can be overridden with `#[retry]`
+    pub retries: u32,
+    /// Failures after which tests are rerun
+    pub retry_on: RetryOn,
     pub is_vm_trace_needed: bool,
     pub cache_dir: Utf8PathBuf,
     pub contracts_data: ContractsData,
     pub environment_variables: HashMap<String, String>,
     pub tracked_resource: ForgeTrackedResource,
     pub launch_debugger: bool,
+    pub predeployment: PredeploymentConfig,
+    /// Contracts from `predeployment` deployed once and reused by all tests
+    pub predeployed_state: PredeployedStateCache,
 }
 
 #[derive(Debug, PartialEq)]
@@ -55,6 +69,17 @@ pub enum ForgeTrackedResource {
     SierraGas,
 }
 
+/// Failures after which a test is rerun when retries are enabled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Eq, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum RetryOn {
+    /// Only failures of fork tests caused by errors of requests to the RPC node
+    #[default]
+    ForkErrors,
+    /// Any failure
+    AllFailures,
+}
+
 impl From<&ForgeTrackedResource> for TrackedResource {
     fn from(m: &ForgeTrackedResource) -> Self {
         match m {
@@ -89,12 +114,15 @@ impl ExecutionDataToSave {
 /// [`TestRunnerConfig`] to another function.
 pub struct RuntimeConfig<'a> {
     pub max_n_steps: Option<u32>,
+    pub timeout: Option<Duration>,
     pub is_vm_trace_needed: bool,
     pub cache_dir: &'a Utf8PathBuf,
     pub contracts_data: &'a ContractsData,
     pub environment_variables: &'a HashMap<String, String>,
     pub tracked_resource: &'a ForgeTrackedResource,
     pub launch_debugger: bool,
+    pub predeployment: &'a PredeploymentConfig,
+    pub predeployed_state: &'a PredeployedStateCache,
 }
 
 impl<'a> RuntimeConfig<'a> {
@@ -102,12 +130,15 @@ impl<'a> RuntimeConfig<'a> {
     pub fn from(value: &'a TestRunnerConfig) -> RuntimeConfig<'a> {
         Self {
             max_n_steps: value.max_n_steps,
+            timeout: value.timeout,
             is_vm_trace_needed: value.is_vm_trace_needed,
             cache_dir: &value.cache_dir,
             contracts_data: &value.contracts_data,
             environment_variables: &value.environment_variables,
             tracked_resource: &value.tracked_resource,
             launch_debugger: value.launch_debugger,
+            predeployment: &value.predeployment,
+            predeployed_state: &value.predeployed_state,
         }
     }
 }
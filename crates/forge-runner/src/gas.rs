@@ -95,6 +95,8 @@ pub fn check_available_gas(
                 fuzzer_args: Vec::default(),
                 test_statistics: (),
                 debugging_trace,
+                after_each_failure: None,
+                fixture_failure: None,
             }
         }
         _ => summary,
@@ -1,7 +1,7 @@
-use crate::backtrace::{LazyContractBacktraceDataMapping, TestAnnotations};
 use crate::coverage_api::run_coverage;
-use crate::forge_config::{ExecutionDataToSave, ForgeConfig};
-use crate::running::{run_fuzz_test, run_test};
+use crate::forge_config::ExecutionDataToSave;
+use crate::running::retries::Retries;
+use crate::running::{TestCaseRunInput, run_fuzz_test, run_test, test_seed};
 use crate::test_case_summary::TestCaseSummary;
 use anyhow::{Result, bail, ensure};
 use build_trace_data::save_trace_data;
@@ -12,7 +12,6 @@ use foundry_ui::UI;
 use foundry_ui::components::warning::WarningMessage;
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
-use package_tests::with_config_resolved::TestCaseWithResolvedConfig;
 use profiler_api::run_profiler;
 use rand::SeedableRng;
 use rand::prelude::StdRng;
@@ -25,7 +24,6 @@ use std::sync::{Arc, Mutex};
 use test_case_summary::{AnyTestCaseSummary, Fuzzing};
 use tokio::sync::mpsc::{Sender, channel};
 use tokio::task::JoinHandle;
-use universal_sierra_compiler_api::representation::RawCasmProgram;
 
 pub mod backtrace;
 pub mod build_trace_data;
@@ -131,43 +129,20 @@ pub fn maybe_generate_coverage(
 #[must_use]
 #[tracing::instrument(skip_all, level = "debug")]
 pub fn run_for_test_case(
-    case: Arc<TestCaseWithResolvedConfig>,
-    casm_program: Arc<RawCasmProgram>,
-    forge_config: Arc<ForgeConfig>,
-    versioned_program_path: Arc<Utf8PathBuf>,
-    test_annotations: TestAnnotations,
-    contract_backtrace_mapping: Arc<LazyContractBacktraceDataMapping>,
+    input: TestCaseRunInput,
     send: Sender<()>,
 ) -> JoinHandle<Result<AnyTestCaseSummary>> {
-    if case.config.fuzzer_config.is_none() {
+    if input.case.config.fuzzer_config.is_none() {
         tokio::task::spawn(async move {
-            let res = run_test(
-                case,
-                casm_program,
-                forge_config,
-                versioned_program_path,
-                test_annotations,
-                contract_backtrace_mapping,
-                send,
-            )
-            .await?;
+            let res = run_test(input, send).await?;
             Ok(AnyTestCaseSummary::Single(res))
         })
     } else {
         tokio::task::spawn(async move {
-            if forge_config.test_runner_config.launch_debugger {
+            if input.forge_config.test_runner_config.launch_debugger {
                 bail!("--launch-debugger is not supported for fuzzer tests");
             }
-            let res = run_with_fuzzing(
-                case,
-                casm_program,
-                forge_config.clone(),
-                versioned_program_path,
-                test_annotations,
-                contract_backtrace_mapping,
-                send,
-            )
-            .await??;
+            let res = run_with_fuzzing(input, send).await??;
             Ok(AnyTestCaseSummary::Fuzzing(res))
         })
     }
@@ -175,22 +150,21 @@ pub fn run_for_test_case(
 
 #[tracing::instrument(skip_all, level = "debug")]
 fn run_with_fuzzing(
-    case: Arc<TestCaseWithResolvedConfig>,
-    casm_program: Arc<RawCasmProgram>,
-    forge_config: Arc<ForgeConfig>,
-    versioned_program_path: Arc<Utf8PathBuf>,
-    test_annotations: TestAnnotations,
-    contract_backtrace_mapping: Arc<LazyContractBacktraceDataMapping>,
+    input: TestCaseRunInput,
     send: Sender<()>,
 ) -> JoinHandle<Result<TestCaseSummary<Fuzzing>>> {
     tokio::task::spawn(async move {
+        let TestCaseRunInput {
+            case,
+            casm_program,
+            forge_config,
+            ..
+        } = &input;
         let test_runner_config = &forge_config.test_runner_config;
         if send.is_closed() {
             return Ok(TestCaseSummary::Interrupted {});
         }
 
-        let (fuzzing_send, mut fuzzing_rec) = channel(1);
-
         let (fuzzer_runs, fuzzer_seed) = match case.config.fuzzer_config {
             Some(RawFuzzerConfig { runs, seed }) => (
                 runs.unwrap_or(test_runner_config.fuzzer_runs),
@@ -202,63 +176,75 @@ fn run_with_fuzzing(
             ),
         };
 
-        let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(fuzzer_seed)));
+        // Each run derives its randomness from the seed and its index,
+        // so e.g. `generate_random_felt` returns different values
+        let seed = test_seed(case, test_runner_config);
 
-        let mut tasks = FuturesUnordered::new();
+        let program = case.try_into_program(casm_program)?;
+        let mut retries = Retries::new(case.config.retries.unwrap_or(test_runner_config.retries));
 
-        let program = case.try_into_program(&casm_program)?;
+        loop {
+            let (fuzzing_send, mut fuzzing_rec) = channel(1);
+            // Retried fuzzing starts from the same fuzzer seed, so it is run with the same arguments
+            let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(fuzzer_seed)));
 
-        for _ in 1..=fuzzer_runs.get() {
-            tasks.push(run_fuzz_test(
-                case.clone(),
-                program.clone(),
-                casm_program.clone(),
-                forge_config.clone(),
-                versioned_program_path.clone(),
-                test_annotations.clone(),
-                contract_backtrace_mapping.clone(),
-                send.clone(),
-                fuzzing_send.clone(),
-                rng.clone(),
-            ));
-        }
+            let mut tasks = FuturesUnordered::new();
+
+            for run in 0..fuzzer_runs.get() {
+                tasks.push(run_fuzz_test(
+                    input.clone(),
+                    program.clone(),
+                    send.clone(),
+                    fuzzing_send.clone(),
+                    rng.clone(),
+                    seed,
+                    run,
+                ));
+            }
 
-        let mut results = vec![];
-        while let Some(task) = tasks.next().await {
-            let result = task?;
+            let mut results = vec![];
+            let mut can_be_retried = false;
+            while let Some(task) = tasks.next().await {
+                let (result, result_can_be_retried) = task?;
 
-            results.push(result.clone());
+                results.push(result.clone());
 
-            if let TestCaseSummary::Failed { .. } = result {
-                fuzzing_rec.close();
-                break;
+                if let TestCaseSummary::Failed { .. } | TestCaseSummary::TimedOut { .. } = result {
+                    can_be_retried = result_can_be_retried;
+                    fuzzing_rec.close();
+                    break;
+                }
             }
-        }
 
-        let runs = u32::try_from(
-            results
-                .iter()
-                .filter(|item| {
-                    matches!(
-                        item,
-                        TestCaseSummary::Passed { .. } | TestCaseSummary::Failed { .. }
-                    )
-                })
-                .count(),
-        )?;
+            let runs = u32::try_from(
+                results
+                    .iter()
+                    .filter(|item| {
+                        matches!(
+                            item,
+                            TestCaseSummary::Passed { .. }
+                                | TestCaseSummary::Failed { .. }
+                                | TestCaseSummary::TimedOut { .. }
+                        )
+                    })
+                    .count(),
+            )?;
 
-        let fuzzing_run_summary: TestCaseSummary<Fuzzing> = TestCaseSummary::from(results);
+            let fuzzing_run_summary: TestCaseSummary<Fuzzing> = TestCaseSummary::from(results);
 
-        if let TestCaseSummary::Passed { .. } = fuzzing_run_summary {
-            // Because we execute tests parallel, it's possible to
-            // get Passed after Skipped. To treat fuzzing a test as Passed
-            // we have to ensure that all fuzzing subtests Passed
-            if runs != fuzzer_runs.get() {
-                return Ok(TestCaseSummary::Interrupted {});
+            if let TestCaseSummary::Passed { .. } = fuzzing_run_summary {
+                // Because we execute tests parallel, it's possible to
+                // get Passed after Skipped. To treat fuzzing a test as Passed
+                // we have to ensure that all fuzzing subtests Passed
+                if runs != fuzzer_runs.get() {
+                    return Ok(TestCaseSummary::Interrupted {});
+                }
             }
-        }
 
-        Ok(fuzzing_run_summary)
+            if let Some(summary) = retries.finish(fuzzing_run_summary, can_be_retried) {
+                return Ok(summary);
+            }
+        }
     })
 }
 
@@ -10,7 +10,10 @@ use serde_json::{Value, json};
 #[derive(Serialize)]
 enum TestResultStatus {
     Passed,
+    Flaky,
     Failed,
+    FixtureFailed,
+    TimedOut,
     Ignored,
     Interrupted,
     ExcludedFromPartition,
@@ -19,10 +22,24 @@ enum TestResultStatus {
 impl From<&AnyTestCaseSummary> for TestResultStatus {
     fn from(test_result: &AnyTestCaseSummary) -> Self {
         match test_result {
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed { retries: 1.., .. })
+            | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { retries: 1.., .. }) => {
+                Self::Flaky
+            }
             AnyTestCaseSummary::Single(TestCaseSummary::Passed { .. })
             | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { .. }) => Self::Passed,
+            AnyTestCaseSummary::Single(TestCaseSummary::Failed {
+                fixture_failure: Some(_),
+                ..
+            })
+            | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed {
+                fixture_failure: Some(_),
+                ..
+            }) => Self::FixtureFailed,
             AnyTestCaseSummary::Single(TestCaseSummary::Failed { .. })
             | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed { .. }) => Self::Failed,
+            AnyTestCaseSummary::Single(TestCaseSummary::TimedOut { .. })
+            | AnyTestCaseSummary::Fuzzing(TestCaseSummary::TimedOut { .. }) => Self::TimedOut,
             AnyTestCaseSummary::Single(TestCaseSummary::Ignored { .. })
             | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Ignored { .. }) => Self::Ignored,
             AnyTestCaseSummary::Single(TestCaseSummary::Interrupted { .. })
@@ -40,6 +57,8 @@ pub struct TestResultMessage {
     status: TestResultStatus,
     name: String,
     msg: Option<String>,
+    after_each_failure: Option<String>,
+    fixture_failure: Option<String>,
     debugging_trace: String,
     fuzzer_report: String,
     gas_usage: String,
@@ -74,6 +93,11 @@ impl TestResultMessage {
                     fuzzer_args,
                     test_statistics: FuzzingStatistics { runs },
                     ..
+                }
+                | TestCaseSummary::TimedOut {
+                    fuzzer_args,
+                    test_statistics: FuzzingStatistics { runs },
+                    ..
                 } => format!(" (runs: {runs}, arguments: {fuzzer_args:?})"),
                 _ => String::new(),
             }
@@ -110,11 +134,19 @@ impl TestResultMessage {
         };
 
         let msg = test_result.msg().map(std::string::ToString::to_string);
+        let after_each_failure = test_result
+            .after_each_failure()
+            .map(std::string::ToString::to_string);
+        let fixture_failure = test_result
+            .fixture_failure()
+            .map(std::string::ToString::to_string);
         let status = TestResultStatus::from(test_result);
         Self {
             status,
             name,
             msg,
+            after_each_failure,
+            fixture_failure,
             debugging_trace,
             fuzzer_report,
             gas_usage,
@@ -126,9 +158,14 @@ impl TestResultMessage {
     fn result_message(&self) -> String {
         if let Some(msg) = &self.msg {
             match self.status {
-                TestResultStatus::Passed => return format!("\n\n{msg}"),
-                TestResultStatus::Failed => return format!("\n\nFailure data:{msg}"),
-                TestResultStatus::Ignored
+                TestResultStatus::Passed | TestResultStatus::Flaky => {
+                    return format!("\n\n{msg}");
+                }
+                TestResultStatus::Failed | TestResultStatus::TimedOut => {
+                    return format!("\n\nFailure data:{msg}");
+                }
+                TestResultStatus::FixtureFailed
+                | TestResultStatus::Ignored
                 | TestResultStatus::Interrupted
                 | TestResultStatus::ExcludedFromPartition => return String::new(),
             }
@@ -136,10 +173,27 @@ impl TestResultMessage {
         String::new()
     }
 
+    fn fixture_message(&self) -> String {
+        self.fixture_failure
+            .as_ref()
+            .map(|failure| format!("\n\n{failure}"))
+            .unwrap_or_default()
+    }
+
+    fn after_each_message(&self) -> String {
+        self.after_each_failure
+            .as_ref()
+            .map(|failure| format!("\n\n{failure}"))
+            .unwrap_or_default()
+    }
+
     fn result_header(&self) -> String {
         match self.status {
             TestResultStatus::Passed => format!("[{}]", style("PASS").green()),
+            TestResultStatus::Flaky => format!("[{}]", style("FLAKY").yellow()),
             TestResultStatus::Failed => format!("[{}]", style("FAIL").red()),
+            TestResultStatus::FixtureFailed => format!("[{}]", style("FIXTURE FAIL").red()),
+            TestResultStatus::TimedOut => format!("[{}]", style("TIMEOUT").red()),
             TestResultStatus::Ignored => format!("[{}]", style("IGNORE").yellow()),
             TestResultStatus::Interrupted => {
                 unreachable!("Interrupted tests should not have visible message representation")
@@ -159,6 +213,8 @@ impl Message for TestResultMessage {
         let result_header = self.result_header();
 
         let result_msg = self.result_message();
+        let fixture_msg = self.fixture_message();
+        let after_each_msg = self.after_each_message();
         let result_debug_trace = &self.debugging_trace;
 
         let fuzzer_report = &self.fuzzer_report;
@@ -167,7 +223,7 @@ impl Message for TestResultMessage {
         let used_resources = &self.used_resources;
 
         format!(
-            "{result_header} {result_name}{fuzzer_report}{gas_usage}{used_resources}{result_msg}{result_debug_trace}{gas_report}"
+            "{result_header} {result_name}{fuzzer_report}{gas_usage}{used_resources}{result_msg}{fixture_msg}{after_each_msg}{result_debug_trace}{gas_report}"
         )
     }
 
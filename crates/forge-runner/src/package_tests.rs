@@ -100,6 +100,29 @@ pub struct TestTarget<C> {
     pub sierra_program_path: Arc<Utf8PathBuf>,
     pub casm_program: Arc<RawCasmProgram>,
     pub test_cases: Vec<TestCase<C>>,
+    pub fixtures: Vec<Fixture>,
+    pub hooks: Vec<TestHook>,
+}
+
+/// Function marked with `#[fixture]`, run once before the tests that are set up with it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixture {
+    pub name: String,
+    pub test_details: TestDetails,
+}
+
+/// Function marked with `#[before_each]` or `#[after_each]`, run around every test of its module
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestHook {
+    pub name: String,
+    pub kind: HookKind,
+    pub test_details: TestDetails,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookKind {
+    BeforeEach,
+    AfterEach,
 }
 
 #[derive(Debug, Clone, PartialEq)]
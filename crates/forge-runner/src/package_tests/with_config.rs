@@ -1,6 +1,6 @@
 use super::{TestCase, TestTarget};
 use crate::{
-    expected_result::{ExpectedPanicValue, ExpectedTestResult},
+    expected_result::{ExpectedPanicValue, ExpectedTestResult, PanicOrigin},
     filtering::TestCaseIsIgnored,
 };
 use cheatnet::runtime_extensions::forge_config_extension::config::{
@@ -9,6 +9,7 @@ use cheatnet::runtime_extensions::forge_config_extension::config::{
 };
 use conversions::serde::serialize::SerializeToFeltVec;
 use starknet_types_core::felt::Felt;
+use std::time::Duration;
 
 pub type TestTargetWithConfig = TestTarget<TestCaseConfig>;
 
@@ -24,6 +25,12 @@ pub struct TestCaseConfig {
     pub fork_config: Option<RawForkConfig>,
     pub fuzzer_config: Option<RawFuzzerConfig>,
     pub disable_predeployed_contracts: bool,
+    pub seed: Option<u64>,
+    pub setup: Option<String>,
+    pub timeout: Option<Duration>,
+    pub max_steps: Option<u32>,
+    pub retries: Option<u32>,
+    pub tags: Vec<String>,
 }
 
 impl TestCaseIsIgnored for TestCaseConfig {
@@ -43,6 +50,15 @@ impl From<RawForgeConfig> for TestCaseConfig {
             disable_predeployed_contracts: value
                 .disable_predeployed_contracts
                 .is_some_and(|v| v.is_disabled),
+            seed: value.seed.map(|v| v.seed),
+            setup: value.setup.map(|v| v.fixture.to_string()),
+            timeout: value.timeout.map(|v| Duration::from_secs(v.seconds)),
+            max_steps: value.max_steps.map(|v| v.max_steps),
+            retries: value.retry.map(|v| v.retries),
+            tags: value
+                .tags
+                .map(|v| v.tags.iter().map(ToString::to_string).collect())
+                .unwrap_or_default(),
         }
     }
 }
@@ -51,20 +67,55 @@ impl From<Option<RawShouldPanicConfig>> for ExpectedTestResult {
     fn from(value: Option<RawShouldPanicConfig>) -> Self {
         match value {
             None => Self::Success,
-            Some(RawShouldPanicConfig { expected }) => Self::Panics(match expected {
-                Expected::Any => ExpectedPanicValue::Any,
-                Expected::Array(arr) => ExpectedPanicValue::Exact(
-                    arr.into_iter()
-                        .flat_map(serialize_expected_tuple_item)
-                        .collect(),
-                ),
-                Expected::ByteArray(arr) => ExpectedPanicValue::Exact(arr.serialize_with_magic()),
-                Expected::ShortString(str) => ExpectedPanicValue::Exact(str.serialize_to_vec()),
-            }),
+            Some(RawShouldPanicConfig {
+                expected,
+                contract,
+                function,
+            }) => {
+                let mut alternatives: Vec<_> =
+                    expected.into_iter().map(expected_panic_value).collect();
+                let value = if alternatives.len() == 1 {
+                    alternatives.remove(0)
+                } else {
+                    ExpectedPanicValue::OneOf(alternatives)
+                };
+
+                Self::Panics(if contract.is_none() && function.is_none() {
+                    value
+                } else {
+                    ExpectedPanicValue::FromOrigin {
+                        value: Box::new(value),
+                        origin: PanicOrigin {
+                            contract: contract.map(|contract| contract.to_string()),
+                            function: function.map(|function| function.to_string()),
+                        },
+                    }
+                })
+            }
         }
     }
 }
 
+fn expected_panic_value(expected: Expected) -> ExpectedPanicValue {
+    match expected {
+        Expected::Any => ExpectedPanicValue::Any,
+        Expected::Array(arr) => ExpectedPanicValue::Exact(
+            arr.into_iter()
+                .flat_map(serialize_expected_tuple_item)
+                .collect(),
+        ),
+        Expected::ByteArray(arr) => ExpectedPanicValue::Exact(arr.serialize_with_magic()),
+        Expected::ShortString(str) => ExpectedPanicValue::Exact(str.serialize_to_vec()),
+        Expected::Serialized(data) => ExpectedPanicValue::Exact(data),
+        // Enums are serialized starting with the index of the variant
+        Expected::Variant(data) => ExpectedPanicValue::Variant {
+            index: data.first().copied().unwrap_or_default(),
+            length: data.len(),
+        },
+        Expected::Regex(pattern) => ExpectedPanicValue::Regex(pattern.to_string()),
+    }
+}
+
 fn serialize_expected_tuple_item(value: ExpectedTupleItem) -> Vec<Felt> {
     match value {
         ExpectedTupleItem::Felt(felt) => vec![felt],
@@ -83,13 +134,15 @@ mod tests {
         let byte_array_error = ByteArray::from("error");
         let byte_array_hello = ByteArray::from("hello");
         let expected = ExpectedTestResult::from(Some(RawShouldPanicConfig {
-            expected: Expected::Array(vec![
+            expected: vec![Expected::Array(vec![
                 ExpectedTupleItem::ByteArray(byte_array_error.clone()),
                 ExpectedTupleItem::Felt(Felt::from(11_u8)),
                 ExpectedTupleItem::ByteArray(byte_array_hello.clone()),
                 ExpectedTupleItem::Felt(Felt::from(5_u8)),
                 ExpectedTupleItem::Felt(Felt::from_bytes_be_slice(b"short_string")),
-            ]),
+            ])],
+            contract: None,
+            function: None,
         }));
 
         let mut expected_data = byte_array_error.serialize_with_magic();
@@ -108,7 +161,9 @@ mod tests {
     fn should_panic_standalone_string_uses_bytearray_magic() {
         let byte_array = ByteArray::from("error");
         let expected = ExpectedTestResult::from(Some(RawShouldPanicConfig {
-            expected: Expected::ByteArray(byte_array.clone()),
+            expected: vec![Expected::ByteArray(byte_array.clone())],
+            contract: None,
+            function: None,
         }));
 
         assert_eq!(
@@ -122,11 +177,13 @@ mod tests {
     #[test]
     fn should_panic_tuple_empty_string() {
         let expected = ExpectedTestResult::from(Some(RawShouldPanicConfig {
-            expected: Expected::Array(vec![
+            expected: vec![Expected::Array(vec![
                 ExpectedTupleItem::ByteArray(ByteArray::from("")),
                 ExpectedTupleItem::Felt(Felt::from(11_u8)),
                 ExpectedTupleItem::ByteArray(ByteArray::from("hello")),
-            ]),
+            ])],
+            contract: None,
+            function: None,
         }));
 
         let mut expected_data = ByteArray::from("").serialize_with_magic();
@@ -143,10 +200,12 @@ mod tests {
     fn should_panic_tuple_long_string() {
         let long_string = "this string is definitely longer than thirty one bytes";
         let expected = ExpectedTestResult::from(Some(RawShouldPanicConfig {
-            expected: Expected::Array(vec![
+            expected: vec![Expected::Array(vec![
                 ExpectedTupleItem::ByteArray(ByteArray::from(long_string)),
                 ExpectedTupleItem::Felt(Felt::from(5_u8)),
-            ]),
+            ])],
+            contract: None,
+            function: None,
         }));
 
         let mut expected_data = ByteArray::from(long_string).serialize_with_magic();
@@ -161,12 +220,47 @@ mod tests {
     #[test]
     fn should_panic_standalone_and_tuple_single_string_use_same_encoding() {
         let standalone = ExpectedTestResult::from(Some(RawShouldPanicConfig {
-            expected: Expected::ByteArray(ByteArray::from("error")),
+            expected: vec![Expected::ByteArray(ByteArray::from("error"))],
+            contract: None,
+            function: None,
         }));
         let tuple = ExpectedTestResult::from(Some(RawShouldPanicConfig {
-            expected: Expected::Array(vec![ExpectedTupleItem::ByteArray(ByteArray::from("error"))]),
+            expected: vec![Expected::Array(vec![ExpectedTupleItem::ByteArray(
+                ByteArray::from("error"),
+            )])],
+            contract: None,
+            function: None,
         }));
 
         assert_eq!(standalone, tuple);
     }
+
+    #[test]
+    fn should_panic_alternatives_with_origin() {
+        let expected = ExpectedTestResult::from(Some(RawShouldPanicConfig {
+            expected: vec![
+                Expected::Variant(vec![Felt::from(2_u8), Felt::from(100_u8)]),
+                Expected::Regex(ByteArray::from("^Insufficient")),
+            ],
+            contract: Some(ByteArray::from("HelloStarknet")),
+            function: None,
+        }));
+
+        assert_eq!(
+            expected,
+            ExpectedTestResult::Panics(ExpectedPanicValue::FromOrigin {
+                value: Box::new(ExpectedPanicValue::OneOf(vec![
+                    ExpectedPanicValue::Variant {
+                        index: Felt::from(2_u8),
+                        length: 2,
+                    },
+                    ExpectedPanicValue::Regex("^Insufficient".to_string()),
+                ])),
+                origin: PanicOrigin {
+                    contract: Some("HelloStarknet".to_string()),
+                    function: None,
+                },
+            })
+        );
+    }
 }
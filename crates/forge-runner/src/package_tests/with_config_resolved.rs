@@ -8,6 +8,7 @@ use cheatnet::runtime_extensions::forge_config_extension::config::{
     RawAvailableResourceBoundsConfig, RawFuzzerConfig,
 };
 use starknet_api::block::BlockNumber;
+use std::time::Duration;
 use universal_sierra_compiler_api::representation::RawCasmProgram;
 use url::Url;
 
@@ -17,9 +18,11 @@ pub type TestCaseWithResolvedConfig = TestCase<TestCaseResolvedConfig>;
 
 #[must_use]
 pub fn sanitize_test_case_name(name: &str) -> String {
-    // Test names generated by `#[test]` and `#[fuzzer]` macros contain internal suffixes
+    // Names generated by `#[test]`, `#[fuzzer]`, `#[fixture]` and hook macros contain internal suffixes
     name.replace("__snforge_internal_test_generated", "")
         .replace("__snforge_internal_fuzzer_generated", "")
+        .replace("__snforge_internal_fixture_generated", "")
+        .replace("__snforge_internal_hook_generated", "")
 }
 
 impl TestCaseWithResolvedConfig {
@@ -54,6 +57,17 @@ pub struct TestCaseResolvedConfig {
     pub fork_config: Option<ResolvedForkConfig>,
     pub fuzzer_config: Option<RawFuzzerConfig>,
     pub disable_predeployed_contracts: bool,
+    pub seed: Option<u64>,
+    /// Name of the `#[fixture]` the test is set up with
+    pub setup: Option<String>,
+    /// Wall-clock limit of the test set with `#[timeout]`
+    pub timeout: Option<Duration>,
+    /// Limit of steps of the test set with `#[max_steps]`
+    pub max_steps: Option<u32>,
+    /// Number of times the test is rerun after failing set with `#[retry]`
+    pub retries: Option<u32>,
+    /// Tags set with `#[tags]`, including the ones assigned automatically, e.g. `fork`
+    pub tags: Vec<String>,
 }
 
 impl TestCaseIsIgnored for TestCaseResolvedConfig {
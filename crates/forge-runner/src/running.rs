@@ -2,7 +2,7 @@ use crate::backtrace::{
     BacktraceSources, LazyContractBacktraceDataMapping, TestAnnotations, TestBacktraceContext,
     TestBacktraceOutcome, add_test_backtrace_footer, is_backtrace_enabled,
 };
-use crate::forge_config::{ForgeConfig, RuntimeConfig};
+use crate::forge_config::{ForgeConfig, RetryOn, RuntimeConfig, TestRunnerConfig};
 use crate::gas::calculate_used_gas;
 use crate::package_tests::with_config_resolved::{ResolvedForkConfig, TestCaseWithResolvedConfig};
 use crate::test_case_summary::{Single, TestCaseSummary};
@@ -19,7 +19,7 @@ use cairo_vm::Felt252;
 use cairo_vm::types::program::Program;
 use cairo_vm::vm::errors::cairo_run_errors::CairoRunError;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
-use cairo_vm::vm::runners::cairo_runner::CairoRunner;
+use cairo_vm::vm::runners::cairo_runner::{CairoRunner, ResourceTracker};
 use camino::{Utf8Path, Utf8PathBuf};
 use cheatnet::constants as cheatnet_constants;
 use cheatnet::forking::data::ForkData;
@@ -32,21 +32,31 @@ use cheatnet::runtime_extensions::forge_runtime_extension::{
 };
 use cheatnet::runtime_extensions::outer_call_runtime_extension::OuterCallExtension;
 use cheatnet::runtime_extensions::outer_call_runtime_extension::rpc::UsedResources;
-use cheatnet::state::{BlockInfoReader, CheatnetState, EncounteredErrors, ExtendedStateReader};
+use cheatnet::state::{
+    BlockInfoReader, CheatnetState, EncounteredErrors, ExtendedStateReader, FixtureSnapshot,
+    ForkClassConflict, TestRng,
+};
 use cheatnet::trace_data::CallTrace;
 use execution::finalize_execution;
+use fixture::FixtureUsage;
+use fork_class_conflicts::format_fork_class_conflicts;
 use hints::hints_by_representation;
+use hooks::{CaseHooks, HookContext, run_hooks};
 use rand::prelude::StdRng;
+use retries::Retries;
 use runtime::starknet::context::{build_context, set_max_steps};
+use runtime::starknet::deadline::Deadline;
 use runtime::{ExtendedRuntime, StarknetRuntime};
 use scarb_oracle_hint_service::OracleHintService;
 use shared::vm::VirtualMachineExt;
 use starknet_api::execution_resources::GasVector;
 use std::cell::RefCell;
 use std::default::Default;
+use std::fmt;
 use std::marker::PhantomData;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::sync::mpsc::Sender;
 use tokio::task::JoinHandle;
 use universal_sierra_compiler_api::representation::RawCasmProgram;
@@ -55,7 +65,11 @@ use cairo_debugger::CairoDebugger;
 
 pub mod config_run;
 mod execution;
+pub mod fixture;
+mod fork_class_conflicts;
 mod hints;
+pub mod hooks;
+pub(crate) mod retries;
 mod setup;
 mod syscall_handler;
 pub mod target;
@@ -66,18 +80,35 @@ use setup::VmExecutionContext;
 pub use syscall_handler::has_segment_arena;
 pub use syscall_handler::syscall_handler_offset;
 
+/// Everything needed to run a test case, shared by all runs of a fuzz test.
+#[derive(Clone)]
+pub struct TestCaseRunInput {
+    pub case: Arc<TestCaseWithResolvedConfig>,
+    pub casm_program: Arc<RawCasmProgram>,
+    pub forge_config: Arc<ForgeConfig>,
+    pub versioned_program_path: Arc<Utf8PathBuf>,
+    pub test_annotations: TestAnnotations,
+    pub contract_backtrace_mapping: Arc<LazyContractBacktraceDataMapping>,
+    /// State left by the fixture the test case is set up with
+    pub fixture_snapshot: Option<Arc<FixtureSnapshot>>,
+    pub hooks: Arc<CaseHooks>,
+}
+
 #[must_use]
 #[tracing::instrument(skip_all, level = "debug")]
-pub fn run_test(
-    case: Arc<TestCaseWithResolvedConfig>,
-    casm_program: Arc<RawCasmProgram>,
-    forge_config: Arc<ForgeConfig>,
-    versioned_program_path: Arc<Utf8PathBuf>,
-    test_annotations: TestAnnotations,
-    contract_backtrace_mapping: Arc<LazyContractBacktraceDataMapping>,
-    send: Sender<()>,
-) -> JoinHandle<TestCaseSummary<Single>> {
+pub fn run_test(input: TestCaseRunInput, send: Sender<()>) -> JoinHandle<TestCaseSummary<Single>> {
     tokio::task::spawn_blocking(move || {
+        let TestCaseRunInput {
+            case,
+            casm_program,
+            forge_config,
+            versioned_program_path,
+            test_annotations,
+            contract_backtrace_mapping,
+            fixture_snapshot,
+            hooks,
+        } = input;
+
         // Due to the inability of spawn_blocking to be abruptly cancelled,
         // a channel is used to receive information indicating
         // that the execution of the task is no longer necessary.
@@ -85,59 +116,93 @@ pub fn run_test(
             return TestCaseSummary::Interrupted {};
         }
 
-        let run_result = case.try_into_program(&casm_program).and_then(|program| {
-            run_test_case(
+        let test_runner_config = &forge_config.test_runner_config;
+        let seed = test_seed(&case, test_runner_config);
+        let fixture = fixture_snapshot.map(FixtureUsage::SetUp);
+        let mut retries = Retries::new(case.config.retries.unwrap_or(test_runner_config.retries));
+
+        loop {
+            let run_result = case.try_into_program(&casm_program).and_then(|program| {
+                run_test_case(
+                    &case,
+                    &program,
+                    &casm_program,
+                    &RuntimeConfig::from(test_runner_config),
+                    None,
+                    &CaseSetup {
+                        seed,
+                        fuzzer_run: 0,
+                        fixture: fixture.as_ref(),
+                        hooks: &hooks,
+                    },
+                    &versioned_program_path,
+                )
+            });
+
+            if send.is_closed() {
+                return TestCaseSummary::Interrupted {};
+            }
+
+            let can_be_retried = can_be_retried(test_runner_config.retry_on, &run_result);
+            let summary = extract_test_case_summary(
+                run_result,
                 &case,
-                &program,
-                &casm_program,
-                &RuntimeConfig::from(&forge_config.test_runner_config),
-                None,
+                &forge_config,
                 &versioned_program_path,
-            )
-        });
+                &test_annotations,
+                &contract_backtrace_mapping,
+            );
 
-        if send.is_closed() {
-            return TestCaseSummary::Interrupted {};
+            if let Some(summary) = retries.finish(summary, can_be_retried) {
+                return summary;
+            }
         }
-
-        extract_test_case_summary(
-            run_result,
-            &case,
-            &forge_config,
-            &versioned_program_path,
-            &test_annotations,
-            &contract_backtrace_mapping,
-        )
     })
 }
 
+/// Runs a single fuzzing run of the test case.
+/// Returns its summary together with whether its failure allows retrying the test case.
 #[tracing::instrument(skip_all, level = "debug")]
-#[allow(clippy::too_many_arguments)]
 pub(crate) fn run_fuzz_test(
-    case: Arc<TestCaseWithResolvedConfig>,
+    input: TestCaseRunInput,
     program: Program,
-    casm_program: Arc<RawCasmProgram>,
-    forge_config: Arc<ForgeConfig>,
-    versioned_program_path: Arc<Utf8PathBuf>,
-    test_annotations: TestAnnotations,
-    contract_backtrace_mapping: Arc<LazyContractBacktraceDataMapping>,
     send: Sender<()>,
     fuzzing_send: Sender<()>,
     rng: Arc<Mutex<StdRng>>,
-) -> JoinHandle<TestCaseSummary<Single>> {
+    seed: u64,
+    fuzzer_run: u32,
+) -> JoinHandle<(TestCaseSummary<Single>, bool)> {
     tokio::task::spawn_blocking(move || {
+        let TestCaseRunInput {
+            case,
+            casm_program,
+            forge_config,
+            versioned_program_path,
+            test_annotations,
+            contract_backtrace_mapping,
+            fixture_snapshot,
+            hooks,
+        } = input;
+
         // Due to the inability of spawn_blocking to be abruptly cancelled,
         // a channel is used to receive information indicating
         // that the execution of the task is no longer necessary.
         if send.is_closed() | fuzzing_send.is_closed() {
-            return TestCaseSummary::Interrupted {};
+            return (TestCaseSummary::Interrupted {}, false);
         }
+        let fixture = fixture_snapshot.map(FixtureUsage::SetUp);
         let run_result = run_test_case(
             &case,
             &program,
             &casm_program,
             &RuntimeConfig::from(&forge_config.test_runner_config),
             Some(rng),
+            &CaseSetup {
+                seed,
+                fuzzer_run,
+                fixture: fixture.as_ref(),
+                hooks: &hooks,
+            },
             &versioned_program_path,
         );
 
@@ -145,20 +210,97 @@ pub(crate) fn run_fuzz_test(
         // remove it after improve exit-first tests
         // issue #1043
         if send.is_closed() {
-            return TestCaseSummary::Interrupted {};
+            return (TestCaseSummary::Interrupted {}, false);
         }
 
-        extract_test_case_summary(
+        let can_be_retried = can_be_retried(forge_config.test_runner_config.retry_on, &run_result);
+        let summary = extract_test_case_summary(
             run_result,
             &case,
             &forge_config,
             &versioned_program_path,
             &test_annotations,
             &contract_backtrace_mapping,
-        )
+        );
+
+        (summary, can_be_retried)
     })
 }
 
+/// Whether a failure of the test case allows rerunning it with `retry_on` in effect.
+fn can_be_retried(retry_on: RetryOn, run_result: &Result<RunResult>) -> bool {
+    match retry_on {
+        RetryOn::AllFailures => true,
+        RetryOn::ForkErrors => is_fork_rpc_failure(run_result),
+    }
+}
+
+/// Whether the test failed because a request to the fork node failed.
+fn is_fork_rpc_failure(run_result: &Result<RunResult>) -> bool {
+    match run_result {
+        Ok(RunResult::Completed(run_completed)) => run_completed.fork_rpc_failed,
+        Ok(RunResult::Error(run_error)) => run_error.fork_rpc_failed,
+        Ok(RunResult::BeforeEachFailed(failed)) => failed.fork_rpc_failed,
+        Err(error) => error.downcast_ref::<ForkRpcFailure>().is_some(),
+    }
+}
+
+/// Error of setting up the test case caused by a failed request to the fork node,
+/// e.g. a network error, which may not occur when the test case is run again.
+#[derive(Debug)]
+struct ForkRpcFailure(anyhow::Error);
+
+impl fmt::Display for ForkRpcFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ForkRpcFailure {}
+
+/// Error of setting up the test case caused by reaching its timeout,
+/// e.g. while waiting for the fork node.
+#[derive(Debug)]
+struct SetupTimedOut(Duration);
+
+impl fmt::Display for SetupTimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Test timed out after {}s", self.0.as_secs())
+    }
+}
+
+impl std::error::Error for SetupTimedOut {}
+
+/// Marks the `error` as a [`SetupTimedOut`] if the `deadline` was reached,
+/// or as a [`ForkRpcFailure`] if a request of the `fork_state_reader` failed.
+fn mark_setup_failure(
+    error: impl Into<anyhow::Error>,
+    fork_state_reader: Option<&ForkStateReader>,
+    deadline: Option<&Deadline>,
+) -> anyhow::Error {
+    let error = error.into();
+    if let Some(deadline) = deadline.filter(|deadline| deadline.is_reached()) {
+        anyhow::Error::new(SetupTimedOut(deadline.timeout()))
+    } else if fork_state_reader.is_some_and(ForkStateReader::rpc_failed) {
+        anyhow::Error::new(ForkRpcFailure(error))
+    } else {
+        error
+    }
+}
+
+/// Seed of the randomness used by cheatcodes in the test.
+/// Seed from the test attribute takes precedence over the one passed with `--seed`,
+/// otherwise a random seed is used.
+pub(crate) fn test_seed(
+    case: &TestCaseWithResolvedConfig,
+    test_runner_config: &TestRunnerConfig,
+) -> u64 {
+    case.config
+        .seed
+        .or(test_runner_config.seed)
+        .unwrap_or_else(rand::random)
+}
+
 pub enum RunStatus {
     Success(Vec<Felt252>),
     Panic(Vec<Felt252>),
@@ -172,7 +314,16 @@ pub struct RunCompleted {
     pub(crate) encountered_errors: EncounteredErrors,
     pub(crate) fuzzer_args: Vec<String>,
     pub(crate) fork_data: Option<ForkData>,
+    pub(crate) fork_class_conflicts: Vec<ForkClassConflict>,
     pub(crate) test_backtrace: TestBacktraceOutcome,
+    /// Seed of the randomness used by cheatcodes, if the test received any random values
+    pub(crate) observed_seed: Option<u64>,
+    /// State left by the run, captured only for fixtures
+    pub(crate) fixture_snapshot: Option<FixtureSnapshot>,
+    /// Whether a request to the fork node failed during the run
+    pub(crate) fork_rpc_failed: bool,
+    /// Failure of an `#[after_each]` hook run after the test case
+    pub(crate) after_each_failure: Option<String>,
 }
 
 pub struct RunError {
@@ -181,12 +332,61 @@ pub struct RunError {
     pub(crate) encountered_errors: EncounteredErrors,
     pub(crate) fuzzer_args: Vec<String>,
     pub(crate) fork_data: Option<ForkData>,
+    pub(crate) fork_class_conflicts: Vec<ForkClassConflict>,
     pub(crate) test_backtrace: TestBacktraceOutcome,
+    /// Seed of the randomness used by cheatcodes, if the test received any random values
+    pub(crate) observed_seed: Option<u64>,
+    /// Per-test limit that stopped the execution, if any
+    pub(crate) exceeded_limit: Option<ExceededLimit>,
+    /// Number of steps executed before the error
+    pub(crate) n_steps: usize,
+    /// Whether a request to the fork node failed during the run
+    pub(crate) fork_rpc_failed: bool,
+}
+
+/// Per-test limit set with `#[timeout]` or `#[max_steps]` attributes or a default timeout.
+pub enum ExceededLimit {
+    Timeout(Duration),
+    Steps(u32),
+}
+
+impl ExceededLimit {
+    fn message(&self, n_steps: usize) -> String {
+        match self {
+            ExceededLimit::Timeout(timeout) => format!(
+                "\n    Test timed out after {}s, executed {n_steps} steps\n",
+                timeout.as_secs()
+            ),
+            ExceededLimit::Steps(max_steps) => {
+                format!("\n    Test exceeded the limit of {max_steps} steps\n")
+            }
+        }
+    }
+}
+
+/// Failure of a `#[before_each]` hook, the test case is not run then.
+pub struct BeforeEachFailed {
+    pub(crate) failure: String,
+    /// Seed of the randomness used by cheatcodes, if the hook received any random values
+    pub(crate) observed_seed: Option<u64>,
+    /// Whether a request to the fork node failed during the run
+    pub(crate) fork_rpc_failed: bool,
 }
 
 pub enum RunResult {
     Completed(Box<RunCompleted>),
     Error(Box<RunError>),
+    BeforeEachFailed(Box<BeforeEachFailed>),
+}
+
+/// State a single run of the test case starts with, besides the test case itself
+pub struct CaseSetup<'a> {
+    /// Seed of the randomness used by cheatcodes
+    pub seed: u64,
+    /// Index of the fuzzer run, 0 for tests without fuzzing
+    pub fuzzer_run: u32,
+    pub fixture: Option<&'a FixtureUsage>,
+    pub hooks: &'a CaseHooks,
 }
 
 #[expect(clippy::too_many_lines)]
@@ -197,8 +397,15 @@ pub fn run_test_case(
     casm_program: &RawCasmProgram,
     runtime_config: &RuntimeConfig,
     fuzzer_rng: Option<Arc<Mutex<StdRng>>>,
+    setup: &CaseSetup,
     versioned_program_path: &Utf8Path,
 ) -> Result<RunResult> {
+    let &CaseSetup {
+        seed,
+        fuzzer_run,
+        fixture,
+        hooks,
+    } = setup;
     let (call, entry_point) =
         setup::build_test_call_and_entry_point(&case.test_details, casm_program, program);
 
@@ -207,29 +414,125 @@ pub fn run_test_case(
         .then(|| CairoDebugger::connect_and_initialize(versioned_program_path.as_std_path()))
         .transpose()?;
 
+    let fixture_setup = match fixture {
+        Some(FixtureUsage::SetUp(snapshot)) => Some(snapshot.clone()),
+        Some(FixtureUsage::Snapshot(_)) | None => None,
+    };
+
+    // Started before the fork state reader is created, so waiting for the node counts towards it
+    let deadline = case
+        .config
+        .timeout
+        .or(runtime_config.timeout)
+        .map(Deadline::after);
+
     let mut state_reader = ExtendedStateReader {
         dict_state_reader: cheatnet_constants::build_testing_state(),
         fork_state_reader: get_fork_state_reader(
             runtime_config.cache_dir,
             case.config.fork_config.as_ref(),
+            deadline.as_ref(),
         )?,
+        fixture_snapshot: fixture_setup.clone(),
     };
 
-    if !case.config.disable_predeployed_contracts {
-        state_reader.predeploy_contracts();
+    let mut rng = TestRng::for_fuzzer_run(seed, fuzzer_run);
+    let predeploy_contracts = !case.config.disable_predeployed_contracts;
+    if predeploy_contracts && runtime_config.predeployment.tokens {
+        state_reader.predeploy_contracts(rng.unobserved());
     }
 
-    let block_info = state_reader.get_block_info()?;
-    let chain_id = state_reader.get_chain_id()?;
+    let block_info = state_reader.get_block_info().map_err(|error| {
+        mark_setup_failure(
+            error,
+            state_reader.fork_state_reader.as_ref(),
+            deadline.as_ref(),
+        )
+    })?;
+    let chain_id = state_reader.get_chain_id().map_err(|error| {
+        mark_setup_failure(
+            error,
+            state_reader.fork_state_reader.as_ref(),
+            deadline.as_ref(),
+        )
+    })?;
     let tracked_resource = TrackedResource::from(runtime_config.tracked_resource);
     let mut context = build_context(&block_info, chain_id, &tracked_resource);
 
-    if let Some(max_n_steps) = runtime_config.max_n_steps {
+    if let Some(max_n_steps) = case.config.max_steps.or(runtime_config.max_n_steps) {
         set_max_steps(&mut context, max_n_steps);
     }
+    let initial_n_steps = context.vm_run_resources.get_n_steps();
     let mut cached_state = CachedState::new(state_reader);
 
+    let predeployed_accounts = if predeploy_contracts {
+        runtime_config
+            .predeployed_state
+            .get_or_create(runtime_config.predeployment, runtime_config.contracts_data)?
+            .predeploy(&mut cached_state)
+            .map_err(|error| {
+                mark_setup_failure(
+                    error,
+                    cached_state.state.fork_state_reader.as_ref(),
+                    deadline.as_ref(),
+                )
+            })?
+    } else {
+        vec![]
+    };
+
     let hints = hints_by_representation(&casm_program.assembled_cairo_program);
+
+    let mut cheatnet_state = CheatnetState {
+        block_info,
+        rng,
+        deadline: deadline.clone(),
+        ..Default::default()
+    };
+    // Test continues with the cheats set by the fixture, deploying at addresses following its ones
+    if let Some(snapshot) = &fixture_setup {
+        snapshot.cheats.restore(&mut cheatnet_state);
+    }
+    cheatnet_state.fixture_snapshot = fixture_setup;
+    cheatnet_state.trace_data.is_vm_trace_needed = runtime_config.is_vm_trace_needed;
+
+    // Requests of cheatcodes go through the client of the fork state reader
+    let fork_state_reader = cached_state.state.fork_state_reader.clone();
+    let forge_extension = || ForgeExtension {
+        environment_variables: runtime_config.environment_variables,
+        contracts_data: runtime_config.contracts_data,
+        predeployed_accounts: predeployed_accounts.clone(),
+        fuzzer_rng: fuzzer_rng.clone(),
+        oracle_hint_service: OracleHintService::new(Some(versioned_program_path.as_std_path())),
+        fork_state_reader: fork_state_reader.clone(),
+    };
+    let hook_context = HookContext {
+        casm_program,
+        hints: &hints,
+        tracked_resource,
+        deadline: deadline.clone(),
+    };
+
+    // Hooks share the state of the test, the test is not run if a `#[before_each]` hook fails
+    if let Some(failure) = run_hooks(
+        &hooks.before_each,
+        &hook_context,
+        &mut cached_state,
+        &mut context,
+        &mut cheatnet_state,
+        forge_extension,
+    )? {
+        return Ok(RunResult::BeforeEachFailed(Box::new(BeforeEachFailed {
+            failure,
+            observed_seed: cheatnet_state.rng.observed_seed(),
+            fork_rpc_failed: cached_state
+                .state
+                .fork_state_reader
+                .as_ref()
+                .is_some_and(ForkStateReader::rpc_failed),
+        })));
+    }
+
     let VmExecutionContext {
         mut runner,
         syscall_handler,
@@ -247,12 +550,6 @@ pub fn run_test_case(
         runner.set_vm_hooks(Box::new(debugger));
     }
 
-    let mut cheatnet_state = CheatnetState {
-        block_info,
-        ..Default::default()
-    };
-    cheatnet_state.trace_data.is_vm_trace_needed = runtime_config.is_vm_trace_needed;
-
     let cheatable_runtime = ExtendedRuntime {
         extension: CheatableStarknetRuntimeExtension {
             cheatnet_state: &mut cheatnet_state,
@@ -260,6 +557,7 @@ pub fn run_test_case(
         extended_runtime: StarknetRuntime {
             hint_handler: syscall_handler,
             panic_traceback: None,
+            deadline: deadline.clone(),
         },
     };
 
@@ -269,15 +567,8 @@ pub fn run_test_case(
         },
         extended_runtime: cheatable_runtime,
     };
-    let forge_extension = ForgeExtension {
-        environment_variables: runtime_config.environment_variables,
-        contracts_data: runtime_config.contracts_data,
-        fuzzer_rng,
-        oracle_hint_service: OracleHintService::new(Some(versioned_program_path.as_std_path())),
-    };
-
     let mut forge_runtime = ExtendedRuntime {
-        extension: forge_extension,
+        extension: forge_extension(),
         extended_runtime: outer_call_runtime,
     };
 
@@ -387,13 +678,52 @@ pub fn run_test_case(
         .fuzzer_args
         .clone();
 
+    let fork_class_conflicts = forge_runtime
+        .extended_runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .fork_class_conflicts
+        .clone();
+
+    let observed_seed = forge_runtime
+        .extended_runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .rng
+        .observed_seed();
+
     let transaction_context = get_context(&forge_runtime).tx_context.clone();
 
+    let remaining_n_steps = get_context(&forge_runtime).vm_run_resources.get_n_steps();
+    let n_steps = initial_n_steps
+        .zip(remaining_n_steps)
+        .map_or(0, |(initial, remaining)| initial - remaining);
+    // The limit may be hit inside a contract call, which fails with a different error than
+    // `UnfinishedExecution` of the test body, so the limits themselves are checked
+    let exceeded_limit = match &result {
+        Err(_) => deadline
+            .filter(Deadline::is_reached)
+            .map(|deadline| ExceededLimit::Timeout(deadline.timeout()))
+            .or(case
+                .config
+                .max_steps
+                .filter(|_| remaining_n_steps == Some(0))
+                .map(ExceededLimit::Steps)),
+        Ok(_) => None,
+    };
+
     let fork_data = cached_state
         .state
         .fork_state_reader
         .as_ref()
-        .map(|fork_state_reader| ForkData::new(&fork_state_reader.compiled_contract_class_map()));
+        .map(ForkStateReader::fork_data);
+    let fork_rpc_failed = cached_state
+        .state
+        .fork_state_reader
+        .as_ref()
+        .is_some_and(ForkStateReader::rpc_failed);
 
     Ok(match result {
         Ok(result) => {
@@ -401,6 +731,27 @@ pub fn run_test_case(
                 get_all_used_resources(&result, &call_trace_ref, &transaction_context);
             let gas_used =
                 calculate_used_gas(&transaction_context, &mut cached_state, &used_resources)?;
+            let fixture_snapshot = match fixture {
+                Some(FixtureUsage::Snapshot(name)) if !result.execution.failed => {
+                    Some(FixtureSnapshot::capture(
+                        &mut cached_state,
+                        &cheatnet_state,
+                        name.clone(),
+                        result.execution.retdata.0.clone(),
+                    )?)
+                }
+                _ => None,
+            };
+            // `#[after_each]` hooks run only if the test body completed, even if it panicked.
+            // Execution stopped by an error or an exceeded limit, e.g. a timeout, skips them
+            let after_each_failure = run_hooks(
+                &hooks.after_each,
+                &hook_context,
+                &mut cached_state,
+                &mut context,
+                &mut cheatnet_state,
+                forge_extension,
+            )?;
 
             RunResult::Completed(Box::new(RunCompleted {
                 status: if result.execution.failed {
@@ -414,7 +765,12 @@ pub fn run_test_case(
                 encountered_errors,
                 fuzzer_args,
                 fork_data,
+                fork_class_conflicts,
                 test_backtrace,
+                observed_seed,
+                fixture_snapshot,
+                fork_rpc_failed,
+                after_each_failure,
             }))
         }
         Err(error) => RunResult::Error(Box::new(RunError {
@@ -423,7 +779,12 @@ pub fn run_test_case(
             encountered_errors,
             fuzzer_args,
             fork_data,
+            fork_class_conflicts,
             test_backtrace,
+            observed_seed,
+            exceeded_limit,
+            n_steps,
+            fork_rpc_failed,
         })),
     })
 }
@@ -473,6 +834,7 @@ fn capture_test_backtrace(
     TestBacktraceOutcome::Panic(context)
 }
 
+#[expect(clippy::too_many_lines)]
 fn extract_test_case_summary(
     run_result: Result<RunResult>,
     case: &TestCaseWithResolvedConfig,
@@ -487,7 +849,20 @@ fn extract_test_case_summary(
         test_annotations,
         contract_backtrace_mapping,
     };
-    match run_result {
+    let fork_class_conflicts = match &run_result {
+        Ok(RunResult::Completed(run_completed)) => &run_completed.fork_class_conflicts[..],
+        Ok(RunResult::Error(run_error)) => &run_error.fork_class_conflicts[..],
+        Ok(RunResult::BeforeEachFailed(_)) | Err(_) => &[],
+    };
+    let fork_class_warnings = format_fork_class_conflicts(fork_class_conflicts, contracts_data);
+    let observed_seed = match &run_result {
+        Ok(RunResult::Completed(run_completed)) => run_completed.observed_seed,
+        Ok(RunResult::Error(run_error)) => run_error.observed_seed,
+        Ok(RunResult::BeforeEachFailed(failed)) => failed.observed_seed,
+        Err(_) => None,
+    };
+
+    let mut summary = match run_result {
         Ok(run_result) => match run_result {
             RunResult::Completed(run_completed) => TestCaseSummary::from_run_completed(
                 *run_completed,
@@ -498,6 +873,39 @@ fn extract_test_case_summary(
                 trace_args,
                 forge_config.output_config.gas_report,
             ),
+            RunResult::Error(run_error) if run_error.exceeded_limit.is_some() => {
+                let message = run_error
+                    .exceeded_limit
+                    .as_ref()
+                    .expect("exceeded limit is checked in the match guard")
+                    .message(run_error.n_steps);
+                TestCaseSummary::TimedOut {
+                    name: case.name.clone(),
+                    msg: Some(add_test_backtrace_footer(
+                        message,
+                        contracts_data,
+                        &run_error.encountered_errors,
+                        &run_error.test_backtrace,
+                        &case.name,
+                        &backtrace_sources,
+                    )),
+                    fuzzer_args: run_error.fuzzer_args,
+                    test_statistics: (),
+                    debugging_trace: trace_args.to_components().map(|components| {
+                        build_debugging_trace(
+                            &run_error.call_trace.borrow(),
+                            components,
+                            case.name.clone(),
+                            build_contracts_data_store(
+                                contracts_data,
+                                run_error.fork_data.as_ref(),
+                                case.config.disable_predeployed_contracts,
+                            ),
+                        )
+                    }),
+                    n_steps: run_error.n_steps,
+                }
+            }
             RunResult::Error(run_error) => {
                 let mut message = format!(
                     "\n    {}\n",
@@ -539,10 +947,35 @@ fn extract_test_case_summary(
                             ),
                         )
                     }),
+                    after_each_failure: None,
+                    fixture_failure: None,
                 }
             }
+            RunResult::BeforeEachFailed(failed) => TestCaseSummary::Failed {
+                name: case.name.clone(),
+                msg: Some(format!("\n    {}\n", failed.failure)),
+                fuzzer_args: Vec::default(),
+                test_statistics: (),
+                debugging_trace: None,
+                after_each_failure: None,
+                fixture_failure: None,
+            },
         },
-        // `ForkStateReader.get_block_info`, `get_fork_state_reader, `calculate_used_gas` may return an error
+        Err(error) if error.is::<SetupTimedOut>() => {
+            let SetupTimedOut(timeout) = error
+                .downcast_ref()
+                .expect("error type is checked in the match guard");
+            TestCaseSummary::TimedOut {
+                name: case.name.clone(),
+                msg: Some(ExceededLimit::Timeout(*timeout).message(0)),
+                debugging_trace: None,
+                fuzzer_args: Vec::default(),
+                test_statistics: (),
+                n_steps: 0,
+            }
+        }
+        // `ForkStateReader.get_block_info`, `get_fork_state_reader, `calculate_used_gas`,
+        // `PredeployedState::predeploy` may return an error
         // `available_gas` may be specified with Scarb ~2.4
         Err(error) => TestCaseSummary::Failed {
             name: case.name.clone(),
@@ -550,17 +983,36 @@ fn extract_test_case_summary(
             fuzzer_args: Vec::default(),
             test_statistics: (),
             debugging_trace: None,
+            after_each_failure: None,
+            fixture_failure: None,
         },
+    };
+
+    if let Some(warnings) = fork_class_warnings {
+        summary.append_to_msg(&warnings);
+    }
+    if let Some(seed) = observed_seed.filter(|_| {
+        matches!(
+            summary,
+            TestCaseSummary::Failed { .. } | TestCaseSummary::TimedOut { .. }
+        )
+    }) {
+        summary.append_to_msg(&format!(
+            "Random values were generated with seed {seed}, use `--seed {seed}` to reproduce them"
+        ));
     }
+
+    summary
 }
 
 fn get_fork_state_reader(
     cache_dir: &Utf8Path,
     fork_config: Option<&ResolvedForkConfig>,
+    deadline: Option<&Deadline>,
 ) -> Result<Option<ForkStateReader>> {
     fork_config
         .map(|ResolvedForkConfig { url, block_number }| {
-            ForkStateReader::new(url.clone(), *block_number, cache_dir)
+            ForkStateReader::new(url.clone(), *block_number, cache_dir, deadline.cloned())
         })
         .transpose()
 }
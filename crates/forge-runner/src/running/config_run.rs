@@ -111,6 +111,7 @@ pub fn run_config_pass(
         extended_runtime: StarknetRuntime {
             hint_handler: syscall_handler,
             panic_traceback: None,
+            deadline: None,
         },
     };
 
@@ -0,0 +1,231 @@
+use super::hooks::CaseHooks;
+use super::{CaseSetup, RunCompleted, RunResult, RunStatus, run_test_case};
+use crate::expected_result::ExpectedTestResult;
+use crate::forge_config::{ForgeConfig, RuntimeConfig};
+use crate::package_tests::Fixture;
+use crate::package_tests::with_config_resolved::{
+    TestCaseResolvedConfig, TestCaseWithResolvedConfig,
+};
+use crate::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use anyhow::Result;
+use camino::{Utf8Path, Utf8PathBuf};
+use cheatnet::state::FixtureSnapshot;
+use shared::utils::build_readable_text;
+use std::collections::HashMap;
+use std::sync::Arc;
+use universal_sierra_compiler_api::representation::RawCasmProgram;
+
+/// Role of a `#[fixture]` in a run
+pub enum FixtureUsage {
+    /// Test starts from the state left by the fixture
+    SetUp(Arc<FixtureSnapshot>),
+    /// Run is the fixture itself, the state it leaves is snapshotted under the given name
+    Snapshot(String),
+}
+
+/// Fixtures of a test target, run before the tests that are set up with them.
+/// Holds the snapshot, or the reason of failure, for every test case that requested a fixture.
+#[derive(Default)]
+pub struct FixtureSetups {
+    setups: HashMap<String, Result<Arc<FixtureSnapshot>, String>>,
+}
+
+impl FixtureSetups {
+    /// Runs every fixture the `test_cases` are set up with exactly once, fixtures run in parallel.
+    pub async fn run<'a>(
+        fixtures: &[Fixture],
+        test_cases: impl IntoIterator<Item = &'a TestCaseWithResolvedConfig>,
+        casm_program: &Arc<RawCasmProgram>,
+        forge_config: &Arc<ForgeConfig>,
+        versioned_program_path: &Arc<Utf8PathBuf>,
+    ) -> Result<Self> {
+        let mut resolved = HashMap::new();
+        let mut tasks = HashMap::new();
+
+        for case in test_cases {
+            let Some(setup) = &case.config.setup else {
+                continue;
+            };
+
+            let fixture_name = resolve_fixture(fixtures, case, setup).map(|fixture| {
+                tasks.entry(fixture.name.clone()).or_insert_with(|| {
+                    let fixture = fixture.clone();
+                    let casm_program = casm_program.clone();
+                    let forge_config = forge_config.clone();
+                    let versioned_program_path = versioned_program_path.clone();
+
+                    tokio::task::spawn_blocking(move || {
+                        run_fixture(
+                            &fixture,
+                            &casm_program,
+                            &forge_config,
+                            &versioned_program_path,
+                        )
+                    })
+                });
+                fixture.name.clone()
+            });
+            resolved.insert(case.name.clone(), fixture_name);
+        }
+
+        let mut snapshots = HashMap::new();
+        for (name, task) in tasks {
+            snapshots.insert(name, task.await?);
+        }
+
+        let setups = resolved
+            .into_iter()
+            .map(|(case_name, fixture_name)| {
+                let setup = fixture_name.and_then(|fixture_name| snapshots[&fixture_name].clone());
+                (case_name, setup)
+            })
+            .collect();
+
+        Ok(Self { setups })
+    }
+
+    /// Returns the snapshot the test case starts from, if the test case is set up with a fixture.
+    /// Returns the summary of the failed test case if the fixture could not be run.
+    pub fn snapshot_for(
+        &self,
+        case: &TestCaseWithResolvedConfig,
+    ) -> Result<Option<Arc<FixtureSnapshot>>, AnyTestCaseSummary> {
+        match self.setups.get(&case.name) {
+            None => Ok(None),
+            Some(Ok(snapshot)) => Ok(Some(snapshot.clone())),
+            Some(Err(message)) => Err(AnyTestCaseSummary::Single(TestCaseSummary::Failed {
+                name: case.name.clone(),
+                msg: None,
+                debugging_trace: None,
+                fuzzer_args: Vec::default(),
+                test_statistics: (),
+                after_each_failure: None,
+                fixture_failure: Some(message.clone()),
+            })),
+        }
+    }
+}
+
+/// Finds the fixture named `setup`, preferring the one defined in the module of the test case.
+fn resolve_fixture<'a>(
+    fixtures: &'a [Fixture],
+    case: &TestCaseWithResolvedConfig,
+    setup: &str,
+) -> Result<&'a Fixture, String> {
+    if case.config.fork_config.is_some() {
+        return Err(format!(
+            "Fixture `{setup}` cannot be used, tests set up with a fixture cannot be forked"
+        ));
+    }
+
+    let suffix = format!("::{setup}");
+    let candidates: Vec<_> = fixtures
+        .iter()
+        .filter(|fixture| fixture.name == setup || fixture.name.ends_with(&suffix))
+        .collect();
+
+    match candidates.as_slice() {
+        [] => Err(format!(
+            "Fixture `{setup}` not found, make sure it is marked with #[fixture]"
+        )),
+        [fixture] => Ok(*fixture),
+        _ => {
+            let module = case.name.rsplit_once("::").map_or("", |(module, _)| module);
+            let local_name = format!("{module}{suffix}");
+
+            candidates
+                .iter()
+                .find(|fixture| fixture.name == local_name)
+                .copied()
+                .ok_or_else(|| {
+                    let names: Vec<_> = candidates
+                        .iter()
+                        .map(|fixture| format!("`{}`", fixture.name))
+                        .collect();
+                    format!(
+                        "Fixture `{setup}` is ambiguous, use a path to one of: {}",
+                        names.join(", ")
+                    )
+                })
+        }
+    }
+}
+
+/// Runs the fixture and snapshots the state it leaves.
+/// Returns the message reported for tests set up with the fixture if it fails.
+fn run_fixture(
+    fixture: &Fixture,
+    casm_program: &RawCasmProgram,
+    forge_config: &ForgeConfig,
+    versioned_program_path: &Utf8Path,
+) -> Result<Arc<FixtureSnapshot>, String> {
+    let failed = |reason: String| format!("Fixture `{}` failed:{reason}", fixture.name);
+
+    let case = TestCaseWithResolvedConfig::new(
+        &fixture.name,
+        fixture.test_details.clone(),
+        TestCaseResolvedConfig {
+            available_gas: None,
+            ignored: false,
+            expected_result: ExpectedTestResult::Success,
+            fork_config: None,
+            fuzzer_config: None,
+            disable_predeployed_contracts: false,
+            seed: None,
+            setup: None,
+            timeout: None,
+            max_steps: None,
+            retries: None,
+            tags: vec![],
+        },
+    );
+    let runtime_config = RuntimeConfig {
+        launch_debugger: false,
+        ..RuntimeConfig::from(&forge_config.test_runner_config)
+    };
+    let seed = forge_config
+        .test_runner_config
+        .seed
+        .unwrap_or_else(rand::random);
+
+    let run_result = case
+        .try_into_program(casm_program)
+        .and_then(|program| {
+            run_test_case(
+                &case,
+                &program,
+                casm_program,
+                &runtime_config,
+                None,
+                &CaseSetup {
+                    seed,
+                    fuzzer_run: 0,
+                    fixture: Some(&FixtureUsage::Snapshot(fixture.name.clone())),
+                    hooks: &CaseHooks::default(),
+                },
+                versioned_program_path,
+            )
+        })
+        .map_err(|error| failed(format!(" {error}")))?;
+
+    match run_result {
+        RunResult::Completed(run_completed) => {
+            let RunCompleted {
+                status,
+                fixture_snapshot,
+                ..
+            } = *run_completed;
+            match (status, fixture_snapshot) {
+                (RunStatus::Success(_), Some(snapshot)) => Ok(Arc::new(snapshot)),
+                (RunStatus::Success(_), None) => Err(failed(" state was not captured".to_string())),
+                (RunStatus::Panic(data), _) => {
+                    Err(failed(build_readable_text(&data).unwrap_or_default()))
+                }
+            }
+        }
+        RunResult::Error(run_error) => Err(failed(format!("\n    {}\n", run_error.error))),
+        RunResult::BeforeEachFailed(before_each) => {
+            Err(failed(format!("\n    {}\n", before_each.failure)))
+        }
+    }
+}
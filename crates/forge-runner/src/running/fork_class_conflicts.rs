@@ -0,0 +1,57 @@
+use crate::gas::report::ContractId;
+use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use cheatnet::state::ForkClassConflict;
+use indoc::formatdoc;
+use starknet_api::core::ClassHash;
+
+/// Builds warnings describing conflicts between local classes and classes from the fork,
+/// or `None` if there were no conflicts.
+pub(super) fn format_fork_class_conflicts(
+    conflicts: &[ForkClassConflict],
+    contracts_data: &ContractsData,
+) -> Option<String> {
+    if conflicts.is_empty() {
+        return None;
+    }
+
+    let warnings = conflicts
+        .iter()
+        .map(|conflict| match conflict {
+            ForkClassConflict::ClassHashCollision(class_hash) => formatdoc! {r"
+                [WARNING] Class hash {:#x} of a local contract already exists on the fork with a different Sierra class
+                    local: {}
+                    fork:  {}
+                The class from the fork is used, declaring the local contract has no effect.",
+                class_hash.0,
+                origin(&contract_id(contracts_data, *class_hash)),
+                origin(&ContractId::ForkedContract(*class_hash)),
+            },
+            ForkClassConflict::ShadowedForkedClass {
+                contract_address,
+                forked_class_hash,
+                class_hash,
+            } => formatdoc! {r"
+                [WARNING] Class of forked contract {:#x} is shadowed with `replace_bytecode`
+                    fork:        {}
+                    replacement: {}",
+                contract_address.0.key(),
+                origin(&ContractId::ForkedContract(*forked_class_hash)),
+                origin(&contract_id(contracts_data, *class_hash)),
+            },
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Some(warnings)
+}
+
+fn contract_id(contracts_data: &ContractsData, class_hash: ClassHash) -> ContractId {
+    match contracts_data.get_contract_name(&class_hash) {
+        Some(name) => ContractId::LocalContract(name),
+        None => ContractId::ForkedContract(class_hash),
+    }
+}
+
+fn origin(contract_id: &ContractId) -> String {
+    contract_id.to_string().replace('\n', " ")
+}
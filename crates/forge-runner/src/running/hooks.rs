@@ -0,0 +1,259 @@
+use super::execution::finalize_execution;
+use super::setup::{self, VmExecutionContext};
+use crate::package_tests::with_config_resolved::TestCaseWithResolvedConfig;
+use crate::package_tests::{HookKind, TestHook};
+use anyhow::Result;
+use blockifier::execution::contract_class::TrackedResource;
+use blockifier::execution::entry_point::EntryPointExecutionContext;
+use blockifier::execution::entry_point_execution::{prepare_call_arguments, run_entry_point};
+use blockifier::state::cached_state::CachedState;
+use cairo_lang_casm::hints::Hint;
+use cheatnet::runtime_extensions::cheatable_starknet_runtime_extension::CheatableStarknetRuntimeExtension;
+use cheatnet::runtime_extensions::forge_runtime_extension::ForgeExtension;
+use cheatnet::runtime_extensions::outer_call_runtime_extension::OuterCallExtension;
+use cheatnet::state::{CheatnetState, ExtendedStateReader};
+use runtime::starknet::deadline::Deadline;
+use runtime::{ExtendedRuntime, StarknetRuntime};
+use shared::utils::build_readable_text;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use universal_sierra_compiler_api::representation::RawCasmProgram;
+
+/// `#[before_each]` and `#[after_each]` hooks run around a single test case
+#[derive(Debug, Clone, Default)]
+pub struct CaseHooks {
+    pub before_each: Vec<TestHook>,
+    pub after_each: Vec<TestHook>,
+}
+
+impl CaseHooks {
+    /// Collects hooks defined in the same module as the test case, ordered by their names.
+    /// Hooks are collected from the compiled test target, which does not keep the order in which
+    /// they were declared, so names give an order that does not change between compilations.
+    #[must_use]
+    pub fn for_case(hooks: &[TestHook], case: &TestCaseWithResolvedConfig) -> Self {
+        let module = module_of(&case.name);
+        let mut case_hooks = Self::default();
+
+        for hook in hooks.iter().filter(|hook| module_of(&hook.name) == module) {
+            match hook.kind {
+                HookKind::BeforeEach => case_hooks.before_each.push(hook.clone()),
+                HookKind::AfterEach => case_hooks.after_each.push(hook.clone()),
+            }
+        }
+        case_hooks.before_each.sort_by(|a, b| a.name.cmp(&b.name));
+        case_hooks.after_each.sort_by(|a, b| a.name.cmp(&b.name));
+
+        case_hooks
+    }
+}
+
+fn module_of(name: &str) -> &str {
+    name.rsplit_once("::").map_or("", |(module, _)| module)
+}
+
+/// State of the test case shared with the hooks run around it
+pub(super) struct HookContext<'a> {
+    pub casm_program: &'a RawCasmProgram,
+    pub hints: &'a HashMap<String, Hint>,
+    pub tracked_resource: TrackedResource,
+    pub deadline: Option<Deadline>,
+}
+
+/// Runs the hooks one after another, stopping at the first one that fails.
+/// Returns the message describing the failure.
+pub(super) fn run_hooks<'a>(
+    hooks: &[TestHook],
+    hook_context: &HookContext,
+    state: &mut CachedState<ExtendedStateReader>,
+    context: &mut EntryPointExecutionContext,
+    cheatnet_state: &mut CheatnetState,
+    forge_extension: impl Fn() -> ForgeExtension<'a>,
+) -> Result<Option<String>> {
+    for hook in hooks {
+        if let Some(reason) = run_hook(
+            hook,
+            hook_context,
+            state,
+            context,
+            cheatnet_state,
+            forge_extension(),
+        )? {
+            let kind = match hook.kind {
+                HookKind::BeforeEach => "Before each",
+                HookKind::AfterEach => "After each",
+            };
+            return Ok(Some(format!("{kind} hook `{}` failed:{reason}", hook.name)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Executes the hook with the cheatnet state of the test case.
+/// Calls made by the hook are not recorded in the trace of the test case.
+fn run_hook(
+    hook: &TestHook,
+    hook_context: &HookContext,
+    state: &mut CachedState<ExtendedStateReader>,
+    context: &mut EntryPointExecutionContext,
+    cheatnet_state: &mut CheatnetState,
+    forge_extension: ForgeExtension,
+) -> Result<Option<String>> {
+    let HookContext {
+        casm_program,
+        hints,
+        tracked_resource,
+        deadline,
+    } = hook_context;
+
+    let program = hook.test_details.try_into_program(casm_program)?;
+    let (call, entry_point) =
+        setup::build_test_call_and_entry_point(&hook.test_details, casm_program, &program);
+    let VmExecutionContext {
+        mut runner,
+        syscall_handler,
+        initial_syscall_ptr,
+        program_extra_data_length,
+    } = setup::initialize_execution_context(call, hints, &program, state, context)?;
+
+    let call_trace = cheatnet_state.trace_data.current_call_stack.top();
+    let n_nested_calls = call_trace.borrow().nested_calls.len();
+
+    let cheatable_runtime = ExtendedRuntime {
+        extension: CheatableStarknetRuntimeExtension { cheatnet_state },
+        extended_runtime: StarknetRuntime {
+            hint_handler: syscall_handler,
+            panic_traceback: None,
+            deadline: deadline.clone(),
+        },
+    };
+    let outer_call_runtime = ExtendedRuntime {
+        extension: OuterCallExtension {
+            lifetime: &PhantomData,
+        },
+        extended_runtime: cheatable_runtime,
+    };
+    let mut forge_runtime = ExtendedRuntime {
+        extension: forge_extension,
+        extended_runtime: outer_call_runtime,
+    };
+
+    let hint_handler = &mut forge_runtime
+        .extended_runtime
+        .extended_runtime
+        .extended_runtime
+        .hint_handler;
+    let entry_point_initial_budget = setup::entry_point_initial_budget(hint_handler);
+    let args = prepare_call_arguments(
+        &hint_handler.base.call.clone(),
+        &mut runner,
+        initial_syscall_ptr,
+        &mut hint_handler.read_only_segments,
+        &entry_point,
+        entry_point_initial_budget,
+    )?;
+    let n_total_args = args.len();
+
+    let program_segment_size = program.data_len() + program_extra_data_length;
+    let failure = match run_entry_point(
+        &mut runner,
+        &mut forge_runtime,
+        entry_point,
+        args,
+        program_segment_size,
+    ) {
+        Ok(()) => {
+            let call_info = finalize_execution(
+                &mut runner,
+                &mut forge_runtime
+                    .extended_runtime
+                    .extended_runtime
+                    .extended_runtime
+                    .hint_handler,
+                n_total_args,
+                program_extra_data_length,
+                *tracked_resource,
+            )?;
+            call_info
+                .execution
+                .failed
+                .then(|| build_readable_text(&call_info.execution.retdata.0).unwrap_or_default())
+        }
+        Err(error) => Some(format!("\n    {error}\n")),
+    };
+
+    call_trace
+        .borrow_mut()
+        .nested_calls
+        .truncate(n_nested_calls);
+
+    Ok(failure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expected_result::ExpectedTestResult;
+    use crate::package_tests::TestDetails;
+    use crate::package_tests::with_config_resolved::TestCaseResolvedConfig;
+
+    fn hook(name: &str, kind: HookKind) -> TestHook {
+        TestHook {
+            name: name.to_string(),
+            kind,
+            test_details: test_details(),
+        }
+    }
+
+    fn test_details() -> TestDetails {
+        TestDetails {
+            sierra_entry_point_statement_idx: 0,
+            parameter_types: vec![],
+        }
+    }
+
+    fn test_case(name: &str) -> TestCaseWithResolvedConfig {
+        TestCaseWithResolvedConfig::new(
+            name,
+            test_details(),
+            TestCaseResolvedConfig {
+                available_gas: None,
+                ignored: false,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: None,
+                fuzzer_config: None,
+                disable_predeployed_contracts: false,
+                seed: None,
+                setup: None,
+                timeout: None,
+                max_steps: None,
+                retries: None,
+                tags: vec![],
+            },
+        )
+    }
+
+    fn names(hooks: &[TestHook]) -> Vec<&str> {
+        hooks.iter().map(|hook| hook.name.as_str()).collect()
+    }
+
+    #[test]
+    fn hooks_of_test_module_only() {
+        let hooks = [
+            hook("pkg::tests::reset", HookKind::BeforeEach),
+            hook("pkg::tests::check", HookKind::AfterEach),
+            hook("pkg::tests::inner::reset", HookKind::BeforeEach),
+            hook("pkg::other::check", HookKind::AfterEach),
+            hook("pkg::tests::assert_empty", HookKind::AfterEach),
+        ];
+
+        let case_hooks = CaseHooks::for_case(&hooks, &test_case("pkg::tests::test_a"));
+
+        assert_eq!(names(&case_hooks.before_each), ["pkg::tests::reset"]);
+        assert_eq!(
+            names(&case_hooks.after_each),
+            ["pkg::tests::assert_empty", "pkg::tests::check"]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::test_case_summary::{TestCaseSummary, TestType};
+
+/// Tracks reruns of a test case that failed in a way allowing it to be retried.
+pub(crate) struct Retries {
+    max_retries: u32,
+    retries: u32,
+    last_failure: Option<String>,
+}
+
+impl Retries {
+    pub(crate) fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            retries: 0,
+            last_failure: None,
+        }
+    }
+
+    /// Returns the final summary of the test case, or `None` if it should be run again.
+    pub(crate) fn finish<T: TestType>(
+        &mut self,
+        mut summary: TestCaseSummary<T>,
+        can_be_retried: bool,
+    ) -> Option<TestCaseSummary<T>> {
+        match &mut summary {
+            TestCaseSummary::Failed { msg, .. } | TestCaseSummary::TimedOut { msg, .. }
+                if can_be_retried && self.retries < self.max_retries =>
+            {
+                self.retries += 1;
+                self.last_failure = msg.take();
+                None
+            }
+            TestCaseSummary::Passed { retries, .. } if self.retries > 0 => {
+                *retries = self.retries;
+                summary.append_to_msg(&format!(
+                    "Passed after {}, last failure:{}",
+                    format_retries(self.retries),
+                    self.last_failure.take().unwrap_or_default()
+                ));
+                Some(summary)
+            }
+            _ => {
+                if self.retries > 0 {
+                    summary
+                        .append_to_msg(&format!("Failed after {}", format_retries(self.retries)));
+                }
+                Some(summary)
+            }
+        }
+    }
+}
+
+fn format_retries(retries: u32) -> String {
+    if retries == 1 {
+        "1 retry".to_string()
+    } else {
+        format!("{retries} retries")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge_config::ForgeTrackedResource;
+    use crate::messages::TestResultMessage;
+    use crate::test_case_summary::{
+        AnyTestCaseSummary, Fuzzing, FuzzingStatistics, GasFuzzingInfo,
+    };
+    use crate::test_target_summary::TestTargetSummary;
+    use crate::tests_summary::TestsSummary;
+    use cheatnet::runtime_extensions::outer_call_runtime_extension::rpc::UsedResources;
+    use foundry_ui::Message;
+    use starknet_api::execution_resources::GasVector;
+
+    fn failed() -> TestCaseSummary<Fuzzing> {
+        TestCaseSummary::Failed {
+            name: "flaky".to_string(),
+            msg: Some("\n    0x6e6f6465206572726f72 ('node error')".to_string()),
+            debugging_trace: None,
+            fuzzer_args: vec!["1".to_string()],
+            test_statistics: FuzzingStatistics { runs: 1 },
+            after_each_failure: None,
+            fixture_failure: None,
+        }
+    }
+
+    fn passed() -> TestCaseSummary<Fuzzing> {
+        TestCaseSummary::Passed {
+            name: "flaky".to_string(),
+            msg: None,
+            debugging_trace: None,
+            gas_info: GasFuzzingInfo::new(&[GasVector::default()]),
+            used_resources: UsedResources::default(),
+            test_statistics: FuzzingStatistics { runs: 256 },
+            trace_data: (),
+            retries: 0,
+        }
+    }
+
+    #[test]
+    fn passing_after_failure_is_flaky() {
+        let mut retries = Retries::new(2);
+
+        assert!(retries.finish(failed(), true).is_none());
+        let summary = AnyTestCaseSummary::Fuzzing(retries.finish(passed(), true).unwrap());
+
+        assert!(summary.is_passed());
+        assert!(summary.is_flaky());
+
+        let message =
+            TestResultMessage::new(&summary, false, ForgeTrackedResource::CairoSteps).text();
+        assert!(message.contains("FLAKY"));
+        assert!(message.contains("Passed after 1 retry, last failure:"));
+        assert!(message.contains("('node error')"));
+
+        let tests_summary = TestsSummary::new(
+            &[TestTargetSummary {
+                test_case_summaries: vec![summary],
+            }],
+            0,
+        );
+        assert_eq!(
+            tests_summary.format_summary_message(),
+            "1 passed (1 flaky), 0 failed, 0 ignored, 0 filtered out"
+        );
+    }
+
+    #[test]
+    fn failure_not_allowing_retry_is_final() {
+        let mut retries = Retries::new(2);
+
+        let summary = retries.finish(failed(), false).unwrap();
+
+        assert!(matches!(summary, TestCaseSummary::Failed { .. }));
+        assert!(!summary.msg().unwrap().contains("Failed after"));
+    }
+
+    #[test]
+    fn retries_are_limited() {
+        let mut retries = Retries::new(2);
+
+        assert!(retries.finish(failed(), true).is_none());
+        assert!(retries.finish(failed(), true).is_none());
+        let summary = retries.finish(failed(), true).unwrap();
+
+        assert!(summary.msg().unwrap().ends_with("Failed after 2 retries"));
+    }
+}
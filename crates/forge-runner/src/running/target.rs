@@ -2,7 +2,7 @@ use crate::{
     filtering::NameFilter,
     forge_config::ForgeTrackedResource,
     package_tests::{
-        TestDetails, TestTargetLocation,
+        Fixture, HookKind, TestDetails, TestHook, TestTargetLocation,
         raw::TestTargetRaw,
         with_config::{TestCaseWithConfig, TestTargetWithConfig},
         with_config_resolved::sanitize_test_case_name,
@@ -43,12 +43,26 @@ pub fn prepare_test_target(
 ) -> Result<PrepareTestTargetResult> {
     let tests_location = test_target_raw.tests_location;
     let default_executables = vec![];
-    let executables = test_target_raw
-        .sierra_program
-        .debug_info
-        .as_ref()
-        .and_then(|info| info.executables.get("snforge_internal_test_executable"))
-        .unwrap_or(&default_executables);
+    let executables_of = |name: &str| {
+        test_target_raw
+            .sierra_program
+            .debug_info
+            .as_ref()
+            .and_then(|info| info.executables.get(name))
+            .unwrap_or(&default_executables)
+    };
+    let executables = executables_of("snforge_internal_test_executable");
+    let fixture_executables = executables_of("snforge_internal_fixture_executable");
+    let hook_executables = [
+        (
+            HookKind::BeforeEach,
+            executables_of("snforge_internal_before_each_executable"),
+        ),
+        (
+            HookKind::AfterEach,
+            executables_of("snforge_internal_after_each_executable"),
+        ),
+    ];
 
     let (matched_cases, prefiltered_out_count) =
         collect_matched_cases(executables, name_filter, partition_config);
@@ -94,10 +108,44 @@ pub fn prepare_test_target(
         })
         .collect::<Result<_>>()?;
 
+    let fixtures = fixture_executables
+        .iter()
+        .map(|fixture| {
+            let name: String = fixture
+                .debug_name
+                .clone()
+                .expect("Failed to get fixture name")
+                .into();
+            Fixture {
+                name: sanitize_test_case_name(&name),
+                test_details: build_test_details(funcs[&fixture.id], &type_declarations),
+            }
+        })
+        .collect();
+
+    let hooks = hook_executables
+        .into_iter()
+        .flat_map(|(kind, executables)| executables.iter().map(move |hook| (kind, hook)))
+        .map(|(kind, hook)| {
+            let name: String = hook
+                .debug_name
+                .clone()
+                .expect("Failed to get hook name")
+                .into();
+            TestHook {
+                name: sanitize_test_case_name(&name),
+                kind,
+                test_details: build_test_details(funcs[&hook.id], &type_declarations),
+            }
+        })
+        .collect();
+
     Ok(PrepareTestTargetResult {
         target: Some(TestTargetWithConfig {
             tests_location,
             test_cases,
+            fixtures,
+            hooks,
             sierra_program: test_target_raw.sierra_program,
             sierra_program_path: test_target_raw.sierra_program_path.into(),
             casm_program,
@@ -3,7 +3,7 @@ use crate::backtrace::{
 };
 use crate::build_trace_data::build_profiler_call_trace;
 use crate::debugging::{TraceArgs, build_contracts_data_store, build_debugging_trace};
-use crate::expected_result::{ExpectedPanicValue, ExpectedTestResult};
+use crate::expected_result::{ExpectedPanicValue, ExpectedTestResult, PanicOrigin};
 use crate::gas::check_available_gas;
 use crate::gas::report::SingleTestGasInfo;
 use crate::gas::stats::GasStats;
@@ -16,9 +16,12 @@ use camino::Utf8Path;
 use cheatnet::forking::data::ForkData;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use cheatnet::runtime_extensions::outer_call_runtime_extension::rpc::UsedResources;
+use cheatnet::trace_data::{CallTrace, CallTraceNode};
 use conversions::byte_array::ByteArray;
-use conversions::string::TryFromHexStr;
+use conversions::felt::ToShortString;
+use conversions::string::{IntoHexStr, TryFromHexStr};
 use num_traits::ToPrimitive;
+use regex::Regex;
 use shared::utils::build_readable_text;
 use starknet_api::execution_resources::GasVector;
 use starknet_api::execution_utils::format_panic_data;
@@ -124,6 +127,8 @@ pub enum TestCaseSummary<T: TestType> {
         test_statistics: <T as TestType>::TestStatistics,
         /// Test trace data
         trace_data: <T as TestType>::TraceData,
+        /// Number of failed runs before the test case passed, it is flaky if greater than 0
+        retries: u32,
     },
     /// Test case failed
     Failed {
@@ -137,6 +142,25 @@ pub enum TestCaseSummary<T: TestType> {
         fuzzer_args: Vec<String>,
         /// Statistics of the test run
         test_statistics: <T as TestType>::TestStatistics,
+        /// Failure of an `#[after_each]` hook, reported separately from the result of the test case
+        after_each_failure: Option<String>,
+        /// Failure of the `#[fixture]` the test case is set up with, the test case was not run then
+        fixture_failure: Option<String>,
+    },
+    /// Test case stopped after exceeding its timeout or limit of steps
+    TimedOut {
+        /// Name of the test case
+        name: String,
+        /// Message describing the exceeded limit
+        msg: Option<String>,
+        /// Trace of the test case run up to the moment it was stopped
+        debugging_trace: Option<debugging::Trace>,
+        /// Random arguments used in the fuzz test case run
+        fuzzer_args: Vec<String>,
+        /// Statistics of the test run
+        test_statistics: <T as TestType>::TestStatistics,
+        /// Number of steps executed before the test case was stopped
+        n_steps: usize,
     },
     /// Test case ignored due to `#[ignored]` attribute or `--ignored` flag
     Ignored {
@@ -163,6 +187,7 @@ impl<T: TestType> TestCaseSummary<T> {
         match self {
             TestCaseSummary::Failed { name, .. }
             | TestCaseSummary::Passed { name, .. }
+            | TestCaseSummary::TimedOut { name, .. }
             | TestCaseSummary::Ignored { name, .. } => Some(name),
             TestCaseSummary::Interrupted { .. } | TestCaseSummary::ExcludedFromPartition { .. } => {
                 None
@@ -174,11 +199,47 @@ impl<T: TestType> TestCaseSummary<T> {
     pub fn msg(&self) -> Option<&str> {
         match self {
             TestCaseSummary::Failed { msg: Some(msg), .. }
-            | TestCaseSummary::Passed { msg: Some(msg), .. } => Some(msg),
+            | TestCaseSummary::Passed { msg: Some(msg), .. }
+            | TestCaseSummary::TimedOut { msg: Some(msg), .. } => Some(msg),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn after_each_failure(&self) -> Option<&str> {
+        match self {
+            TestCaseSummary::Failed {
+                after_each_failure: Some(failure),
+                ..
+            } => Some(failure),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn fixture_failure(&self) -> Option<&str> {
+        match self {
+            TestCaseSummary::Failed {
+                fixture_failure: Some(failure),
+                ..
+            } => Some(failure),
             _ => None,
         }
     }
 
+    /// Appends `text` to the message printed with the test result.
+    pub(crate) fn append_to_msg(&mut self, text: &str) {
+        if let TestCaseSummary::Failed { msg, .. }
+        | TestCaseSummary::Passed { msg, .. }
+        | TestCaseSummary::TimedOut { msg, .. } = self
+        {
+            *msg = Some(match msg.take() {
+                Some(msg) => format!("{msg}\n{text}"),
+                None => text.to_string(),
+            });
+        }
+    }
+
     #[must_use]
     pub fn debugging_trace(&self) -> Option<&debugging::Trace> {
         match self {
@@ -187,6 +248,9 @@ impl<T: TestType> TestCaseSummary<T> {
             }
             | TestCaseSummary::Failed {
                 debugging_trace, ..
+            }
+            | TestCaseSummary::TimedOut {
+                debugging_trace, ..
             } => debugging_trace.as_ref(),
             _ => None,
         }
@@ -211,6 +275,7 @@ impl TestCaseSummary<Fuzzing> {
                 test_statistics: (),
                 trace_data: _,
                 debugging_trace,
+                retries,
             } => {
                 let runs = results.len();
                 let gas_usages: Vec<GasVector> = results
@@ -229,6 +294,7 @@ impl TestCaseSummary<Fuzzing> {
                     test_statistics: FuzzingStatistics { runs },
                     trace_data: (),
                     debugging_trace,
+                    retries,
                 }
             }
             TestCaseSummary::Failed {
@@ -237,6 +303,8 @@ impl TestCaseSummary<Fuzzing> {
                 fuzzer_args,
                 debugging_trace,
                 test_statistics: (),
+                after_each_failure,
+                fixture_failure,
             } => TestCaseSummary::Failed {
                 name,
                 msg,
@@ -245,6 +313,25 @@ impl TestCaseSummary<Fuzzing> {
                     runs: results.len(),
                 },
                 debugging_trace,
+                after_each_failure,
+                fixture_failure,
+            },
+            TestCaseSummary::TimedOut {
+                name,
+                msg,
+                fuzzer_args,
+                debugging_trace,
+                test_statistics: (),
+                n_steps,
+            } => TestCaseSummary::TimedOut {
+                name,
+                msg,
+                fuzzer_args,
+                test_statistics: FuzzingStatistics {
+                    runs: results.len(),
+                },
+                debugging_trace,
+                n_steps,
             },
             TestCaseSummary::Ignored { name } => TestCaseSummary::Ignored { name: name.clone() },
             TestCaseSummary::Interrupted {} => TestCaseSummary::Interrupted {},
@@ -262,6 +349,14 @@ fn build_expected_panic_message(expected_panic_value: &ExpectedPanicValue) -> St
                 format_panic_data(panic_data)
             )
         }
+        ExpectedPanicValue::FromOrigin { value, origin } => format!(
+            "{}    Expected panic origin:  {origin}\n",
+            build_expected_panic_message(value)
+        ),
+        other => format!(
+            "\n    Expected to panic, but no panic occurred\n    Expected panic data:  {}\n",
+            describe_expected_panic_value(other)
+        ),
     }
 }
 
@@ -269,30 +364,150 @@ fn check_if_matching_and_get_message(
     actual_panic_value: &[Felt],
     expected_panic_value: &ExpectedPanicValue,
 ) -> (bool, Option<String>) {
-    let expected_data = match expected_panic_value {
-        ExpectedPanicValue::Exact(data) => Some(data),
-        ExpectedPanicValue::Any => None,
-    };
+    if is_matching_expected_panic_value(actual_panic_value, expected_panic_value) {
+        return (true, None);
+    }
 
-    match expected_data {
-        Some(expected) if is_matching_should_panic_data(actual_panic_value, expected) => {
-            (true, None)
-        }
-        Some(expected) => {
+    let message = match expected_panic_value {
+        ExpectedPanicValue::Exact(expected) => {
             let byte_array_note = malformed_byte_array_note(actual_panic_value, expected);
-            let message = Some(format!(
-                "\n    Incorrect panic data\n    {}\n    {}{}\n",
+            format!(
+                "\n    Incorrect panic data\n    {}\n    {}{}{}\n",
                 format_args!(
                     "Actual:    {}",
                     format_panic_data_with_types(actual_panic_value)
                 ),
                 format_args!("Expected:  {}", format_panic_data_with_types(expected)),
+                panic_data_diff(actual_panic_value, expected),
                 byte_array_note
-            ));
-            (false, message)
+            )
+        }
+        ExpectedPanicValue::FromOrigin { value, .. } => {
+            return check_if_matching_and_get_message(actual_panic_value, value);
+        }
+        other => format!(
+            "\n    Incorrect panic data\n    Actual:    {}\n    Expected:  {}\n",
+            format_panic_data_with_types(actual_panic_value),
+            describe_expected_panic_value(other)
+        ),
+    };
+
+    (false, Some(message))
+}
+
+fn is_matching_expected_panic_value(actual: &[Felt], expected: &ExpectedPanicValue) -> bool {
+    match expected {
+        ExpectedPanicValue::Any => true,
+        ExpectedPanicValue::Exact(pattern) => is_matching_should_panic_data(actual, pattern),
+        ExpectedPanicValue::Variant { index, length } => {
+            // Only generic errors propagated from failed calls may follow the enum
+            actual.first() == Some(index)
+                && actual.len() >= *length
+                && actual[*length..]
+                    .iter()
+                    .all(|felt| *felt == ENTRYPOINT_FAILED_ERROR_FELT)
+        }
+        ExpectedPanicValue::Regex(pattern) => {
+            // Patterns are validated when the test is compiled, so this only guards against
+            // configs built by hand.
+            Regex::new(pattern).is_ok_and(|regex| {
+                panic_data_strings(actual)
+                    .iter()
+                    .any(|text| regex.is_match(text))
+            })
+        }
+        ExpectedPanicValue::OneOf(alternatives) => alternatives
+            .iter()
+            .any(|alternative| is_matching_expected_panic_value(actual, alternative)),
+        ExpectedPanicValue::FromOrigin { value, .. } => {
+            is_matching_expected_panic_value(actual, value)
+        }
+    }
+}
+
+fn describe_expected_panic_value(expected: &ExpectedPanicValue) -> String {
+    match expected {
+        ExpectedPanicValue::Any => "any panic data".to_string(),
+        ExpectedPanicValue::Exact(data) => format_panic_data_with_types(data),
+        ExpectedPanicValue::Variant { index, length } => {
+            format!("enum variant with index {index} serialized to {length} felts")
+        }
+        ExpectedPanicValue::Regex(pattern) => format!("string matching regex `{pattern}`"),
+        ExpectedPanicValue::OneOf(alternatives) => format!(
+            "one of: {}",
+            alternatives
+                .iter()
+                .map(describe_expected_panic_value)
+                .collect::<Vec<_>>()
+                .join(" | ")
+        ),
+        ExpectedPanicValue::FromOrigin { value, .. } => describe_expected_panic_value(value),
+    }
+}
+
+/// Strings that can be decoded from the panic data, both `ByteArray`s and short strings.
+fn panic_data_strings(data: &[Felt]) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut remaining = data;
+
+    while !remaining.is_empty() {
+        if let Some(byte_array_data) = take_byte_array(remaining) {
+            if let Some(text) = convert_felts_to_byte_array_string(byte_array_data) {
+                strings.push(text);
+            }
+            remaining = &remaining[byte_array_data.len()..];
+        } else {
+            let (felt, rest) = remaining
+                .split_first()
+                .expect("remaining panic data is not empty");
+            if let Ok(text) = felt.to_short_string() {
+                strings.push(text);
+            }
+            remaining = rest;
+        }
+    }
+
+    strings
+}
+
+/// Line by line diff of the panic data values, shown only when there is more than one value.
+fn panic_data_diff(actual: &[Felt], expected: &[Felt]) -> String {
+    let actual = panic_data_values(actual);
+    let expected = panic_data_values(expected);
+
+    if actual.len() <= 1 && expected.len() <= 1 {
+        return String::new();
+    }
+
+    // Longest common subsequence table, `lcs[i][j]` is computed for `expected[i..]` and `actual[j..]`.
+    let mut lcs = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
         }
-        None => (true, None),
     }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push(format!("        {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if j == actual.len() || (i < expected.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(format!("      - {}", expected[i]));
+            i += 1;
+        } else {
+            lines.push(format!("      + {}", actual[j]));
+            j += 1;
+        }
+    }
+
+    format!("\n    Diff (- expected, + actual):\n{}", lines.join("\n"))
 }
 
 fn malformed_byte_array_note(actual: &[Felt], expected: &[Felt]) -> String {
@@ -322,6 +537,16 @@ fn starts_with_malformed_byte_array(data: &[Felt]) -> bool {
 }
 
 fn format_panic_data_with_types(data: &[Felt]) -> String {
+    let formatted_values = panic_data_values(data);
+
+    if let [single_value] = formatted_values.as_slice() {
+        single_value.clone()
+    } else {
+        format!("({})", formatted_values.join(", "))
+    }
+}
+
+fn panic_data_values(data: &[Felt]) -> Vec<String> {
     let mut formatted_values = Vec::with_capacity(data.len());
     let mut remaining = data;
 
@@ -342,11 +567,7 @@ fn format_panic_data_with_types(data: &[Felt]) -> String {
         }
     }
 
-    if let [single_value] = formatted_values.as_slice() {
-        single_value.clone()
-    } else {
-        format!("({})", formatted_values.join(", "))
-    }
+    formatted_values
 }
 
 fn take_byte_array(data: &[Felt]) -> Option<&[Felt]> {
@@ -375,7 +596,12 @@ impl TestCaseSummary<Single> {
             encountered_errors,
             fuzzer_args,
             fork_data,
+            fork_class_conflicts: _,
             test_backtrace,
+            observed_seed: _,
+            fixture_snapshot: _,
+            fork_rpc_failed: _,
+            after_each_failure,
         }: RunCompleted,
         test_case: &TestCaseWithResolvedConfig,
         contracts_data: &ContractsData,
@@ -419,7 +645,9 @@ impl TestCaseSummary<Single> {
             )
         });
 
-        match status {
+        let after_each = after_each_failure.map(|failure| (failure, fuzzer_args.clone()));
+
+        let summary = match status {
             RunStatus::Success(data) => match &test_case.config.expected_result {
                 ExpectedTestResult::Success => {
                     let summary = TestCaseSummary::Passed {
@@ -435,6 +663,7 @@ impl TestCaseSummary<Single> {
                             versioned_program_path,
                         )),
                         debugging_trace,
+                        retries: 0,
                     };
                     check_available_gas(test_case.config.available_gas, summary)
                 }
@@ -444,6 +673,8 @@ impl TestCaseSummary<Single> {
                     fuzzer_args,
                     test_statistics: (),
                     debugging_trace,
+                    after_each_failure: None,
+                    fixture_failure: None,
                 },
             },
             RunStatus::Panic(value) => match &test_case.config.expected_result {
@@ -462,10 +693,22 @@ impl TestCaseSummary<Single> {
                     fuzzer_args,
                     test_statistics: (),
                     debugging_trace,
+                    after_each_failure: None,
+                    fixture_failure: None,
                 },
                 ExpectedTestResult::Panics(expected_panic_value) => {
                     let (matching, msg) =
                         check_if_matching_and_get_message(&value, expected_panic_value);
+                    let (matching, msg) = if matching {
+                        check_panic_origin(
+                            &value,
+                            expected_panic_value,
+                            &call_trace.borrow(),
+                            contracts_data,
+                        )
+                    } else {
+                        (matching, msg)
+                    };
                     if matching {
                         let backtrace_msg = is_backtrace_enabled()
                             .then(|| {
@@ -492,6 +735,7 @@ impl TestCaseSummary<Single> {
                                 versioned_program_path,
                             )),
                             debugging_trace,
+                            retries: 0,
                         }
                     } else {
                         TestCaseSummary::Failed {
@@ -509,12 +753,144 @@ impl TestCaseSummary<Single> {
                             fuzzer_args,
                             test_statistics: (),
                             debugging_trace,
+                            after_each_failure: None,
+                            fixture_failure: None,
                         }
                     }
                 }
             },
+        };
+
+        match after_each {
+            Some((failure, fuzzer_args)) => summary.with_after_each_failure(failure, fuzzer_args),
+            None => summary,
         }
     }
+
+    /// Fails the test case because of an `#[after_each]` hook.
+    /// Failure of the test case itself is kept in `msg`.
+    fn with_after_each_failure(self, failure: String, fuzzer_args: Vec<String>) -> Self {
+        match self {
+            TestCaseSummary::Passed {
+                name,
+                debugging_trace,
+                ..
+            } => TestCaseSummary::Failed {
+                name,
+                msg: None,
+                debugging_trace,
+                fuzzer_args,
+                test_statistics: (),
+                after_each_failure: Some(failure),
+                fixture_failure: None,
+            },
+            TestCaseSummary::Failed {
+                name,
+                msg,
+                debugging_trace,
+                fuzzer_args,
+                test_statistics,
+                after_each_failure: _,
+                fixture_failure,
+            } => TestCaseSummary::Failed {
+                name,
+                msg,
+                debugging_trace,
+                fuzzer_args,
+                test_statistics,
+                after_each_failure: Some(failure),
+                fixture_failure,
+            },
+            TestCaseSummary::TimedOut { .. }
+            | TestCaseSummary::Ignored { .. }
+            | TestCaseSummary::Interrupted {}
+            | TestCaseSummary::ExcludedFromPartition {} => {
+                unreachable!("`#[after_each]` hooks run only after the test case completed")
+            }
+        }
+    }
+}
+
+fn check_panic_origin(
+    actual_panic_value: &[Felt],
+    expected_panic_value: &ExpectedPanicValue,
+    call_trace: &CallTrace,
+    contracts_data: &ContractsData,
+) -> (bool, Option<String>) {
+    let ExpectedPanicValue::FromOrigin { origin, .. } = expected_panic_value else {
+        return (true, None);
+    };
+
+    let actual_origin = actual_panic_origin(actual_panic_value, call_trace, contracts_data);
+    let is_matching = |expected: Option<&String>, actual: Option<&String>| {
+        expected.is_none_or(|expected| Some(expected) == actual)
+    };
+
+    if is_matching(origin.contract.as_ref(), actual_origin.contract.as_ref())
+        && is_matching(origin.function.as_ref(), actual_origin.function.as_ref())
+    {
+        (true, None)
+    } else {
+        let message = format!(
+            "\n    Panic originated in an unexpected place\n    Actual:    {actual_origin}\n    Expected:  {origin}\n"
+        );
+        (false, Some(message))
+    }
+}
+
+/// Finds the deepest failed contract call the panic was propagated from.
+/// Panics raised directly in the test have no contract and function.
+fn actual_panic_origin(
+    actual_panic_value: &[Felt],
+    call_trace: &CallTrace,
+    contracts_data: &ContractsData,
+) -> PanicOrigin {
+    let no_origin = PanicOrigin {
+        contract: None,
+        function: None,
+    };
+
+    // Panics propagated from contract calls always end with `ENTRYPOINT_FAILED`.
+    if actual_panic_value.last() != Some(&ENTRYPOINT_FAILED_ERROR_FELT) {
+        return no_origin;
+    }
+
+    let failed_call = |nested_calls: &[CallTraceNode]| {
+        nested_calls.iter().rev().find_map(|node| match node {
+            CallTraceNode::EntryPointCall(call) if call.borrow().result.is_err() => {
+                Some(call.clone())
+            }
+            _ => None,
+        })
+    };
+
+    let mut origin = None;
+    let mut next = failed_call(&call_trace.nested_calls);
+    while let Some(call) = next {
+        next = failed_call(&call.borrow().nested_calls);
+        origin = Some(call);
+    }
+
+    let Some(origin) = origin else {
+        return no_origin;
+    };
+    let origin = origin.borrow();
+    let entry_point = &origin.entry_point;
+
+    PanicOrigin {
+        contract: Some(
+            entry_point
+                .class_hash
+                .and_then(|class_hash| contracts_data.get_contract_name(&class_hash))
+                .unwrap_or_else(|| entry_point.storage_address.into_hex_string()),
+        ),
+        function: Some(
+            contracts_data
+                .get_function_name(&entry_point.entry_point_selector)
+                .cloned()
+                .unwrap_or_else(|| entry_point.entry_point_selector.0.into_hex_string()),
+        ),
+    }
 }
 
 fn is_matching_should_panic_data(data: &[Felt], pattern: &[Felt]) -> bool {
@@ -576,6 +952,28 @@ impl AnyTestCaseSummary {
         }
     }
 
+    #[must_use]
+    pub fn after_each_failure(&self) -> Option<&str> {
+        match self {
+            AnyTestCaseSummary::Fuzzing(case) => case.after_each_failure(),
+            AnyTestCaseSummary::Single(case) => case.after_each_failure(),
+        }
+    }
+
+    #[must_use]
+    pub fn fixture_failure(&self) -> Option<&str> {
+        match self {
+            AnyTestCaseSummary::Fuzzing(case) => case.fixture_failure(),
+            AnyTestCaseSummary::Single(case) => case.fixture_failure(),
+        }
+    }
+
+    /// Whether the test case was not run because the fixture it is set up with failed.
+    #[must_use]
+    pub fn is_fixture_failed(&self) -> bool {
+        self.fixture_failure().is_some()
+    }
+
     #[must_use]
     pub fn debugging_trace(&self) -> Option<&debugging::Trace> {
         match self {
@@ -593,12 +991,35 @@ impl AnyTestCaseSummary {
         )
     }
 
+    /// Timed out test cases are failed as well.
     #[must_use]
     pub fn is_failed(&self) -> bool {
         matches!(
             self,
-            AnyTestCaseSummary::Single(TestCaseSummary::Failed { .. })
-                | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed { .. })
+            AnyTestCaseSummary::Single(
+                TestCaseSummary::Failed { .. } | TestCaseSummary::TimedOut { .. }
+            ) | AnyTestCaseSummary::Fuzzing(
+                TestCaseSummary::Failed { .. } | TestCaseSummary::TimedOut { .. }
+            )
+        )
+    }
+
+    /// Whether the test case passed only after being rerun.
+    #[must_use]
+    pub fn is_flaky(&self) -> bool {
+        matches!(
+            self,
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed { retries: 1.., .. })
+                | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { retries: 1.., .. })
+        )
+    }
+
+    #[must_use]
+    pub fn is_timed_out(&self) -> bool {
+        matches!(
+            self,
+            AnyTestCaseSummary::Single(TestCaseSummary::TimedOut { .. })
+                | AnyTestCaseSummary::Fuzzing(TestCaseSummary::TimedOut { .. })
         )
     }
 
@@ -633,6 +1054,7 @@ impl AnyTestCaseSummary {
 #[cfg(test)]
 mod tests {
     use crate::test_case_summary::*;
+    use conversions::serde::serialize::SerializeToFeltVec;
     use starknet_api::execution_resources::{GasAmount, GasVector};
 
     const FLOAT_ERROR: f64 = 0.01;
@@ -760,7 +1182,14 @@ mod tests {
             message.unwrap(),
             "\n    Incorrect panic data\
              \n    Actual:    (ByteArray(\"this_string_is_longer_than_31_bytes\"), felt252 0xb, ByteArray(\"hello\"), felt252 0x5, felt252 0x73686f72745f737472696e67 ('short_string'))\
-             \n    Expected:  (ByteArray(\"this_string_is_longer_than_31_bytes\"), felt252 0xb, ByteArray(\"helloo\"), felt252 0x5, felt252 0x73686f72745f737472696e67 ('short_string'))\n"
+             \n    Expected:  (ByteArray(\"this_string_is_longer_than_31_bytes\"), felt252 0xb, ByteArray(\"helloo\"), felt252 0x5, felt252 0x73686f72745f737472696e67 ('short_string'))\
+             \n    Diff (- expected, + actual):\
+             \n        ByteArray(\"this_string_is_longer_than_31_bytes\")\
+             \n        felt252 0xb\
+             \n      - ByteArray(\"helloo\")\
+             \n      + ByteArray(\"hello\")\
+             \n        felt252 0x5\
+             \n        felt252 0x73686f72745f737472696e67 ('short_string')\n"
         );
     }
 
@@ -786,6 +1215,12 @@ mod tests {
             "\n    Incorrect panic data\
              \n    Actual:    (felt252 0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3, felt252 0x0 (''), felt252 0x78 ('x'), felt252 0x64 ('d'))\
              \n    Expected:  felt252 0x70616e6963206d657373616765 ('panic message')\
+             \n    Diff (- expected, + actual):\
+             \n      - felt252 0x70616e6963206d657373616765 ('panic message')\
+             \n      + felt252 0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3\
+             \n      + felt252 0x0 ('')\
+             \n      + felt252 0x78 ('x')\
+             \n      + felt252 0x64 ('d')\
              \n    Note: actual panic data starts with ByteArray magic, but is not a valid ByteArray serialization\n"
         );
     }
@@ -824,4 +1259,93 @@ mod tests {
         let pattern = ByteArray::from("ENTRYPOINT_FAILED").serialize_with_magic();
         assert!(is_matching_should_panic_data(&data, &pattern));
     }
+
+    #[test]
+    fn test_is_matching_expected_panic_value_variant() {
+        let actual = vec![
+            Felt::from(1_u8),
+            Felt::from(42_u8),
+            ENTRYPOINT_FAILED_ERROR_FELT,
+        ];
+
+        let variant = |index: u8, length| ExpectedPanicValue::Variant {
+            index: Felt::from(index),
+            length,
+        };
+
+        assert!(is_matching_expected_panic_value(&actual, &variant(1, 2)));
+        assert!(!is_matching_expected_panic_value(&actual, &variant(0, 2)));
+        assert!(!is_matching_expected_panic_value(&actual, &variant(1, 1)));
+    }
+
+    #[test]
+    fn test_is_matching_expected_panic_value_variant_requires_payload_length() {
+        let variant = ExpectedPanicValue::Variant {
+            index: Felt::from(0_u8),
+            length: 2,
+        };
+        // `panic!((0,))`
+        let tuple = vec![Felt::from(0_u8)];
+        // `ByteArray` serialized without magic starts with the number of full words
+        let byte_array = ByteArray::from("short").serialize_to_vec();
+
+        assert!(!is_matching_expected_panic_value(&tuple, &variant));
+        assert!(!is_matching_expected_panic_value(&byte_array, &variant));
+        assert!(!is_matching_expected_panic_value(
+            &ByteArray::from("").serialize_with_magic(),
+            &variant
+        ));
+    }
+
+    #[test]
+    fn test_is_matching_expected_panic_value_regex() {
+        let mut actual = ByteArray::from("Insufficient balance: 100").serialize_with_magic();
+        actual.push(Felt::from_bytes_be_slice(b"short_string"));
+
+        let regex = |pattern: &str| ExpectedPanicValue::Regex(pattern.to_string());
+
+        assert!(is_matching_expected_panic_value(
+            &actual,
+            &regex(r"^Insufficient balance: \d+$")
+        ));
+        assert!(is_matching_expected_panic_value(&actual, &regex("^short_")));
+        assert!(!is_matching_expected_panic_value(
+            &actual,
+            &regex("^balance")
+        ));
+    }
+
+    #[test]
+    fn test_is_matching_expected_panic_value_one_of() {
+        let actual = vec![Felt::from_bytes_be_slice(b"second")];
+        let expected = ExpectedPanicValue::OneOf(vec![
+            ExpectedPanicValue::Exact(vec![Felt::from_bytes_be_slice(b"first")]),
+            ExpectedPanicValue::Exact(vec![Felt::from_bytes_be_slice(b"second")]),
+        ]);
+
+        assert!(is_matching_expected_panic_value(&actual, &expected));
+        assert!(!is_matching_expected_panic_value(
+            &[Felt::from_bytes_be_slice(b"third")],
+            &expected
+        ));
+    }
+
+    #[test]
+    fn test_incorrect_panic_data_message_one_of() {
+        let actual = vec![Felt::from_bytes_be_slice(b"third")];
+        let expected = ExpectedPanicValue::OneOf(vec![
+            ExpectedPanicValue::Exact(vec![Felt::from_bytes_be_slice(b"first")]),
+            ExpectedPanicValue::Regex("^sec".to_string()),
+        ]);
+
+        let (matching, message) = check_if_matching_and_get_message(&actual, &expected);
+
+        assert!(!matching);
+        assert_eq!(
+            message.unwrap(),
+            "\n    Incorrect panic data\
+             \n    Actual:    felt252 0x7468697264 ('third')\
+             \n    Expected:  one of: felt252 0x6669727374 ('first') | string matching regex `^sec`\n"
+        );
+    }
 }
@@ -16,6 +16,14 @@ impl TestTargetSummary {
             .count()
     }
 
+    #[must_use]
+    pub fn count_flaky(&self) -> usize {
+        self.test_case_summaries
+            .iter()
+            .filter(|tu| tu.is_flaky())
+            .count()
+    }
+
     #[must_use]
     pub fn count_failed(&self) -> usize {
         self.test_case_summaries
@@ -24,6 +32,14 @@ impl TestTargetSummary {
             .count()
     }
 
+    #[must_use]
+    pub fn count_fixture_failed(&self) -> usize {
+        self.test_case_summaries
+            .iter()
+            .filter(|tu| tu.is_fixture_failed())
+            .count()
+    }
+
     #[must_use]
     pub fn count_interrupted(&self) -> usize {
         self.test_case_summaries
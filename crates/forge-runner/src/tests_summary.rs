@@ -4,7 +4,9 @@ use serde::Serialize;
 #[derive(Serialize)]
 pub struct TestsSummary {
     passed: usize,
+    flaky: usize,
     failed: usize,
+    fixture_failed: usize,
     interrupted: usize,
     ignored: usize,
     filtered: usize,
@@ -14,7 +16,12 @@ impl TestsSummary {
     #[must_use]
     pub fn new(summaries: &[TestTargetSummary], filtered: usize) -> Self {
         let passed = summaries.iter().map(TestTargetSummary::count_passed).sum();
+        let flaky = summaries.iter().map(TestTargetSummary::count_flaky).sum();
         let failed = summaries.iter().map(TestTargetSummary::count_failed).sum();
+        let fixture_failed = summaries
+            .iter()
+            .map(TestTargetSummary::count_fixture_failed)
+            .sum();
         let interrupted = summaries
             .iter()
             .map(TestTargetSummary::count_interrupted)
@@ -23,7 +30,9 @@ impl TestsSummary {
 
         Self {
             passed,
+            flaky,
             failed,
+            fixture_failed,
             interrupted,
             ignored,
             filtered,
@@ -38,8 +47,20 @@ impl TestsSummary {
             String::new()
         };
 
+        let flaky = if self.flaky > 0 {
+            format!(" ({} flaky)", self.flaky)
+        } else {
+            String::new()
+        };
+
+        let fixture_failed = if self.fixture_failed > 0 {
+            format!(" ({} in fixtures)", self.fixture_failed)
+        } else {
+            String::new()
+        };
+
         format!(
-            "{} passed, {} failed, {} ignored, {} filtered out{interrupted}",
+            "{} passed{flaky}, {} failed{fixture_failed}, {} ignored, {} filtered out{interrupted}",
             self.passed, self.failed, self.ignored, self.filtered
         )
     }
@@ -1,4 +1,5 @@
-use anyhow::{Result, anyhow};
+use crate::fork_lock::{FORK_LOCK_FILE_NAME, ForkLock, LockedFork};
+use anyhow::{Result, anyhow, ensure};
 use conversions::{IntoConv, string::IntoHexStr};
 use starknet_api::block::BlockNumber;
 use starknet_rust::{
@@ -6,7 +7,7 @@ use starknet_rust::{
     providers::{JsonRpcClient, Provider, jsonrpc::HttpTransport},
 };
 use starknet_types_core::felt::Felt;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Mutex;
 use tokio::runtime::Handle;
 use url::Url;
@@ -16,9 +17,81 @@ use url::Url;
 pub struct BlockNumberMap {
     url_to_latest_block_number: Mutex<HashMap<Url, BlockNumber>>,
     url_and_hash_to_block_number: Mutex<HashMap<(Url, Felt), BlockNumber>>,
+    fork_lock: Option<ForkLock>,
+    /// Names of pinned forks whose block hash was checked against the node
+    verified_forks: Mutex<HashSet<String>>,
 }
 
 impl BlockNumberMap {
+    /// Creates a map resolving block tags of named forks to blocks pinned in the `fork_lock`.
+    #[must_use]
+    pub fn with_fork_lock(fork_lock: Option<ForkLock>) -> Self {
+        Self {
+            fork_lock,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the block pinned for the named fork, or the latest block if pinning is disabled.
+    /// Forks that are not pinned yet are pinned to the latest block.
+    pub async fn get_pinned_block_number(&self, name: &str, url: Url) -> Result<BlockNumber> {
+        let Some(fork_lock) = &self.fork_lock else {
+            return self.get_latest_block_number(url).await;
+        };
+
+        if let Some(locked_fork) = fork_lock.get(name, &url) {
+            self.verify_locked_fork(&locked_fork).await?;
+            return Ok(BlockNumber(locked_fork.block_number));
+        }
+
+        let (block_number, block_hash) = fetch_latest_block(url.clone()).await?;
+        fork_lock.insert(LockedFork {
+            name: name.to_string(),
+            url: url.clone(),
+            block_number: block_number.0,
+            block_hash,
+        });
+
+        let mut map = self.url_to_latest_block_number.lock().unwrap();
+        map.entry(url).or_insert(block_number);
+
+        Ok(block_number)
+    }
+
+    /// Checks that the pinned block has the same hash on the node,
+    /// so the fork is not silently moved to a different block, e.g. after a reorg.
+    async fn verify_locked_fork(&self, locked_fork: &LockedFork) -> Result<()> {
+        if self
+            .verified_forks
+            .lock()
+            .unwrap()
+            .contains(&locked_fork.name)
+        {
+            return Ok(());
+        }
+
+        let block_hash =
+            fetch_block_hash(locked_fork.url.clone(), locked_fork.block_number).await?;
+        ensure!(
+            block_hash == locked_fork.block_hash,
+            "Block number = {} of fork named = {} has hash = {block_hash:#x} on the node, but {:#x} is pinned in the {FORK_LOCK_FILE_NAME}, run `snforge fork update` to update it",
+            locked_fork.block_number,
+            locked_fork.name,
+            locked_fork.block_hash,
+        );
+
+        self.verified_forks
+            .lock()
+            .unwrap()
+            .insert(locked_fork.name.clone());
+        Ok(())
+    }
+
+    /// Saves blocks pinned during this run to the lockfile.
+    pub fn save_fork_lock(&self) -> Result<()> {
+        self.fork_lock.as_ref().map_or(Ok(()), ForkLock::save)
+    }
+
     pub async fn get_latest_block_number(&self, url: Url) -> Result<BlockNumber> {
         // Release lock before awaiting.
         {
@@ -64,6 +137,34 @@ async fn fetch_latest_block_number(url: Url) -> Result<BlockNumber> {
         .map(BlockNumber)?)
 }
 
+pub(crate) async fn fetch_latest_block(url: Url) -> Result<(BlockNumber, Felt)> {
+    let client = JsonRpcClient::new(HttpTransport::new(url));
+
+    let block = Handle::current()
+        .spawn(async move { client.block_hash_and_number().await })
+        .await??;
+
+    Ok((BlockNumber(block.block_number), block.block_hash))
+}
+
+async fn fetch_block_hash(url: Url, block_number: u64) -> Result<Felt> {
+    let client = JsonRpcClient::new(HttpTransport::new(url));
+
+    match Handle::current()
+        .spawn(async move {
+            client
+                .get_block_with_tx_hashes(BlockId::Number(block_number))
+                .await
+        })
+        .await?
+    {
+        Ok(MaybePreConfirmedBlockWithTxHashes::Block(block)) => Ok(block.block_hash),
+        _ => Err(anyhow!(
+            "Could not get the hash of block with number = {block_number}"
+        )),
+    }
+}
+
 async fn fetch_block_number_for_hash(url: Url, block_hash: Felt) -> Result<BlockNumber> {
     let client = JsonRpcClient::new(HttpTransport::new(url));
 
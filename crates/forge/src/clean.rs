@@ -1,4 +1,5 @@
 use crate::shared_cache::FILE_WITH_PREV_TESTS_FAILED;
+use crate::test_impact::FILE_WITH_TESTS_IMPACT;
 use crate::{CleanArgs, CleanComponent};
 use anyhow::{Context, Result, ensure};
 use camino::{Utf8Path, Utf8PathBuf};
@@ -109,7 +110,7 @@ fn is_snfoundry_cache_file(path: &Utf8Path) -> bool {
         return false;
     };
 
-    if file_name == FILE_WITH_PREV_TESTS_FAILED {
+    if file_name == FILE_WITH_PREV_TESTS_FAILED || file_name == FILE_WITH_TESTS_IMPACT {
         return true;
     }
 
@@ -131,6 +132,11 @@ mod tests {
         assert!(is_snfoundry_cache_file(Utf8Path::new(".prev_tests_failed")));
     }
 
+    #[test]
+    fn recognizes_tests_impact_file() {
+        assert!(is_snfoundry_cache_file(Utf8Path::new(".tests_impact.json")));
+    }
+
     #[test]
     fn recognizes_rpc_cache_file() {
         assert!(is_snfoundry_cache_file(Utf8Path::new(
@@ -1,6 +1,7 @@
 use crate::TestArgs;
 use crate::scarb::config::ForgeConfigFromScarb;
 use camino::Utf8PathBuf;
+use cheatnet::predeployment::configured::PredeployedStateCache;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use forge_runner::forge_config::{
     ExecutionDataToSave, ForgeConfig, OutputConfig, TestRunnerConfig,
@@ -9,6 +10,7 @@ use rand::{RngCore, thread_rng};
 use std::env;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub fn combine_configs(
     args: &TestArgs,
@@ -39,13 +41,25 @@ pub fn combine_configs(
                 .fuzzer_seed
                 .or(forge_config_from_scarb.fuzzer_seed)
                 .unwrap_or_else(|| thread_rng().next_u64()),
+            seed: args.seed.or(forge_config_from_scarb.seed),
             max_n_steps: args.max_n_steps.or(forge_config_from_scarb.max_n_steps),
+            timeout: args
+                .timeout
+                .or(forge_config_from_scarb.timeout)
+                .map(Duration::from_secs),
+            retries: args
+                .retries
+                .or(forge_config_from_scarb.retries)
+                .unwrap_or_default(),
+            retry_on: args.retry_on.unwrap_or(forge_config_from_scarb.retry_on),
             is_vm_trace_needed: execution_data_to_save.is_vm_trace_needed(),
             cache_dir,
             contracts_data,
             tracked_resource,
             environment_variables: env::vars().collect(),
             launch_debugger: args.launch_debugger,
+            predeployment: forge_config_from_scarb.predeployment.clone(),
+            predeployed_state: PredeployedStateCache::default(),
         }),
         output_config: Arc::new(OutputConfig {
             trace_args: args.trace_args.clone(),
@@ -63,14 +77,18 @@ mod tests {
     use crate::TestArgs;
     use crate::scarb::config::ForgeConfigFromScarb;
     use camino::Utf8PathBuf;
+    use cheatnet::predeployment::config::PredeploymentConfig;
+    use cheatnet::predeployment::configured::PredeployedStateCache;
     use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
     use clap::Parser;
     use forge_runner::debugging::TraceArgs;
     use forge_runner::forge_config::{
-        ExecutionDataToSave, ForgeConfig, ForgeTrackedResource, OutputConfig, TestRunnerConfig,
+        ExecutionDataToSave, ForgeConfig, ForgeTrackedResource, OutputConfig, RetryOn,
+        TestRunnerConfig,
     };
     use std::num::NonZeroU32;
     use std::sync::Arc;
+    use std::time::Duration;
 
     #[test]
     fn fuzzer_default_seed() {
@@ -113,13 +131,19 @@ mod tests {
                     deterministic_output: false,
                     fuzzer_runs: NonZeroU32::new(256).unwrap(),
                     fuzzer_seed: config.test_runner_config.fuzzer_seed,
+                    seed: None,
                     max_n_steps: None,
+                    timeout: None,
+                    retries: 0,
+                    retry_on: RetryOn::ForkErrors,
                     tracked_resource: ForgeTrackedResource::SierraGas,
                     is_vm_trace_needed: false,
                     cache_dir: Utf8PathBuf::default(),
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                     launch_debugger: false,
+                    predeployment: PredeploymentConfig::default(),
+                    predeployed_state: PredeployedStateCache::default(),
                 }),
                 output_config: Arc::new(OutputConfig {
                     detailed_resources: false,
@@ -138,13 +162,18 @@ mod tests {
             fork: vec![],
             fuzzer_runs: Some(NonZeroU32::new(1234).unwrap()),
             fuzzer_seed: Some(500),
+            seed: Some(700),
             detailed_resources: true,
             save_trace_data: true,
             build_profile: true,
             coverage: true,
             gas_report: true,
             max_n_steps: Some(1_000_000),
+            timeout: Some(60),
+            retries: Some(2),
+            retry_on: RetryOn::AllFailures,
             tracked_resource: ForgeTrackedResource::CairoSteps,
+            predeployment: PredeploymentConfig::default(),
         };
 
         let args = TestArgs::parse_from(["snforge"]);
@@ -162,13 +191,19 @@ mod tests {
                     deterministic_output: false,
                     fuzzer_runs: NonZeroU32::new(1234).unwrap(),
                     fuzzer_seed: 500,
+                    seed: Some(700),
                     max_n_steps: Some(1_000_000),
+                    timeout: Some(Duration::from_secs(60)),
+                    retries: 2,
+                    retry_on: RetryOn::AllFailures,
                     tracked_resource: ForgeTrackedResource::CairoSteps,
                     is_vm_trace_needed: true,
                     cache_dir: Utf8PathBuf::default(),
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                     launch_debugger: false,
+                    predeployment: PredeploymentConfig::default(),
+                    predeployed_state: PredeployedStateCache::default(),
                 }),
                 output_config: Arc::new(OutputConfig {
                     detailed_resources: true,
@@ -192,13 +227,18 @@ mod tests {
             fork: vec![],
             fuzzer_runs: Some(NonZeroU32::new(1234).unwrap()),
             fuzzer_seed: Some(1000),
+            seed: Some(2000),
             detailed_resources: false,
             save_trace_data: false,
             build_profile: false,
             coverage: false,
             gas_report: false,
             max_n_steps: Some(1234),
+            timeout: Some(60),
+            retries: Some(2),
+            retry_on: RetryOn::AllFailures,
             tracked_resource: ForgeTrackedResource::SierraGas,
+            predeployment: PredeploymentConfig::default(),
         };
         // Note: --build-profile and --coverage conflict in clap, so only one can be used at a time.
         // We use --save-trace-data + --build-profile here to verify precedence for trace/profile flags.
@@ -209,12 +249,20 @@ mod tests {
             "100",
             "--fuzzer-seed",
             "32",
+            "--seed",
+            "64",
             "--detailed-resources",
             "--save-trace-data",
             "--build-profile",
             "--gas-report",
             "--max-n-steps",
             "1000000",
+            "--timeout",
+            "30",
+            "--retries",
+            "5",
+            "--retry-on",
+            "fork-errors",
             "--tracked-resource",
             "cairo-steps",
         ]);
@@ -233,13 +281,19 @@ mod tests {
                     deterministic_output: false,
                     fuzzer_runs: NonZeroU32::new(100).unwrap(),
                     fuzzer_seed: 32,
+                    seed: Some(64),
                     max_n_steps: Some(1_000_000),
+                    timeout: Some(Duration::from_secs(30)),
+                    retries: 5,
+                    retry_on: RetryOn::ForkErrors,
                     tracked_resource: ForgeTrackedResource::CairoSteps,
                     is_vm_trace_needed: true,
                     cache_dir: Utf8PathBuf::default(),
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                     launch_debugger: false,
+                    predeployment: PredeploymentConfig::default(),
+                    predeployed_state: PredeployedStateCache::default(),
                 }),
                 output_config: Arc::new(OutputConfig {
                     detailed_resources: true,
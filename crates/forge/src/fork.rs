@@ -0,0 +1,75 @@
+use crate::ForkUpdateArgs;
+use crate::block_number_map::fetch_latest_block;
+use crate::fork_lock::{ForkLock, LockedFork};
+use crate::scarb::config::{ForgeConfigFromScarb, ForkTarget};
+use crate::scarb::load_package_config;
+use anyhow::{Result, bail};
+use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
+use foundry_ui::UI;
+use scarb_api::metadata::{MetadataOpts, metadata_with_opts};
+
+/// Pins named forks using a block tag to the latest block in the `snfoundry.lock` file.
+pub async fn update(args: ForkUpdateArgs, ui: &UI) -> Result<()> {
+    let scarb_metadata = metadata_with_opts(MetadataOpts {
+        no_deps: true,
+        ..MetadataOpts::default()
+    })?;
+
+    let mut fork_targets: Vec<ForkTarget> = vec![];
+    for package in &scarb_metadata.packages {
+        let config = load_package_config::<ForgeConfigFromScarb>(&scarb_metadata, &package.id)?;
+
+        for fork_target in config.fork {
+            if fork_target.block_id != BlockId::BlockTag {
+                continue;
+            }
+            match fork_targets
+                .iter()
+                .find(|fork| fork.name == fork_target.name)
+            {
+                Some(fork) if fork.url != fork_target.url => bail!(
+                    "Fork named = {} is defined with different urls in multiple packages",
+                    fork.name
+                ),
+                Some(_) => {}
+                None => fork_targets.push(fork_target),
+            }
+        }
+    }
+
+    for name in &args.names {
+        if !fork_targets.iter().any(|fork| &fork.name == name) {
+            bail!("Fork named = {name} using a block tag not found in the Scarb.toml");
+        }
+    }
+
+    let workspace_root = &scarb_metadata.workspace.root;
+    let fork_lock =
+        ForkLock::load(workspace_root)?.unwrap_or_else(|| ForkLock::new(workspace_root));
+
+    if args.names.is_empty() {
+        let names: Vec<&str> = fork_targets.iter().map(|fork| fork.name.as_str()).collect();
+        fork_lock.retain(&names);
+    }
+
+    for fork_target in fork_targets
+        .into_iter()
+        .filter(|fork| args.names.is_empty() || args.names.contains(&fork.name))
+    {
+        let (block_number, block_hash) = fetch_latest_block(fork_target.url.clone()).await?;
+
+        ui.println(&format!(
+            "Pinned fork {} to block number = {block_number} (hash = {block_hash:#x})",
+            fork_target.name
+        ));
+
+        fork_lock.insert(LockedFork {
+            name: fork_target.name,
+            url: fork_target.url,
+            block_number: block_number.0,
+            block_hash,
+        });
+    }
+
+    fork_lock.save()
+}
@@ -0,0 +1,189 @@
+use anyhow::{Context, Result, ensure};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
+use std::fs;
+use std::sync::Mutex;
+use url::Url;
+
+pub const FORK_LOCK_FILE_NAME: &str = "snfoundry.lock";
+const FORK_LOCK_VERSION: u8 = 1;
+const FORK_LOCK_HEADER: &str = "\
+# This file is automatically generated by snforge.
+# It pins blocks of named forks using a block tag, run `snforge fork update` to update them.
+";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedFork {
+    pub name: String,
+    pub url: Url,
+    pub block_number: u64,
+    pub block_hash: Felt,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ForkLockContent {
+    version: u8,
+    #[serde(default, rename = "fork")]
+    forks: Vec<LockedFork>,
+    #[serde(skip)]
+    modified: bool,
+}
+
+impl Default for ForkLockContent {
+    fn default() -> Self {
+        Self {
+            version: FORK_LOCK_VERSION,
+            forks: Vec::default(),
+            modified: true,
+        }
+    }
+}
+
+/// Blocks of named forks pinned in the `snfoundry.lock` file.
+#[derive(Debug)]
+pub struct ForkLock {
+    path: Utf8PathBuf,
+    content: Mutex<ForkLockContent>,
+}
+
+impl ForkLock {
+    /// Creates an empty lock, that will be saved in the `workspace_root`.
+    #[must_use]
+    pub fn new(workspace_root: &Utf8Path) -> Self {
+        Self {
+            path: workspace_root.join(FORK_LOCK_FILE_NAME),
+            content: Mutex::new(ForkLockContent::default()),
+        }
+    }
+
+    /// Loads the lock from the `workspace_root`.
+    /// Returns `None` if the lockfile does not exist, as pinning forks is opt-in.
+    pub fn load(workspace_root: &Utf8Path) -> Result<Option<Self>> {
+        let path = workspace_root.join(FORK_LOCK_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let raw = fs::read_to_string(&path).with_context(|| format!("Failed to read {path}"))?;
+        let content: ForkLockContent =
+            toml::from_str(&raw).with_context(|| format!("Failed to parse {path}"))?;
+        ensure!(
+            content.version == FORK_LOCK_VERSION,
+            "Unsupported {FORK_LOCK_FILE_NAME} version = {}, run `snforge fork update` to regenerate it",
+            content.version
+        );
+
+        Ok(Some(Self {
+            path,
+            content: Mutex::new(content),
+        }))
+    }
+
+    /// Returns the fork with `name` pinned in the lock.
+    /// Forks with a different url are treated as not pinned.
+    #[must_use]
+    pub fn get(&self, name: &str, url: &Url) -> Option<LockedFork> {
+        self.content
+            .lock()
+            .unwrap()
+            .forks
+            .iter()
+            .find(|fork| fork.name == name && &fork.url == url)
+            .cloned()
+    }
+
+    /// Pins the block of the fork, replacing the previous entry with the same name.
+    pub fn insert(&self, locked_fork: LockedFork) {
+        let mut content = self.content.lock().unwrap();
+        content.forks.retain(|fork| fork.name != locked_fork.name);
+        content.forks.push(locked_fork);
+        content.forks.sort_by(|a, b| a.name.cmp(&b.name));
+        content.modified = true;
+    }
+
+    /// Removes pinned forks whose names are not in `names`.
+    pub fn retain(&self, names: &[&str]) {
+        let mut content = self.content.lock().unwrap();
+        let len = content.forks.len();
+        content
+            .forks
+            .retain(|fork| names.contains(&fork.name.as_str()));
+
+        if content.forks.len() != len {
+            content.modified = true;
+        }
+    }
+
+    /// Writes the lock to the `snfoundry.lock` file if it was modified.
+    pub fn save(&self) -> Result<()> {
+        let mut content = self.content.lock().unwrap();
+        if !content.modified {
+            return Ok(());
+        }
+
+        let serialized = toml::to_string(&*content)?;
+        fs::write(&self.path, format!("{FORK_LOCK_HEADER}\n{serialized}"))
+            .with_context(|| format!("Failed to write {}", self.path))?;
+
+        content.modified = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn locked_fork(name: &str, block_number: u64) -> LockedFork {
+        LockedFork {
+            name: name.to_string(),
+            url: Url::parse("http://example.com").unwrap(),
+            block_number,
+            block_hash: Felt::from(block_number),
+        }
+    }
+
+    #[test]
+    fn missing_lockfile_is_not_loaded() {
+        let temp = tempdir().unwrap();
+        let root = Utf8Path::from_path(temp.path()).unwrap();
+
+        assert!(ForkLock::load(root).unwrap().is_none());
+    }
+
+    #[test]
+    fn saved_lock_is_loaded() {
+        let temp = tempdir().unwrap();
+        let root = Utf8Path::from_path(temp.path()).unwrap();
+
+        let lock = ForkLock::new(root);
+        lock.insert(locked_fork("SEPOLIA", 123));
+        lock.insert(locked_fork("MAINNET", 456));
+        lock.save().unwrap();
+
+        let lock = ForkLock::load(root).unwrap().unwrap();
+        let url = Url::parse("http://example.com").unwrap();
+        assert_eq!(lock.get("SEPOLIA", &url), Some(locked_fork("SEPOLIA", 123)));
+        assert_eq!(lock.get("MAINNET", &url), Some(locked_fork("MAINNET", 456)));
+        assert_eq!(
+            lock.get("SEPOLIA", &Url::parse("http://other.com").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn insert_replaces_fork_with_same_name() {
+        let temp = tempdir().unwrap();
+        let root = Utf8Path::from_path(temp.path()).unwrap();
+        let url = Url::parse("http://example.com").unwrap();
+
+        let lock = ForkLock::new(root);
+        lock.insert(locked_fork("SEPOLIA", 123));
+        lock.insert(locked_fork("SEPOLIA", 124));
+
+        assert_eq!(lock.get("SEPOLIA", &url), Some(locked_fork("SEPOLIA", 124)));
+        assert_eq!(lock.content.lock().unwrap().forks.len(), 1);
+    }
+}
@@ -5,10 +5,12 @@ use clap::builder::BoolishValueParser;
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use derive_more::Display;
 use forge_runner::debugging::TraceArgs;
-use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::filtering::TagExpression;
+use forge_runner::forge_config::{ForgeTrackedResource, RetryOn};
 use forge_runner::partition::Partition;
 use foundry_ui::UI;
 use foundry_ui::components::warning::WarningMessage;
+use run_tests::watch::run_in_watch_mode;
 use run_tests::workspace::run_for_workspace;
 use scarb_api::ScarbCommand;
 use scarb_ui::args::{FeaturesSpec, PackagesFilter, ProfileSpec};
@@ -27,6 +29,8 @@ pub mod block_number_map;
 mod clean;
 mod combine_configs;
 mod compatibility_check;
+mod fork;
+pub mod fork_lock;
 mod new;
 mod optimize_inlining;
 mod profile_validation;
@@ -34,6 +38,7 @@ pub mod run_tests;
 pub mod scarb;
 pub mod shared_cache;
 pub mod test_filter;
+pub mod test_impact;
 mod warn;
 
 pub const CAIRO_EDITION: &str = "2024_07";
@@ -107,6 +112,23 @@ enum ForgeSubcommand {
         #[command(flatten)]
         args: Box<optimize_inlining::OptimizeInliningArgs>,
     },
+    /// Manage blocks of forks pinned in the `snfoundry.lock` file
+    Fork {
+        #[command(subcommand)]
+        command: ForkCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ForkCommand {
+    /// Pin named forks using a block tag to the latest block, creating `snfoundry.lock` if it does not exist
+    Update(ForkUpdateArgs),
+}
+
+#[derive(Parser, Debug)]
+pub struct ForkUpdateArgs {
+    /// Names of the forks to update, all forks using a block tag are updated if not provided
+    pub names: Vec<String>,
 }
 
 #[derive(Parser, Debug)]
@@ -160,6 +182,14 @@ pub struct TestArgs {
     #[arg(long)]
     skip: Vec<String>,
 
+    /// Run only tests whose tags match the given expression, e.g. `fork & !slow`
+    #[arg(long, value_name = "EXPRESSION")]
+    tags: Option<TagExpression>,
+
+    /// Skip tests whose tags match the given expression, e.g. `slow | fork`
+    #[arg(long, value_name = "EXPRESSION")]
+    exclude_tags: Option<TagExpression>,
+
     /// Stop executing tests after the first failed test
     #[arg(short = 'x', long)]
     exit_first: bool,
@@ -174,6 +204,9 @@ pub struct TestArgs {
     /// Seed for the fuzzer
     #[arg(short = 's', long, env = "SNFORGE_FUZZER_SEED")]
     fuzzer_seed: Option<u64>,
+    /// Seed for random values generated by cheatcodes, e.g. `generate_random_felt`
+    #[arg(long, env = "SNFORGE_SEED")]
+    seed: Option<u64>,
 
     /// Run only tests marked with `#[ignore]` attribute
     #[arg(long = "ignored")]
@@ -194,6 +227,18 @@ pub struct TestArgs {
     #[arg(long)]
     rerun_failed: bool,
 
+    /// Run only tests whose code or called contracts changed since their last passed run
+    #[arg(long, conflicts_with = "changed_since")]
+    affected: bool,
+
+    /// Run only tests that executed code from files changed since the given git reference, e.g. `main`
+    #[arg(long, value_name = "GIT_REF")]
+    changed_since: Option<String>,
+
+    /// Rerun tests of targets affected by changes in the package sources after every change
+    #[arg(long, conflicts_with_all = ["partition", "launch_debugger"])]
+    watch: bool,
+
     /// Save execution traces of all test which have passed and are not fuzz tests
     #[arg(long)]
     #[cfg_attr(feature = "cairo-native", arg(conflicts_with = "run_native"))]
@@ -213,6 +258,18 @@ pub struct TestArgs {
     #[arg(long)]
     max_n_steps: Option<u32>,
 
+    /// Maximum time in seconds a single test can run for. For fuzz tests this value is applied to each subtest separately.
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<u64>,
+
+    /// Number of times a failed test is rerun. Overrides `Scarb.toml` when passed.
+    #[arg(long, value_name = "N")]
+    retries: Option<u32>,
+
+    /// Failures after which tests are rerun. Overrides `Scarb.toml` when passed.
+    #[arg(long, value_enum)]
+    retry_on: Option<RetryOn>,
+
     /// Build contracts separately in the scarb starknet contract target
     #[arg(long)]
     no_optimization: bool,
@@ -336,7 +393,11 @@ pub fn main_execution(ui: Arc<UI>) -> Result<ExitStatus> {
                 .enable_all()
                 .build()?;
 
-            rt.block_on(run_for_workspace(*args, ui))
+            if args.watch {
+                rt.block_on(run_in_watch_mode(*args, ui))
+            } else {
+                rt.block_on(run_for_workspace(*args, ui))
+            }
         }
         ForgeSubcommand::CheckRequirements => {
             check_requirements(true, &ui)?;
@@ -351,6 +412,13 @@ pub fn main_execution(ui: Arc<UI>) -> Result<ExitStatus> {
             check_requirements(false, &ui)?;
             optimize_inlining::optimize_inlining(&args, cores, &ui)
         }
+        ForgeSubcommand::Fork {
+            command: ForkCommand::Update(args),
+        } => {
+            let rt = Builder::new_multi_thread().enable_all().build()?;
+            rt.block_on(fork::update(args, &ui))?;
+            Ok(ExitStatus::Success)
+        }
     }
 }
 
@@ -2,6 +2,7 @@ use crate::optimize_inlining::args::OptimizeInliningArgs;
 use crate::optimize_inlining::contract_size::{
     ContractArtifactType, ContractSizeInfo, check_and_validate_contract_sizes,
 };
+use crate::run_tests::watch::AffectedTargets;
 use crate::run_tests::workspace::execute_workspace;
 use anyhow::{Context, Result};
 use blockifier::blockifier_versioned_constants::VersionedConstants;
@@ -255,6 +256,7 @@ fn run_tests_with_execute_workspace(
         &args.test_args,
         ui.clone(),
         &scarb_metadata,
+        &AffectedTargets::All,
     ));
 
     env::set_current_dir(&original_cwd)?;
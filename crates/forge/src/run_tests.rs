@@ -3,4 +3,5 @@ pub mod messages;
 pub mod package;
 pub mod resolve_config;
 pub mod test_target;
+pub mod watch;
 pub mod workspace;
@@ -5,3 +5,4 @@ pub mod partition;
 pub mod tests_failure_summary;
 pub mod tests_run;
 pub mod tests_summary;
+pub mod watch;
@@ -0,0 +1,95 @@
+use camino::Utf8PathBuf;
+use console::style;
+use forge_runner::test_target_summary::TestTargetSummary;
+use forge_runner::tests_summary::TestsSummary;
+use foundry_ui::{Message, components::labeled::LabeledMessage};
+use serde::Serialize;
+use serde_json::{Value, json};
+
+#[derive(Serialize)]
+pub struct WatchIterationStartedMessage {
+    iteration: usize,
+    changed_files: Vec<Utf8PathBuf>,
+}
+
+impl WatchIterationStartedMessage {
+    #[must_use]
+    pub fn new(iteration: usize, changed_files: Vec<Utf8PathBuf>) -> Self {
+        Self {
+            iteration,
+            changed_files,
+        }
+    }
+
+    fn summary(&self) -> String {
+        if self.changed_files.is_empty() {
+            return format!("#{}, running all tests", self.iteration);
+        }
+
+        let changed_files = self
+            .changed_files
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("#{}, changed files: {changed_files}", self.iteration)
+    }
+}
+
+impl Message for WatchIterationStartedMessage {
+    fn text(&self) -> String {
+        let label = style("Watch iteration").bold().to_string();
+        LabeledMessage::new(&label, &self.summary()).text()
+    }
+
+    fn json(&self) -> Value {
+        json!(self)
+    }
+}
+
+#[derive(Serialize)]
+pub struct WatchIterationFinishedMessage {
+    iteration: usize,
+    summary: Option<TestsSummary>,
+}
+
+impl WatchIterationFinishedMessage {
+    #[must_use]
+    pub fn new(iteration: usize, summaries: &[TestTargetSummary], filtered: usize) -> Self {
+        Self {
+            iteration,
+            summary: Some(TestsSummary::new(summaries, filtered)),
+        }
+    }
+
+    /// Creates a message for an iteration in which tests could not be run, e.g. because of a build error.
+    #[must_use]
+    pub fn failed(iteration: usize) -> Self {
+        Self {
+            iteration,
+            summary: None,
+        }
+    }
+
+    fn summary(&self) -> String {
+        let result = self.summary.as_ref().map_or_else(
+            || "tests could not be run".to_string(),
+            TestsSummary::format_summary_message,
+        );
+        format!(
+            "#{}, {result}\nWaiting for changes, press Ctrl+C to exit",
+            self.iteration
+        )
+    }
+}
+
+impl Message for WatchIterationFinishedMessage {
+    fn text(&self) -> String {
+        let label = style("Finished watch iteration").bold().to_string();
+        LabeledMessage::new(&label, &self.summary()).text()
+    }
+
+    fn json(&self) -> Value {
+        json!(self)
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
     resolve_config::resolve_config,
     test_target::{ExitFirstChannel, TestTargetRunResult, run_for_test_target},
+    watch::AffectedTargets,
 };
 use crate::scarb::{
     config::{ForgeConfigFromScarb, ForkTarget},
@@ -16,6 +17,7 @@ use crate::{
     },
     shared_cache::FailedTestsCache,
     test_filter::TestsFilter,
+    test_impact::{ImpactSelection, TestImpact, TestsImpactRecorder},
     warn::warn_if_incompatible_rpc_version,
 };
 use anyhow::Result;
@@ -41,6 +43,7 @@ use forge_runner::{
 use foundry_ui::{UI, components::labeled::LabeledMessage};
 use scarb_api::{CompilationOpts, get_contracts_artifacts_and_source_sierra_paths};
 use scarb_metadata::{Metadata, PackageMetadata};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::task::JoinHandle;
 
@@ -49,14 +52,20 @@ type PrepareTargetHandle = JoinHandle<Result<PrepareTestTargetResult>>;
 pub struct PackageTestResult {
     summaries: Vec<TestTargetSummary>,
     filtered: usize,
+    tests_impact: HashMap<String, Option<TestImpact>>,
 }
 
 impl PackageTestResult {
     #[must_use]
-    pub fn new(summaries: Vec<TestTargetSummary>, filtered: usize) -> Self {
+    pub fn new(
+        summaries: Vec<TestTargetSummary>,
+        filtered: usize,
+        tests_impact: HashMap<String, Option<TestImpact>>,
+    ) -> Self {
         Self {
             summaries,
             filtered,
+            tests_impact,
         }
     }
 
@@ -65,6 +74,12 @@ impl PackageTestResult {
         self.filtered
     }
 
+    /// Returns impacts of the tests that were run, `None` for tests whose impact is unknown
+    #[must_use]
+    pub fn take_tests_impact(&mut self) -> HashMap<String, Option<TestImpact>> {
+        std::mem::take(&mut self.tests_impact)
+    }
+
     #[must_use]
     pub fn summaries(self) -> Vec<TestTargetSummary> {
         self.summaries
@@ -78,20 +93,42 @@ pub struct RunForPackageArgs {
     pub fork_targets: Vec<ForkTarget>,
     pub package_name: String,
     pub package_root: Utf8PathBuf,
+    /// Names of tests that are started before others, e.g. tests that failed in the previous run
+    pub prioritized_tests: Vec<String>,
+    /// Selection of tests affected by changes, set with `--affected` or `--changed-since`
+    pub impact_selection: Option<ImpactSelection>,
+}
+
+/// Inputs shared by all packages of the workspace that are run
+pub struct WorkspaceRunInput<'a> {
+    pub scarb_metadata: &'a Metadata,
+    pub args: &'a TestArgs,
+    pub cache_dir: &'a Utf8PathBuf,
+    pub artifacts_dir: &'a Utf8Path,
+    pub partitioning_config: &'a PartitionConfig,
+    /// Test targets to run, all of them unless in `--watch` mode
+    pub affected_targets: &'a AffectedTargets,
 }
 
 impl RunForPackageArgs {
     #[tracing::instrument(skip_all, level = "debug")]
     pub fn build(
         package: PackageMetadata,
-        scarb_metadata: &Metadata,
-        args: &TestArgs,
-        cache_dir: &Utf8PathBuf,
-        artifacts_dir: &Utf8Path,
-        partitioning_config: PartitionConfig,
+        workspace: &WorkspaceRunInput,
         ui: &UI,
     ) -> Result<RunForPackageArgs> {
+        let WorkspaceRunInput {
+            scarb_metadata,
+            args,
+            cache_dir,
+            artifacts_dir,
+            partitioning_config,
+            affected_targets,
+        } = *workspace;
+
         let mut raw_test_targets = load_test_artifacts(artifacts_dir, &package)?;
+        raw_test_targets
+            .retain(|target| affected_targets.contains(&package.name, target.tests_location));
 
         let contracts = get_contracts_artifacts_and_source_sierra_paths(
             artifacts_dir,
@@ -122,17 +159,28 @@ impl RunForPackageArgs {
             args.test_filter.clone(),
             args.exact,
             args.skip.clone(),
+            args.tags.clone(),
+            args.exclude_tags.clone(),
             args.only_ignored,
             args.include_ignored,
             args.rerun_failed,
             FailedTestsCache::new(cache_dir),
-            partitioning_config,
+            partitioning_config.clone(),
         );
 
         if args.deterministic_output {
             raw_test_targets.sort_by_key(|t| t.tests_location);
         }
 
+        let prioritized_tests = if args.watch {
+            FailedTestsCache::new(cache_dir).load()?
+        } else {
+            vec![]
+        };
+
+        let impact_selection =
+            ImpactSelection::from_args(args, cache_dir, &scarb_metadata.workspace.root)?;
+
         let tracked_resource = forge_config.test_runner_config.tracked_resource;
 
         let target_handles = raw_test_targets
@@ -154,6 +202,8 @@ impl RunForPackageArgs {
             fork_targets: forge_config_from_scarb.fork,
             package_name: package.name.clone(),
             package_root: package.root,
+            prioritized_tests,
+            impact_selection,
         })
     }
 }
@@ -187,6 +237,7 @@ fn sum_test_cases_from_test_target(
 }
 
 #[tracing::instrument(skip_all, level = "debug")]
+#[expect(clippy::too_many_lines)]
 pub async fn run_for_package(
     RunForPackageArgs {
         target_handles,
@@ -195,6 +246,8 @@ pub async fn run_for_package(
         fork_targets,
         package_name,
         package_root: _,
+        prioritized_tests,
+        impact_selection,
     }: RunForPackageArgs,
     block_number_map: &BlockNumberMap,
     ui: Arc<UI>,
@@ -208,6 +261,8 @@ pub async fn run_for_package(
     let mut not_filtered_total = 0;
     // Tests excluded before config resolution, currently by name filtering in `prepare_test_target`.
     let mut prefiltered_out_total = 0;
+    let contracts_data = &forge_config.test_runner_config.contracts_data;
+    let mut impact_recorder = TestsImpactRecorder::default();
 
     for handle in target_handles {
         let PrepareTestTargetResult {
@@ -235,6 +290,18 @@ pub async fn run_for_package(
             &tests_filter.partitioning_config,
         );
         tests_filter.filter_tests(&mut resolved.test_cases)?;
+        impact_recorder.add_target(&resolved);
+        if let Some(impact_selection) = &impact_selection {
+            resolved.test_cases.retain(|test_case| {
+                impact_selection.is_selected(
+                    &test_case.name,
+                    impact_recorder.code(&test_case.name),
+                    contracts_data,
+                )
+            });
+        }
+
+        prioritize_tests(&mut resolved.test_cases, &prioritized_tests);
         let not_filtered = sum_test_cases_from_test_target(
             &resolved.test_cases,
             &tests_filter.partitioning_config,
@@ -284,6 +351,7 @@ pub async fn run_for_package(
         .await?;
 
         let (TestTargetRunResult::Ok(s) | TestTargetRunResult::Interrupted(s)) = summary;
+        impact_recorder.record(&s.test_case_summaries, contracts_data);
         summaries.push(s);
     }
 
@@ -306,5 +374,57 @@ pub async fn run_for_package(
         ));
     }
 
-    Ok(PackageTestResult::new(summaries, filtered_count))
+    Ok(PackageTestResult::new(
+        summaries,
+        filtered_count,
+        impact_recorder.into_impacts(),
+    ))
+}
+
+/// Moves the prioritized tests to the front, so that they are started first
+fn prioritize_tests(test_cases: &mut [TestCaseWithResolvedConfig], prioritized_tests: &[String]) {
+    // Stable sort keeps the original order within both groups
+    test_cases.sort_by_key(|test_case| !prioritized_tests.contains(&test_case.name));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forge_runner::expected_result::ExpectedTestResult;
+    use forge_runner::package_tests::TestDetails;
+    use forge_runner::package_tests::with_config_resolved::TestCaseResolvedConfig;
+
+    fn test_case(name: &str) -> TestCaseWithResolvedConfig {
+        TestCaseWithResolvedConfig::new(
+            name,
+            TestDetails::default(),
+            TestCaseResolvedConfig {
+                available_gas: None,
+                ignored: false,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: None,
+                fuzzer_config: None,
+                disable_predeployed_contracts: false,
+                seed: None,
+                setup: None,
+                timeout: None,
+                max_steps: None,
+                retries: None,
+                tags: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn prioritized_tests_are_run_first() {
+        let mut test_cases = ["first", "second", "third", "fourth"].map(test_case);
+
+        prioritize_tests(
+            &mut test_cases,
+            &["fourth".to_string(), "second".to_string()],
+        );
+
+        let names: Vec<_> = test_cases.iter().map(|case| case.name.as_str()).collect();
+        assert_eq!(names, ["second", "fourth", "first", "third"]);
+    }
 }
@@ -19,6 +19,9 @@ use forge_runner::{
 };
 use starknet_api::block::BlockNumber;
 
+/// Tag assigned to every test using `#[fork]`
+pub const FORK_TAG: &str = "fork";
+
 #[tracing::instrument(skip_all, level = "debug")]
 pub async fn resolve_config(
     test_target: TestTargetWithConfig,
@@ -30,8 +33,10 @@ pub async fn resolve_config(
     let env_ignore_fork_tests = env_ignore_fork_tests();
 
     for case in test_target.test_cases {
+        let tags = resolve_tags(case.config.tags.clone(), case.config.fork_config.is_some());
         let filter_result = tests_filter.filter(&case);
-        let should_be_run = matches!(filter_result, FilterResult::Included);
+        let should_be_run =
+            matches!(filter_result, FilterResult::Included) && tests_filter.matches_tags(&tags);
 
         test_cases.push(TestCaseWithResolvedConfig::new(
             &case.name,
@@ -49,6 +54,12 @@ pub async fn resolve_config(
                 expected_result: case.config.expected_result,
                 fuzzer_config: case.config.fuzzer_config,
                 disable_predeployed_contracts: case.config.disable_predeployed_contracts,
+                seed: case.config.seed,
+                setup: case.config.setup,
+                timeout: case.config.timeout,
+                max_steps: case.config.max_steps,
+                retries: case.config.retries,
+                tags,
             },
         ));
     }
@@ -59,9 +70,18 @@ pub async fn resolve_config(
         sierra_program_path: test_target.sierra_program_path,
         casm_program: test_target.casm_program,
         test_cases,
+        fixtures: test_target.fixtures,
+        hooks: test_target.hooks,
     })
 }
 
+fn resolve_tags(mut tags: Vec<String>, is_fork_test: bool) -> Vec<String> {
+    if is_fork_test && !tags.iter().any(|tag| tag == FORK_TAG) {
+        tags.push(FORK_TAG.to_string());
+    }
+    tags
+}
+
 async fn resolve_fork_config(
     fork_config: Option<RawForkConfig>,
     block_number_map: &BlockNumberMap,
@@ -71,6 +91,12 @@ async fn resolve_fork_config(
         return Ok(None);
     };
 
+    // Only block tags of forks defined in `Scarb.toml` are pinned in the lockfile
+    let fork_name = match &fc {
+        RawForkConfig::Named(name) => Some(name.to_string()),
+        RawForkConfig::Inline(_) | RawForkConfig::Overridden(_) => None,
+    };
+
     let raw_fork_params = replace_id_with_params(fc, fork_targets)?;
 
     let url = raw_fork_params.url;
@@ -82,11 +108,18 @@ async fn resolve_fork_config(
                 .get_block_number_for_hash(url.clone(), hash)
                 .await?
         }
-        BlockId::BlockTag => {
-            block_number_map
-                .get_latest_block_number(url.clone())
-                .await?
-        }
+        BlockId::BlockTag => match fork_name {
+            Some(name) => {
+                block_number_map
+                    .get_pinned_block_number(&name, url.clone())
+                    .await?
+            }
+            None => {
+                block_number_map
+                    .get_latest_block_number(url.clone())
+                    .await?
+            }
+        },
     };
 
     Ok(Some(ResolvedForkConfig { url, block_number }))
@@ -173,6 +206,12 @@ mod tests {
                 fork_config,
                 fuzzer_config: None,
                 disable_predeployed_contracts: false,
+                seed: None,
+                setup: None,
+                timeout: None,
+                max_steps: None,
+                retries: None,
+                tags: vec![],
             },
             test_details: TestDetails {
                 sierra_entry_point_statement_idx: 100,
@@ -193,6 +232,8 @@ mod tests {
                     .unwrap(),
             ),
             test_cases,
+            fixtures: vec![],
+            hooks: vec![],
             tests_location: TestTargetLocation::Lib,
         }
     }
@@ -217,6 +258,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -255,6 +298,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -291,6 +336,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             false,
             true,
             false,
@@ -336,6 +383,8 @@ mod tests {
             Some("different_pattern".to_string()),
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -393,6 +442,8 @@ mod tests {
             Some("matching".to_string()),
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -462,6 +513,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             true,
             false,
             false,
@@ -520,6 +573,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             false,
             true,
             false,
@@ -570,6 +625,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -614,6 +671,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -659,6 +718,8 @@ mod tests {
             None,
             false,
             vec!["skipped".to_string()],
+            None,
+            None,
             false,
             false,
             false,
@@ -685,4 +746,39 @@ mod tests {
         assert_eq!(fork_config.url.as_str(), "https://example.com/");
         assert_eq!(fork_config.block_number.0, 600);
     }
+
+    #[tokio::test]
+    async fn fork_tests_are_tagged_and_excluded_before_fork_resolution() {
+        let fork_test = create_test_case_with_config(
+            "fork_test",
+            false,
+            Some(RawForkConfig::Named("non_existent_fork".into())),
+        );
+
+        let test_target = create_test_target_with_cases(vec![fork_test]);
+
+        let tests_filter = TestsFilter::from_flags(
+            None,
+            false,
+            Vec::new(),
+            None,
+            Some(FORK_TAG.parse().unwrap()),
+            false,
+            false,
+            false,
+            FailedTestsCache::default(),
+            PartitionConfig::default(),
+        );
+
+        let resolved = resolve_config(test_target, &[], &BlockNumberMap::default(), &tests_filter)
+            .await
+            .unwrap();
+
+        assert_eq!(resolved.test_cases.len(), 1);
+        assert_eq!(
+            resolved.test_cases[0].config.tags,
+            vec![FORK_TAG.to_string()]
+        );
+        assert!(resolved.test_cases[0].config.fork_config.is_none());
+    }
 }
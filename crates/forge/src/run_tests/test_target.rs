@@ -4,6 +4,9 @@ use forge_runner::backtrace::{
 };
 use forge_runner::filtering::{ExcludeReason, FilterResult, TestCaseFilter};
 use forge_runner::messages::TestResultMessage;
+use forge_runner::running::TestCaseRunInput;
+use forge_runner::running::fixture::FixtureSetups;
+use forge_runner::running::hooks::CaseHooks;
 use forge_runner::{
     forge_config::ForgeConfig,
     maybe_generate_coverage, maybe_save_trace_and_profile,
@@ -75,6 +78,19 @@ pub async fn run_for_test_target(
     // Shared across all test case tasks, so each contract's backtrace data is built once and reused.
     let contract_backtrace_mapping = Arc::new(LazyContractBacktraceDataMapping::new());
 
+    // Fixtures are run before any of the tests that are set up with them
+    let fixture_setups = FixtureSetups::run(
+        &tests.fixtures,
+        tests
+            .test_cases
+            .iter()
+            .filter(|case| matches!(tests_filter.filter(*case), FilterResult::Included)),
+        &casm_program,
+        &forge_config,
+        &tests.sierra_program_path,
+    )
+    .await?;
+
     let mut tasks = FuturesUnordered::new();
 
     for case in tests.test_cases {
@@ -98,17 +114,25 @@ pub async fn run_for_test_target(
                     }));
                 }
             },
-            FilterResult::Included => {
-                tasks.push(run_for_test_case(
-                    Arc::new(case),
-                    casm_program.clone(),
-                    forge_config.clone(),
-                    tests.sierra_program_path.clone(),
-                    test_annotations.clone(),
-                    contract_backtrace_mapping.clone(),
-                    exit_first_channel.sender(),
-                ));
-            }
+            FilterResult::Included => match fixture_setups.snapshot_for(&case) {
+                Ok(fixture_snapshot) => {
+                    let hooks = Arc::new(CaseHooks::for_case(&tests.hooks, &case));
+                    let input = TestCaseRunInput {
+                        case: Arc::new(case),
+                        casm_program: casm_program.clone(),
+                        forge_config: forge_config.clone(),
+                        versioned_program_path: tests.sierra_program_path.clone(),
+                        test_annotations: test_annotations.clone(),
+                        contract_backtrace_mapping: contract_backtrace_mapping.clone(),
+                        fixture_snapshot,
+                        hooks,
+                    };
+                    tasks.push(run_for_test_case(input, exit_first_channel.sender()));
+                }
+                Err(summary) => {
+                    tasks.push(tokio::task::spawn(async { Ok(summary) }));
+                }
+            },
         }
     }
 
@@ -0,0 +1,414 @@
+use super::messages::watch::{WatchIterationFinishedMessage, WatchIterationStartedMessage};
+use super::workspace::{
+    WorkspaceExecutionSummary, build_workspace, execute_workspace, load_metadata,
+};
+use crate::{ExitStatus, TestArgs};
+use anyhow::Result;
+use camino::{Utf8Path, Utf8PathBuf};
+use console::Term;
+use forge_runner::package_tests::TestTargetLocation;
+use foundry_ui::{OutputFormat, UI, components::error::ErrorMessage};
+use scarb_api::packages_from_filter;
+use scarb_metadata::PackageMetadata;
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use walkdir::{DirEntry, WalkDir};
+
+/// How often the watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Test targets that are run, identified by the package name and location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AffectedTargets {
+    All,
+    Only(HashSet<(String, TestTargetLocation)>),
+}
+
+impl AffectedTargets {
+    #[must_use]
+    pub fn contains(&self, package_name: &str, location: TestTargetLocation) -> bool {
+        match self {
+            AffectedTargets::All => true,
+            AffectedTargets::Only(targets) => {
+                targets.contains(&(package_name.to_string(), location))
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn contains_package(&self, package_name: &str) -> bool {
+        match self {
+            AffectedTargets::All => true,
+            AffectedTargets::Only(targets) => targets.iter().any(|(name, _)| name == package_name),
+        }
+    }
+
+    /// Changes in the `tests` directory of a package affect only its integration tests.
+    /// Other changes in a package affect all of its targets and targets of packages depending on it,
+    /// directly or through other packages.
+    /// Changes outside the tested packages, e.g. in the workspace manifest, affect all targets.
+    fn from_changed_files(changed_files: &[Utf8PathBuf], packages: &[WatchedPackage]) -> Self {
+        let mut targets = HashSet::new();
+
+        for file in changed_files {
+            // The deepest root is chosen, as packages can be nested in the workspace root
+            let Some(package) = packages
+                .iter()
+                .filter(|package| file.starts_with(&package.root))
+                .max_by_key(|package| package.root.components().count())
+            else {
+                return AffectedTargets::All;
+            };
+
+            if file.starts_with(package.root.join("tests")) {
+                targets.insert((package.name.clone(), TestTargetLocation::Tests));
+                continue;
+            }
+
+            for affected in with_dependents(package, packages) {
+                targets.insert((affected.name.clone(), TestTargetLocation::Lib));
+                targets.insert((affected.name.clone(), TestTargetLocation::Tests));
+            }
+        }
+
+        AffectedTargets::Only(targets)
+    }
+}
+
+/// Returns the `package` followed by all packages depending on it, directly or transitively
+fn with_dependents<'a>(
+    package: &'a WatchedPackage,
+    packages: &'a [WatchedPackage],
+) -> Vec<&'a WatchedPackage> {
+    let mut affected = vec![package];
+    let mut next = 0;
+
+    while let Some(current) = affected.get(next) {
+        let dependents: Vec<_> = packages
+            .iter()
+            .filter(|other| other.dependencies.contains(&current.name))
+            .filter(|other| !affected.iter().any(|known| known.name == other.name))
+            .collect();
+        affected.extend(dependents);
+        next += 1;
+    }
+
+    affected
+}
+
+/// Tested package with the names of its dependencies
+struct WatchedPackage {
+    name: String,
+    root: Utf8PathBuf,
+    dependencies: Vec<String>,
+}
+
+impl From<PackageMetadata> for WatchedPackage {
+    fn from(package: PackageMetadata) -> Self {
+        Self {
+            name: package.name,
+            root: package.root,
+            dependencies: package
+                .dependencies
+                .into_iter()
+                .map(|dependency| dependency.name)
+                .collect(),
+        }
+    }
+}
+
+/// Modification times of the workspace files that can affect tests
+#[derive(Debug, Default)]
+struct WatchedFiles(HashMap<Utf8PathBuf, SystemTime>);
+
+impl WatchedFiles {
+    fn scan(root: &Utf8Path) -> Self {
+        let files = WalkDir::new(root)
+            .into_iter()
+            .filter_entry(|entry| entry.depth() == 0 || !is_ignored_dir(entry))
+            .filter_map(Result::ok)
+            .filter(is_watched_file)
+            .filter_map(|entry| {
+                let modified = entry.metadata().ok()?.modified().ok()?;
+                let path = Utf8PathBuf::from_path_buf(entry.into_path()).ok()?;
+                Some((path, modified))
+            })
+            .collect();
+
+        Self(files)
+    }
+
+    /// Returns files added, modified or removed since the `previous` scan
+    fn changed_since(&self, previous: &Self) -> Vec<Utf8PathBuf> {
+        let modified = self
+            .0
+            .iter()
+            .filter(|(path, modified)| previous.0.get(*path) != Some(*modified))
+            .map(|(path, _)| path);
+        let removed = previous.0.keys().filter(|path| !self.0.contains_key(*path));
+
+        modified.chain(removed).cloned().collect()
+    }
+}
+
+/// Build artifacts, caches and VCS directories are not watched
+fn is_ignored_dir(entry: &DirEntry) -> bool {
+    let name = entry.file_name().to_string_lossy();
+    entry.file_type().is_dir() && (name.starts_with('.') || name == "target")
+}
+
+fn is_watched_file(entry: &DirEntry) -> bool {
+    entry.file_type().is_file()
+        && (entry.path().extension().is_some_and(|ext| ext == "cairo")
+            || entry.file_name() == "Scarb.toml")
+}
+
+/// Runs tests and reruns tests of the affected targets after every change of the workspace files.
+/// Never returns unless the initial metadata cannot be loaded, it is stopped with Ctrl+C.
+#[tracing::instrument(skip_all, level = "debug")]
+pub async fn run_in_watch_mode(args: TestArgs, ui: Arc<UI>) -> Result<ExitStatus> {
+    let workspace_root = load_metadata(&args)?.workspace.root;
+    let cwd = env::current_dir()?;
+
+    let mut watched_files = WatchedFiles::scan(&workspace_root);
+    // Changes are kept until tests are run successfully, so a build error does not lose them
+    let mut pending_changes: Vec<Utf8PathBuf> = vec![];
+    let mut all_tests_run = false;
+    let mut iteration = 0;
+
+    loop {
+        iteration += 1;
+        clear_terminal(&ui);
+        // A failed iteration can leave the working directory set to one of the packages
+        env::set_current_dir(&cwd)?;
+
+        let changed_files = all_tests_run.then_some(pending_changes.as_slice());
+        let relative_changed_files = changed_files
+            .unwrap_or_default()
+            .iter()
+            .map(|file| {
+                file.strip_prefix(&workspace_root)
+                    .unwrap_or(file.as_path())
+                    .to_owned()
+            })
+            .collect();
+        ui.println(&WatchIterationStartedMessage::new(
+            iteration,
+            relative_changed_files,
+        ));
+
+        match run_iteration(&args, ui.clone(), changed_files).await {
+            Ok(WorkspaceExecutionSummary {
+                all_tests,
+                filtered,
+            }) => {
+                all_tests_run = true;
+                pending_changes.clear();
+                ui.println(&WatchIterationFinishedMessage::new(
+                    iteration, &all_tests, filtered,
+                ));
+            }
+            Err(error) => {
+                ui.println(&ErrorMessage::from(error));
+                ui.println(&WatchIterationFinishedMessage::failed(iteration));
+            }
+        }
+
+        pending_changes.extend(wait_for_changes(&workspace_root, &mut watched_files).await);
+        pending_changes.sort();
+        pending_changes.dedup();
+    }
+}
+
+/// Runs tests of targets affected by `changed_files`, or all tests if they are `None`
+async fn run_iteration(
+    args: &TestArgs,
+    ui: Arc<UI>,
+    changed_files: Option<&[Utf8PathBuf]>,
+) -> Result<WorkspaceExecutionSummary> {
+    let scarb_metadata = load_metadata(args)?;
+
+    let affected_targets = if let Some(changed_files) = changed_files {
+        let packages: Vec<WatchedPackage> =
+            packages_from_filter(&scarb_metadata, &args.scarb_args.packages_filter)?
+                .into_iter()
+                .map(WatchedPackage::from)
+                .collect();
+        AffectedTargets::from_changed_files(changed_files, &packages)
+    } else {
+        AffectedTargets::All
+    };
+
+    build_workspace(args, &scarb_metadata)?;
+    execute_workspace(args, ui, &scarb_metadata, &affected_targets).await
+}
+
+/// Waits until the watched files change and stop changing, e.g. after an editor saved all of them
+async fn wait_for_changes(root: &Utf8Path, watched_files: &mut WatchedFiles) -> Vec<Utf8PathBuf> {
+    let mut changed_files = vec![];
+
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+
+        let current = WatchedFiles::scan(root);
+        let new_changes = current.changed_since(watched_files);
+        *watched_files = current;
+
+        if new_changes.is_empty() && !changed_files.is_empty() {
+            return changed_files;
+        }
+        changed_files.extend(new_changes);
+    }
+}
+
+fn clear_terminal(ui: &UI) {
+    let term = Term::stdout();
+    if ui.output_format() == OutputFormat::Human && term.is_term() {
+        // Failing to clear the terminal only leaves the previous output visible
+        term.clear_screen().ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(name: &str, root: &str, dependencies: &[&str]) -> WatchedPackage {
+        WatchedPackage {
+            name: name.to_string(),
+            root: Utf8PathBuf::from(root),
+            dependencies: dependencies.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn targets(targets: &[(&str, TestTargetLocation)]) -> AffectedTargets {
+        AffectedTargets::Only(
+            targets
+                .iter()
+                .map(|(name, location)| ((*name).to_string(), *location))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn changes_in_tests_affect_only_integration_tests() {
+        let packages = [package("a", "/ws/a", &[])];
+
+        let affected = AffectedTargets::from_changed_files(
+            &[Utf8PathBuf::from("/ws/a/tests/test_contract.cairo")],
+            &packages,
+        );
+
+        assert_eq!(affected, targets(&[("a", TestTargetLocation::Tests)]));
+    }
+
+    #[test]
+    fn changes_in_sources_affect_dependent_packages() {
+        let packages = [
+            package("a", "/ws/a", &[]),
+            package("b", "/ws/b", &["a"]),
+            package("c", "/ws/c", &[]),
+        ];
+
+        let affected = AffectedTargets::from_changed_files(
+            &[Utf8PathBuf::from("/ws/a/src/lib.cairo")],
+            &packages,
+        );
+
+        assert_eq!(
+            affected,
+            targets(&[
+                ("a", TestTargetLocation::Lib),
+                ("a", TestTargetLocation::Tests),
+                ("b", TestTargetLocation::Lib),
+                ("b", TestTargetLocation::Tests),
+            ])
+        );
+        assert!(!affected.contains_package("c"));
+    }
+
+    #[test]
+    fn changes_in_sources_affect_transitive_dependents() {
+        let packages = [
+            package("a", "/ws/a", &[]),
+            package("b", "/ws/b", &["a"]),
+            package("c", "/ws/c", &["b"]),
+        ];
+
+        let affected = AffectedTargets::from_changed_files(
+            &[Utf8PathBuf::from("/ws/a/src/lib.cairo")],
+            &packages,
+        );
+
+        assert_eq!(
+            affected,
+            targets(&[
+                ("a", TestTargetLocation::Lib),
+                ("a", TestTargetLocation::Tests),
+                ("b", TestTargetLocation::Lib),
+                ("b", TestTargetLocation::Tests),
+                ("c", TestTargetLocation::Lib),
+                ("c", TestTargetLocation::Tests),
+            ])
+        );
+    }
+
+    #[test]
+    fn changes_outside_packages_affect_all_targets() {
+        let packages = [package("a", "/ws/a", &[])];
+
+        let affected = AffectedTargets::from_changed_files(
+            &[
+                Utf8PathBuf::from("/ws/a/tests/test_contract.cairo"),
+                Utf8PathBuf::from("/ws/Scarb.toml"),
+            ],
+            &packages,
+        );
+
+        assert_eq!(affected, AffectedTargets::All);
+    }
+
+    #[test]
+    fn nested_package_is_matched_before_workspace_root() {
+        let packages = [package("root", "/ws", &[]), package("a", "/ws/a", &[])];
+
+        let affected = AffectedTargets::from_changed_files(
+            &[Utf8PathBuf::from("/ws/a/tests/test_contract.cairo")],
+            &packages,
+        );
+
+        assert_eq!(affected, targets(&[("a", TestTargetLocation::Tests)]));
+    }
+
+    #[test]
+    fn detects_modified_and_removed_files() {
+        let now = SystemTime::now();
+        let previous = WatchedFiles(HashMap::from([
+            (Utf8PathBuf::from("kept.cairo"), now),
+            (Utf8PathBuf::from("modified.cairo"), now),
+            (Utf8PathBuf::from("removed.cairo"), now),
+        ]));
+        let current = WatchedFiles(HashMap::from([
+            (Utf8PathBuf::from("kept.cairo"), now),
+            (
+                Utf8PathBuf::from("modified.cairo"),
+                now + Duration::from_secs(1),
+            ),
+            (Utf8PathBuf::from("added.cairo"), now),
+        ]));
+
+        let mut changed = current.changed_since(&previous);
+        changed.sort();
+
+        assert_eq!(
+            changed,
+            [
+                Utf8PathBuf::from("added.cairo"),
+                Utf8PathBuf::from("modified.cairo"),
+                Utf8PathBuf::from("removed.cairo"),
+            ]
+        );
+    }
+}
@@ -1,10 +1,12 @@
-use super::package::RunForPackageArgs;
+use super::package::{RunForPackageArgs, WorkspaceRunInput};
+use crate::fork_lock::ForkLock;
 use crate::profile_validation::check_compiler_config_compatibility;
 use crate::profile_validation::enable_gas::check_enable_gas;
 use crate::run_tests::messages::latest_blocks_numbers::LatestBlocksNumbersMessage;
 use crate::run_tests::messages::overall_summary::OverallSummaryMessage;
 use crate::run_tests::messages::partition::{PartitionFinishedMessage, PartitionStartedMessage};
 use crate::run_tests::messages::tests_failure_summary::TestsFailureSummaryMessage;
+use crate::run_tests::watch::AffectedTargets;
 use crate::warn::{
     error_if_snforge_std_not_compatible, warn_if_snforge_std_does_not_match_package_version,
 };
@@ -12,6 +14,7 @@ use crate::{
     ColorOption, ExitStatus, TestArgs, block_number_map::BlockNumberMap,
     run_tests::package::run_for_package, run_tests::test_target::ExitFirstChannel,
     scarb::build_artifacts_with_scarb, shared_cache::FailedTestsCache,
+    test_impact::TestsImpactCache,
 };
 use anyhow::Result;
 use forge_runner::partition::PartitionConfig;
@@ -26,26 +29,45 @@ use scarb_api::{
 };
 use scarb_ui::args::PackagesFilter;
 use shared::consts::SNFORGE_TEST_FILTER;
+use std::collections::HashSet;
 use std::env;
 use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct WorkspaceExecutionSummary {
     pub all_tests: Vec<TestTargetSummary>,
+    pub filtered: usize,
 }
 
 #[tracing::instrument(skip_all, level = "debug")]
-#[allow(clippy::too_many_lines)]
 pub async fn run_for_workspace(args: TestArgs, ui: Arc<UI>) -> Result<ExitStatus> {
-    let scarb_metadata = metadata_with_opts(MetadataOpts {
+    let scarb_metadata = load_metadata(&args)?;
+    build_workspace(&args, &scarb_metadata)?;
+
+    let WorkspaceExecutionSummary { all_tests, .. } =
+        execute_workspace(&args, ui, &scarb_metadata, &AffectedTargets::All).await?;
+    let has_failures = extract_failed_tests(&all_tests).next().is_some();
+    Ok(if has_failures {
+        ExitStatus::Failure
+    } else {
+        ExitStatus::Success
+    })
+}
+
+pub(crate) fn load_metadata(args: &TestArgs) -> Result<Metadata> {
+    Ok(metadata_with_opts(MetadataOpts {
         profile: args.scarb_args.profile.specified(),
         ..MetadataOpts::default()
-    })?;
+    })?)
+}
 
+/// Builds test artifacts of the selected packages with Scarb.
+#[tracing::instrument(skip_all, level = "debug")]
+pub(crate) fn build_workspace(args: &TestArgs, scarb_metadata: &Metadata) -> Result<()> {
     let packages: Vec<PackageMetadata> =
-        packages_from_filter(&scarb_metadata, &args.scarb_args.packages_filter)?;
+        packages_from_filter(scarb_metadata, &args.scarb_args.packages_filter)?;
 
-    check_enable_gas(&scarb_metadata, &packages)?;
+    check_enable_gas(scarb_metadata, &packages)?;
 
     let filter = PackagesFilter::generate_for::<Metadata>(packages.iter());
 
@@ -56,20 +78,15 @@ pub async fn run_for_workspace(args: TestArgs, ui: Arc<UI>) -> Result<ExitStatus
         args.no_optimization,
     )?;
 
-    let WorkspaceExecutionSummary { all_tests } =
-        execute_workspace(&args, ui, &scarb_metadata).await?;
-    let has_failures = extract_failed_tests(&all_tests).next().is_some();
-    Ok(if has_failures {
-        ExitStatus::Failure
-    } else {
-        ExitStatus::Success
-    })
+    Ok(())
 }
 
+#[expect(clippy::too_many_lines)]
 pub async fn execute_workspace(
     args: &TestArgs,
     ui: Arc<UI>,
     scarb_metadata: &Metadata,
+    affected_targets: &AffectedTargets,
 ) -> Result<WorkspaceExecutionSummary> {
     let deterministic_output = args.deterministic_output;
     match args.color {
@@ -81,7 +98,10 @@ pub async fn execute_workspace(
     }
 
     let packages: Vec<PackageMetadata> =
-        packages_from_filter(scarb_metadata, &args.scarb_args.packages_filter)?;
+        packages_from_filter(scarb_metadata, &args.scarb_args.packages_filter)?
+            .into_iter()
+            .filter(|package| affected_targets.contains_package(&package.name))
+            .collect();
 
     check_compiler_config_compatibility(args, scarb_metadata, &packages)?;
 
@@ -100,31 +120,34 @@ pub async fn execute_workspace(
         }
     }
 
-    let block_number_map = BlockNumberMap::default();
+    let block_number_map =
+        BlockNumberMap::with_fork_lock(ForkLock::load(&scarb_metadata.workspace.root)?);
     let mut all_tests = vec![];
     let mut total_filtered_count = 0;
     let mut exit_first_channel = ExitFirstChannel::new();
 
     let cache_dir = resolve_cache_dir(&scarb_metadata.workspace.root)?;
+    let tests_impact_cache = TestsImpactCache::new(&cache_dir);
+    let mut tests_impact = tests_impact_cache.load()?;
     let packages_len = packages.len();
 
     let partitioning_config = get_partitioning_config(args, &ui, &packages, &artifacts_dir_path)?;
 
     // Spawn config passes for all packages before running any tests so that
     // compilation overlaps with test execution across packages.
+    let workspace_input = WorkspaceRunInput {
+        scarb_metadata,
+        args,
+        cache_dir: &cache_dir,
+        artifacts_dir: &artifacts_dir_path,
+        partitioning_config: &partitioning_config,
+        affected_targets,
+    };
     let mut all_package_args = Vec::with_capacity(packages.len());
     for pkg in packages {
         let cwd = env::current_dir()?;
         env::set_current_dir(&pkg.root)?;
-        let pkg_args = RunForPackageArgs::build(
-            pkg,
-            scarb_metadata,
-            args,
-            &cache_dir,
-            &artifacts_dir_path,
-            partitioning_config.clone(),
-            &ui,
-        )?;
+        let pkg_args = RunForPackageArgs::build(pkg, &workspace_input, &ui)?;
         env::set_current_dir(&cwd)?;
         all_package_args.push(pkg_args);
     }
@@ -133,7 +156,7 @@ pub async fn execute_workspace(
         let cwd = env::current_dir()?;
         env::set_current_dir(&pkg_args.package_root)?;
 
-        let result = run_for_package(
+        let mut result = run_for_package(
             pkg_args,
             &block_number_map,
             ui.clone(),
@@ -141,6 +164,7 @@ pub async fn execute_workspace(
         )
         .await?;
 
+        tests_impact.update(result.take_tests_impact());
         total_filtered_count += result.filtered();
         all_tests.extend(result.summaries());
         env::set_current_dir(&cwd)?;
@@ -152,7 +176,29 @@ pub async fn execute_workspace(
         all_failed_tests.sort_by(|a, b| a.name().unwrap_or("").cmp(b.name().unwrap_or("")));
     }
 
-    FailedTestsCache::new(&cache_dir).save_failed_tests(&all_failed_tests)?;
+    let failed_tests_cache = FailedTestsCache::new(&cache_dir);
+    let mut failed_test_names: Vec<String> = all_failed_tests
+        .iter()
+        .filter_map(|summary| summary.name())
+        .map(String::from)
+        .collect();
+    if let AffectedTargets::Only(_) = affected_targets {
+        // Tests of targets that were not rerun in `--watch` mode keep their previous result
+        let run_tests: HashSet<&str> = all_tests
+            .iter()
+            .flat_map(|target| &target.test_case_summaries)
+            .filter_map(AnyTestCaseSummary::name)
+            .collect();
+        failed_test_names.extend(
+            failed_tests_cache
+                .load()?
+                .into_iter()
+                .filter(|name| !run_tests.contains(name.as_str())),
+        );
+    }
+    failed_tests_cache.save_failed_tests(&failed_test_names)?;
+    tests_impact_cache.save(&tests_impact)?;
+    block_number_map.save_fork_lock()?;
 
     let url_to_block_number = block_number_map.get_url_to_latest_block_number();
     if !url_to_block_number.is_empty() {
@@ -186,7 +232,10 @@ pub async fn execute_workspace(
         unset_forge_test_filter();
     }
 
-    Ok(WorkspaceExecutionSummary { all_tests })
+    Ok(WorkspaceExecutionSummary {
+        all_tests,
+        filtered: total_filtered_count,
+    })
 }
 
 fn get_partitioning_config(
@@ -1,6 +1,7 @@
 use anyhow::Result;
+use cheatnet::predeployment::config::PredeploymentConfig;
 use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
-use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::forge_config::{ForgeTrackedResource, RetryOn};
 use serde::{Deserialize, Deserializer};
 use std::{collections::HashSet, num::NonZeroU32};
 use url::Url;
@@ -12,6 +13,9 @@ pub const SCARB_MANIFEST_TEMPLATE_CONTENT: &str = r#"
 # exit_first = true                                          # Stop tests execution immediately upon the first failure
 # fuzzer_runs = 1234                                         # Number of runs of the random fuzzer
 # fuzzer_seed = 1111                                         # Seed for the random fuzzer
+# seed = 2222                                                # Seed for random values generated by cheatcodes
+# timeout = 60                                               # Maximum time in seconds a single test can run for
+# retries = 2                                                # Number of times a test failed on a fork error is rerun
 # tracked_resource = "sierra-gas"                            # Resource tracked by `snforge`
 
 # [[tool.snforge.fork]]                                      # Used for fork testing
@@ -29,6 +33,14 @@ pub const SCARB_MANIFEST_TEMPLATE_CONTENT: &str = r#"
 # url = "http://your.third.rpc.url"
 # block_id.hash = "0x123"                                    # Block to fork from (block hash)
 
+# [tool.snforge.predeployment]                               # Contracts predeployed before running each test
+# udc = "UniversalDeployer"                                  # Universal Deployer Contract predeployed at `UDC_ADDRESS`
+
+# [[tool.snforge.predeployment.accounts]]                    # Accounts funded with STRK, with keys returned by `predeployed_accounts`
+# contract = "AccountUpgradeable"                            # Name of the account contract
+# kind = "open-zeppelin"                                     # Kind of the account (open-zeppelin, argent or braavos)
+# count = 2                                                  # Number of accounts
+
 # [profile.dev.cairo]                                        # Configure Cairo compiler
 # unstable-add-statements-code-locations-debug-info = true   # Should be used if you want to use coverage
 # unstable-add-statements-functions-debug-info = true        # Should be used if you want to use coverage/profiler
@@ -48,6 +60,8 @@ pub struct ForgeConfigFromScarb {
     pub fuzzer_runs: Option<NonZeroU32>,
     /// Seed to be used by fuzzer
     pub fuzzer_seed: Option<u64>,
+    /// Seed to be used by cheatcodes generating random values
+    pub seed: Option<u64>,
     /// Display more detailed info about used resources
     #[serde(default)]
     pub detailed_resources: bool,
@@ -68,9 +82,19 @@ pub struct ForgeConfigFromScarb {
     pub fork: Vec<ForkTarget>,
     /// Limit of steps
     pub max_n_steps: Option<u32>,
+    /// Limit of time in seconds a single test can run for
+    pub timeout: Option<u64>,
+    /// Number of times a failed test is rerun
+    pub retries: Option<u32>,
+    /// Failures after which tests are rerun
+    #[serde(default)]
+    pub retry_on: RetryOn,
     /// Set tracked resource
     #[serde(default)]
     pub tracked_resource: ForgeTrackedResource,
+    /// Contracts predeployed before running each test
+    #[serde(default)]
+    pub predeployment: PredeploymentConfig,
 }
 
 #[derive(Debug, PartialEq, Clone, Deserialize)]
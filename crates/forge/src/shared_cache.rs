@@ -1,6 +1,5 @@
 use anyhow::Result;
 use camino::Utf8PathBuf;
-use forge_runner::test_case_summary::AnyTestCaseSummary;
 use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, ErrorKind, Write};
 
@@ -32,16 +31,14 @@ impl FailedTestsCache {
         Ok(tests)
     }
 
-    pub fn save_failed_tests(&self, all_failed_tests: &[&AnyTestCaseSummary]) -> Result<()> {
+    pub fn save_failed_tests(&self, failed_tests: &[String]) -> Result<()> {
         std::fs::create_dir_all(self.cache_file.parent().unwrap())?;
 
         let file = File::create(&self.cache_file)?;
 
         let mut file = BufWriter::new(file);
 
-        for line in all_failed_tests {
-            let name = line.name().unwrap();
-
+        for name in failed_tests {
             writeln!(file, "{name}")?;
         }
         Ok(())
@@ -1,7 +1,7 @@
 use crate::shared_cache::FailedTestsCache;
 use anyhow::Result;
 use forge_runner::filtering::{
-    ExcludeReason, FilterResult, NameFilter, TestCaseFilter, TestCaseIsIgnored,
+    ExcludeReason, FilterResult, NameFilter, TagExpression, TestCaseFilter, TestCaseIsIgnored,
 };
 use forge_runner::package_tests::TestCase;
 use forge_runner::package_tests::with_config_resolved::{
@@ -20,6 +20,10 @@ pub struct TestsFilter {
     last_failed_filter: bool,
     // based on `--skip` flag
     skip_filter: Vec<String>,
+    // based on `--tags` flag
+    tags_filter: Option<TagExpression>,
+    // based on `--exclude-tags` flag
+    exclude_tags_filter: Option<TagExpression>,
 
     failed_tests_cache: FailedTestsCache,
     pub(crate) partitioning_config: PartitionConfig,
@@ -40,6 +44,8 @@ impl TestsFilter {
         test_name_filter: Option<String>,
         exact_match: bool,
         skip: Vec<String>,
+        tags: Option<TagExpression>,
+        exclude_tags: Option<TagExpression>,
         only_ignored: bool,
         include_ignored: bool,
         rerun_failed: bool,
@@ -64,6 +70,8 @@ impl TestsFilter {
             ignored_filter,
             last_failed_filter: rerun_failed,
             skip_filter: skip,
+            tags_filter: tags,
+            exclude_tags_filter: exclude_tags,
             failed_tests_cache,
             partitioning_config,
         }
@@ -96,8 +104,22 @@ impl TestsFilter {
             test_cases.retain(|tc| !self.skip_filter.iter().any(|s| tc.name.contains(s)));
         }
 
+        test_cases.retain(|tc| self.matches_tags(&tc.config.tags));
+
         Ok(())
     }
+
+    /// Checks tags of a test case against `--tags` and `--exclude-tags` expressions.
+    #[must_use]
+    pub(crate) fn matches_tags(&self, tags: &[String]) -> bool {
+        self.tags_filter
+            .as_ref()
+            .is_none_or(|expression| expression.matches(tags))
+            && !self
+                .exclude_tags_filter
+                .as_ref()
+                .is_some_and(|expression| expression.matches(tags))
+    }
 }
 
 impl TestCaseFilter for TestsFilter {
@@ -168,6 +190,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             true,
             true,
             false,
@@ -183,6 +207,8 @@ mod tests {
             None,
             true,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -201,6 +227,8 @@ mod tests {
                 compile_raw_sierra(&serde_json::to_value(&program_for_testing().program).unwrap())
                     .unwrap(),
             ),
+            fixtures: vec![],
+            hooks: vec![],
             test_cases: vec![
                 TestCaseWithResolvedConfig {
                     name: "crate1::do_thing".to_string(),
@@ -213,6 +241,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -226,6 +260,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -239,6 +279,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -252,6 +298,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
             ],
@@ -262,6 +314,8 @@ mod tests {
             Some("do".to_string()),
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -286,6 +340,12 @@ mod tests {
                     fork_config: None,
                     fuzzer_config: None,
                     disable_predeployed_contracts: false,
+                    seed: None,
+                    setup: None,
+                    timeout: None,
+                    max_steps: None,
+                    retries: None,
+                    tags: vec![],
                 },
             },]
         );
@@ -294,6 +354,8 @@ mod tests {
             Some("te2::run".to_string()),
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -317,6 +379,12 @@ mod tests {
                     fork_config: None,
                     fuzzer_config: None,
                     disable_predeployed_contracts: false,
+                    seed: None,
+                    setup: None,
+                    timeout: None,
+                    max_steps: None,
+                    retries: None,
+                    tags: vec![],
                 },
             },]
         );
@@ -325,6 +393,8 @@ mod tests {
             Some("thing".to_string()),
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -349,6 +419,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -362,6 +438,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -375,6 +457,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -388,6 +476,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
             ]
@@ -397,6 +491,8 @@ mod tests {
             Some("nonexistent".to_string()),
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -413,6 +509,8 @@ mod tests {
             Some(String::new()),
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -437,6 +535,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -450,6 +554,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -463,6 +573,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -476,12 +592,99 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
             ]
         );
     }
 
+    fn test_case_with_tags(name: &str, tags: &[&str]) -> TestCaseWithResolvedConfig {
+        TestCaseWithResolvedConfig {
+            name: name.to_string(),
+            test_details: TestDetails::default(),
+
+            config: TestCaseResolvedConfig {
+                available_gas: None,
+                ignored: false,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: None,
+                fuzzer_config: None,
+                disable_predeployed_contracts: false,
+                seed: None,
+                setup: None,
+                timeout: None,
+                max_steps: None,
+                retries: None,
+                tags: tags.iter().map(ToString::to_string).collect(),
+            },
+        }
+    }
+
+    #[test]
+    fn filtering_with_tags() {
+        let test_cases = vec![
+            test_case_with_tags("crate1::unit", &["unit"]),
+            test_case_with_tags("crate1::slow_unit", &["unit", "slow"]),
+            test_case_with_tags("crate1::forked", &["fork"]),
+            test_case_with_tags("crate1::untagged", &[]),
+        ];
+
+        let tests_filter = TestsFilter::from_flags(
+            None,
+            false,
+            Vec::new(),
+            Some("unit | fork".parse().unwrap()),
+            Some("slow".parse().unwrap()),
+            false,
+            false,
+            false,
+            FailedTestsCache::default(),
+            PartitionConfig::default(),
+        );
+
+        let mut filtered = test_cases.clone();
+        tests_filter.filter_tests(&mut filtered).unwrap();
+
+        assert_eq!(
+            filtered,
+            vec![
+                test_case_with_tags("crate1::unit", &["unit"]),
+                test_case_with_tags("crate1::forked", &["fork"]),
+            ]
+        );
+
+        let tests_filter = TestsFilter::from_flags(
+            None,
+            false,
+            Vec::new(),
+            None,
+            Some("fork".parse().unwrap()),
+            false,
+            false,
+            false,
+            FailedTestsCache::default(),
+            PartitionConfig::default(),
+        );
+
+        let mut filtered = test_cases;
+        tests_filter.filter_tests(&mut filtered).unwrap();
+
+        assert_eq!(
+            filtered,
+            vec![
+                test_case_with_tags("crate1::unit", &["unit"]),
+                test_case_with_tags("crate1::slow_unit", &["unit", "slow"]),
+                test_case_with_tags("crate1::untagged", &[]),
+            ]
+        );
+    }
+
     #[test]
     fn filtering_with_no_tests() {
         let mocked_tests = TestTargetWithResolvedConfig {
@@ -492,6 +695,8 @@ mod tests {
                     .unwrap(),
             ),
             test_cases: vec![],
+            fixtures: vec![],
+            hooks: vec![],
             tests_location: TestTargetLocation::Lib,
         };
 
@@ -499,6 +704,8 @@ mod tests {
             Some(String::new()),
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -515,6 +722,8 @@ mod tests {
             Some("thing".to_string()),
             false,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -538,6 +747,8 @@ mod tests {
                 compile_raw_sierra(&serde_json::to_value(&program_for_testing().program).unwrap())
                     .unwrap(),
             ),
+            fixtures: vec![],
+            hooks: vec![],
             test_cases: vec![
                 TestCaseWithResolvedConfig {
                     name: "crate1::do_thing".to_string(),
@@ -550,6 +761,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -563,6 +780,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -576,6 +799,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -589,6 +818,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
             ],
@@ -599,6 +834,8 @@ mod tests {
             Some(String::new()),
             true,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -615,6 +852,8 @@ mod tests {
             Some("thing".to_string()),
             true,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -631,6 +870,8 @@ mod tests {
             Some("do_thing".to_string()),
             true,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -654,6 +895,12 @@ mod tests {
                     fork_config: None,
                     fuzzer_config: None,
                     disable_predeployed_contracts: false,
+                    seed: None,
+                    setup: None,
+                    timeout: None,
+                    max_steps: None,
+                    retries: None,
+                    tags: vec![],
                 },
             },]
         );
@@ -662,6 +909,8 @@ mod tests {
             Some("crate1::do_thing".to_string()),
             true,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -685,6 +934,12 @@ mod tests {
                     fork_config: None,
                     fuzzer_config: None,
                     disable_predeployed_contracts: false,
+                    seed: None,
+                    setup: None,
+                    timeout: None,
+                    max_steps: None,
+                    retries: None,
+                    tags: vec![],
                 },
             },]
         );
@@ -693,6 +948,8 @@ mod tests {
             Some("crate3::run_other_thing".to_string()),
             true,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -709,6 +966,8 @@ mod tests {
             Some("outer::crate3::run_other_thing".to_string()),
             true,
             Vec::new(),
+            None,
+            None,
             false,
             false,
             false,
@@ -732,6 +991,12 @@ mod tests {
                     fork_config: None,
                     fuzzer_config: None,
                     disable_predeployed_contracts: false,
+                    seed: None,
+                    setup: None,
+                    timeout: None,
+                    max_steps: None,
+                    retries: None,
+                    tags: vec![],
                 },
             },]
         );
@@ -746,6 +1011,8 @@ mod tests {
                 compile_raw_sierra(&serde_json::to_value(&program_for_testing().program).unwrap())
                     .unwrap(),
             ),
+            fixtures: vec![],
+            hooks: vec![],
             test_cases: vec![
                 TestCaseWithResolvedConfig {
                     name: "crate1::do_thing".to_string(),
@@ -758,6 +1025,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -771,6 +1044,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -784,6 +1063,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -797,6 +1082,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
             ],
@@ -807,6 +1098,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             true,
             false,
             false,
@@ -830,6 +1123,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -843,6 +1142,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
             ]
@@ -859,6 +1164,8 @@ mod tests {
                 compile_raw_sierra(&serde_json::to_value(&program_for_testing().program).unwrap())
                     .unwrap(),
             ),
+            fixtures: vec![],
+            hooks: vec![],
             test_cases: vec![
                 TestCaseWithResolvedConfig {
                     name: "crate1::do_thing".to_string(),
@@ -871,6 +1178,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -884,6 +1197,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -897,6 +1216,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -910,6 +1235,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
             ],
@@ -920,6 +1251,8 @@ mod tests {
             None,
             false,
             Vec::new(),
+            None,
+            None,
             false,
             true,
             false,
@@ -943,6 +1276,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -956,6 +1295,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -969,6 +1314,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -982,6 +1333,12 @@ mod tests {
                         fork_config: None,
                         fuzzer_config: None,
                         disable_predeployed_contracts: false,
+                        seed: None,
+                        setup: None,
+                        timeout: None,
+                        max_steps: None,
+                        retries: None,
+                        tags: vec![],
                     },
                 },
             ]
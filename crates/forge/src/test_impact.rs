@@ -0,0 +1,406 @@
+use crate::TestArgs;
+use anyhow::{Context, Result, ensure};
+use cairo_annotations::trace_data::{CallTraceNode, CallTraceV1, VersionedCallTrace};
+use camino::{Utf8Path, Utf8PathBuf};
+use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use code::{TargetCode, TestCode, contract_source_files};
+use forge_runner::package_tests::with_config_resolved::TestTargetWithResolvedConfig;
+use forge_runner::running::hooks::CaseHooks;
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use serde::{Deserialize, Serialize};
+use starknet_api::core::ClassHash;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::io::ErrorKind;
+use std::process::Command;
+
+pub mod code;
+
+pub(crate) const FILE_WITH_TESTS_IMPACT: &str = ".tests_impact.json";
+
+/// Changes in these files can affect any test, e.g. by changing dependencies or compiler config
+const MANIFEST_FILES: [&str; 3] = ["Scarb.toml", "Scarb.lock", "snfoundry.toml"];
+
+/// Code executed by a test during its last passed run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestImpact {
+    code_hash: String,
+    /// Class hashes of the contracts declared in the tested package called by the test
+    contracts: BTreeSet<ClassHash>,
+    /// Source files of the test code and called contracts, `None` if any of them was compiled
+    /// without statements code locations
+    source_files: Option<BTreeSet<Utf8PathBuf>>,
+}
+
+impl TestImpact {
+    /// Returns the impact of a test that passed, `None` for other results as they are not
+    /// guaranteed to execute all the code the test can execute
+    #[must_use]
+    fn from_summary(
+        summary: &AnyTestCaseSummary,
+        code: &TestCode,
+        contracts_data: &ContractsData,
+        contracts_source_files: &mut ContractsSourceFiles,
+    ) -> Option<Self> {
+        let AnyTestCaseSummary::Single(TestCaseSummary::Passed {
+            trace_data: VersionedCallTrace::V1(call_trace),
+            ..
+        }) = summary
+        else {
+            return None;
+        };
+
+        let mut contracts = BTreeSet::new();
+        collect_class_hashes(call_trace, &mut contracts);
+        contracts.retain(|class_hash| {
+            contracts_data
+                .get_contract_by_class_hash(class_hash)
+                .is_some()
+        });
+
+        let source_files = contracts.iter().try_fold(
+            code.source_files.clone()?,
+            |mut source_files, class_hash| {
+                let contract_files = contracts_source_files.get(class_hash, contracts_data)?;
+                source_files.extend(contract_files.iter().cloned());
+                Some(source_files)
+            },
+        );
+
+        Some(Self {
+            code_hash: code.hash.clone(),
+            contracts,
+            source_files,
+        })
+    }
+}
+
+/// Source files of the called contracts, read from their debug info once per package
+#[derive(Debug, Default)]
+struct ContractsSourceFiles(HashMap<ClassHash, Option<BTreeSet<Utf8PathBuf>>>);
+
+impl ContractsSourceFiles {
+    fn get(
+        &mut self,
+        class_hash: &ClassHash,
+        contracts_data: &ContractsData,
+    ) -> Option<&BTreeSet<Utf8PathBuf>> {
+        self.0
+            .entry(*class_hash)
+            .or_insert_with(|| {
+                contracts_data
+                    .get_contract_by_class_hash(class_hash)
+                    .and_then(contract_source_files)
+            })
+            .as_ref()
+    }
+}
+
+/// Computes the code of tests before they are run and their impacts after they are run
+#[derive(Default)]
+pub struct TestsImpactRecorder {
+    tests_code: HashMap<String, TestCode>,
+    contracts_source_files: ContractsSourceFiles,
+    impacts: HashMap<String, Option<TestImpact>>,
+}
+
+impl TestsImpactRecorder {
+    pub fn add_target(&mut self, target: &TestTargetWithResolvedConfig) {
+        let target_code = TargetCode::new(&target.sierra_program);
+        for test_case in &target.test_cases {
+            let hooks = CaseHooks::for_case(&target.hooks, test_case);
+            let code = target_code.test_case_code(test_case, &target.fixtures, &hooks);
+            self.tests_code.insert(test_case.name.clone(), code);
+        }
+    }
+
+    #[must_use]
+    pub fn code(&self, test_name: &str) -> Option<&TestCode> {
+        self.tests_code.get(test_name)
+    }
+
+    pub fn record(&mut self, summaries: &[AnyTestCaseSummary], contracts_data: &ContractsData) {
+        for summary in summaries {
+            // Ignored tests keep the impact from their last run
+            if summary.is_ignored() {
+                continue;
+            }
+            if let Some(name) = summary.name()
+                && let Some(code) = self.tests_code.get(name)
+            {
+                let impact = TestImpact::from_summary(
+                    summary,
+                    code,
+                    contracts_data,
+                    &mut self.contracts_source_files,
+                );
+                self.impacts.insert(name.to_string(), impact);
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn into_impacts(self) -> HashMap<String, Option<TestImpact>> {
+        self.impacts
+    }
+}
+
+fn collect_class_hashes(call_trace: &CallTraceV1, class_hashes: &mut BTreeSet<ClassHash>) {
+    if let Some(class_hash) = &call_trace.entry_point.class_hash {
+        class_hashes.insert(ClassHash(class_hash.0));
+    }
+    for nested_call in &call_trace.nested_calls {
+        if let CallTraceNode::EntryPointCall(nested_call) = nested_call {
+            collect_class_hashes(nested_call, class_hashes);
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TestsImpactMap {
+    /// Hashes of the test code computed by different versions are not comparable
+    snforge_version: String,
+    tests: HashMap<String, TestImpact>,
+}
+
+impl TestsImpactMap {
+    /// Saves impacts of the tests that were run, tests without impact are removed from the map
+    pub fn update(&mut self, impacts: impl IntoIterator<Item = (String, Option<TestImpact>)>) {
+        for (name, impact) in impacts {
+            match impact {
+                Some(impact) => self.tests.insert(name, impact),
+                None => self.tests.remove(&name),
+            };
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct TestsImpactCache {
+    cache_file: Utf8PathBuf,
+}
+
+impl TestsImpactCache {
+    #[must_use]
+    pub fn new(cache_dir: &Utf8Path) -> Self {
+        Self {
+            cache_file: cache_dir.join(FILE_WITH_TESTS_IMPACT),
+        }
+    }
+
+    /// Returns an empty map if the cache does not exist or was saved by another version of snforge
+    pub fn load(&self) -> Result<TestsImpactMap> {
+        let content = match fs::read_to_string(&self.cache_file) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(current_version_map()),
+            Err(err) => Err(err)?,
+        };
+
+        Ok(serde_json::from_str::<TestsImpactMap>(&content)
+            .ok()
+            .filter(|map| map.snforge_version == env!("CARGO_PKG_VERSION"))
+            .unwrap_or_else(current_version_map))
+    }
+
+    pub fn save(&self, map: &TestsImpactMap) -> Result<()> {
+        fs::create_dir_all(self.cache_file.parent().unwrap())?;
+        fs::write(&self.cache_file, serde_json::to_string(map)?)?;
+        Ok(())
+    }
+}
+
+fn current_version_map() -> TestsImpactMap {
+    TestsImpactMap {
+        snforge_version: env!("CARGO_PKG_VERSION").to_string(),
+        tests: HashMap::new(),
+    }
+}
+
+/// Tests selected with `--affected` or `--changed-since`, based on the impact map of the previous runs.
+/// Tests without a saved impact, e.g. new, failed or fuzz tests, are always selected.
+#[derive(Debug)]
+pub struct ImpactSelection {
+    previous: TestsImpactMap,
+    /// Files changed since the git reference passed with `--changed-since`, `None` with `--affected`
+    changed_files: Option<HashSet<Utf8PathBuf>>,
+}
+
+impl ImpactSelection {
+    /// Returns `None` if all tests should be run
+    pub fn from_args(
+        args: &TestArgs,
+        cache_dir: &Utf8Path,
+        workspace_root: &Utf8Path,
+    ) -> Result<Option<Self>> {
+        let changed_files = match &args.changed_since {
+            Some(git_ref) => {
+                let changed_files = changed_files_since(git_ref, workspace_root)?;
+                if changed_files.iter().any(|file| {
+                    file.file_name()
+                        .is_some_and(|name| MANIFEST_FILES.contains(&name))
+                }) {
+                    return Ok(None);
+                }
+                Some(changed_files)
+            }
+            None if args.affected => None,
+            None => return Ok(None),
+        };
+
+        Ok(Some(Self {
+            previous: TestsImpactCache::new(cache_dir).load()?,
+            changed_files,
+        }))
+    }
+
+    #[must_use]
+    pub fn is_selected(
+        &self,
+        name: &str,
+        code: Option<&TestCode>,
+        contracts_data: &ContractsData,
+    ) -> bool {
+        let (Some(previous), Some(code)) = (self.previous.tests.get(name), code) else {
+            return true;
+        };
+        if previous.code_hash != code.hash {
+            return true;
+        }
+
+        match &self.changed_files {
+            None => previous.contracts.iter().any(|class_hash| {
+                contracts_data
+                    .get_contract_by_class_hash(class_hash)
+                    .is_none()
+            }),
+            Some(changed_files) => previous.source_files.as_ref().is_none_or(|source_files| {
+                source_files.iter().any(|file| changed_files.contains(file))
+            }),
+        }
+    }
+}
+
+/// Returns files changed since `git_ref`, including uncommitted and untracked ones
+fn changed_files_since(git_ref: &str, workspace_root: &Utf8Path) -> Result<HashSet<Utf8PathBuf>> {
+    let repository_root =
+        Utf8PathBuf::from(git(workspace_root, &["rev-parse", "--show-toplevel"])?.trim());
+    let changed = git(workspace_root, &["diff", "--name-only", git_ref, "--"])
+        .with_context(|| format!("Failed to find files changed since `{git_ref}`"))?;
+    let untracked = git(
+        workspace_root,
+        &["ls-files", "--others", "--exclude-standard", "--full-name"],
+    )?;
+
+    Ok(changed
+        .lines()
+        .chain(untracked.lines())
+        .map(|file| repository_root.join(file))
+        .collect())
+}
+
+fn git(current_dir: &Utf8Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(current_dir)
+        .output()
+        .context("Failed to run git, make sure it is installed")?;
+    ensure!(
+        output.status.success(),
+        "git {} failed: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet_types_core::felt::Felt;
+
+    fn code(hash: &str) -> TestCode {
+        TestCode {
+            hash: hash.to_string(),
+            source_files: None,
+        }
+    }
+
+    fn impact(contracts: &[u64], source_files: Option<&[&str]>) -> TestImpact {
+        TestImpact {
+            code_hash: "a1".to_string(),
+            contracts: contracts
+                .iter()
+                .map(|class_hash| ClassHash(Felt::from(*class_hash)))
+                .collect(),
+            source_files: source_files
+                .map(|files| files.iter().map(|file| Utf8PathBuf::from(*file)).collect()),
+        }
+    }
+
+    fn selection(tests: &[(&str, TestImpact)], changed_files: Option<&[&str]>) -> ImpactSelection {
+        let mut previous = current_version_map();
+        previous.update(
+            tests
+                .iter()
+                .map(|(name, impact)| ((*name).to_string(), Some(impact.clone()))),
+        );
+
+        ImpactSelection {
+            previous,
+            changed_files: changed_files
+                .map(|files| files.iter().map(|file| Utf8PathBuf::from(*file)).collect()),
+        }
+    }
+
+    #[test]
+    fn affected_selects_tests_with_changed_code_or_contracts() {
+        let selection = selection(
+            &[
+                ("unchanged", impact(&[], None)),
+                ("calls_contract", impact(&[1], None)),
+            ],
+            None,
+        );
+        let contracts_data = ContractsData::default();
+
+        assert!(!selection.is_selected("unchanged", Some(&code("a1")), &contracts_data));
+        assert!(selection.is_selected("unchanged", Some(&code("b2")), &contracts_data));
+        assert!(selection.is_selected("calls_contract", Some(&code("a1")), &contracts_data));
+        assert!(selection.is_selected("new", Some(&code("a1")), &contracts_data));
+    }
+
+    #[test]
+    fn changed_since_selects_tests_executing_changed_files() {
+        let selection = selection(
+            &[
+                (
+                    "executes_changed",
+                    impact(&[], Some(&["/ws/src/a.cairo", "/ws/src/b.cairo"])),
+                ),
+                (
+                    "executes_unchanged",
+                    impact(&[], Some(&["/ws/src/b.cairo"])),
+                ),
+                ("without_debug_info", impact(&[], None)),
+            ],
+            Some(&["/ws/src/a.cairo"]),
+        );
+        let contracts_data = ContractsData::default();
+
+        assert!(selection.is_selected("executes_changed", Some(&code("a1")), &contracts_data));
+        assert!(!selection.is_selected("executes_unchanged", Some(&code("a1")), &contracts_data));
+        assert!(selection.is_selected("without_debug_info", Some(&code("a1")), &contracts_data));
+    }
+
+    #[test]
+    fn update_removes_tests_without_impact() {
+        let mut map = current_version_map();
+        map.update([("passed".to_string(), Some(impact(&[], None)))]);
+        map.update([
+            ("passed".to_string(), None),
+            ("other".to_string(), Some(impact(&[], None))),
+        ]);
+
+        assert_eq!(map.tests.keys().collect::<Vec<_>>(), ["other"]);
+    }
+}
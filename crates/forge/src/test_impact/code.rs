@@ -0,0 +1,428 @@
+use cairo_annotations::annotations::TryFromDebugInfo;
+use cairo_annotations::annotations::coverage::{CodeLocation, VersionedCoverageAnnotations};
+use cairo_lang_sierra::debug_info::DebugInfo;
+use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId, VarId};
+use cairo_lang_sierra::program::{
+    BranchTarget, ConcreteLibfuncLongId, ConcreteTypeLongId, GenericArg, Program, ProgramArtifact,
+    Statement, StatementIdx,
+};
+use cairo_lang_starknet_classes::contract_class::ContractClass;
+use camino::Utf8PathBuf;
+use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractData;
+use forge_runner::package_tests::Fixture;
+use forge_runner::package_tests::with_config_resolved::TestCaseWithResolvedConfig;
+use forge_runner::running::hooks::CaseHooks;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::iter;
+
+/// Code a test executes from its own test target
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCode {
+    /// Hash of all functions reachable from the test, it changes only if one of them changes
+    pub hash: String,
+    /// Source files of the reachable functions, `None` if the target has no statements code locations
+    pub source_files: Option<BTreeSet<Utf8PathBuf>>,
+}
+
+struct FunctionCode {
+    key: String,
+    hash: u64,
+    callees: Vec<usize>,
+    source_files: BTreeSet<Utf8PathBuf>,
+}
+
+/// Functions of a test target with hashes independent of the ids assigned by the compiler,
+/// so that changes in one function do not change hashes of the others.
+pub struct TargetCode {
+    functions: Vec<FunctionCode>,
+    entry_points: HashMap<usize, usize>,
+    has_source_files: bool,
+}
+
+impl TargetCode {
+    #[must_use]
+    pub fn new(sierra_program: &ProgramArtifact) -> Self {
+        let program = &sierra_program.program;
+        let declarations = Declarations::new(program);
+        let code_locations = sierra_program
+            .debug_info
+            .as_ref()
+            .and_then(statements_code_locations);
+
+        let mut funcs: Vec<_> = program.funcs.iter().collect();
+        funcs.sort_by_key(|function| function.entry_point.0);
+
+        let function_indices: HashMap<u64, usize> = funcs
+            .iter()
+            .enumerate()
+            .map(|(index, function)| (function.id.id, index))
+            .collect();
+
+        let functions = funcs
+            .iter()
+            .enumerate()
+            .map(|(index, function)| {
+                let start = function.entry_point.0;
+                let end = funcs
+                    .get(index + 1)
+                    .map_or(program.statements.len(), |next| next.entry_point.0);
+
+                let mut hasher = DefaultHasher::new();
+                for param in &function.params {
+                    declarations.hash_type(&param.ty, &mut vec![], &mut hasher);
+                }
+                for ret_type in &function.signature.ret_types {
+                    declarations.hash_type(ret_type, &mut vec![], &mut hasher);
+                }
+
+                let mut callees = BTreeSet::new();
+                let mut source_files = BTreeSet::new();
+                for idx in start..end {
+                    let statement = &program.statements[idx];
+                    declarations.hash_statement(statement, start, &mut hasher);
+
+                    if let Statement::Invocation(invocation) = statement {
+                        callees.extend(
+                            declarations
+                                .called_functions(&invocation.libfunc_id)
+                                .filter_map(|id| function_indices.get(&id.id)),
+                        );
+                    }
+                    if let Some(code_locations) = &code_locations
+                        && let Some(locations) = code_locations.get(&StatementIdx(idx))
+                    {
+                        source_files.extend(locations.iter().map(source_file));
+                    }
+                }
+
+                FunctionCode {
+                    key: function_key(&function.id),
+                    hash: hasher.finish(),
+                    callees: callees.into_iter().collect(),
+                    source_files,
+                }
+            })
+            .collect();
+
+        let entry_points = funcs
+            .iter()
+            .enumerate()
+            .map(|(index, function)| (function.entry_point.0, index))
+            .collect();
+
+        Self {
+            functions,
+            entry_points,
+            has_source_files: code_locations.is_some(),
+        }
+    }
+
+    /// Returns the code reachable from the test case, the fixture it is set up with and its hooks
+    #[must_use]
+    pub fn test_case_code(
+        &self,
+        test_case: &TestCaseWithResolvedConfig,
+        fixtures: &[Fixture],
+        hooks: &CaseHooks,
+    ) -> TestCode {
+        let fixture_entry_points = test_case.config.setup.iter().flat_map(|setup| {
+            let suffix = format!("::{setup}");
+            fixtures
+                .iter()
+                .filter(move |fixture| fixture.name == *setup || fixture.name.ends_with(&suffix))
+                .map(|fixture| fixture.test_details.sierra_entry_point_statement_idx)
+        });
+
+        let hook_entry_points = hooks
+            .before_each
+            .iter()
+            .chain(&hooks.after_each)
+            .map(|hook| hook.test_details.sierra_entry_point_statement_idx);
+
+        self.reachable_code(
+            iter::once(test_case.test_details.sierra_entry_point_statement_idx)
+                .chain(fixture_entry_points)
+                .chain(hook_entry_points),
+        )
+    }
+
+    fn reachable_code(&self, entry_points: impl IntoIterator<Item = usize>) -> TestCode {
+        let mut reachable = BTreeSet::new();
+        let mut queue: VecDeque<usize> = entry_points
+            .into_iter()
+            .filter_map(|entry_point| self.entry_points.get(&entry_point).copied())
+            .collect();
+
+        while let Some(index) = queue.pop_front() {
+            if reachable.insert(index) {
+                queue.extend(self.functions[index].callees.iter().copied());
+            }
+        }
+
+        let mut functions: Vec<_> = reachable
+            .into_iter()
+            .map(|index| &self.functions[index])
+            .collect();
+        functions.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let mut hasher = DefaultHasher::new();
+        for function in &functions {
+            function.key.hash(&mut hasher);
+            function.hash.hash(&mut hasher);
+        }
+
+        let source_files = self.has_source_files.then(|| {
+            functions
+                .iter()
+                .flat_map(|function| function.source_files.iter().cloned())
+                .collect()
+        });
+
+        TestCode {
+            hash: format!("{:016x}", hasher.finish()),
+            source_files,
+        }
+    }
+}
+
+/// Returns all source files of a contract, `None` if it was compiled without statements code locations
+#[must_use]
+pub fn contract_source_files(contract: &ContractData) -> Option<BTreeSet<Utf8PathBuf>> {
+    let contract_class = serde_json::from_str::<ContractClass>(&contract.artifacts.sierra).ok()?;
+    let code_locations = statements_code_locations(&contract_class.sierra_program_debug_info?)?;
+
+    Some(code_locations.values().flatten().map(source_file).collect())
+}
+
+fn statements_code_locations(
+    debug_info: &DebugInfo,
+) -> Option<HashMap<StatementIdx, Vec<CodeLocation>>> {
+    let VersionedCoverageAnnotations::V1(coverage) =
+        VersionedCoverageAnnotations::try_from_debug_info(debug_info).ok()?;
+    Some(coverage.statements_code_locations)
+}
+
+/// Code generated by macros is located in virtual files, e.g. `src/lib.cairo[contract]`,
+/// which are mapped to the files they were generated from.
+fn source_file(CodeLocation(path, _): &CodeLocation) -> Utf8PathBuf {
+    let path = path.to_string();
+    let path = path
+        .strip_suffix(']')
+        .and_then(|path| path.rsplit_once('['))
+        .map_or(path.as_str(), |(path, _)| path);
+    Utf8PathBuf::from(path)
+}
+
+fn function_key(id: &FunctionId) -> String {
+    id.debug_name
+        .as_ref()
+        .map_or_else(|| id.id.to_string(), ToString::to_string)
+}
+
+/// Type and libfunc declarations used to hash statements by what they do instead of their ids
+struct Declarations<'a> {
+    types: HashMap<u64, &'a ConcreteTypeLongId>,
+    libfuncs: HashMap<u64, &'a ConcreteLibfuncLongId>,
+    libfunc_hashes: HashMap<u64, u64>,
+}
+
+impl<'a> Declarations<'a> {
+    fn new(program: &'a Program) -> Self {
+        let mut declarations = Self {
+            types: program
+                .type_declarations
+                .iter()
+                .map(|declaration| (declaration.id.id, &declaration.long_id))
+                .collect(),
+            libfuncs: program
+                .libfunc_declarations
+                .iter()
+                .map(|declaration| (declaration.id.id, &declaration.long_id))
+                .collect(),
+            libfunc_hashes: HashMap::new(),
+        };
+
+        declarations.libfunc_hashes = program
+            .libfunc_declarations
+            .iter()
+            .map(|declaration| {
+                let mut hasher = DefaultHasher::new();
+                declarations.hash_libfunc(&declaration.id, &mut vec![], &mut hasher);
+                (declaration.id.id, hasher.finish())
+            })
+            .collect();
+
+        declarations
+    }
+
+    fn called_functions(
+        &self,
+        libfunc_id: &ConcreteLibfuncId,
+    ) -> impl Iterator<Item = &'a FunctionId> {
+        self.libfuncs
+            .get(&libfunc_id.id)
+            .copied()
+            .into_iter()
+            .flat_map(|long_id| &long_id.generic_args)
+            .filter_map(|arg| match arg {
+                GenericArg::UserFunc(id) => Some(id),
+                _ => None,
+            })
+    }
+
+    fn hash_statement(
+        &self,
+        statement: &Statement,
+        function_start: usize,
+        state: &mut impl Hasher,
+    ) {
+        match statement {
+            Statement::Invocation(invocation) => {
+                0_u8.hash(state);
+                self.libfunc_hashes
+                    .get(&invocation.libfunc_id.id)
+                    .hash(state);
+                hash_vars(&invocation.args, state);
+                invocation.branches.len().hash(state);
+                for branch in &invocation.branches {
+                    // Targets are relative, so moving the whole function does not change its hash
+                    match branch.target {
+                        BranchTarget::Fallthrough => None,
+                        BranchTarget::Statement(idx) => Some(idx.0.wrapping_sub(function_start)),
+                    }
+                    .hash(state);
+                    hash_vars(&branch.results, state);
+                }
+            }
+            Statement::Return(vars) => {
+                1_u8.hash(state);
+                hash_vars(vars, state);
+            }
+        }
+    }
+
+    fn hash_libfunc(
+        &self,
+        id: &ConcreteLibfuncId,
+        visiting: &mut Vec<u64>,
+        state: &mut impl Hasher,
+    ) {
+        match self.libfuncs.get(&id.id) {
+            Some(long_id) => {
+                long_id.generic_id.0.hash(state);
+                self.hash_generic_args(&long_id.generic_args, visiting, state);
+            }
+            None => id.id.hash(state),
+        }
+    }
+
+    fn hash_type(&self, id: &ConcreteTypeId, visiting: &mut Vec<u64>, state: &mut impl Hasher) {
+        match self.types.get(&id.id) {
+            // Recursive types refer to themselves, e.g. through `Box`
+            Some(long_id) if !visiting.contains(&id.id) => {
+                visiting.push(id.id);
+                long_id.generic_id.0.hash(state);
+                self.hash_generic_args(&long_id.generic_args, visiting, state);
+                visiting.pop();
+            }
+            _ => id.debug_name.hash(state),
+        }
+    }
+
+    fn hash_generic_args(
+        &self,
+        args: &[GenericArg],
+        visiting: &mut Vec<u64>,
+        state: &mut impl Hasher,
+    ) {
+        args.len().hash(state);
+        for arg in args {
+            match arg {
+                GenericArg::UserType(id) => {
+                    0_u8.hash(state);
+                    id.id.hash(state);
+                }
+                GenericArg::Type(id) => {
+                    1_u8.hash(state);
+                    self.hash_type(id, visiting, state);
+                }
+                GenericArg::Value(value) => {
+                    2_u8.hash(state);
+                    value.hash(state);
+                }
+                GenericArg::UserFunc(id) => {
+                    3_u8.hash(state);
+                    function_key(id).hash(state);
+                }
+                GenericArg::Libfunc(id) => {
+                    4_u8.hash(state);
+                    self.hash_libfunc(id, visiting, state);
+                }
+            }
+        }
+    }
+}
+
+fn hash_vars(vars: &[VarId], state: &mut impl Hasher) {
+    vars.len().hash(state);
+    for var in vars {
+        var.id.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cairo_lang_sierra::ProgramParser;
+    use indoc::formatdoc;
+
+    fn target_code(helper_libfunc: &str, unrelated_libfunc: &str) -> TargetCode {
+        let program = ProgramParser::new()
+            .parse(&formatdoc! {"
+                type felt252 = felt252;
+
+                libfunc felt252_add = felt252_add;
+                libfunc felt252_sub = felt252_sub;
+                libfunc store_temp<felt252> = store_temp<felt252>;
+                libfunc function_call<user@helper> = function_call<user@helper>;
+
+                function_call<user@helper>([0]) -> ([1]);
+                return([1]);
+                {unrelated_libfunc}([0], [0]) -> ([1]);
+                store_temp<felt252>([1]) -> ([1]);
+                return([1]);
+                {helper_libfunc}([0], [0]) -> ([1]);
+                store_temp<felt252>([1]) -> ([1]);
+                return([1]);
+
+                test@0([0]: felt252) -> (felt252);
+                unrelated@2([0]: felt252) -> (felt252);
+                helper@5([0]: felt252) -> (felt252);
+            "})
+            .unwrap();
+
+        TargetCode::new(&ProgramArtifact {
+            program,
+            debug_info: None,
+        })
+    }
+
+    #[test]
+    fn hash_changes_only_with_reachable_code() {
+        let original = target_code("felt252_add", "felt252_add").reachable_code([0]);
+
+        let unrelated_changed = target_code("felt252_add", "felt252_sub").reachable_code([0]);
+        let helper_changed = target_code("felt252_sub", "felt252_add").reachable_code([0]);
+
+        assert_eq!(original, unrelated_changed);
+        assert_ne!(original, helper_changed);
+    }
+
+    #[test]
+    fn no_source_files_without_debug_info() {
+        let code = target_code("felt252_add", "felt252_add").reachable_code([0]);
+
+        assert_eq!(code.source_files, None);
+    }
+}
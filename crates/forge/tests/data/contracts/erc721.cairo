@@ -0,0 +1,54 @@
+use starknet::ContractAddress;
+
+#[starknet::interface]
+trait IERC721<TContractState> {
+    fn balance_of(self: @TContractState, account: ContractAddress) -> u256;
+    fn owner_of(self: @TContractState, token_id: u256) -> ContractAddress;
+    fn transfer_from(
+        ref self: TContractState, from: ContractAddress, to: ContractAddress, token_id: u256,
+    );
+}
+
+// Minimal ERC721 without approvals, the token with `token_id` is minted to `recipient`
+#[starknet::contract]
+mod ERC721 {
+    use core::num::traits::Zero;
+    use starknet::storage::{Map, StorageMapReadAccess, StorageMapWriteAccess};
+    use starknet::{ContractAddress, get_caller_address};
+
+    #[storage]
+    struct Storage {
+        token_owners: Map<u256, ContractAddress>,
+        owned_tokens_count: Map<ContractAddress, u256>,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, recipient: ContractAddress, token_id: u256) {
+        self.token_owners.write(token_id, recipient);
+        self.owned_tokens_count.write(recipient, 1);
+    }
+
+    #[abi(embed_v0)]
+    impl IERC721Impl of super::IERC721<ContractState> {
+        fn balance_of(self: @ContractState, account: ContractAddress) -> u256 {
+            self.owned_tokens_count.read(account)
+        }
+
+        fn owner_of(self: @ContractState, token_id: u256) -> ContractAddress {
+            let owner = self.token_owners.read(token_id);
+            assert(owner.is_non_zero(), 'Invalid token id');
+            owner
+        }
+
+        fn transfer_from(
+            ref self: ContractState, from: ContractAddress, to: ContractAddress, token_id: u256,
+        ) {
+            assert(self.token_owners.read(token_id) == from, 'Wrong owner');
+            assert(get_caller_address() == from, 'Caller is not owner');
+
+            self.token_owners.write(token_id, to);
+            self.owned_tokens_count.write(from, self.owned_tokens_count.read(from) - 1);
+            self.owned_tokens_count.write(to, self.owned_tokens_count.read(to) + 1);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use starknet::account::Call;
+
+#[starknet::interface]
+trait ISimpleAccount<TContractState> {
+    fn __validate__(self: @TContractState, calls: Array<Call>) -> felt252;
+    fn __validate_declare__(self: @TContractState, class_hash: felt252) -> felt252;
+    fn __validate_deploy__(
+        self: @TContractState, class_hash: felt252, contract_address_salt: felt252, secret: felt252,
+    ) -> felt252;
+    fn __execute__(ref self: TContractState, calls: Array<Call>) -> Array<Span<felt252>>;
+}
+
+// Account accepting transactions signed with a single felt equal to the secret
+#[starknet::contract(account)]
+mod SimpleAccount {
+    use starknet::account::Call;
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+    use starknet::syscalls::call_contract_syscall;
+    use starknet::{SyscallResultTrait, VALIDATED, get_tx_info};
+
+    #[storage]
+    struct Storage {
+        secret: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, secret: felt252) {
+        self.secret.write(secret);
+    }
+
+    #[abi(embed_v0)]
+    impl ISimpleAccountImpl of super::ISimpleAccount<ContractState> {
+        fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+            self.validate_signature()
+        }
+
+        fn __validate_declare__(self: @ContractState, class_hash: felt252) -> felt252 {
+            self.validate_signature()
+        }
+
+        fn __validate_deploy__(
+            self: @ContractState, class_hash: felt252, contract_address_salt: felt252, secret: felt252,
+        ) -> felt252 {
+            self.validate_signature()
+        }
+
+        fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+            let mut results = array![];
+            for call in calls {
+                let Call { to, selector, calldata } = call;
+                results.append(call_contract_syscall(to, selector, calldata).unwrap_syscall());
+            }
+            results
+        }
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn validate_signature(self: @ContractState) -> felt252 {
+            let signature = get_tx_info().unbox().signature;
+            assert(signature.len() == 1, 'Invalid signature length');
+            assert(*signature.at(0) == self.secret.read(), 'Invalid signature');
+            VALIDATED
+        }
+    }
+}
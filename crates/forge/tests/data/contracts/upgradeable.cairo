@@ -0,0 +1,50 @@
+#[starknet::interface]
+trait IUpgradeable<TContractState> {
+    fn get_version(self: @TContractState) -> felt252;
+    fn upgrade(ref self: TContractState, class_hash: starknet::ClassHash);
+    fn upgrade_and_panic(ref self: TContractState, class_hash: starknet::ClassHash);
+}
+
+#[starknet::contract]
+mod UpgradeableV1 {
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl IUpgradeableImpl of super::IUpgradeable<ContractState> {
+        fn get_version(self: @ContractState) -> felt252 {
+            1
+        }
+
+        fn upgrade(ref self: ContractState, class_hash: starknet::ClassHash) {
+            starknet::syscalls::replace_class_syscall(class_hash).unwrap();
+        }
+
+        fn upgrade_and_panic(ref self: ContractState, class_hash: starknet::ClassHash) {
+            starknet::syscalls::replace_class_syscall(class_hash).unwrap();
+            panic!("Upgrade reverted");
+        }
+    }
+}
+
+#[starknet::contract]
+mod UpgradeableV2 {
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl IUpgradeableImpl of super::IUpgradeable<ContractState> {
+        fn get_version(self: @ContractState) -> felt252 {
+            2
+        }
+
+        fn upgrade(ref self: ContractState, class_hash: starknet::ClassHash) {
+            starknet::syscalls::replace_class_syscall(class_hash).unwrap();
+        }
+
+        fn upgrade_and_panic(ref self: ContractState, class_hash: starknet::ClassHash) {
+            starknet::syscalls::replace_class_syscall(class_hash).unwrap();
+            panic!("Upgrade reverted");
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use snforge_std::fuzzable::strategies::{
+    Strategy, draw, filter, just, map, one_of, range, vec_of, weighted,
+};
+
+#[derive(Debug, Drop, Fuzzable)]
+enum Side {
+    Buy,
+    Sell: u8,
+}
+
+#[derive(Debug, Drop, Fuzzable)]
+struct Order {
+    side: Side,
+    amounts: Array<u64>,
+    fills: Span<Array<u8>>,
+}
+
+#[test]
+fn strategies_respect_constraints() {
+    let even = filter(range(0_u32, 100), |x: @u32| *x % 2 == 0);
+    let doubled = map(range(1_u64, 1000), |x: u64| x * 2);
+    let fee = one_of(array![just(5_u16), just(30_u16), just(100_u16)]);
+    let rare = weighted(array![(1, range(100_u8, 200)), (0, range(0_u8, 10))]);
+    let prices = vec_of(range(1_u128, 10), 2, 5);
+    let negative = range(-100_i8, -50_i8);
+    let around_zero = range(-5_i128, 5_i128);
+    let large = range(
+        0x100000000000000000000000000000000_u256, 0x100000000000000000000000000000005_u256,
+    );
+
+    let mut i = 0;
+    while i != 20 {
+        assert(even.generate() % 2 == 0, 'not even');
+
+        let doubled = doubled.generate();
+        assert(doubled >= 2 && doubled <= 2000 && doubled % 2 == 0, 'wrong doubled');
+
+        let fee = fee.generate();
+        assert(fee == 5 || fee == 30 || fee == 100, 'wrong fee');
+
+        assert(rare.generate() >= 100, 'zero weight choice');
+
+        let prices = prices.generate();
+        assert(prices.len() >= 2 && prices.len() <= 5, 'wrong length');
+        for price in prices.span() {
+            assert(*price >= 1 && *price <= 10, 'wrong price');
+        }
+
+        let negative = negative.generate();
+        assert(negative >= -100 && negative <= -50, 'wrong negative');
+
+        let around_zero = around_zero.generate();
+        assert(around_zero >= -5 && around_zero <= 5, 'wrong around zero');
+
+        let large = large.generate();
+        assert(
+            large >= 0x100000000000000000000000000000000
+                && large <= 0x100000000000000000000000000000005,
+            'wrong large',
+        );
+
+        i += 1;
+    }
+}
+
+#[fuzzer(runs: 10)]
+#[test]
+fn nested_collections(order: Order) {
+    assert(order.amounts.len() <= 32, 'array too long');
+    assert(order.fills.len() <= 32, 'span too long');
+    for fill in order.fills {
+        assert(fill.len() <= 32, 'nested array too long');
+    }
+}
+
+#[fuzzer(runs: 10)]
+#[test]
+fn drawn_values_are_reported(_x: u8) {
+    let y = draw(@range(-10_i8, -5_i8));
+    assert(y > 0, 'drawn value is negative');
+}
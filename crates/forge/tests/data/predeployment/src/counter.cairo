@@ -0,0 +1,26 @@
+#[starknet::interface]
+pub trait ICounter<TContractState> {
+    fn get(self: @TContractState) -> felt252;
+}
+
+#[starknet::contract]
+pub mod Counter {
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+    #[storage]
+    struct Storage {
+        value: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, value: felt252) {
+        self.value.write(value);
+    }
+
+    #[abi(embed_v0)]
+    impl CounterImpl of super::ICounter<ContractState> {
+        fn get(self: @ContractState) -> felt252 {
+            self.value.read()
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use predeployment::counter::{ICounterDispatcher, ICounterDispatcherTrait};
+use snforge_std::{
+    DeclareResultTrait, Token, TokenTrait, UDC_ADDRESS, declare, predeployed_accounts,
+};
+use starknet::syscalls::call_contract_syscall;
+use starknet::{ClassHash, ContractAddress};
+
+fn strk_balance(account: ContractAddress) -> u256 {
+    let mut calldata = array![];
+    account.serialize(ref calldata);
+    let mut result = call_contract_syscall(
+        Token::STRK.contract_address(), selector!("balance_of"), calldata.span(),
+    )
+        .unwrap();
+
+    Serde::deserialize(ref result).unwrap()
+}
+
+fn public_key(account: ContractAddress) -> felt252 {
+    let mut result = call_contract_syscall(account, selector!("get_public_key"), array![].span())
+        .unwrap();
+
+    Serde::deserialize(ref result).unwrap()
+}
+
+fn udc_deploy(class_hash: ClassHash, constructor_calldata: Span<felt252>) -> ContractAddress {
+    let mut calldata = array![];
+    (class_hash, 'salt', false, constructor_calldata).serialize(ref calldata);
+    let mut result = call_contract_syscall(
+        UDC_ADDRESS.try_into().unwrap(), selector!("deploy_contract"), calldata.span(),
+    )
+        .unwrap();
+
+    Serde::deserialize(ref result).unwrap()
+}
+
+#[test]
+fn predeployed_contract() {
+    let counter = ICounterDispatcher { contract_address: 0x123.try_into().unwrap() };
+
+    assert_eq!(counter.get(), 5);
+}
+
+#[test]
+fn predeployed_accounts_are_funded() {
+    let accounts = predeployed_accounts();
+    assert_eq!(accounts.len(), 2);
+
+    for account in accounts {
+        assert_eq!(public_key(account.contract_address), account.public_key);
+        assert_eq!(strk_balance(account.contract_address), 0x1000);
+    }
+}
+
+#[test]
+fn predeployed_accounts_have_distinct_keys() {
+    let accounts = predeployed_accounts();
+
+    assert_ne!(accounts.at(0).private_key, accounts.at(1).private_key);
+    assert_ne!(accounts.at(0).contract_address, accounts.at(1).contract_address);
+}
+
+#[test]
+fn deploy_with_udc() {
+    let class_hash = *declare("Counter").unwrap().contract_class().class_hash;
+
+    let address = udc_deploy(class_hash, array![7].span());
+
+    assert_eq!(ICounterDispatcher { contract_address: address }.get(), 7);
+}
+
+#[test]
+#[disable_predeployed_contracts]
+fn without_predeployed_contracts() {
+    assert_eq!(predeployed_accounts().len(), 0);
+}
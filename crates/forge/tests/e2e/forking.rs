@@ -216,3 +216,65 @@ fn with_skip_fork_tests_env() {
         "},
     );
 }
+
+#[test]
+fn pinned_fork_uses_locked_block() {
+    let temp =
+        setup_package_with_file_patterns(Package::Name("forking".to_string()), BASE_FILE_PATTERNS);
+    let node_rpc_url = node_rpc_url();
+    let manifest_path = temp.path().join("Scarb.toml");
+    let scarb_toml = fs::read_to_string(&manifest_path).unwrap();
+    fs::write(
+        &manifest_path,
+        formatdoc! {r#"
+            {scarb_toml}
+            [[tool.snforge.fork]]
+            name = "PINNED"
+            url = "{node_rpc_url}"
+            block_id = {{ tag = "latest" }}
+        "#},
+    )
+    .unwrap();
+
+    runner(&temp).args(["fork", "update"]).assert().code(0);
+
+    let lock_path = temp.path().join("snfoundry.lock");
+    let lock = fs::read_to_string(&lock_path).unwrap();
+    let locked_fork = &lock.parse::<toml::Table>().unwrap()["fork"][0];
+    let block_number = locked_fork["block_number"].as_integer().unwrap();
+    let block_hash = locked_fork["block_hash"].as_str().unwrap().to_string();
+
+    fs::create_dir_all(temp.path().join("tests")).unwrap();
+    fs::write(
+        temp.path().join("tests/pinned.cairo"),
+        formatdoc! {r#"
+            #[test]
+            #[fork("PINNED")]
+            fn uses_pinned_block() {{
+                assert_eq!(starknet::get_block_info().unbox().block_number, {block_number});
+            }}
+        "#},
+    )
+    .unwrap();
+
+    test_runner(&temp).arg("uses_pinned_block").assert().code(0);
+
+    // Block with a different hash on the node is not used
+    fs::write(&lock_path, lock.replace(&block_hash, "0x123")).unwrap();
+
+    let output = test_runner(&temp).arg("uses_pinned_block").assert().code(2);
+
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+            [ERROR] Block number = {block_number} of fork named = PINNED has hash = {block_hash} on the node, but 0x123 is pinned in the snfoundry.lock, run `snforge fork update` to update it
+        "},
+    );
+
+    runner(&temp).args(["fork", "update"]).assert().code(0);
+
+    let updated_lock = fs::read_to_string(&lock_path).unwrap();
+    let updated_fork = &updated_lock.parse::<toml::Table>().unwrap()["fork"][0];
+    assert!(updated_fork["block_number"].as_integer().unwrap() >= block_number);
+    assert_ne!(updated_fork["block_hash"].as_str().unwrap(), "0x123");
+}
@@ -36,6 +36,7 @@ mod package_warnings;
 mod partitioning;
 mod plugin_diagnostics;
 mod plugin_versions;
+mod predeployment;
 mod profiles;
 mod requirements;
 mod running;
@@ -0,0 +1,85 @@
+use super::common::runner::{setup_package, test_runner};
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stdout_contains;
+use std::fs;
+use toml_edit::{DocumentMut, value};
+
+#[test]
+fn configured_contracts_are_predeployed() {
+    let temp = setup_package("predeployment");
+
+    let output = test_runner(&temp).assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+        Collected 5 test(s) from predeployment package
+        Running 5 test(s) from tests/
+        [PASS] predeployment_integrationtest::test_predeployment::predeployed_contract [..]
+        [PASS] predeployment_integrationtest::test_predeployment::predeployed_accounts_are_funded [..]
+        [PASS] predeployment_integrationtest::test_predeployment::predeployed_accounts_have_distinct_keys [..]
+        [PASS] predeployment_integrationtest::test_predeployment::deploy_with_udc [..]
+        [PASS] predeployment_integrationtest::test_predeployment::without_predeployed_contracts [..]
+        Running 0 test(s) from src/
+        Tests: 5 passed, 0 failed, 0 ignored, 0 filtered out
+        "},
+    );
+}
+
+#[test]
+fn unknown_predeployed_contract() {
+    let temp = setup_package("predeployment");
+    let manifest_path = temp.child("Scarb.toml");
+    let mut scarb_toml = fs::read_to_string(&manifest_path)
+        .unwrap()
+        .parse::<DocumentMut>()
+        .unwrap();
+    scarb_toml["tool"]["snforge"]["predeployment"]["udc"] = value("NonExistentDeployer");
+    manifest_path.write_str(&scarb_toml.to_string()).unwrap();
+
+    let output = test_runner(&temp)
+        .arg("predeployed_contract")
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [FAIL] predeployment_integrationtest::test_predeployment::predeployed_contract
+
+        Failure data:
+        [..]Failed to declare predeployed contract NonExistentDeployer
+        "},
+    );
+}
+
+#[test]
+fn accounts_are_not_funded_without_tokens() {
+    let temp = setup_package("predeployment");
+    let manifest_path = temp.child("Scarb.toml");
+    let mut scarb_toml = fs::read_to_string(&manifest_path)
+        .unwrap()
+        .parse::<DocumentMut>()
+        .unwrap();
+    scarb_toml["tool"]["snforge"]["predeployment"]["tokens"] = value(false);
+    manifest_path.write_str(&scarb_toml.to_string()).unwrap();
+
+    let output = test_runner(&temp)
+        .arg("predeployed_contract")
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [FAIL] predeployment_integrationtest::test_predeployment::predeployed_contract
+
+        Failure data:
+        [..]Predeployed accounts cannot be funded, as STRK token is not predeployed (`tokens = false`)
+        "},
+    );
+}
@@ -852,6 +852,42 @@ fn with_rerun_failed_flag_and_custom_cache_dir() {
     );
 }
 
+#[test]
+fn with_affected_flag() {
+    let temp = setup_package("simple_package");
+
+    test_runner(&temp).assert().code(1);
+
+    temp.child("tests/without_prefix.cairo")
+        .write_str(indoc! {r"
+            #[test]
+            fn five() {
+                assert(5 == 5, 'five');
+            }
+        "})
+        .unwrap();
+
+    let output = test_runner(&temp).arg("--affected").assert().code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+        Collected 5 test(s) from simple_package package
+        Running 1 test(s) from src/
+        [IGNORE] simple_package::tests::ignored_test
+        Running 4 test(s) from tests/
+        [PASS] simple_package_integrationtest::without_prefix::five [..]
+        [FAIL] simple_package_integrationtest::test_simple::test_failing
+        [FAIL] simple_package_integrationtest::test_simple::test_another_failing
+        [IGNORE] simple_package_integrationtest::ext_function_test::ignored_test
+        Tests: 1 passed, 2 failed, 2 ignored, 8 filtered out
+        "},
+    );
+}
+
 #[test]
 fn fails_with_relative_custom_cache_dir() {
     let temp = setup_package("simple_package");
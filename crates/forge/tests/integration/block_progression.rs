@@ -0,0 +1,161 @@
+use crate::utils::runner::{assert_case_output_contains, assert_failed, assert_passed};
+use crate::utils::running_tests::run_test_case;
+use crate::utils::test_case;
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+
+#[test]
+fn roll_and_warp() {
+    let test = test_case!(indoc!(
+        r#"
+            use starknet::{get_block_info, SyscallResultTrait};
+            use starknet::syscalls::get_block_hash_syscall;
+            use snforge_std::{
+                Block, roll, roll_with_hook, set_block_time, start_cheat_block_hash_global,
+                start_cheat_block_number_global, warp,
+            };
+
+            fn current_block() -> Block {
+                let block_info = get_block_info().unbox();
+                Block {
+                    block_number: block_info.block_number,
+                    block_timestamp: block_info.block_timestamp,
+                }
+            }
+
+            #[test]
+            fn roll_advances_timestamp() {
+                let start = current_block();
+
+                let block = roll(3);
+
+                assert_eq!(block, current_block());
+                assert_eq!(block.block_number, start.block_number + 3);
+                assert_eq!(block.block_timestamp, start.block_timestamp + 18);
+            }
+
+            #[test]
+            fn warp_advances_block_number() {
+                let start = current_block();
+                set_block_time(10);
+
+                let block = warp(15);
+                assert_eq!(block, current_block());
+                assert_eq!(block.block_number, start.block_number + 1);
+                assert_eq!(block.block_timestamp, start.block_timestamp + 15);
+
+                let block = warp(3);
+                assert_eq!(block.block_number, start.block_number + 1);
+                assert_eq!(block.block_timestamp, start.block_timestamp + 18);
+
+                let block = warp(2);
+                assert_eq!(block.block_number, start.block_number + 2);
+                assert_eq!(block.block_timestamp, start.block_timestamp + 20);
+            }
+
+            #[test]
+            fn roll_after_warp_starts_new_block() {
+                let start = current_block();
+                set_block_time(10);
+
+                warp(4);
+                let block = roll(2);
+
+                assert_eq!(block.block_number, start.block_number + 2);
+                assert_eq!(block.block_timestamp, start.block_timestamp + 20);
+            }
+
+            #[test]
+            fn roll_after_other_cheats() {
+                start_cheat_block_number_global(5000);
+
+                let block = roll(1);
+
+                assert_eq!(block.block_number, 5001);
+                assert_eq!(current_block().block_number, 5001);
+            }
+
+            #[test]
+            fn produced_blocks_have_hashes() {
+                let start = current_block();
+
+                roll(2);
+
+                // Hashes of the last 10 blocks are not stored yet
+                assert!(get_block_hash_syscall(start.block_number).is_err());
+
+                roll(10);
+
+                let first_hash = get_block_hash_syscall(start.block_number).unwrap_syscall();
+                let second_hash = get_block_hash_syscall(start.block_number + 1).unwrap_syscall();
+                assert_ne!(first_hash, 0);
+                assert_ne!(second_hash, 0);
+                assert_ne!(first_hash, second_hash);
+                assert!(get_block_hash_syscall(start.block_number + 3).is_err());
+
+                // Blocks are not produced again
+                roll(1);
+                assert_eq!(get_block_hash_syscall(start.block_number).unwrap_syscall(), first_hash);
+
+                start_cheat_block_hash_global(start.block_number, 123);
+                assert_eq!(get_block_hash_syscall(start.block_number).unwrap_syscall(), 123);
+            }
+
+            #[test]
+            fn hook_sees_each_block() {
+                let start = current_block();
+
+                roll_with_hook(
+                    3,
+                    |block: Block| {
+                        assert_eq!(block, current_block());
+                    },
+                );
+
+                assert_eq!(current_block().block_number, start.block_number + 3);
+            }
+
+            #[test]
+            #[should_panic(expected: "hook called for the second block")]
+            fn hook_is_called_for_each_block() {
+                let second_block = current_block().block_number + 2;
+
+                roll_with_hook(
+                    3,
+                    |block: Block| {
+                        if block.block_number == second_block {
+                            panic!("hook called for the second block");
+                        }
+                    },
+                );
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn set_block_time_to_zero() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::set_block_time;
+
+            #[test]
+            fn zero_block_time() {
+                set_block_time(0);
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "zero_block_time",
+        "Block time has to be greater than 0",
+    );
+}
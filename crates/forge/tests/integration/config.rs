@@ -1,13 +1,17 @@
 use crate::e2e::common::runner::setup_package;
 use assert_fs::TempDir;
 use assert_fs::fixture::{FileWriteStr, PathChild};
+use cheatnet::predeployment::config::{
+    AccountKind, PredeployedAccountsConfig, PredeployedContractConfig, PredeploymentConfig,
+};
 use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
 use forge::scarb::config::{ForgeConfigFromScarb, ForkTarget};
 use forge::scarb::load_package_config;
-use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::forge_config::{ForgeTrackedResource, RetryOn};
 use indoc::{formatdoc, indoc};
 use scarb_api::metadata::metadata_for_dir;
 use scarb_metadata::PackageId;
+use starknet_types_core::felt::Felt;
 use std::{env, fs};
 use toml_edit::{DocumentMut, value};
 
@@ -85,13 +89,18 @@ fn get_forge_config_for_package() {
             ],
             fuzzer_runs: None,
             fuzzer_seed: None,
+            seed: None,
             max_n_steps: None,
+            timeout: None,
+            retries: None,
+            retry_on: RetryOn::ForkErrors,
             tracked_resource: ForgeTrackedResource::SierraGas,
             detailed_resources: false,
             save_trace_data: false,
             build_profile: false,
             coverage: false,
             gas_report: false,
+            predeployment: PredeploymentConfig::default(),
         }
     );
 }
@@ -345,13 +354,85 @@ fn get_forge_config_resolves_env_variables() {
             }],
             fuzzer_runs: None,
             fuzzer_seed: None,
+            seed: None,
             max_n_steps: None,
+            timeout: None,
+            retries: None,
+            retry_on: RetryOn::ForkErrors,
             tracked_resource: ForgeTrackedResource::SierraGas,
             detailed_resources: false,
             save_trace_data: false,
             build_profile: false,
             coverage: false,
             gas_report: false,
+            predeployment: PredeploymentConfig::default(),
+        }
+    );
+}
+
+#[test]
+fn get_forge_config_for_package_with_predeployment() {
+    let temp = setup_package_with_toml();
+    let manifest_path = temp.child("Scarb.toml");
+    let manifest_contents = fs::read_to_string(&manifest_path).unwrap();
+    let manifest_contents = formatdoc!(
+        r#"
+        {manifest_contents}
+
+        [tool.snforge.predeployment]
+        tokens = false
+        udc = "UniversalDeployer"
+
+        [[tool.snforge.predeployment.accounts]]
+        contract = "AccountUpgradeable"
+        kind = "open-zeppelin"
+        count = 2
+        balance = "0x100"
+
+        [[tool.snforge.predeployment.accounts]]
+        contract = "ArgentAccount"
+        kind = "argent"
+
+        [[tool.snforge.predeployment.contracts]]
+        contract = "HelloStarknet"
+        address = "0x123"
+        constructor_calldata = ["0x1", "2"]
+    "#
+    );
+    manifest_path.write_str(manifest_contents.as_str()).unwrap();
+
+    let scarb_metadata = metadata_for_dir(temp.path()).unwrap();
+
+    let config = load_package_config::<ForgeConfigFromScarb>(
+        &scarb_metadata,
+        &scarb_metadata.workspace.members[0],
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.predeployment,
+        PredeploymentConfig {
+            tokens: false,
+            udc: Some("UniversalDeployer".to_string()),
+            accounts: vec![
+                PredeployedAccountsConfig {
+                    contract: "AccountUpgradeable".to_string(),
+                    kind: AccountKind::OpenZeppelin,
+                    count: 2,
+                    balance: Felt::from(0x100),
+                },
+                PredeployedAccountsConfig {
+                    contract: "ArgentAccount".to_string(),
+                    kind: AccountKind::Argent,
+                    count: 1,
+                    balance: Felt::from(1_000_000_000_000_000_000_000_u128),
+                },
+            ],
+            contracts: vec![PredeployedContractConfig {
+                contract: "HelloStarknet".to_string(),
+                address: Felt::from(0x123),
+                constructor_calldata: vec![Felt::from(1), Felt::from(2)],
+            }],
         }
     );
 }
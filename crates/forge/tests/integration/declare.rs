@@ -139,3 +139,51 @@ fn redeclare() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn declare_errors() {
+    let contract = Contract::from_code_path(
+        "contract::HelloStarknet".to_string(),
+        Path::new("tests/data/contracts/hello_starknet.cairo"),
+    )
+    .unwrap();
+
+    let test = test_case!(
+        indoc!(
+            r#"
+        use snforge_std::{declare_cairo0, declare_sierra, try_declare, DeclareError};
+
+        #[test]
+        fn contract_not_found() {
+            let error = try_declare("GoodbyeStarknet").unwrap_err();
+
+            assert!(error == DeclareError::ContractNotFound("GoodbyeStarknet"));
+            assert!(
+                format!("{}", error) == "Failed to get contract artifact for identifier = GoodbyeStarknet."
+            );
+        }
+
+        #[test]
+        fn invalid_sierra() {
+            match declare_sierra("{}") {
+                Result::Err(DeclareError::InvalidClass(_)) => {},
+                _ => panic!("Expected InvalidClass"),
+            }
+        }
+
+        #[test]
+        fn invalid_cairo0_class() {
+            match declare_cairo0("{}") {
+                Result::Err(DeclareError::InvalidClass(_)) => {},
+                _ => panic!("Expected InvalidClass"),
+            }
+        }
+        "#
+        ),
+        contract
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
@@ -0,0 +1,256 @@
+use crate::utils::runner::{Contract, assert_case_failure_contains, assert_failed, assert_passed};
+use crate::utils::running_tests::run_test_case;
+use crate::utils::test_case;
+use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::test_case_summary::AnyTestCaseSummary;
+use indoc::indoc;
+use std::path::Path;
+
+#[test]
+fn setup_with_fixture() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use snforge_std::{declare, ContractClassTrait, DeclareResultTrait};
+
+        #[starknet::interface]
+        trait IHelloStarknet<TContractState> {
+            fn increase_balance(ref self: TContractState, amount: felt252);
+            fn get_balance(self: @TContractState) -> felt252;
+        }
+
+        #[fixture]
+        fn deployed() -> IHelloStarknetDispatcher {
+            let contract = declare("HelloStarknet").unwrap().contract_class();
+            let (contract_address, _) = contract.deploy(@array![]).unwrap();
+            let dispatcher = IHelloStarknetDispatcher { contract_address };
+            dispatcher.increase_balance(5);
+            dispatcher
+        }
+
+        #[test]
+        #[setup(deployed)]
+        fn first_test() {
+            let dispatcher = deployed();
+            assert_eq!(dispatcher.get_balance(), 5);
+            dispatcher.increase_balance(1);
+            assert_eq!(dispatcher.get_balance(), 6);
+        }
+
+        #[test]
+        #[setup(deployed)]
+        fn second_test() {
+            let dispatcher = deployed();
+            assert_eq!(dispatcher.get_balance(), 5);
+            dispatcher.increase_balance(2);
+            assert_eq!(dispatcher.get_balance(), 7);
+        }
+
+        #[test]
+        #[setup(deployed)]
+        fn deploys_next_to_fixture() {
+            let dispatcher = deployed();
+            let contract = declare("HelloStarknet").unwrap().contract_class();
+            let (contract_address, _) = contract.deploy(@array![]).unwrap();
+            assert(contract_address != dispatcher.contract_address, 'Same address');
+            assert_eq!(IHelloStarknetDispatcher { contract_address }.get_balance(), 0);
+        }
+
+        #[test]
+        fn without_setup() {
+            let dispatcher = deployed();
+            assert_eq!(dispatcher.get_balance(), 5);
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "contract::HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn fixtures_with_same_name_in_different_modules() {
+    let test = test_case!(indoc!(
+        r"
+        mod first {
+            #[fixture]
+            pub fn value() -> felt252 {
+                1
+            }
+        }
+
+        mod second {
+            #[fixture]
+            pub fn value() -> felt252 {
+                2
+            }
+        }
+
+        #[test]
+        #[setup(first::value)]
+        fn calls_other_fixture_with_same_name() {
+            assert_eq!(first::value(), 1);
+            assert_eq!(second::value(), 2);
+        }
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn identical_fixtures_in_different_modules() {
+    let test = test_case!(indoc!(
+        r"
+        mod first {
+            const VALUE: felt252 = 1;
+
+            #[fixture]
+            pub fn value() -> felt252 {
+                VALUE
+            }
+        }
+
+        mod second {
+            const VALUE: felt252 = 2;
+
+            #[fixture]
+            pub fn value() -> felt252 {
+                VALUE
+            }
+        }
+
+        #[test]
+        #[setup(first::value)]
+        fn set_up_with_first() {
+            assert_eq!(first::value(), 1);
+            assert_eq!(second::value(), 2);
+        }
+
+        #[test]
+        #[setup(second::value)]
+        fn set_up_with_second() {
+            assert_eq!(first::value(), 1);
+            assert_eq!(second::value(), 2);
+        }
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn setup_with_failing_fixture() {
+    let test = test_case!(indoc!(
+        r"
+        #[fixture]
+        fn failing() -> felt252 {
+            core::panic_with_felt252('fixture panicked')
+        }
+
+        #[test]
+        #[setup(failing)]
+        fn with_failing_fixture() {
+            failing();
+        }
+
+        #[test]
+        #[setup(missing)]
+        fn with_missing_fixture() {}
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_failure_contains(
+        &result,
+        "with_failing_fixture",
+        AnyTestCaseSummary::fixture_failure,
+        "::failing` failed:",
+    );
+    assert_case_failure_contains(
+        &result,
+        "with_failing_fixture",
+        AnyTestCaseSummary::fixture_failure,
+        "fixture panicked",
+    );
+    assert_case_failure_contains(
+        &result,
+        "with_missing_fixture",
+        AnyTestCaseSummary::fixture_failure,
+        "Fixture `missing` not found, make sure it is marked with #[fixture]",
+    );
+}
+
+#[test]
+fn fixture_cheats_are_kept() {
+    let test = test_case!(
+        indoc!(
+            r#"
+        use snforge_std::{
+            declare, ContractClassTrait, DeclareResultTrait, start_cheat_block_number, start_mock_call
+        };
+
+        #[starknet::interface]
+        trait ICheatBlockNumberChecker<TContractState> {
+            fn get_block_number(ref self: TContractState) -> u64;
+        }
+
+        fn deploy() -> ICheatBlockNumberCheckerDispatcher {
+            let contract = declare("CheatBlockNumberChecker").unwrap().contract_class();
+            let (contract_address, _) = contract.deploy(@array![]).unwrap();
+            ICheatBlockNumberCheckerDispatcher { contract_address }
+        }
+
+        #[fixture]
+        fn cheated() -> ICheatBlockNumberCheckerDispatcher {
+            let dispatcher = deploy();
+            start_cheat_block_number(dispatcher.contract_address, 123);
+            dispatcher
+        }
+
+        #[fixture]
+        fn mocked() -> ICheatBlockNumberCheckerDispatcher {
+            let dispatcher = deploy();
+            start_mock_call(dispatcher.contract_address, selector!("get_block_number"), 456_u64);
+            dispatcher
+        }
+
+        #[test]
+        #[setup(cheated)]
+        fn cheat_from_fixture() {
+            let dispatcher = cheated();
+            assert_eq!(dispatcher.get_block_number(), 123);
+        }
+
+        #[test]
+        #[setup(mocked)]
+        fn mock_from_fixture() {
+            let dispatcher = mocked();
+            assert_eq!(dispatcher.get_block_number(), 456);
+        }
+    "#
+        ),
+        Contract::from_code_path(
+            "contract::CheatBlockNumberChecker".to_string(),
+            Path::new("tests/data/contracts/cheat_block_number_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
@@ -1,4 +1,4 @@
-use crate::utils::runner::assert_passed;
+use crate::utils::runner::{TestCase, assert_case_output_contains, assert_failed, assert_passed};
 use crate::utils::running_tests::run_test_case;
 use crate::utils::test_case;
 use forge_runner::forge_config::ForgeTrackedResource;
@@ -42,3 +42,62 @@ fn simple_generate_random_felt() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn generate_random_felt_with_seed() {
+    let test = test_case!(indoc!(
+        r#"
+        use snforge_std::generate_random_felt;
+
+        #[test]
+        #[seed(1234)]
+        fn first_with_seed() {
+            panic!("{}", generate_random_felt());
+        }
+
+        #[test]
+        #[seed(1234)]
+        fn second_with_seed() {
+            panic!("{}", generate_random_felt());
+        }
+
+        #[test]
+        #[seed(4321)]
+        fn other_seed() {
+            panic!("{}", generate_random_felt());
+        }
+
+        #[test]
+        #[seed(1234)]
+        fn without_random_values() {
+            panic!("no random values");
+        }
+        "#
+    ),);
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+
+    let msg = |test_case_name: &str| {
+        TestCase::find_test_result(&result)
+            .test_case_summaries
+            .iter()
+            .find(|case| {
+                case.name()
+                    .is_some_and(|name| name.ends_with(&format!("::{test_case_name}")))
+            })
+            .and_then(|case| case.msg())
+            .unwrap()
+            .to_string()
+    };
+
+    assert_eq!(msg("first_with_seed"), msg("second_with_seed"));
+    assert_ne!(msg("first_with_seed"), msg("other_seed"));
+    assert!(!msg("without_random_values").contains("Random values"));
+    assert_case_output_contains(
+        &result,
+        "first_with_seed",
+        "Random values were generated with seed 1234, use `--seed 1234` to reproduce them",
+    );
+}
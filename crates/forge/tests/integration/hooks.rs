@@ -0,0 +1,109 @@
+use crate::utils::runner::{
+    assert_case_failure_contains, assert_case_output_contains, assert_failed, assert_passed,
+};
+use crate::utils::running_tests::run_test_case;
+use crate::utils::test_case;
+use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::test_case_summary::AnyTestCaseSummary;
+use indoc::indoc;
+
+#[test]
+fn hooks_share_state_with_test() {
+    let test = test_case!(indoc!(
+        r"
+        use snforge_std::start_cheat_block_number_global;
+
+        fn block_number() -> u64 {
+            starknet::get_block_info().unbox().block_number
+        }
+
+        #[before_each]
+        fn cheat_block_number() {
+            start_cheat_block_number_global(123);
+        }
+
+        #[after_each]
+        fn block_number_is_cheated() {
+            assert_eq!(block_number(), 123);
+        }
+
+        #[test]
+        fn sees_cheat_from_before_each() {
+            assert_eq!(block_number(), 123);
+        }
+
+        #[test]
+        #[should_panic(expected: 'expected panic')]
+        fn panics() {
+            core::panic_with_felt252('expected panic');
+        }
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn failing_hooks() {
+    let test = test_case!(indoc!(
+        r"
+        mod failing_before_each {
+            #[before_each]
+            fn failing() {
+                core::panic_with_felt252('before each panicked');
+            }
+
+            #[test]
+            fn not_run() {
+                core::panic_with_felt252('test body was run');
+            }
+        }
+
+        mod failing_after_each {
+            #[after_each]
+            fn failing() {
+                core::panic_with_felt252('after each panicked');
+            }
+
+            #[test]
+            fn passing_body() {}
+
+            #[test]
+            fn failing_body() {
+                core::panic_with_felt252('test body panicked');
+            }
+        }
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "not_run",
+        "::failing_before_each::failing` failed:",
+    );
+    assert_case_output_contains(&result, "not_run", "before each panicked");
+    assert_case_failure_contains(
+        &result,
+        "passing_body",
+        AnyTestCaseSummary::after_each_failure,
+        "::failing_after_each::failing` failed:",
+    );
+    assert_case_failure_contains(
+        &result,
+        "passing_body",
+        AnyTestCaseSummary::after_each_failure,
+        "after each panicked",
+    );
+    assert_case_output_contains(&result, "failing_body", "test body panicked");
+    assert_case_failure_contains(
+        &result,
+        "failing_body",
+        AnyTestCaseSummary::after_each_failure,
+        "after each panicked",
+    );
+}
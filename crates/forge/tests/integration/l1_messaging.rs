@@ -0,0 +1,262 @@
+use crate::utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use crate::utils::running_tests::run_test_case;
+use crate::utils::test_case;
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+
+fn contracts() -> (Contract, Contract) {
+    (
+        Contract::from_code_path(
+            "contract::l1_handler_executor".to_string(),
+            Path::new("tests/data/contracts/l1_handler_execute_checker.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "contract::MessageToL1Checker".to_string(),
+            Path::new("tests/data/contracts/message_to_l1_checker.cairo"),
+        )
+        .unwrap(),
+    )
+}
+
+#[test]
+fn messages_to_l2() {
+    let (l1_handler_executor, message_to_l1_checker) = contracts();
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, MessageToL2Status, MessageToL2Trait,
+                send_message_to_l2,
+            };
+            use starknet::contract_address_const;
+
+            #[starknet::interface]
+            trait IBalanceToken<TContractState> {
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn messages_to_l2() {
+                let contract = declare("l1_handler_executor").unwrap().contract_class();
+                let contract_address = contract_address_const::<0x456>();
+                contract.deploy_at(@array![0x123], contract_address).unwrap();
+
+                // `L1Data { balance: 42, token_id: 8888 }`
+                let payload = array![42, 8888, 0];
+                let message = send_message_to_l2(
+                    0x123.try_into().unwrap(),
+                    contract_address,
+                    selector!("process_l1_message"),
+                    payload.span(),
+                    100,
+                );
+
+                assert(message.nonce == 0, 'Wrong nonce');
+                assert(message.fee == 100, 'Wrong fee');
+                assert(
+                    message.hash == 0xf447033eb9873c822e7fedd9d0ff946c28e840fba0b0984d4fc44a2c9679d5fb,
+                    'Wrong hash',
+                );
+                assert(message.status() == MessageToL2Status::Pending, 'Wrong status');
+
+                message.execute().unwrap();
+
+                assert(message.status() == MessageToL2Status::Consumed, 'Not consumed');
+                let dispatcher = IBalanceTokenDispatcher { contract_address };
+                assert(dispatcher.get_balance() == 42, 'Message not handled');
+
+                let message = send_message_to_l2(
+                    0x123.try_into().unwrap(),
+                    contract_address,
+                    selector!("process_l1_message"),
+                    payload.span(),
+                    100,
+                );
+                assert(message.nonce == 1, 'Wrong nonce');
+                assert(
+                    message.hash == 0x40eb60da75326f75eeeb274429f71da1284ceeb88c3841b58d206c331b57500b,
+                    'Wrong hash',
+                );
+            }
+
+            #[test]
+            fn failing_message_to_l2_stays_pending() {
+                let contract = declare("l1_handler_executor").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![0x123]).unwrap();
+
+                let message = send_message_to_l2(
+                    0x123.try_into().unwrap(),
+                    contract_address,
+                    selector!("panicking_l1_handler"),
+                    array![].span(),
+                    100,
+                );
+
+                let panic_data = message.execute().unwrap_err();
+
+                assert(panic_data == array!['custom', 'panic'], 'Wrong panic data');
+                assert(message.status() == MessageToL2Status::Pending, 'Wrong status');
+            }
+
+            #[test]
+            fn message_to_l2_cancellation() {
+                let contract = declare("l1_handler_executor").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![0x123]).unwrap();
+
+                let message = send_message_to_l2(
+                    0x123.try_into().unwrap(),
+                    contract_address,
+                    selector!("process_l1_message"),
+                    array![42, 8888, 0].span(),
+                    100,
+                );
+
+                snforge_std::set_message_cancellation_delay(100);
+                message.start_cancellation();
+                let started_at = starknet::get_block_timestamp();
+                assert(
+                    message.status() == MessageToL2Status::CancellationStarted(started_at),
+                    'Wrong status',
+                );
+
+                snforge_std::warp(100);
+                message.cancel();
+
+                assert(message.status() == MessageToL2Status::Cancelled, 'Not cancelled');
+            }
+        "#
+        ),
+        l1_handler_executor,
+        message_to_l1_checker
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn invalid_messages_to_l2() {
+    let (l1_handler_executor, message_to_l1_checker) = contracts();
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, MessageToL2Trait, send_message_to_l2,
+                warp,
+            };
+
+            #[test]
+            fn zero_fee() {
+                send_message_to_l2(
+                    0x123.try_into().unwrap(),
+                    0x456.try_into().unwrap(),
+                    selector!("process_l1_message"),
+                    array![].span(),
+                    0,
+                );
+            }
+
+            #[test]
+            fn cancel_before_delay() {
+                let message = send_message_to_l2(
+                    0x123.try_into().unwrap(),
+                    0x456.try_into().unwrap(),
+                    selector!("process_l1_message"),
+                    array![].span(),
+                    100,
+                );
+
+                message.start_cancellation();
+                warp(100);
+                message.cancel();
+            }
+
+            #[test]
+            fn execute_cancelled_message() {
+                let contract = declare("l1_handler_executor").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![0x123]).unwrap();
+
+                let message = send_message_to_l2(
+                    0x123.try_into().unwrap(),
+                    contract_address,
+                    selector!("process_l1_message"),
+                    array![42, 8888, 0].span(),
+                    100,
+                );
+
+                snforge_std::set_message_cancellation_delay(0);
+                message.start_cancellation();
+                message.cancel();
+                message.execute().unwrap();
+            }
+        "#
+        ),
+        l1_handler_executor,
+        message_to_l1_checker
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "zero_fee", "Message fee has to be greater than 0");
+    assert_case_output_contains(
+        &result,
+        "cancel_before_delay",
+        "cannot be cancelled before timestamp",
+    );
+    assert_case_output_contains(&result, "execute_cancelled_message", "was cancelled");
+}
+
+#[test]
+fn consume_messages_from_l2() {
+    let (l1_handler_executor, message_to_l1_checker) = contracts();
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, consume_message_from_l2};
+            use starknet::{contract_address_const, EthAddress};
+
+            #[starknet::interface]
+            trait IMessageToL1Checker<TContractState> {
+                fn send_message(ref self: TContractState, some_data: Array<felt252>, to_address: EthAddress);
+            }
+
+            #[test]
+            fn consume_messages_from_l2() {
+                let contract = declare("MessageToL1Checker").unwrap().contract_class();
+                let contract_address = contract_address_const::<0x789>();
+                contract.deploy_at(@array![], contract_address).unwrap();
+                let to_address: EthAddress = 0x321.try_into().unwrap();
+
+                assert(
+                    consume_message_from_l2(contract_address, to_address, array![1, 2].span()).is_none(),
+                    'Consumed unsent message',
+                );
+
+                let dispatcher = IMessageToL1CheckerDispatcher { contract_address };
+                dispatcher.send_message(array![1, 2], to_address);
+
+                let hash = consume_message_from_l2(contract_address, to_address, array![1, 2].span())
+                    .unwrap();
+                assert(
+                    hash == 0x38b437e683a2e8d2eab88116b8714f6bd463d47fb10114594a966fa10277efb9,
+                    'Wrong hash',
+                );
+                assert(
+                    consume_message_from_l2(contract_address, to_address, array![1, 2].span()).is_none(),
+                    'Consumed message twice',
+                );
+            }
+        "#
+        ),
+        l1_handler_executor,
+        message_to_l1_checker
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
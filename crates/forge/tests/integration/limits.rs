@@ -0,0 +1,197 @@
+use crate::utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use crate::utils::running_tests::run_test_case;
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::{formatdoc, indoc};
+use std::net::TcpListener;
+
+#[test]
+fn max_steps_not_exceeded() {
+    let test = crate::utils::test_case!(indoc!(
+        r"
+            #[test]
+            #[max_steps(100000)]
+            fn short_loop() {
+                let mut i: u32 = 0;
+                while i != 100 {
+                    i += 1;
+                };
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn max_steps_exceeded() {
+    let test = crate::utils::test_case!(indoc!(
+        r"
+            #[test]
+            #[max_steps(1000)]
+            fn long_loop() {
+                let mut i: u32 = 0;
+                while i != 100000 {
+                    i += 1;
+                };
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "long_loop",
+        "Test exceeded the limit of 1000 steps",
+    );
+}
+
+#[test]
+fn timeout_exceeded() {
+    let test = crate::utils::test_case!(indoc!(
+        r"
+            #[test]
+            #[timeout(1)]
+            #[max_steps(4000000000)]
+            fn endless_loop() {
+                let mut i: u64 = 0;
+                loop {
+                    i += 1;
+                }
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "endless_loop", "Test timed out after 1s");
+}
+
+fn looping_contract() -> Contract {
+    Contract::new(
+        "contract::Looping",
+        indoc!(
+            r"
+            #[starknet::interface]
+            trait ILooping<TContractState> {
+                fn spin(self: @TContractState, iterations: u64);
+            }
+
+            #[starknet::contract]
+            mod Looping {
+                #[storage]
+                struct Storage {}
+
+                #[abi(embed_v0)]
+                impl LoopingImpl of super::ILooping<ContractState> {
+                    fn spin(self: @ContractState, iterations: u64) {
+                        let mut i: u64 = 0;
+                        while i != iterations {
+                            i += 1;
+                        }
+                    }
+                }
+            }
+            "
+        ),
+    )
+}
+
+#[test]
+fn max_steps_exceeded_in_contract_call() {
+    let test = crate::utils::test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{ContractClassTrait, DeclareResultTrait, declare};
+
+            #[starknet::interface]
+            trait ILooping<TContractState> {
+                fn spin(self: @TContractState, iterations: u64);
+            }
+
+            #[test]
+            #[max_steps(100000)]
+            fn long_loop_in_contract() {
+                let contract = declare("Looping").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+
+                ILoopingDispatcher { contract_address }.spin(1000000);
+            }
+        "#
+        ),
+        looping_contract()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "long_loop_in_contract",
+        "Test exceeded the limit of 100000 steps",
+    );
+}
+
+#[test]
+fn timeout_exceeded_in_contract_call() {
+    let test = crate::utils::test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{ContractClassTrait, DeclareResultTrait, declare};
+
+            #[starknet::interface]
+            trait ILooping<TContractState> {
+                fn spin(self: @TContractState, iterations: u64);
+            }
+
+            #[test]
+            #[timeout(1)]
+            #[max_steps(4000000000)]
+            fn endless_loop_in_contract() {
+                let contract = declare("Looping").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+
+                ILoopingDispatcher { contract_address }.spin(0xffffffffffffffff);
+            }
+        "#
+        ),
+        looping_contract()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "endless_loop_in_contract",
+        "Test timed out after 1s",
+    );
+}
+
+#[test]
+fn timeout_exceeded_waiting_for_fork() {
+    // Connections are accepted by the system, but the node never responds to requests
+    let stalled_node = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", stalled_node.local_addr().unwrap());
+
+    let test = crate::utils::test_case!(
+        formatdoc!(
+            r#"
+            #[test]
+            #[timeout(1)]
+            #[fork(url: "{url}", block_number: 1)]
+            fn stalled_fork() {{}}
+        "#
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "stalled_fork", "Test timed out after 1s");
+}
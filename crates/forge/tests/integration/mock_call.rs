@@ -208,3 +208,56 @@ fn mock_calls() {
     let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
     assert_passed(&result);
 }
+
+#[test]
+fn mock_call_when() {
+    let test = test_case!(indoc!(
+        r#"
+        use starknet::ContractAddress;
+        use snforge_std::{CalldataMatcher, MockedResponse, mock_call_when};
+
+        #[starknet::interface]
+        trait IToken<TContractState> {
+            fn balance_of(self: @TContractState, account: ContractAddress) -> u256;
+        }
+
+        #[test]
+        #[feature("safe_dispatcher")]
+        fn mock_call_when() {
+            let token: ContractAddress = 123.try_into().unwrap();
+            let alice: ContractAddress = 1.try_into().unwrap();
+            let bob: ContractAddress = 2.try_into().unwrap();
+
+            mock_call_when(
+                token,
+                selector!("balance_of"),
+                CalldataMatcher::Exact(array![alice.into()]),
+                array![MockedResponse::Return(array![100, 0]), MockedResponse::Return(array![200, 0])],
+            );
+            mock_call_when(
+                token,
+                selector!("balance_of"),
+                CalldataMatcher::Any,
+                array![MockedResponse::Revert(array!['unknown account'])],
+            );
+
+            let dispatcher = ITokenDispatcher { contract_address: token };
+            assert(dispatcher.balance_of(alice) == 100, 'Incorrect first balance');
+            assert(dispatcher.balance_of(alice) == 200, 'Incorrect second balance');
+            assert(dispatcher.balance_of(alice) == 200, 'Incorrect third balance');
+
+            let safe_dispatcher = ITokenSafeDispatcher { contract_address: token };
+            match safe_dispatcher.balance_of(bob) {
+                Result::Ok(_) => panic!("Call should revert"),
+                Result::Err(panic_data) => {
+                    assert(*panic_data.at(0) == 'unknown account', 'Incorrect panic data');
+                },
+            }
+
+        }
+    "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+    assert_passed(&result);
+}
@@ -1,4 +1,5 @@
 mod available_gas;
+mod block_progression;
 mod builtins;
 mod cheat_block_hash;
 mod cheat_block_number;
@@ -14,29 +15,38 @@ mod deploy_at;
 mod dict;
 mod dispatchers;
 mod env;
+mod fixtures;
 mod fuzzing;
 mod gas;
 mod generate_random_felt;
 mod get_available_gas;
 mod get_class_hash;
 mod get_current_vm_step;
+mod hooks;
 mod interact_with_state;
 mod l1_handler_executor;
+mod l1_messaging;
+mod limits;
 mod message_to_l1;
 mod meta_tx_v0;
 mod mock_call;
 mod precalculate_address;
 mod pure_cairo;
 mod replace_bytecode;
+mod replay_transaction;
 mod resources;
+mod retries;
 mod reverts;
 mod runtime;
 mod set_balance;
+mod set_token_storage;
 mod setup_fork;
 mod should_panic;
 mod signing;
 mod spy_events;
+mod storage_accesses;
 mod store_load;
+mod submit_transaction;
 mod syscalls;
 mod test_state;
 mod too_many_events;
@@ -1,8 +1,9 @@
-use crate::utils::runner::{Contract, assert_passed};
+use crate::utils::runner::{Contract, assert_case_output_contains, assert_passed};
 use crate::utils::running_tests::run_test_case;
 use crate::utils::test_case;
 use forge_runner::forge_config::ForgeTrackedResource;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::node_url::node_rpc_url;
 use std::path::Path;
 
 #[test]
@@ -179,3 +180,351 @@ fn class_hash_not_declared() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn upgrade_contract_not_deployed() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{upgrade_forked_contract, ReplaceBytecodeError};
+            use starknet::contract_address_const;
+
+            #[test]
+            fn upgrade_contract_not_deployed() {
+                let non_existing_contract_address = contract_address_const::<0x2>();
+                let result = upgrade_forked_contract(non_existing_contract_address, "ReplaceBytecodeA");
+
+                assert(result == Result::Err(ReplaceBytecodeError::ContractNotDeployed), 'Wrong error type');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::ReplaceBytecodeA",
+            Path::new("tests/data/contracts/two_implementations.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn upgrade_forked_contract() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use snforge_std::{{get_class_hash, upgrade_forked_contract}};
+            use starknet::contract_address_const;
+
+            #[starknet::interface]
+            trait IReplaceBytecode<TContractState> {{
+                fn get(self: @TContractState) -> felt252;
+            }}
+
+            #[test]
+            #[fork(url: "{}", block_number: 54060)]
+            fn upgrade_forked_contract() {{
+                let contract_address = contract_address_const::<
+                    0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7
+                >();
+
+                let class_hash = upgrade_forked_contract(contract_address, "ReplaceBytecodeA").unwrap();
+
+                assert(get_class_hash(contract_address) == class_hash, 'Class hash not changed');
+                assert(IReplaceBytecodeDispatcher {{ contract_address }}.get() == 2137, 'Not upgraded');
+            }}
+        "#,
+            node_rpc_url()
+        )
+        .as_str(),
+        Contract::from_code_path(
+            "contract::ReplaceBytecodeA",
+            Path::new("tests/data/contracts/two_implementations.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn replace_bytecode_of_forked_contract_warns() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use snforge_std::{{declare, replace_bytecode, DeclareResultTrait}};
+            use starknet::contract_address_const;
+
+            #[test]
+            #[fork(url: "{}", block_number: 54060)]
+            fn replace_bytecode_of_forked_contract_warns() {{
+                let contract_address = contract_address_const::<
+                    0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7
+                >();
+                let class_hash = *declare("ReplaceBytecodeA").unwrap().contract_class().class_hash;
+
+                replace_bytecode(contract_address, class_hash).unwrap();
+            }}
+        "#,
+            node_rpc_url()
+        )
+        .as_str(),
+        Contract::from_code_path(
+            "contract::ReplaceBytecodeA",
+            Path::new("tests/data/contracts/two_implementations.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+    assert_case_output_contains(
+        &result,
+        "replace_bytecode_of_forked_contract_warns",
+        indoc!(
+            "
+            [WARNING] Class of forked contract 0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7 is shadowed with `replace_bytecode`
+                fork:        forked contract (class hash: 0x"
+        ),
+    );
+    assert_case_output_contains(
+        &result,
+        "replace_bytecode_of_forked_contract_warns",
+        "replacement: ReplaceBytecodeA Contract",
+    );
+}
+
+#[test]
+fn cheat_class_hash() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, cheat_class_hash, start_cheat_class_hash, stop_cheat_class_hash,
+                replace_bytecode, CheatSpan, ContractClassTrait, DeclareResultTrait,
+            };
+
+            #[starknet::interface]
+            trait IReplaceBytecode<TContractState> {
+                fn get(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn cheat_class_hash_for_calls() {
+                let contract = declare("ReplaceBytecodeA").unwrap().contract_class();
+                let contract_b_class = *declare("ReplaceBytecodeB").unwrap().contract_class().class_hash;
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IReplaceBytecodeDispatcher { contract_address };
+
+                cheat_class_hash(contract_address, contract_b_class, CheatSpan::TargetCalls(2))
+                    .unwrap();
+
+                assert(dispatcher.get() == 420, 'Not cheated');
+                assert(dispatcher.get() == 420, 'Not cheated');
+                assert(dispatcher.get() == 2137, 'Not restored');
+                assert(
+                    starknet::syscalls::get_class_hash_at_syscall(contract_address).unwrap() == *contract.class_hash,
+                    'Class hash changed',
+                );
+            }
+
+            #[test]
+            fn start_and_stop_cheat_class_hash() {
+                let contract = declare("ReplaceBytecodeA").unwrap().contract_class();
+                let contract_b_class = *declare("ReplaceBytecodeB").unwrap().contract_class().class_hash;
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IReplaceBytecodeDispatcher { contract_address };
+
+                start_cheat_class_hash(contract_address, contract_b_class).unwrap();
+
+                assert(dispatcher.get() == 420, 'Not cheated');
+                assert(dispatcher.get() == 420, 'Not cheated');
+
+                stop_cheat_class_hash(contract_address);
+
+                assert(dispatcher.get() == 2137, 'Not restored');
+            }
+
+            #[test]
+            fn cheat_class_hash_over_replaced_bytecode() {
+                let contract = declare("ReplaceBytecodeA").unwrap().contract_class();
+                let contract_b_class = *declare("ReplaceBytecodeB").unwrap().contract_class().class_hash;
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IReplaceBytecodeDispatcher { contract_address };
+
+                replace_bytecode(contract_address, contract_b_class).unwrap();
+                cheat_class_hash(contract_address, *contract.class_hash, CheatSpan::TargetCalls(1))
+                    .unwrap();
+
+                assert(dispatcher.get() == 2137, 'Not cheated');
+                assert(dispatcher.get() == 420, 'Replaced bytecode not restored');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::ReplaceBytecodeA",
+            Path::new("tests/data/contracts/two_implementations.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "contract::ReplaceBytecodeB",
+            Path::new("tests/data/contracts/two_implementations.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn cheat_class_hash_errors() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, start_cheat_class_hash, ReplaceBytecodeError, ContractClassTrait,
+                DeclareResultTrait,
+            };
+            use starknet::{ClassHash, contract_address_const};
+
+            #[test]
+            fn cheat_class_hash_errors() {
+                let contract = declare("ReplaceBytecodeA").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let undeclared_class: ClassHash = 'xyz'.try_into().unwrap();
+
+                assert(
+                    start_cheat_class_hash(contract_address_const::<2>(), *contract.class_hash) == Result::Err(
+                        ReplaceBytecodeError::ContractNotDeployed,
+                    ),
+                    'Wrong error',
+                );
+                assert(
+                    start_cheat_class_hash(contract_address, undeclared_class) == Result::Err(
+                        ReplaceBytecodeError::UndeclaredClassHash,
+                    ),
+                    'Wrong error',
+                );
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::ReplaceBytecodeA",
+            Path::new("tests/data/contracts/two_implementations.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn spy_class_replacements() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, replace_bytecode, spy_class_replacements, ClassReplacement,
+                ClassReplacementSpyAssertionsTrait, ClassReplacementSpyTrait, ContractClassTrait,
+                DeclareResultTrait,
+            };
+
+            #[starknet::interface]
+            trait IUpgradeable<TContractState> {
+                fn get_version(self: @TContractState) -> felt252;
+                fn upgrade(ref self: TContractState, class_hash: starknet::ClassHash);
+                fn upgrade_and_panic(ref self: TContractState, class_hash: starknet::ClassHash);
+            }
+
+            #[test]
+            fn spy_class_replacements() {
+                let contract = declare("UpgradeableV1").unwrap().contract_class();
+                let v1_class = *contract.class_hash;
+                let v2_class = *declare("UpgradeableV2").unwrap().contract_class().class_hash;
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IUpgradeableDispatcher { contract_address };
+
+                let mut spy = spy_class_replacements();
+
+                dispatcher.upgrade(v2_class);
+                assert(dispatcher.get_version() == 2, 'Not upgraded');
+                dispatcher.upgrade(v1_class);
+
+                let expected_upgrade = ClassReplacement {
+                    contract_address, old_class_hash: v1_class, new_class_hash: v2_class,
+                };
+                let expected_downgrade = ClassReplacement {
+                    contract_address, old_class_hash: v2_class, new_class_hash: v1_class,
+                };
+                assert(
+                    spy.get_class_replacements() == array![expected_upgrade, expected_downgrade],
+                    'Wrong replacements',
+                );
+                spy.assert_replaced(@array![expected_downgrade]);
+
+                let mut spy = spy_class_replacements();
+                assert(spy.get_class_replacements() == array![], 'Spied before creation');
+                spy.assert_not_replaced(@array![expected_upgrade]);
+            }
+
+            #[test]
+            fn upgrade_takes_precedence_over_replaced_bytecode() {
+                let contract = declare("UpgradeableV1").unwrap().contract_class();
+                let v1_class = *contract.class_hash;
+                let v2_class = *declare("UpgradeableV2").unwrap().contract_class().class_hash;
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IUpgradeableDispatcher { contract_address };
+
+                replace_bytecode(contract_address, v2_class).unwrap();
+                assert(dispatcher.get_version() == 2, 'Bytecode not replaced');
+
+                dispatcher.upgrade(v1_class);
+
+                assert(dispatcher.get_version() == 1, 'Not upgraded');
+            }
+
+            #[test]
+            #[feature("safe_dispatcher")]
+            fn reverted_upgrade_is_dropped() {
+                let contract = declare("UpgradeableV1").unwrap().contract_class();
+                let v1_class = *contract.class_hash;
+                let v2_class = *declare("UpgradeableV2").unwrap().contract_class().class_hash;
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IUpgradeableDispatcher { contract_address };
+                let safe_dispatcher = IUpgradeableSafeDispatcher { contract_address };
+
+                replace_bytecode(contract_address, v2_class).unwrap();
+                let mut spy = spy_class_replacements();
+
+                assert(safe_dispatcher.upgrade_and_panic(v1_class).is_err(), 'Upgrade not reverted');
+
+                assert(dispatcher.get_version() == 2, 'Replaced bytecode not restored');
+                assert(spy.get_class_replacements() == array![], 'Reverted replacement spied');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::UpgradeableV1",
+            Path::new("tests/data/contracts/upgradeable.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "contract::UpgradeableV2",
+            Path::new("tests/data/contracts/upgradeable.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
@@ -0,0 +1,339 @@
+use crate::utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use crate::utils::running_tests::run_test_case;
+use crate::utils::test_case;
+use axum::response::{IntoResponse, Redirect, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::{formatdoc, indoc};
+use serde_json::{Value, json};
+use shared::test_utils::node_url::node_rpc_url;
+use starknet_api::abi::abi_utils::selector_from_name;
+use starknet_rust::core::types::BlockId;
+use starknet_rust::providers::jsonrpc::HttpTransport;
+use starknet_rust::providers::{JsonRpcClient, Provider};
+use starknet_types_core::felt::Felt;
+use std::path::Path;
+use std::sync::{Arc, LazyLock};
+use tokio::runtime::{Builder, Runtime};
+
+/// Block the replayed transactions are included in, the tests are forked at the block preceding it
+const REPLAYED_BLOCK_NUMBER: u64 = 7_776_133;
+const INVOKE_TX_HASH: &str = "0x04cba686fa76bfa4b4ac788bf2ca9bfac3dd354561f2621c2ac7cf17fa46f75a";
+/// Invoke transaction included in an earlier block, sent by a different account
+const OTHER_INVOKE_TX_HASH: &str =
+    "0x044eee159a2983e017adfe9dc84908b9f6ba0e938c9e30c0ed613fe72cf5dea3";
+/// L1 handler transaction served only by the [`start_replay_node`]
+const L1_HANDLER_TX_HASH: &str = "0x1234";
+/// Address the tests deploy the contract handling the [`L1_HANDLER_TX_HASH`] transaction at
+const L1_HANDLER_CONTRACT_ADDRESS: &str = "0x5678";
+
+/// Node serving the [`REPLAYED_BLOCK_NUMBER`] block with `transactions` and the transaction with
+/// [`L1_HANDLER_TX_HASH`], all the other requests are redirected to the Sepolia node.
+fn start_replay_node(transactions: &[&str]) -> String {
+    static RUNTIME: LazyLock<Runtime> =
+        LazyLock::new(|| Builder::new_multi_thread().enable_all().build().unwrap());
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let transactions: Arc<Vec<String>> =
+        Arc::new(transactions.iter().map(ToString::to_string).collect());
+    RUNTIME.spawn(async move {
+        let app = Router::new().route(
+            "/",
+            post(move |Json(request): Json<Value>| {
+                handle_replay_node_request(request, transactions.clone())
+            }),
+        );
+        let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    url
+}
+
+async fn handle_replay_node_request(request: Value, transactions: Arc<Vec<String>>) -> Response {
+    let result = match request["method"].as_str() {
+        Some("starknet_getBlockWithTxHashes")
+            if request_param(&request, 0, "block_id")["block_number"]
+                == json!(REPLAYED_BLOCK_NUMBER) =>
+        {
+            let block = JsonRpcClient::new(HttpTransport::new(node_rpc_url()))
+                .get_block_with_tx_hashes(BlockId::Number(REPLAYED_BLOCK_NUMBER))
+                .await
+                .unwrap();
+            let mut block = serde_json::to_value(block).unwrap();
+            block["transactions"] = json!(transactions.as_slice());
+            block
+        }
+        Some("starknet_getTransactionByHash")
+            if request_param(&request, 0, "transaction_hash")
+                .as_str()
+                .and_then(|hash| Felt::from_hex(hash).ok())
+                == Felt::from_hex(L1_HANDLER_TX_HASH).ok() =>
+        {
+            json!({
+                "type": "L1_HANDLER",
+                "transaction_hash": L1_HANDLER_TX_HASH,
+                "version": "0x0",
+                "nonce": "0x0",
+                "contract_address": L1_HANDLER_CONTRACT_ADDRESS,
+                "entry_point_selector": selector_from_name("process_l1_message").0.to_hex_string(),
+                // L1 sender, followed by the balance and the token id
+                "calldata": ["0x123", "0x2a", "0x22b8", "0x0"],
+            })
+        }
+        _ => return Redirect::temporary(node_rpc_url().as_str()).into_response(),
+    };
+
+    Json(json!({ "jsonrpc": "2.0", "id": request["id"], "result": result })).into_response()
+}
+
+/// Parameters of requests may be passed either by position or by name.
+fn request_param<'a>(request: &'a Value, position: usize, name: &str) -> &'a Value {
+    match &request["params"] {
+        params @ Value::Array(_) => &params[position],
+        params => &params[name],
+    }
+}
+
+fn l1_handler_contract() -> Contract {
+    Contract::from_code_path(
+        "contract::l1_handler_executor".to_string(),
+        Path::new("tests/data/contracts/l1_handler_execute_checker.cairo"),
+    )
+    .unwrap()
+}
+
+#[test]
+fn replay_transaction_without_fork() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::replay_transaction;
+
+            #[test]
+            fn replay_transaction_without_fork() {
+                replay_transaction(0x123, Default::default()).unwrap();
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "replay_transaction_without_fork",
+        "Replaying a transaction is only possible in a forked test",
+    );
+}
+
+#[test]
+fn replay_transaction_not_in_next_block() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use snforge_std::replay_transaction;
+
+            #[test]
+            #[fork(url: "{}", block_number: 54060)]
+            fn replay_transaction_not_in_next_block() {{
+                replay_transaction(0x123, Default::default()).unwrap();
+            }}
+        "#,
+            node_rpc_url()
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "replay_transaction_not_in_next_block",
+        "Transaction 0x123 is not included in block 54061",
+    );
+}
+
+#[test]
+fn replay_invoke_transaction() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use snforge_std::trace::{{CallResult, get_call_trace}};
+            use snforge_std::{{ReplayConfig, replay_transaction}};
+
+            #[test]
+            #[fork(url: "{}", block_number: 7776132)]
+            fn replay_invoke_transaction() {{
+                let config = ReplayConfig {{
+                    replay_preceding_transactions: false, skip_validation: false,
+                }};
+
+                let ret_data = replay_transaction(
+                    0x04cba686fa76bfa4b4ac788bf2ca9bfac3dd354561f2621c2ac7cf17fa46f75a, config,
+                )
+                    .unwrap();
+
+                let trace = get_call_trace();
+                assert_eq!(trace.nested_calls.len(), 2);
+
+                let validate = trace.nested_calls.at(0);
+                let execute = trace.nested_calls.at(1);
+                assert_eq!(*validate.entry_point.entry_point_selector, selector!("__validate__"));
+                assert_eq!(*execute.entry_point.entry_point_selector, selector!("__execute__"));
+                assert_eq!(
+                    *execute.entry_point.contract_address, *validate.entry_point.contract_address,
+                );
+
+                let mut expected_ret_data = array![];
+                expected_ret_data.append_span(ret_data);
+                assert_eq!(execute.result.clone(), CallResult::Success(expected_ret_data));
+            }}
+        "#,
+            node_rpc_url()
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn replay_l1_handler_transaction() {
+    let node_url = start_replay_node(&[L1_HANDLER_TX_HASH]);
+
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use snforge_std::trace::{{EntryPointType, get_call_trace}};
+            use snforge_std::{{ContractClassTrait, DeclareResultTrait, declare, replay_transaction}};
+
+            #[starknet::interface]
+            trait IBalanceToken<TContractState> {{
+                fn get_balance(self: @TContractState) -> felt252;
+            }}
+
+            #[test]
+            #[fork(url: "{node_url}", block_number: 7776132)]
+            fn replay_l1_handler_transaction() {{
+                let contract = declare("l1_handler_executor").unwrap().contract_class();
+                let address: felt252 = {L1_HANDLER_CONTRACT_ADDRESS};
+                let (contract_address, _) = contract
+                    .deploy_at(@array![0x123], address.try_into().unwrap())
+                    .unwrap();
+
+                replay_transaction({L1_HANDLER_TX_HASH}, Default::default()).unwrap();
+
+                let trace = get_call_trace();
+                let l1_handler = trace.nested_calls.at(trace.nested_calls.len() - 1);
+                assert!(l1_handler.entry_point.entry_point_type.clone() == EntryPointType::L1Handler);
+                assert_eq!(*l1_handler.entry_point.contract_address, contract_address);
+
+                assert_eq!(IBalanceTokenDispatcher {{ contract_address }}.get_balance(), 42);
+            }}
+        "#
+        )
+        .as_str(),
+        l1_handler_contract()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn replay_transaction_with_preceding_transactions() {
+    let node_url = start_replay_node(&[L1_HANDLER_TX_HASH, INVOKE_TX_HASH]);
+
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use snforge_std::trace::get_call_trace;
+            use snforge_std::{{ContractClassTrait, DeclareResultTrait, declare, replay_transaction}};
+
+            #[starknet::interface]
+            trait IBalanceToken<TContractState> {{
+                fn get_balance(self: @TContractState) -> felt252;
+            }}
+
+            #[test]
+            #[fork(url: "{node_url}", block_number: 7776132)]
+            fn replay_transaction_with_preceding_transactions() {{
+                let contract = declare("l1_handler_executor").unwrap().contract_class();
+                let address: felt252 = {L1_HANDLER_CONTRACT_ADDRESS};
+                let (contract_address, _) = contract
+                    .deploy_at(@array![0x123], address.try_into().unwrap())
+                    .unwrap();
+
+                replay_transaction({INVOKE_TX_HASH}, Default::default()).unwrap();
+
+                // The preceding L1 handler transaction is executed before the replayed one
+                let trace = get_call_trace();
+                let n_calls = trace.nested_calls.len();
+                let l1_handler = trace.nested_calls.at(n_calls - 3);
+                let validate = trace.nested_calls.at(n_calls - 2);
+                let execute = trace.nested_calls.at(n_calls - 1);
+                assert_eq!(*l1_handler.entry_point.contract_address, contract_address);
+                assert_eq!(*validate.entry_point.entry_point_selector, selector!("__validate__"));
+                assert_eq!(*execute.entry_point.entry_point_selector, selector!("__execute__"));
+
+                assert_eq!(IBalanceTokenDispatcher {{ contract_address }}.get_balance(), 42);
+            }}
+        "#
+        )
+        .as_str(),
+        l1_handler_contract()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn replay_transaction_skips_validation_of_preceding_transactions() {
+    let node_url = start_replay_node(&[OTHER_INVOKE_TX_HASH, INVOKE_TX_HASH]);
+
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use snforge_std::trace::get_call_trace;
+            use snforge_std::{{ReplayConfig, replay_transaction}};
+
+            #[test]
+            #[fork(url: "{node_url}", block_number: 7776132)]
+            fn replay_transaction_skips_validation_of_preceding_transactions() {{
+                let config = ReplayConfig {{
+                    replay_preceding_transactions: true, skip_validation: true,
+                }};
+
+                replay_transaction({INVOKE_TX_HASH}, config).unwrap();
+
+                let trace = get_call_trace();
+                let mut n_executes = 0;
+                for call in trace.nested_calls.span() {{
+                    let selector = *call.entry_point.entry_point_selector;
+                    assert!(selector != selector!("__validate__"), "Validation was not skipped");
+                    if selector == selector!("__execute__") {{
+                        n_executes += 1;
+                    }}
+                }}
+                assert_eq!(n_executes, 2);
+            }}
+        "#
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
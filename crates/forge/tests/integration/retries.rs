@@ -0,0 +1,47 @@
+use crate::utils::runner::{assert_case_output_contains, assert_failed};
+use crate::utils::running_tests::run_test_case;
+use crate::utils::test_case;
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::node_url::node_rpc_url;
+
+#[test]
+fn fork_error_is_retried() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            #[test]
+            #[fork(url: "{}", block_number: 999999999999)]
+            #[retry(2)]
+            fn fork_error_is_retried() {{
+                starknet::get_block_info();
+            }}
+        "#,
+            node_rpc_url()
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "fork_error_is_retried", "Failed after 2 retries");
+}
+
+#[test]
+fn other_failure_is_not_retried() {
+    let test = test_case!(indoc!(
+        r"
+            #[test]
+            #[retry(2)]
+            fn other_failure_is_not_retried() {
+                assert(1 == 2, 'always fails');
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "other_failure_is_not_retried", "always fails");
+}
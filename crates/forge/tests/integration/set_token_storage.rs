@@ -0,0 +1,178 @@
+use crate::utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use crate::utils::running_tests::run_test_case;
+use crate::utils::test_case as utils_test_case;
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+
+#[test]
+fn set_token_balance_and_allowance() {
+    let test = utils_test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, set_token_allowance, set_token_balance, start_cheat_caller_address,
+                ContractClassTrait, DeclareResultTrait,
+            };
+            use starknet::{ContractAddress, syscalls, SyscallResultTrait};
+
+            fn deploy_erc20() -> ContractAddress {
+                let contract = declare("ERC20").unwrap().contract_class();
+                let constructor_calldata = array![
+                    'CustomToken', 'CT', 18, 1_000_000_000, 0, 123,
+                ];
+                let (contract_address, _) = contract.deploy(@constructor_calldata).unwrap();
+                contract_address
+            }
+
+            fn call(token: ContractAddress, selector: felt252, calldata: Array<felt252>) -> Span<felt252> {
+                syscalls::call_contract_syscall(token, selector, calldata.span()).unwrap_syscall()
+            }
+
+            #[test]
+            fn set_balance_of_custom_token() {
+                let token = deploy_erc20();
+                let account: ContractAddress = 0x123.try_into().unwrap();
+
+                set_token_balance(token, account, 10);
+                assert_eq!(call(token, selector!("balance_of"), array![0x123]), array![10, 0].span());
+
+                // Slot is cached for the class, the balance of other accounts is not changed
+                let other: ContractAddress = 0x456.try_into().unwrap();
+                set_token_balance(token, other, 0x100000000000000000000000000000000);
+                assert_eq!(call(token, selector!("balance_of"), array![0x456]), array![0, 1].span());
+                assert_eq!(call(token, selector!("balance_of"), array![0x123]), array![10, 0].span());
+            }
+
+            #[test]
+            fn set_allowance_of_custom_token() {
+                let token = deploy_erc20();
+                let owner: ContractAddress = 0x123.try_into().unwrap();
+                let spender: ContractAddress = 0x456.try_into().unwrap();
+
+                set_token_allowance(token, owner, spender, 100);
+                assert_eq!(
+                    call(token, selector!("allowance"), array![0x123, 0x456]), array![100, 0].span(),
+                );
+
+                start_cheat_caller_address(token, spender);
+                call(token, selector!("transfer_from"), array![0x123, 0x789, 40, 0]);
+                assert_eq!(
+                    call(token, selector!("allowance"), array![0x123, 0x456]), array![60, 0].span(),
+                );
+                assert_eq!(call(token, selector!("balance_of"), array![0x789]), array![40, 0].span());
+            }
+
+            #[test]
+            fn set_balance_of_strk() {
+                let token = snforge_std::Token::STRK.contract_address();
+                let account: ContractAddress = 0x123.try_into().unwrap();
+
+                set_token_balance(token, account, 10);
+                assert_eq!(call(token, selector!("balance_of"), array![0x123]), array![10, 0].span());
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::ERC20",
+            Path::new("tests/data/contracts/erc20.cairo")
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn set_token_owner() {
+    let test = utils_test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, set_token_owner, ContractClassTrait, DeclareResultTrait};
+            use starknet::{ContractAddress, syscalls, SyscallResultTrait};
+
+            #[test]
+            fn set_owner_of_nft() {
+                let contract = declare("ERC721").unwrap().contract_class();
+                let (token, _) = contract.deploy(@array![0x123, 1, 0]).unwrap();
+                let new_owner: ContractAddress = 0x456.try_into().unwrap();
+
+                set_token_owner(token, 1, new_owner);
+
+                let owner = syscalls::call_contract_syscall(
+                    token, selector!("owner_of"), array![1, 0].span(),
+                )
+                    .unwrap_syscall();
+                assert_eq!(owner, array![0x456].span());
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::ERC721",
+            Path::new("tests/data/contracts/erc721.cairo")
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn set_token_balance_of_undeployed_token() {
+    let test = utils_test_case!(indoc!(
+        r"
+            use snforge_std::set_token_balance;
+
+            #[test]
+            fn set_balance_of_undeployed_token() {
+                set_token_balance(0x123.try_into().unwrap(), 0x456.try_into().unwrap(), 10);
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "set_balance_of_undeployed_token",
+        "Token 0x123 is not deployed",
+    );
+}
+
+#[test]
+fn set_token_balance_without_getter() {
+    let test = utils_test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, set_token_balance, ContractClassTrait, DeclareResultTrait};
+
+            #[test]
+            fn set_balance_without_getter() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (token, _) = contract.deploy(@array![]).unwrap();
+
+                set_token_balance(token, 0x456.try_into().unwrap(), 10);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::HelloStarknet",
+            Path::new("tests/data/simple_package/src/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "set_balance_without_getter",
+        "does not implement the getter or it failed",
+    );
+}
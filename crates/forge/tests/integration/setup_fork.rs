@@ -18,6 +18,8 @@ use tokio::runtime::Runtime;
 use crate::utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
 use crate::utils::running_tests::run_test_case;
 use crate::utils::test_case;
+use cheatnet::predeployment::config::PredeploymentConfig;
+use cheatnet::predeployment::configured::PredeployedStateCache;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use forge::run_tests::package::RunForPackageArgs;
 use forge::shared_cache::FailedTestsCache;
@@ -26,7 +28,7 @@ use forge_runner::debugging::TraceArgs;
 use forge_runner::filtering::NameFilter;
 use forge_runner::forge_config::ForgeTrackedResource;
 use forge_runner::forge_config::{
-    ExecutionDataToSave, ForgeConfig, OutputConfig, TestRunnerConfig,
+    ExecutionDataToSave, ForgeConfig, OutputConfig, RetryOn, TestRunnerConfig,
 };
 use forge_runner::running::target::prepare_test_target;
 use forge_runner::scarb::load_test_artifacts;
@@ -154,10 +156,14 @@ fn fork_aliased_decorator() {
                     target_handles,
                     package_name: "test_package".to_string(),
                     package_root: Utf8PathBuf::default(),
+                    prioritized_tests: vec![],
+                    impact_selection: None,
                     tests_filter: TestsFilter::from_flags(
                         None,
                         false,
                         Vec::new(),
+                        None,
+                        None,
                         false,
                         false,
                         false,
@@ -170,7 +176,11 @@ fn fork_aliased_decorator() {
                             deterministic_output: false,
                             fuzzer_runs: NonZeroU32::new(256).unwrap(),
                             fuzzer_seed: 12345,
+                            seed: None,
                             max_n_steps: None,
+                            timeout: None,
+                            retries: 0,
+                            retry_on: RetryOn::ForkErrors,
                             is_vm_trace_needed: false,
                             cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().keep())
                                 .unwrap()
@@ -183,6 +193,8 @@ fn fork_aliased_decorator() {
                             tracked_resource: ForgeTrackedResource::CairoSteps,
                             environment_variables: test.env().clone(),
                             launch_debugger: false,
+                            predeployment: PredeploymentConfig::default(),
+                            predeployed_state: PredeployedStateCache::default(),
                         }),
                         output_config: Arc::new(OutputConfig {
                             detailed_resources: false,
@@ -269,10 +281,14 @@ fn fork_aliased_decorator_overriding() {
                     target_handles,
                     package_name: "test_package".to_string(),
                     package_root: Utf8PathBuf::default(),
+                    prioritized_tests: vec![],
+                    impact_selection: None,
                     tests_filter: TestsFilter::from_flags(
                         None,
                         false,
                         Vec::new(),
+                        None,
+                        None,
                         false,
                         false,
                         false,
@@ -285,7 +301,11 @@ fn fork_aliased_decorator_overriding() {
                             deterministic_output: false,
                             fuzzer_runs: NonZeroU32::new(256).unwrap(),
                             fuzzer_seed: 12345,
+                            seed: None,
                             max_n_steps: None,
+                            timeout: None,
+                            retries: 0,
+                            retry_on: RetryOn::ForkErrors,
                             is_vm_trace_needed: false,
                             cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().keep())
                                 .unwrap()
@@ -298,6 +318,8 @@ fn fork_aliased_decorator_overriding() {
                             tracked_resource: ForgeTrackedResource::CairoSteps,
                             environment_variables: test.env().clone(),
                             launch_debugger: false,
+                            predeployment: PredeploymentConfig::default(),
+                            predeployed_state: PredeployedStateCache::default(),
                         }),
                         output_config: Arc::new(OutputConfig {
                             detailed_resources: false,
@@ -1,7 +1,7 @@
 use forge_runner::forge_config::ForgeTrackedResource;
 use std::path::Path;
 
-use crate::utils::runner::{Contract, assert_passed};
+use crate::utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
 use crate::utils::running_tests::run_test_case;
 use crate::utils::test_case;
 use indoc::indoc;
@@ -79,3 +79,122 @@ fn should_panic_unknown_entry_point() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn should_panic_structured_expectations() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn do_a_panic(self: @TContractState);
+            }
+
+            #[derive(Drop, Serde)]
+            enum MyError {
+                NotOwner,
+                InsufficientBalance: u256,
+            }
+
+            fn panic_with_error(error: MyError) {
+                let mut data = array![];
+                error.serialize(ref data);
+                panic(data);
+            }
+
+            #[test]
+            #[should_panic(expected: error(MyError::InsufficientBalance(100)))]
+            fn should_panic_with_error() {
+                panic_with_error(MyError::InsufficientBalance(100));
+            }
+
+            #[test]
+            #[should_panic(expected: variant(MyError::InsufficientBalance(0)))]
+            fn should_panic_with_variant() {
+                panic_with_error(MyError::InsufficientBalance(100));
+            }
+
+            #[test]
+            #[should_panic(expected: regex("^Insufficient balance: \\d+$"))]
+            fn should_panic_with_regex() {
+                panic!("Insufficient balance: {}", 100);
+            }
+
+            #[test]
+            #[should_panic(expected: one_of('first', 'second'))]
+            fn should_panic_with_one_of() {
+                panic_with_felt252('second');
+            }
+
+            #[test]
+            #[should_panic(
+                expected: ('PANIC', 'DAYTAH'), contract: "HelloStarknet", function: "do_a_panic",
+            )]
+            fn should_panic_with_origin() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                IHelloStarknetDispatcher { contract_address }.do_a_panic();
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn should_panic_unexpected_origin() {
+    let test = test_case!(indoc!(
+        r#"
+            #[test]
+            #[should_panic(expected: 'panic message', contract: "HelloStarknet")]
+            fn should_panic_in_contract() {
+                panic_with_felt252('panic message');
+            }
+
+            #[test]
+            #[should_panic(expected: one_of('first', regex("^sec")))]
+            fn should_panic_with_one_of() {
+                panic_with_felt252('third');
+            }
+
+            #[test]
+            #[should_panic(expected: variant(Option::<felt252>::Some(0)))]
+            fn should_panic_with_variant() {
+                panic(array![0]);
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "should_panic_in_contract",
+        indoc! {"
+            Panic originated in an unexpected place
+            Actual:    test body
+            Expected:  contract `HelloStarknet`
+        "},
+    );
+    assert_case_output_contains(
+        &result,
+        "should_panic_with_one_of",
+        "Expected:  one of: felt252 0x6669727374 ('first') | string matching regex `^sec`",
+    );
+    assert_case_output_contains(
+        &result,
+        "should_panic_with_variant",
+        "Expected:  enum variant with index 0 serialized to 2 felts",
+    );
+}
@@ -0,0 +1,126 @@
+use crate::utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use crate::utils::running_tests::run_test_case;
+use crate::utils::test_case;
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+
+#[test]
+fn storage_accesses() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, get_storage_accesses,
+                record_storage_accesses, StorageAccessesTrait,
+            };
+            use starknet::ContractAddress;
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            fn deploy_hello_starknet() -> IHelloStarknetDispatcher {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                IHelloStarknetDispatcher { contract_address }
+            }
+
+            #[test]
+            fn records_reads_and_writes() {
+                let hello_starknet = deploy_hello_starknet();
+
+                record_storage_accesses();
+                hello_starknet.increase_balance(5);
+
+                let accesses = get_storage_accesses(hello_starknet.contract_address);
+                assert_eq!(accesses.reads.len(), 1);
+                assert_eq!(*accesses.reads[0].key, selector!("balance"));
+                assert_eq!(*accesses.reads[0].value, 0);
+                assert_eq!(accesses.writes.len(), 1);
+                assert_eq!(*accesses.writes[0].key, selector!("balance"));
+                assert_eq!(*accesses.writes[0].value, 5);
+
+                assert!(accesses.was_read(selector!("balance")));
+                assert!(accesses.was_written(selector!("balance")));
+                assert!(!accesses.was_written(selector!("other")));
+            }
+
+            #[test]
+            fn view_call_does_not_write() {
+                let hello_starknet = deploy_hello_starknet();
+                hello_starknet.increase_balance(5);
+
+                record_storage_accesses();
+                hello_starknet.get_balance();
+
+                let accesses = get_storage_accesses(hello_starknet.contract_address);
+                assert_eq!(accesses.reads.len(), 1);
+                assert_eq!(*accesses.reads[0].value, 5);
+                assert!(accesses.writes.is_empty());
+            }
+
+            #[test]
+            fn accesses_are_filtered_by_contract() {
+                let first = deploy_hello_starknet();
+                let second = deploy_hello_starknet();
+
+                record_storage_accesses();
+                first.increase_balance(1);
+
+                let accesses = get_storage_accesses(second.contract_address);
+                assert!(accesses.reads.is_empty());
+                assert!(accesses.writes.is_empty());
+            }
+
+            #[test]
+            fn recording_restarts() {
+                let hello_starknet = deploy_hello_starknet();
+
+                record_storage_accesses();
+                hello_starknet.increase_balance(1);
+                record_storage_accesses();
+                hello_starknet.increase_balance(2);
+
+                let accesses = get_storage_accesses(hello_starknet.contract_address);
+                assert_eq!(accesses.writes.len(), 1);
+                assert_eq!(*accesses.writes[0].value, 3);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::HelloStarknet",
+            Path::new("tests/data/simple_package/src/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn storage_accesses_not_recorded() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::{get_storage_accesses, test_address};
+
+            #[test]
+            fn not_recorded() {
+                get_storage_accesses(test_address());
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "not_recorded",
+        "Storage accesses are not recorded, use `record_storage_accesses` first",
+    );
+}
@@ -0,0 +1,180 @@
+use crate::utils::runner::{Contract, assert_passed};
+use crate::utils::running_tests::run_test_case;
+use crate::utils::test_case;
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+
+#[test]
+fn submit_account_transactions() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use core::num::traits::Zero;
+            use starknet::{ContractAddress, SyscallResultTrait};
+            use starknet::account::Call;
+            use starknet::syscalls::call_contract_syscall;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, set_balance, Token, TokenTrait,
+                submit_transaction, AccountTransaction, DeployAccountTransaction,
+                DeployAccountTransactionTrait, InvokeTransaction, DeclareTransaction,
+                TransactionParams, TransactionReceipt,
+            };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            const SECRET: felt252 = 'secret';
+
+            fn signed() -> TransactionParams {
+                TransactionParams { signature: array![SECRET], ..Default::default() }
+            }
+
+            fn strk_balance(account: ContractAddress) -> u256 {
+                let mut balance = call_contract_syscall(
+                    Token::STRK.contract_address(), selector!("balance_of"), array![account.into()].span(),
+                )
+                    .unwrap_syscall();
+                Serde::deserialize(ref balance).unwrap()
+            }
+
+            fn deploy_account() -> ContractAddress {
+                let class_hash = *declare("SimpleAccount").unwrap().contract_class().class_hash;
+                let transaction = DeployAccountTransaction {
+                    class_hash, contract_address_salt: 123, constructor_calldata: array![SECRET],
+                };
+                let account = transaction.precalculate_address();
+                set_balance(account, 10_000_000_000_000_000_000_000, Token::STRK);
+
+                let receipt = submit_transaction(
+                    AccountTransaction::DeployAccount(transaction), signed(),
+                )
+                    .unwrap();
+                assert(receipt.revert_reason.is_none(), 'Deployment reverted');
+                assert(receipt.actual_fee > 0, 'Fee not charged');
+
+                account
+            }
+
+            fn invoke(account: ContractAddress, call: Call) -> Result<TransactionReceipt, ByteArray> {
+                let mut calldata = array![];
+                array![call].serialize(ref calldata);
+
+                submit_transaction(
+                    AccountTransaction::Invoke(
+                        InvokeTransaction {
+                            sender_address: account, calldata, account_deployment_data: array![],
+                        },
+                    ),
+                    signed(),
+                )
+            }
+
+            #[test]
+            fn invoke_transaction() {
+                let account = deploy_account();
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+
+                let balance_before = strk_balance(account);
+                let call = Call {
+                    to: contract_address,
+                    selector: selector!("increase_balance"),
+                    calldata: array![5].span(),
+                };
+                let receipt = invoke(account, call).unwrap();
+
+                assert(receipt.revert_reason.is_none(), 'Invoke reverted');
+                assert(receipt.actual_fee > 0, 'Fee not charged');
+                assert(
+                    strk_balance(account) == balance_before - receipt.actual_fee.into(),
+                    'Incorrect balance after fee',
+                );
+                assert(
+                    IHelloStarknetDispatcher { contract_address }.get_balance() == 5,
+                    'Call not executed',
+                );
+            }
+
+            #[test]
+            fn reverted_transaction() {
+                let account = deploy_account();
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+
+                let call = Call {
+                    to: contract_address, selector: selector!("do_a_panic"), calldata: array![].span(),
+                };
+                let receipt = invoke(account, call).unwrap();
+
+                assert(receipt.revert_reason.is_some(), 'Invoke should revert');
+                assert(receipt.actual_fee > 0, 'Fee not charged');
+            }
+
+            #[test]
+            fn rejected_transaction() {
+                let account = deploy_account();
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+
+                let mut calldata = array![];
+                array![
+                    Call {
+                        to: contract_address,
+                        selector: selector!("increase_balance"),
+                        calldata: array![5].span(),
+                    },
+                ]
+                    .serialize(ref calldata);
+                let result = submit_transaction(
+                    AccountTransaction::Invoke(
+                        InvokeTransaction {
+                            sender_address: account, calldata, account_deployment_data: array![],
+                        },
+                    ),
+                    TransactionParams { signature: array!['wrong'], ..Default::default() },
+                );
+
+                assert(result.is_err(), 'Transaction should be rejected');
+                assert(
+                    IHelloStarknetDispatcher { contract_address }.get_balance().is_zero(),
+                    'Call should not be executed',
+                );
+            }
+
+            #[test]
+            fn declare_transaction() {
+                let account = deploy_account();
+
+                let receipt = submit_transaction(
+                    AccountTransaction::Declare(
+                        DeclareTransaction { sender_address: account, contract_name: "HelloStarknet" },
+                    ),
+                    signed(),
+                )
+                    .unwrap();
+
+                assert(receipt.revert_reason.is_none(), 'Declare reverted');
+                assert(receipt.actual_fee > 0, 'Fee not charged');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "contract::SimpleAccount",
+            Path::new("tests/data/contracts/simple_account.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "contract::HelloStarknet",
+            Path::new("tests/data/simple_package/src/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
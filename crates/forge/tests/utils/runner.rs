@@ -325,6 +325,37 @@ pub fn assert_case_output_contains(
     );
 }
 
+pub fn assert_case_failure_contains(
+    result: &[TestTargetSummary],
+    test_case_name: &str,
+    failure: fn(&AnyTestCaseSummary) -> Option<&str>,
+    asserted_msg: &str,
+) {
+    let test_name_suffix = format!("::{test_case_name}");
+
+    let result = TestCase::find_test_result(result);
+
+    let any_case = result
+        .test_case_summaries
+        .iter()
+        .find(|any_case| {
+            any_case
+                .name()
+                .is_some_and(|name| name.ends_with(test_name_suffix.as_str()))
+        })
+        .unwrap_or_else(|| {
+            panic!("Output assertion failed: test case `{test_case_name}` was not found")
+        });
+
+    let actual_failure = failure(any_case).unwrap_or_default();
+    let name = matched_test_case_name(any_case);
+
+    assert!(
+        actual_failure.contains(asserted_msg),
+        "Failure assertion failed for test case `{name}`.\nexpected failure to contain: {asserted_msg}\nactual:                      {actual_failure}"
+    );
+}
+
 pub fn assert_gas(result: &[TestTargetSummary], test_case_name: &str, asserted_gas: GasVector) {
     let test_name_suffix = format!("::{test_case_name}");
 
@@ -674,6 +705,8 @@ mod tests {
             debugging_trace: None,
             fuzzer_args: Vec::new(),
             test_statistics: (),
+            after_each_failure: None,
+            fixture_failure: None,
         })
     }
 
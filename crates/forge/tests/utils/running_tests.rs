@@ -1,5 +1,7 @@
 use crate::utils::runner::TestCase;
 use camino::Utf8PathBuf;
+use cheatnet::predeployment::config::PredeploymentConfig;
+use cheatnet::predeployment::configured::PredeployedStateCache;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use forge::shared_cache::FailedTestsCache;
 use forge::{
@@ -12,7 +14,7 @@ use forge_runner::DEFAULT_CACHE_DIR;
 use forge_runner::debugging::TraceArgs;
 use forge_runner::filtering::NameFilter;
 use forge_runner::forge_config::{
-    ExecutionDataToSave, ForgeConfig, ForgeTrackedResource, OutputConfig, TestRunnerConfig,
+    ExecutionDataToSave, ForgeConfig, ForgeTrackedResource, OutputConfig, RetryOn, TestRunnerConfig,
 };
 use forge_runner::partition::PartitionConfig;
 use forge_runner::running::target::prepare_test_target;
@@ -70,10 +72,14 @@ pub fn run_test_case(
                 target_handles,
                 package_name: "test_package".to_string(),
                 package_root: Utf8PathBuf::default(),
+                prioritized_tests: vec![],
+                impact_selection: None,
                 tests_filter: TestsFilter::from_flags(
                     None,
                     false,
                     Vec::new(),
+                    None,
+                    None,
                     false,
                     false,
                     false,
@@ -86,7 +92,11 @@ pub fn run_test_case(
                         deterministic_output: false,
                         fuzzer_runs: NonZeroU32::new(256).unwrap(),
                         fuzzer_seed: 12345,
+                        seed: None,
                         max_n_steps: None,
+                        timeout: None,
+                        retries: 0,
+                        retry_on: RetryOn::ForkErrors,
                         is_vm_trace_needed: false,
                         cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().keep())
                             .unwrap()
@@ -99,6 +109,8 @@ pub fn run_test_case(
                         tracked_resource,
                         environment_variables: test.env().clone(),
                         launch_debugger: false,
+                        predeployment: PredeploymentConfig::default(),
+                        predeployed_state: PredeployedStateCache::default(),
                     }),
                     output_config: Arc::new(OutputConfig {
                         detailed_resources: false,
@@ -1,3 +1,4 @@
+use crate::starknet::deadline::Deadline;
 use crate::vm::{cell_ref_to_relocatable, extract_relocatable, get_val, vm_get_range};
 use anyhow::Result;
 use blockifier::execution::errors::EntryPointExecutionError;
@@ -57,9 +58,15 @@ pub trait SyscallPtrAccess {
     fn get_mut_syscall_ptr(&mut self) -> &mut Relocatable;
 }
 
+pub trait DeadlineAccess {
+    fn deadline(&self) -> Option<&Deadline>;
+}
+
 pub struct StarknetRuntime<'a> {
     pub hint_handler: SyscallHintProcessor<'a>,
     pub panic_traceback: Option<Vec<usize>>,
+    /// Wall-clock limit of the execution, it is stopped like after running out of steps
+    pub deadline: Option<Deadline>,
 }
 
 impl SyscallPtrAccess for StarknetRuntime<'_> {
@@ -68,12 +75,22 @@ impl SyscallPtrAccess for StarknetRuntime<'_> {
     }
 }
 
+impl DeadlineAccess for StarknetRuntime<'_> {
+    fn deadline(&self) -> Option<&Deadline> {
+        self.deadline.as_ref()
+    }
+}
+
 impl ResourceTracker for StarknetRuntime<'_> {
     fn consumed(&self) -> bool {
-        self.hint_handler.base.context.vm_run_resources.consumed()
+        self.deadline.as_ref().is_some_and(Deadline::is_reached)
+            || self.hint_handler.base.context.vm_run_resources.consumed()
     }
 
     fn consume_step(&mut self) {
+        if let Some(deadline) = &mut self.deadline {
+            deadline.consume_step();
+        }
         self.hint_handler
             .base
             .context
@@ -211,6 +228,14 @@ impl<Extension: ExtensionLogic> HintProcessorLogic for ExtendedRuntime<Extension
     ) -> Result<(), HintError> {
         let maybe_extended_hint = hint_data.downcast_ref::<Hint>();
 
+        // Cheatcodes and syscalls may take long without executing any steps,
+        // e.g. when they wait for the fork node, so the deadline is checked before each of them
+        if let Some(Hint::Starknet(_)) = maybe_extended_hint
+            && self.deadline().is_some_and(Deadline::check)
+        {
+            return Err(CustomHint(Box::from("Test timed out")));
+        }
+
         match maybe_extended_hint {
             Some(Hint::Starknet(starknet_hint)) => match starknet_hint {
                 StarknetHint::Cheatcode {
@@ -376,6 +401,12 @@ impl<Extension: ExtensionLogic> SyscallPtrAccess for ExtendedRuntime<Extension>
     }
 }
 
+impl<Extension: ExtensionLogic> DeadlineAccess for ExtendedRuntime<Extension> {
+    fn deadline(&self) -> Option<&Deadline> {
+        self.extended_runtime.deadline()
+    }
+}
+
 impl<Extension: ExtensionLogic> ResourceTracker for ExtendedRuntime<Extension> {
     fn consumed(&self) -> bool {
         self.extended_runtime.consumed()
@@ -413,7 +444,7 @@ impl CheatcodeHandlingResult {
 }
 
 pub trait ExtensionLogic {
-    type Runtime: HintProcessor + SyscallPtrAccess + SignalPropagator;
+    type Runtime: HintProcessor + SyscallPtrAccess + DeadlineAccess + SignalPropagator;
 
     fn override_system_call(
         &mut self,
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Number of VM steps between consecutive reads of the clock, reading it on every step is too slow.
+const STEPS_BETWEEN_CHECKS: u32 = 10_000;
+
+/// Wall-clock limit of a test execution, checked while executing VM steps, at cheatcode and syscall
+/// boundaries and before requests to the fork node.
+///
+/// Clones share the information whether the deadline was reached, so once it is noticed in one of
+/// the nested calls or in the fork client, all runtimes executing the test stop.
+#[derive(Debug, Clone)]
+pub struct Deadline {
+    at: Instant,
+    timeout: Duration,
+    reached: Arc<AtomicBool>,
+    steps_until_check: u32,
+}
+
+impl Deadline {
+    #[must_use]
+    pub fn after(timeout: Duration) -> Self {
+        Self {
+            at: Instant::now() + timeout,
+            timeout,
+            reached: Arc::new(AtomicBool::new(false)),
+            steps_until_check: STEPS_BETWEEN_CHECKS,
+        }
+    }
+
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Whether the deadline was noticed before, does not read the clock.
+    #[must_use]
+    pub fn is_reached(&self) -> bool {
+        self.reached.load(Ordering::Relaxed)
+    }
+
+    /// Time left until the deadline, `None` if it passed.
+    /// Reads the clock and marks the deadline as reached once it passed.
+    #[must_use]
+    pub fn remaining(&self) -> Option<Duration> {
+        let remaining = self
+            .at
+            .checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero());
+        if remaining.is_none() {
+            self.mark_reached();
+        }
+        remaining
+    }
+
+    /// Whether the deadline passed, reads the clock unlike `is_reached`.
+    #[must_use]
+    pub fn check(&self) -> bool {
+        self.remaining().is_none()
+    }
+
+    /// Marks the deadline as reached, e.g. after waiting for something for all the remaining time.
+    pub fn mark_reached(&self) {
+        self.reached.store(true, Ordering::Relaxed);
+    }
+
+    pub fn consume_step(&mut self) {
+        self.steps_until_check -= 1;
+        if self.steps_until_check == 0 {
+            self.steps_until_check = STEPS_BETWEEN_CHECKS;
+            if Instant::now() >= self.at {
+                self.mark_reached();
+            }
+        }
+    }
+}
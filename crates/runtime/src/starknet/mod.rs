@@ -1,3 +1,4 @@
 pub mod constants;
 pub mod context;
+pub mod deadline;
 pub mod state;
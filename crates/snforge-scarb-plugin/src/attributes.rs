@@ -4,13 +4,22 @@ use cairo_lang_parser::utils::SimpleParserDatabase;
 
 pub mod available_gas;
 pub mod disable_predeployed_contracts;
+pub mod fixture;
 pub mod fork;
 pub mod fuzzer;
+pub mod hooks;
 pub mod ignore;
 pub mod internal_config_statement;
+pub mod max_steps;
+pub mod retry;
+pub mod seed;
+pub mod setup;
 pub mod should_panic;
+pub mod tags;
 pub mod test;
 pub mod test_case;
+pub mod test_cases_from_file;
+pub mod timeout;
 
 pub trait AttributeInfo {
     const ATTR_NAME: &'static str;
@@ -0,0 +1,153 @@
+use super::{AttributeInfo, ErrorExt};
+use crate::asserts::assert_is_used_once;
+use crate::common::{has_hook_attribute, has_parameters, has_test_attribute};
+use crate::utils::{create_single_token, get_statements};
+use crate::{
+    args::Arguments,
+    common::{into_proc_macro_result, with_parsed_values},
+    format_ident,
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream, TokenTree, quote};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+use cairo_lang_syntax::node::with_db::SyntaxNodeWithDb;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode, ast::FunctionWithBody};
+use xxhash_rust::xxh3::xxh3_64;
+
+pub struct FixtureCollector;
+
+impl AttributeInfo for FixtureCollector {
+    const ATTR_NAME: &'static str = "fixture";
+}
+
+#[must_use]
+pub fn fixture(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    into_proc_macro_result(args, item, |args, item, warns| {
+        let definition_site = definition_site(item);
+
+        with_parsed_values::<FixtureCollector>(
+            args,
+            item,
+            warns,
+            |db, func, args_db, args, warns| {
+                fixture_internal(db, func, args_db, args, warns, definition_site.as_deref())
+            },
+        )
+    })
+}
+
+/// File and offset the fixture is defined at, `None` if the item does not come from a file.
+fn definition_site(item: &TokenStream) -> Option<String> {
+    let file = item.metadata().original_file_path.as_deref()?;
+    let offset = item.tokens.first().map_or(0, |token| match token {
+        TokenTree::Ident(token) => token.span.start,
+    });
+
+    Some(format!("{file}:{offset}"))
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn fixture_internal(
+    db: &SimpleParserDatabase,
+    func: &FunctionWithBody,
+    _args_db: &SimpleParserDatabase,
+    args: Arguments,
+    _warns: &mut Vec<Diagnostic>,
+    definition_site: Option<&str>,
+) -> Result<TokenStream, Diagnostics> {
+    assert_is_used_once::<FixtureCollector>(db, func)?;
+    args.assert_is_empty::<FixtureCollector>()?;
+
+    if has_test_attribute(db, func) {
+        Err(FixtureCollector::error(
+            "cannot be used together with #[test]",
+        ))?;
+    }
+    if has_hook_attribute(db, func) {
+        Err(FixtureCollector::error(
+            "cannot be used together with #[before_each] or #[after_each]",
+        ))?;
+    }
+    if has_parameters(db, func) {
+        Err(FixtureCollector::error(
+            "function with parameters cannot be a fixture",
+        ))?;
+    }
+
+    let name = func.declaration(db).name(db).text(db).to_string();
+
+    let fixture_func = TokenStream::new(vec![format_ident!(
+        "{}__snforge_internal_fixture_generated",
+        name
+    )]);
+    let called_func = TokenStream::new(vec![format_ident!("{name}")]);
+    let fixture_key =
+        create_single_token(format!("\"{}\"", fixture_key(db, func, definition_site)));
+    let out_of_gas = create_single_token("'Out of gas'");
+    let invalid_result = create_single_token("'Invalid fixture result'");
+
+    let vis = func.visibility(db).as_syntax_node();
+    let vis = SyntaxNodeWithDb::new(&vis, db);
+
+    let attributes = func.attributes(db).as_syntax_node();
+    let attributes = SyntaxNodeWithDb::new(&attributes, db);
+
+    let declaration = func.declaration(db).as_syntax_node();
+    let declaration = SyntaxNodeWithDb::new(&declaration, db);
+
+    let (statements, _) = get_statements(db, func);
+
+    // The fixture is run once by the wrapper, which returns its serialized result.
+    // Tests set up with the fixture get the cached result instead of running the body again.
+    Ok(quote!(
+        #[implicit_precedence(core::pedersen::Pedersen, core::RangeCheck, core::integer::Bitwise, core::ec::EcOp, core::poseidon::Poseidon, core::SegmentArena, core::circuit::RangeCheck96, core::circuit::AddMod, core::circuit::MulMod, core::gas::GasBuiltin, System)]
+        #[snforge_internal_fixture_executable]
+        fn #fixture_func(mut _data: Span<felt252>) -> Span::<felt252> {
+            core::internal::require_implicit::<System>();
+            core::internal::revoke_ap_tracking();
+            core::option::OptionTraitImpl::expect(core::gas::withdraw_gas(), #out_of_gas);
+
+            core::option::OptionTraitImpl::expect(
+                core::gas::withdraw_gas_all(core::gas::get_builtin_costs()), #out_of_gas
+            );
+            let result = #called_func();
+
+            let mut arr = ArrayTrait::new();
+            core::serde::Serde::serialize(@result, ref arr);
+            core::array::ArrayTrait::span(@arr)
+        }
+
+        #attributes
+        #vis #declaration {
+            if let Option::Some(mut result) = snforge_std::_internals::fixture_result(#fixture_key) {
+                return core::option::OptionTraitImpl::expect(
+                    core::serde::Serde::deserialize(ref result), #invalid_result
+                );
+            };
+
+            #statements
+        }
+    ))
+}
+
+/// Identifies the fixture when its cached result is requested.
+/// The module path is not known when the macro is expanded, so the key is the hash of the place
+/// the fixture is defined at and its code. Every `#[fixture]` function has its own key,
+/// even if the same code is defined in several modules, where imports may resolve differently.
+/// Only the code is hashed for items that do not come from a file.
+fn fixture_key(
+    db: &SimpleParserDatabase,
+    func: &FunctionWithBody,
+    definition_site: Option<&str>,
+) -> String {
+    let code: String = func
+        .as_syntax_node()
+        .get_text(db)
+        .split_whitespace()
+        .collect();
+    let keyed = match definition_site {
+        Some(definition_site) => format!("{definition_site}\n{code}"),
+        None => code,
+    };
+
+    format!("{:016x}", xxh3_64(keyed.as_bytes()))
+}
@@ -0,0 +1,118 @@
+use super::{AttributeInfo, ErrorExt};
+use crate::asserts::assert_is_used_once;
+use crate::common::{has_fixture_attribute, has_parameters, has_test_attribute};
+use crate::utils::create_single_token;
+use crate::{
+    args::Arguments,
+    common::{into_proc_macro_result, with_parsed_values},
+    format_ident,
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream, quote};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+use cairo_lang_syntax::node::with_db::SyntaxNodeWithDb;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode, ast::FunctionWithBody};
+
+pub struct BeforeEachCollector;
+
+impl AttributeInfo for BeforeEachCollector {
+    const ATTR_NAME: &'static str = "before_each";
+}
+
+pub struct AfterEachCollector;
+
+impl AttributeInfo for AfterEachCollector {
+    const ATTR_NAME: &'static str = "after_each";
+}
+
+/// Hook attribute marking the function with its own executable, by which the runner finds it
+trait HookInfo: AttributeInfo {
+    const EXECUTABLE_NAME: &'static str;
+}
+
+impl HookInfo for BeforeEachCollector {
+    const EXECUTABLE_NAME: &'static str = "snforge_internal_before_each_executable";
+}
+
+impl HookInfo for AfterEachCollector {
+    const EXECUTABLE_NAME: &'static str = "snforge_internal_after_each_executable";
+}
+
+#[must_use]
+pub fn before_each(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    into_proc_macro_result(args, item, |args, item, warns| {
+        with_parsed_values::<BeforeEachCollector>(
+            args,
+            item,
+            warns,
+            hook_internal::<BeforeEachCollector>,
+        )
+    })
+}
+
+#[must_use]
+pub fn after_each(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    into_proc_macro_result(args, item, |args, item, warns| {
+        with_parsed_values::<AfterEachCollector>(
+            args,
+            item,
+            warns,
+            hook_internal::<AfterEachCollector>,
+        )
+    })
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn hook_internal<Hook: HookInfo>(
+    db: &SimpleParserDatabase,
+    func: &FunctionWithBody,
+    _args_db: &SimpleParserDatabase,
+    args: Arguments,
+    _warns: &mut Vec<Diagnostic>,
+) -> Result<TokenStream, Diagnostics> {
+    assert_is_used_once::<Hook>(db, func)?;
+    args.assert_is_empty::<Hook>()?;
+
+    if has_test_attribute(db, func) {
+        Err(Hook::error("cannot be used together with #[test]"))?;
+    }
+    if has_fixture_attribute(db, func) {
+        Err(Hook::error("cannot be used together with #[fixture]"))?;
+    }
+    if has_parameters(db, func) {
+        Err(Hook::error("function with parameters cannot be a hook"))?;
+    }
+
+    let name = func.declaration(db).name(db).text(db).to_string();
+
+    let hook_func = TokenStream::new(vec![format_ident!(
+        "{}__snforge_internal_hook_generated",
+        name
+    )]);
+    let called_func = TokenStream::new(vec![format_ident!("{name}")]);
+    let executable = create_single_token(Hook::EXECUTABLE_NAME);
+    let out_of_gas = create_single_token("'Out of gas'");
+
+    let func_item = func.as_syntax_node();
+    let func_item = SyntaxNodeWithDb::new(&func_item, db);
+
+    // The runner executes the wrapper around every test of the module, in the state of the test
+    Ok(quote!(
+        #[implicit_precedence(core::pedersen::Pedersen, core::RangeCheck, core::integer::Bitwise, core::ec::EcOp, core::poseidon::Poseidon, core::SegmentArena, core::circuit::RangeCheck96, core::circuit::AddMod, core::circuit::MulMod, core::gas::GasBuiltin, System)]
+        #[#executable]
+        fn #hook_func(mut _data: Span<felt252>) -> Span::<felt252> {
+            core::internal::require_implicit::<System>();
+            core::internal::revoke_ap_tracking();
+            core::option::OptionTraitImpl::expect(core::gas::withdraw_gas(), #out_of_gas);
+
+            core::option::OptionTraitImpl::expect(
+                core::gas::withdraw_gas_all(core::gas::get_builtin_costs()), #out_of_gas
+            );
+            #called_func();
+
+            let mut arr = ArrayTrait::new();
+            core::array::ArrayTrait::span(@arr)
+        }
+
+        #func_item
+    ))
+}
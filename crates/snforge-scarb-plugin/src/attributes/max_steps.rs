@@ -0,0 +1,55 @@
+use super::{AttributeInfo, AttributeTypeData, ErrorExt};
+use crate::{
+    args::Arguments,
+    attributes::AttributeCollector,
+    cairo_expression::CairoExpression,
+    config_statement::extend_with_config_cheatcodes,
+    types::{Number, ParseFromExpr},
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream, quote};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+use num_bigint::BigInt;
+
+pub struct MaxStepsCollector;
+
+impl AttributeInfo for MaxStepsCollector {
+    const ATTR_NAME: &'static str = "max_steps";
+}
+
+impl AttributeTypeData for MaxStepsCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_max_steps";
+}
+
+impl AttributeCollector for MaxStepsCollector {
+    fn args_into_config_expression(
+        db: &SimpleParserDatabase,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<TokenStream, Diagnostics> {
+        let &[arg] = args.unnamed_only::<Self>()?.of_length::<1, Self>()?;
+
+        let max_steps = Number::parse_from_expr::<Self>(db, arg.1, "max_steps")?;
+
+        if max_steps.0 <= BigInt::from(0) {
+            Err(Self::error("max_steps must be greater than 0"))?;
+        }
+
+        let max = u32::MAX;
+        if max_steps > Number(max.into()) {
+            Err(Self::error(format!(
+                "max_steps is too large (max permissible value is {max})"
+            )))?;
+        }
+
+        let max_steps = max_steps.as_cairo_expression();
+
+        Ok(quote!(
+            snforge_std::_internals::config_types::MaxStepsConfig { max_steps: #max_steps }
+        ))
+    }
+}
+
+#[must_use]
+pub fn max_steps(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<MaxStepsCollector>(args, item)
+}
@@ -0,0 +1,48 @@
+use super::{AttributeInfo, AttributeTypeData, ErrorExt};
+use crate::{
+    args::Arguments,
+    attributes::AttributeCollector,
+    cairo_expression::CairoExpression,
+    config_statement::extend_with_config_cheatcodes,
+    types::{Number, ParseFromExpr},
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream, quote};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+
+pub struct RetryCollector;
+
+impl AttributeInfo for RetryCollector {
+    const ATTR_NAME: &'static str = "retry";
+}
+
+impl AttributeTypeData for RetryCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_retry";
+}
+
+impl AttributeCollector for RetryCollector {
+    fn args_into_config_expression(
+        db: &SimpleParserDatabase,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<TokenStream, Diagnostics> {
+        let &[arg] = args.unnamed_only::<Self>()?.of_length::<1, Self>()?;
+
+        let retries = Number::parse_from_expr::<Self>(db, arg.1, "retries")?;
+
+        let max = u32::MAX;
+        if retries > Number(max.into()) {
+            Err(Self::error(format!(
+                "retries is too large (max permissible value is {max})"
+            )))?;
+        }
+
+        let retries = retries.as_cairo_expression();
+
+        Ok(quote!(snforge_std::_internals::config_types::RetryConfig { retries: #retries }))
+    }
+}
+
+#[must_use]
+pub fn retry(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<RetryCollector>(args, item)
+}
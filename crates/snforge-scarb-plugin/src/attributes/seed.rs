@@ -0,0 +1,48 @@
+use super::{AttributeInfo, AttributeTypeData, ErrorExt};
+use crate::{
+    args::Arguments,
+    attributes::AttributeCollector,
+    cairo_expression::CairoExpression,
+    config_statement::extend_with_config_cheatcodes,
+    types::{Number, ParseFromExpr},
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream, quote};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+
+pub struct SeedCollector;
+
+impl AttributeInfo for SeedCollector {
+    const ATTR_NAME: &'static str = "seed";
+}
+
+impl AttributeTypeData for SeedCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_seed";
+}
+
+impl AttributeCollector for SeedCollector {
+    fn args_into_config_expression(
+        db: &SimpleParserDatabase,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<TokenStream, Diagnostics> {
+        let &[arg] = args.unnamed_only::<Self>()?.of_length::<1, Self>()?;
+
+        let seed = Number::parse_from_expr::<Self>(db, arg.1, "seed")?;
+
+        let max = u64::MAX;
+        if seed > Number(max.into()) {
+            Err(Self::error(format!(
+                "seed is too large (max permissible value is {max})"
+            )))?;
+        }
+
+        let seed = seed.as_cairo_expression();
+
+        Ok(quote!(snforge_std::_internals::config_types::SeedConfig { seed: #seed }))
+    }
+}
+
+#[must_use]
+pub fn seed(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<SeedCollector>(args, item)
+}
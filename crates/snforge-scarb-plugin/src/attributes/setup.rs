@@ -0,0 +1,44 @@
+use super::{AttributeInfo, AttributeTypeData, ErrorExt};
+use crate::{
+    args::Arguments, attributes::AttributeCollector, cairo_expression::CairoExpression,
+    config_statement::extend_with_config_cheatcodes,
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream, quote};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+use cairo_lang_syntax::node::{TypedSyntaxNode, ast::Expr};
+
+pub struct SetupCollector;
+
+impl AttributeInfo for SetupCollector {
+    const ATTR_NAME: &'static str = "setup";
+}
+
+impl AttributeTypeData for SetupCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_setup";
+}
+
+impl AttributeCollector for SetupCollector {
+    fn args_into_config_expression(
+        db: &SimpleParserDatabase,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<TokenStream, Diagnostics> {
+        let &[arg] = args.unnamed_only::<Self>()?.of_length::<1, Self>()?;
+
+        let Expr::Path(path) = arg.1 else {
+            Err(Self::error(
+                "<fixture> argument must be a path to a function marked with #[fixture]",
+            ))?
+        };
+
+        let fixture = path.as_syntax_node().get_text_without_trivia(db);
+        let fixture = fixture.as_cairo_expression();
+
+        Ok(quote!(snforge_std::_internals::config_types::SetupConfig { fixture: #fixture }))
+    }
+}
+
+#[must_use]
+pub fn setup(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<SetupCollector>(args, item)
+}
@@ -1,4 +1,4 @@
-use self::expected::Expected;
+use self::expected::{Expected, parse_expected_alternatives};
 use crate::{
     args::Arguments,
     attributes::{AttributeCollector, AttributeInfo, AttributeTypeData},
@@ -27,19 +27,32 @@ impl AttributeCollector for ShouldPanicCollector {
         args: Arguments,
         _warns: &mut Vec<Diagnostic>,
     ) -> Result<TokenStream, Diagnostics> {
-        let named_args = args.named_only::<Self>(db, &["expected"])?;
+        let named_args = args.named_only::<Self>(db, &["expected", "contract", "function"])?;
 
-        let expected = named_args.as_once_optional("expected")?;
-
-        let expected = expected
-            .map(|expr| Expected::parse_from_expr::<Self>(db, expr, "expected"))
+        let expected = named_args
+            .as_once_optional("expected")?
+            .map(|expr| parse_expected_alternatives(db, expr, "expected"))
             .transpose()?
-            .unwrap_or_default();
+            .unwrap_or_else(|| vec![Expected::default()]);
+        let contract = named_args
+            .as_once_optional("contract")?
+            .map(|expr| String::parse_from_expr::<Self>(db, expr, "contract"))
+            .transpose()?;
+        let function = named_args
+            .as_once_optional("function")?
+            .map(|expr| String::parse_from_expr::<Self>(db, expr, "function"))
+            .transpose()?;
 
         let expected = expected.as_cairo_expression();
+        let contract = contract.as_cairo_expression();
+        let function = function.as_cairo_expression();
 
         Ok(quote!(
-            snforge_std::_internals::config_types::ShouldPanicConfig { expected: #expected }
+            snforge_std::_internals::config_types::ShouldPanicConfig {
+                expected: #expected,
+                contract: #contract,
+                function: #function,
+            }
         ))
     }
 }
@@ -1,4 +1,5 @@
 use super::ShouldPanicCollector;
+use crate::utils::create_single_token;
 use crate::{
     attributes::{AttributeInfo, ErrorExt},
     cairo_expression::CairoExpression,
@@ -6,13 +7,23 @@ use crate::{
 };
 use cairo_lang_macro::{Diagnostic, TokenStream, quote};
 use cairo_lang_parser::utils::SimpleParserDatabase;
-use cairo_lang_syntax::node::{Terminal, ast::Expr};
+use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::{
+    Terminal, TypedSyntaxNode,
+    ast::{ArgClause, Expr, ExprFunctionCall},
+};
+use regex::Regex;
 
 #[derive(Debug, Clone, Default)]
 pub enum Expected {
     Felt(Felt),
     ByteArray(String),
     Array(Vec<ExpectedTupleItem>),
+    /// Serialized value of a Cairo expression, e.g. a typed error
+    Serialized(String),
+    /// Variant of a serialized enum, given by a Cairo expression of that variant
+    Variant(String),
+    Regex(String),
     #[default]
     Any,
 }
@@ -58,11 +69,142 @@ impl CairoExpression for Expected {
 
                 quote!(snforge_std::_internals::config_types::Expected::Array(#arr))
             }
+            Self::Serialized(expr) => {
+                let serialized = serialize_expression(expr);
+
+                quote!(snforge_std::_internals::config_types::Expected::Serialized(#serialized))
+            }
+            Self::Variant(expr) => {
+                let serialized = serialize_expression(expr);
+
+                quote!(snforge_std::_internals::config_types::Expected::Variant(#serialized))
+            }
+            Self::Regex(pattern) => {
+                let pattern = pattern.as_cairo_expression();
+
+                quote!(snforge_std::_internals::config_types::Expected::Regex(#pattern))
+            }
             Self::Any => quote!(snforge_std::_internals::config_types::Expected::Any),
         }
     }
 }
 
+fn serialize_expression(expr: &str) -> TokenStream {
+    let expr = create_single_token(expr);
+
+    quote!(snforge_std::_internals::config_types::serialized(#expr))
+}
+
+/// Parses the `expected` argument, which can list multiple alternatives with `one_of(...)`.
+pub fn parse_expected_alternatives(
+    db: &SimpleParserDatabase,
+    expr: &Expr,
+    arg_name: &str,
+) -> Result<Vec<Expected>, Diagnostic> {
+    match expr {
+        Expr::FunctionCall(call) if function_name(db, call) == "one_of" => {
+            let alternatives = call_args(db, call, arg_name)?;
+            if alternatives.is_empty() {
+                return Err(ShouldPanicCollector::error(format!(
+                    "<{arg_name}> one_of(...) requires at least one expectation"
+                )));
+            }
+
+            alternatives
+                .iter()
+                .map(|expr| Expected::parse_from_expr::<ShouldPanicCollector>(db, expr, arg_name))
+                .collect()
+        }
+        _ => Ok(vec![Expected::parse_from_expr::<ShouldPanicCollector>(
+            db, expr, arg_name,
+        )?]),
+    }
+}
+
+fn function_name(db: &SimpleParserDatabase, call: &ExprFunctionCall) -> String {
+    call.path(db)
+        .segments(db)
+        .elements(db)
+        .map(|segment| segment.identifier(db).to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+fn call_args(
+    db: &SimpleParserDatabase,
+    call: &ExprFunctionCall,
+    arg_name: &str,
+) -> Result<Vec<Expr>, Diagnostic> {
+    call.arguments(db)
+        .arguments(db)
+        .elements(db)
+        .map(|arg| match arg.arg_clause(db) {
+            ArgClause::Unnamed(value) => Ok(value.value(db)),
+            _ => Err(ShouldPanicCollector::error(format!(
+                "<{arg_name}> {}(...) accepts unnamed arguments only",
+                function_name(db, call)
+            ))),
+        })
+        .collect()
+}
+
+fn single_call_arg(
+    db: &SimpleParserDatabase,
+    call: &ExprFunctionCall,
+    arg_name: &str,
+) -> Result<Expr, Diagnostic> {
+    let args = call_args(db, call, arg_name)?;
+
+    match <[Expr; 1]>::try_from(args) {
+        Ok([arg]) => Ok(arg),
+        Err(args) => Err(ShouldPanicCollector::error(format!(
+            "<{arg_name}> {}(...) expects exactly one argument, got: {}",
+            function_name(db, call),
+            args.len()
+        ))),
+    }
+}
+
+fn parse_matcher(
+    db: &SimpleParserDatabase,
+    call: &ExprFunctionCall,
+    arg_name: &str,
+    error_msg: String,
+) -> Result<Expected, Diagnostic> {
+    match function_name(db, call).as_str() {
+        "error" => {
+            let arg = single_call_arg(db, call, arg_name)?;
+            Ok(Expected::Serialized(
+                arg.as_syntax_node().get_text_without_trivia(db),
+            ))
+        }
+        "variant" => {
+            let arg = single_call_arg(db, call, arg_name)?;
+            Ok(Expected::Variant(
+                arg.as_syntax_node().get_text_without_trivia(db),
+            ))
+        }
+        "regex" => {
+            let Expr::String(pattern) = single_call_arg(db, call, arg_name)? else {
+                return Err(ShouldPanicCollector::error(format!(
+                    "<{arg_name}> regex(...) argument must be a string"
+                )));
+            };
+            let pattern = pattern.text(db).trim_matches('"').to_string();
+
+            Regex::new(&unescape(&pattern)).map_err(|err| {
+                ShouldPanicCollector::error(format!("<{arg_name}> invalid regex: {err}"))
+            })?;
+
+            Ok(Expected::Regex(pattern))
+        }
+        "one_of" => Err(ShouldPanicCollector::error(format!(
+            "<{arg_name}> one_of(...) cannot be nested"
+        ))),
+        _ => Err(ShouldPanicCollector::error(error_msg)),
+    }
+}
+
 impl ParseFromExpr<Expr> for Expected {
     fn parse_from_expr<T: AttributeInfo>(
         db: &SimpleParserDatabase,
@@ -70,7 +212,7 @@ impl ParseFromExpr<Expr> for Expected {
         arg_name: &str,
     ) -> Result<Self, Diagnostic> {
         let error_msg = format!(
-            "<{arg_name}> argument must be a string, short string, number, or tuple containing any mix of strings, short strings, and numbers in parentheses ()"
+            "<{arg_name}> argument must be a string, short string, number, tuple containing any mix of strings, short strings, and numbers in parentheses (), or one of: error(...), variant(...), regex(...), one_of(...)"
         );
 
         match expr {
@@ -98,6 +240,7 @@ impl ParseFromExpr<Expr> for Expected {
 
                 Ok(Self::Array(elements))
             }
+            Expr::FunctionCall(call) => parse_matcher(db, call, arg_name, error_msg),
             _ => Err(ShouldPanicCollector::error(error_msg)),
         }
     }
@@ -122,3 +265,21 @@ impl ParseFromExpr<Expr> for ExpectedTupleItem {
         }
     }
 }
+
+/// Reverts escaping of a Cairo string literal for the escape sequences that matter in regexes.
+fn unescape(literal: &str) -> String {
+    let mut unescaped = String::with_capacity(literal.len());
+    let mut chars = literal.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some(next @ ('\\' | '"' | '\''))) => {
+                chars.next();
+                unescaped.push(next);
+            }
+            _ => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
@@ -0,0 +1,60 @@
+use super::{AttributeInfo, AttributeTypeData, ErrorExt};
+use crate::{
+    args::Arguments, attributes::AttributeCollector, cairo_expression::CairoExpression,
+    config_statement::extend_with_config_cheatcodes, types::ParseFromExpr,
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream, quote};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+
+pub struct TagsCollector;
+
+impl AttributeInfo for TagsCollector {
+    const ATTR_NAME: &'static str = "tags";
+}
+
+impl AttributeTypeData for TagsCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_tags";
+}
+
+impl AttributeCollector for TagsCollector {
+    fn args_into_config_expression(
+        db: &SimpleParserDatabase,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<TokenStream, Diagnostics> {
+        let args = args.unnamed_only::<Self>()?;
+
+        if args.is_empty() {
+            Err(Self::error("expected at least one tag"))?;
+        }
+
+        let mut tags: Vec<String> = Vec::with_capacity(args.len());
+        for (position, expr) in args.iter() {
+            let tag = String::parse_from_expr::<Self>(db, expr, &position.to_string())?;
+
+            if tag.is_empty()
+                || !tag
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                Err(Self::error(format!(
+                    "tag \"{tag}\" must be non-empty and contain only ASCII letters, digits, `_` and `-`"
+                )))?;
+            }
+            if tags.contains(&tag) {
+                Err(Self::error(format!("tag \"{tag}\" is used more than once")))?;
+            }
+
+            tags.push(tag);
+        }
+
+        let tags = tags.as_cairo_expression();
+
+        Ok(quote!(snforge_std::_internals::config_types::TagsConfig { tags: #tags }))
+    }
+}
+
+#[must_use]
+pub fn tags(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<TagsCollector>(args, item)
+}
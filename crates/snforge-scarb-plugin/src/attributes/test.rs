@@ -1,6 +1,9 @@
 use super::{AttributeInfo, ErrorExt, internal_config_statement::InternalConfigStatementCollector};
 use crate::asserts::assert_is_used_once;
-use crate::common::{has_fuzzer_attribute, has_test_case_attribute};
+use crate::common::{
+    has_fixture_attribute, has_fuzzer_attribute, has_hook_attribute, has_parameters,
+    has_test_case_attribute,
+};
 use crate::external_inputs::ExternalInput;
 use crate::utils::{create_single_token, get_statements};
 use crate::{
@@ -36,6 +39,16 @@ fn test_internal(
 ) -> Result<TokenStream, Diagnostics> {
     assert_is_used_once::<TestCollector>(db, func)?;
     args.assert_is_empty::<TestCollector>()?;
+    if has_fixture_attribute(db, func) {
+        Err(TestCollector::error(
+            "cannot be used together with #[fixture]",
+        ))?;
+    }
+    if has_hook_attribute(db, func) {
+        Err(TestCollector::error(
+            "cannot be used together with #[before_each] or #[after_each]",
+        ))?;
+    }
     ensure_parameters_only_with_fuzzer_or_test_case_attribute(db, func)?;
 
     let has_test_case = has_test_case_attribute(db, func);
@@ -137,15 +150,6 @@ fn ensure_parameters_only_with_fuzzer_or_test_case_attribute(
     Ok(())
 }
 
-fn has_parameters(db: &SimpleParserDatabase, func: &FunctionWithBody) -> bool {
-    func.declaration(db)
-        .signature(db)
-        .parameters(db)
-        .elements(db)
-        .len()
-        != 0
-}
-
 #[must_use]
 pub fn test_func_with_attrs(
     test_fn_name: &TokenStream,
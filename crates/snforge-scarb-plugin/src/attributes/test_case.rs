@@ -15,7 +15,7 @@ use cairo_lang_syntax::node::ast::FunctionWithBody;
 use cairo_lang_syntax::node::with_db::SyntaxNodeWithDb;
 use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
 
-mod name;
+pub(crate) mod name;
 
 pub struct TestCaseCollector;
 
@@ -132,7 +132,7 @@ fn ensure_params_valid(
     Ok(())
 }
 
-fn collect_preserved_attributes_for_test_case(
+pub(crate) fn collect_preserved_attributes_for_test_case(
     func: &FunctionWithBody,
     func_db: &SimpleParserDatabase,
 ) -> TokenStream {
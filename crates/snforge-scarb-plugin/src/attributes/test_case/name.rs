@@ -11,21 +11,25 @@ use std::sync::LazyLock;
 static RE_SANITIZE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"[^a-zA-Z0-9]+").expect("Failed to create regex"));
 
-fn sanitize_expr(expr: &Expr, db: &SimpleParserDatabase) -> String {
-    let expr_text = &expr.as_syntax_node().get_text(db);
-    let expr_sanitized = RE_SANITIZE
-        .replace_all(expr_text, "_")
+/// Turns arbitrary text into a part of a valid function name.
+pub fn sanitize(text: &str) -> String {
+    let sanitized = RE_SANITIZE
+        .replace_all(text, "_")
         .to_lowercase()
         .trim_matches('_')
         .to_string();
 
-    if expr_sanitized.is_empty() {
+    if sanitized.is_empty() {
         "_empty".into()
     } else {
-        expr_sanitized
+        sanitized
     }
 }
 
+fn sanitize_expr(expr: &Expr, db: &SimpleParserDatabase) -> String {
+    sanitize(&expr.as_syntax_node().get_text(db))
+}
+
 fn generate_case_suffix(unnamed_args: &UnnamedArgs, db: &SimpleParserDatabase) -> String {
     if unnamed_args.is_empty() {
         unreachable!("Arguments cannot be empty for case name generation");
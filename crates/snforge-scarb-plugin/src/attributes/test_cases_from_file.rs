@@ -0,0 +1,231 @@
+use crate::args::Arguments;
+use crate::attributes::internal_config_statement::InternalConfigStatementCollector;
+use crate::attributes::test::test_func_with_attrs;
+use crate::attributes::test_case::collect_preserved_attributes_for_test_case;
+use crate::attributes::test_case::name::sanitize;
+use crate::attributes::{AttributeInfo, ErrorExt};
+use crate::common::{
+    has_fuzzer_attribute, has_test_attribute, into_proc_macro_result, with_parsed_values,
+};
+use crate::external_inputs::ExternalInput;
+use crate::types::ParseFromExpr;
+use crate::utils::SyntaxNodeUtils;
+use crate::{create_single_token, format_ident};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream, quote};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+use cairo_lang_syntax::node::ast::{FunctionWithBody, OptionTypeClause};
+use cairo_lang_syntax::node::with_db::SyntaxNodeWithDb;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
+use literal::ParamType;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+mod literal;
+mod rows;
+
+pub struct TestCasesFromFileCollector;
+
+impl AttributeInfo for TestCasesFromFileCollector {
+    const ATTR_NAME: &'static str = "test_cases_from_file";
+}
+
+#[must_use]
+pub fn test_cases_from_file(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    into_proc_macro_result(args, item, |args, item, warns| {
+        // Relative paths are resolved against the directory of the file the test is defined in.
+        let base_dir = item
+            .metadata()
+            .original_file_path
+            .as_deref()
+            .and_then(|file| Path::new(file).parent())
+            .map(Path::to_path_buf);
+
+        with_parsed_values::<TestCasesFromFileCollector>(
+            args,
+            item,
+            warns,
+            |db, func, args_db, args, warns| {
+                test_cases_from_file_internal(db, func, args_db, args, warns, base_dir.as_deref())
+            },
+        )
+    })
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn test_cases_from_file_internal(
+    db: &SimpleParserDatabase,
+    func: &FunctionWithBody,
+    args_db: &SimpleParserDatabase,
+    args: Arguments,
+    _warns: &mut Vec<Diagnostic>,
+    base_dir: Option<&Path>,
+) -> Result<TokenStream, Diagnostics> {
+    let &[arg] = args
+        .unnamed_only::<TestCasesFromFileCollector>()?
+        .of_length::<1, TestCasesFromFileCollector>()?;
+
+    let path = String::parse_from_expr::<TestCasesFromFileCollector>(args_db, arg.1, "path")?;
+
+    let params = params(db, func)?;
+    let param_names: Vec<_> = params.iter().map(|(name, _)| name.clone()).collect();
+
+    let path = resolve_path(base_dir, &path);
+    let content = ExternalInput::read_data_file(&path).map_err(|err| {
+        TestCasesFromFileCollector::error(format!("failed to read {}: {err}", path.display()))
+    })?;
+
+    let rows = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => rows::parse_json(&content, &param_names),
+        Some("csv") => rows::parse_csv(&content, &param_names),
+        _ => Err("unsupported file format, expected a .json or .csv file".to_string()),
+    }
+    .map_err(|message| {
+        TestCasesFromFileCollector::error(format!("{}: {message}", path.display()))
+    })?;
+
+    if rows.is_empty() {
+        Err(TestCasesFromFileCollector::error(format!(
+            "{}: file does not contain any test cases",
+            path.display()
+        )))?;
+    }
+
+    let func_name = func.declaration(db).name(db);
+    let func_name_text = func_name.text(db);
+    let func_name = func_name.to_token_stream(db);
+
+    let mut case_names = HashSet::new();
+    let mut test_funcs = TokenStream::empty();
+
+    for (i, row) in rows.iter().enumerate() {
+        let row_error = |message: String| {
+            TestCasesFromFileCollector::error(format!(
+                "{}: row {}: {message}",
+                path.display(),
+                i + 1
+            ))
+        };
+
+        if row.values.len() != params.len() {
+            Err(row_error(format!(
+                "expected {} arguments, but got {}",
+                params.len(),
+                row.values.len()
+            )))?;
+        }
+
+        let literals = params
+            .iter()
+            .zip(&row.values)
+            .map(|((name, param_type), value)| {
+                param_type
+                    .literal(value)
+                    .map_err(|message| row_error(format!("parameter `{name}`: {message}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let suffix = row.name.as_deref().map_or_else(
+            || {
+                literals
+                    .iter()
+                    .map(|literal| sanitize(literal))
+                    .collect::<Vec<_>>()
+                    .join("_")
+            },
+            sanitize,
+        );
+        let case_fn_name = format!("{func_name_text}_{suffix}");
+
+        if !case_names.insert(case_fn_name.clone()) {
+            Err(row_error(format!(
+                "duplicate test case name `{case_fn_name}`"
+            )))?;
+        }
+
+        let case_fn_name = TokenStream::new(vec![format_ident!("{}", case_fn_name)]);
+        let call_args = literals
+            .iter()
+            .fold(TokenStream::empty(), |mut acc, literal| {
+                let literal = create_single_token(literal);
+                acc.extend(quote! { #literal, });
+                acc
+            });
+
+        test_funcs.extend(test_func_with_attrs(&case_fn_name, &func_name, &call_args));
+    }
+
+    let filtered_fn_attrs = collect_preserved_attributes_for_test_case(func, db);
+
+    let signature = func.declaration(db).signature(db).as_syntax_node();
+    let signature = SyntaxNodeWithDb::new(&signature, db);
+
+    let func_body = func.body(db).as_syntax_node();
+    let func_body = SyntaxNodeWithDb::new(&func_body, db);
+
+    // Same as for `#[test_case]`, the config statement is added by `#[test]` when used with `#[fuzzer]`.
+    let skip_internal_config = has_fuzzer_attribute(db, func) && has_test_attribute(db, func);
+    let internal_config_attr = if skip_internal_config {
+        TokenStream::empty()
+    } else {
+        let internal_config = create_single_token(InternalConfigStatementCollector::ATTR_NAME);
+        quote!(#[#internal_config])
+    };
+
+    Ok(quote!(
+        #test_funcs
+
+        #filtered_fn_attrs
+
+        #internal_config_attr
+        fn #func_name #signature
+        #func_body
+    ))
+}
+
+/// Names and types of the function parameters, in order.
+fn params(
+    db: &SimpleParserDatabase,
+    func: &FunctionWithBody,
+) -> Result<Vec<(String, ParamType)>, Diagnostic> {
+    let params: Vec<_> = func
+        .declaration(db)
+        .signature(db)
+        .parameters(db)
+        .elements(db)
+        .collect();
+
+    if params.is_empty() {
+        Err(TestCasesFromFileCollector::error(
+            "The function must have at least one parameter to use #[test_cases_from_file] attribute",
+        ))?;
+    }
+
+    params
+        .iter()
+        .map(|param| {
+            let name = param.name(db).text(db).to_string();
+            let OptionTypeClause::TypeClause(type_clause) = param.type_clause(db) else {
+                Err(TestCasesFromFileCollector::error(format!(
+                    "parameter `{name}` must have a type"
+                )))?
+            };
+            let type_text = type_clause
+                .ty(db)
+                .as_syntax_node()
+                .get_text_without_trivia(db);
+
+            let param_type = ParamType::parse(&type_text).map_err(|message| {
+                TestCasesFromFileCollector::error(format!("parameter `{name}`: {message}"))
+            })?;
+
+            Ok((name, param_type))
+        })
+        .collect()
+}
+
+fn resolve_path(base_dir: Option<&Path>, path: &str) -> PathBuf {
+    match base_dir {
+        Some(base_dir) => base_dir.join(path),
+        None => PathBuf::from(path),
+    }
+}
@@ -0,0 +1,155 @@
+use super::rows::Value;
+use num_bigint::BigInt;
+use std::sync::LazyLock;
+
+/// The prime defining the `felt252` field, 2^251 + 17 * 2^192 + 1.
+static FELT252_PRIME: LazyLock<BigInt> =
+    LazyLock::new(|| (BigInt::from(1) << 251) + (BigInt::from(17) << 192) + 1);
+
+const SUPPORTED_TYPES: &str =
+    "felt252, bool, ByteArray, u8, u16, u32, u64, u128, u256, usize, i8, i16, i32, i64, i128";
+
+/// Parameter types that values from data files can be converted to.
+pub enum ParamType {
+    Felt252,
+    Bool,
+    ByteArray,
+    Integer { signed: bool, bits: u32 },
+}
+
+impl ParamType {
+    pub fn parse(type_text: &str) -> Result<Self, String> {
+        let name = type_text.rsplit("::").next().unwrap_or(type_text);
+
+        let param_type = match name {
+            "felt252" => Self::Felt252,
+            "bool" => Self::Bool,
+            "ByteArray" => Self::ByteArray,
+            "usize" => Self::Integer {
+                signed: false,
+                bits: 32,
+            },
+            _ => {
+                let (signed, bits) = if let Some(bits) = name.strip_prefix('u') {
+                    (false, bits)
+                } else if let Some(bits) = name.strip_prefix('i') {
+                    (true, bits)
+                } else {
+                    (false, "")
+                };
+
+                match (signed, bits) {
+                    (false, "8" | "16" | "32" | "64" | "128" | "256")
+                    | (true, "8" | "16" | "32" | "64" | "128") => Self::Integer {
+                        signed,
+                        bits: bits.parse().expect("Bits are a valid number"),
+                    },
+                    _ => {
+                        return Err(format!(
+                            "unsupported type `{type_text}`, supported types are: {SUPPORTED_TYPES}"
+                        ));
+                    }
+                }
+            }
+        };
+
+        Ok(param_type)
+    }
+
+    /// Converts the value into a Cairo literal of this type.
+    pub fn literal(&self, value: &Value) -> Result<String, String> {
+        match (self, value) {
+            (Self::Bool, Value::Bool(value)) => Ok(value.to_string()),
+            (Self::Bool, Value::String(text)) if matches!(text.as_str(), "true" | "false") => {
+                Ok(text.clone())
+            }
+            (Self::Bool, _) => Err("expected `true` or `false`".to_string()),
+
+            (Self::ByteArray, Value::String(text)) => Ok(format!("\"{}\"", escape(text, '"')?)),
+            (Self::ByteArray, _) => Err("expected a string".to_string()),
+
+            (Self::Felt252, Value::String(text)) if parse_integer(text).is_none() => {
+                if text.len() > 31 {
+                    return Err(format!(
+                        "short string `{text}` is longer than 31 characters"
+                    ));
+                }
+                Ok(format!("'{}'", escape(text, '\'')?))
+            }
+            (Self::Felt252, value) => {
+                let number = integer(value)?;
+                if number.magnitude() >= FELT252_PRIME.magnitude() {
+                    return Err(format!("`{number}` does not fit into felt252"));
+                }
+                Ok(number.to_string())
+            }
+
+            (Self::Integer { signed, bits }, value) => {
+                let number = integer(value)?;
+                let (min, max) = if *signed {
+                    (
+                        -(BigInt::from(1) << (bits - 1)),
+                        BigInt::from(1) << (bits - 1),
+                    )
+                } else {
+                    (BigInt::from(0), BigInt::from(1) << bits)
+                };
+                if number < min || number >= max {
+                    let prefix = if *signed { 'i' } else { 'u' };
+                    return Err(format!("`{number}` does not fit into {prefix}{bits}"));
+                }
+                Ok(number.to_string())
+            }
+        }
+    }
+}
+
+fn integer(value: &Value) -> Result<BigInt, String> {
+    match value {
+        Value::Integer(text) | Value::String(text) => {
+            parse_integer(text).ok_or_else(|| format!("`{text}` is not an integer"))
+        }
+        Value::Bool(value) => Err(format!("expected an integer, got `{value}`")),
+    }
+}
+
+/// Parses a decimal or `0x` prefixed hexadecimal integer.
+fn parse_integer(text: &str) -> Option<BigInt> {
+    let text = text.trim();
+    let (negative, digits) = text
+        .strip_prefix('-')
+        .map_or((false, text), |digits| (true, digits));
+
+    let number = if let Some(hex) = digits.strip_prefix("0x") {
+        BigInt::parse_bytes(hex.as_bytes(), 16)?
+    } else if !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        BigInt::parse_bytes(digits.as_bytes(), 10)?
+    } else {
+        return None;
+    };
+
+    Some(if negative { -number } else { number })
+}
+
+/// Escapes the text so that it can be placed between `quote` characters of a Cairo literal.
+fn escape(text: &str, quote: char) -> Result<String, String> {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c if c == quote => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c if c.is_ascii_graphic() || c == ' ' => escaped.push(c),
+            c if c.is_ascii() => escaped.push_str(&format!("\\x{:02x}", c as u8)),
+            c => return Err(format!("non-ASCII character `{c}` is not supported")),
+        }
+    }
+
+    Ok(escaped)
+}
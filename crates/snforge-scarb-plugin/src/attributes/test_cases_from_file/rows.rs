@@ -0,0 +1,185 @@
+use serde_json::Value as JsonValue;
+
+/// Key of the optional column or object entry holding the name of a test case.
+const NAME_KEY: &str = "name";
+
+/// Single test case read from a data file, with values ordered like function parameters.
+pub struct Row {
+    pub name: Option<String>,
+    pub values: Vec<Value>,
+}
+
+pub enum Value {
+    Integer(String),
+    Bool(bool),
+    String(String),
+}
+
+/// Parses a JSON array of rows, each being an array of values in the order of parameters
+/// or an object mapping parameter names to values.
+pub fn parse_json(content: &str, params: &[String]) -> Result<Vec<Row>, String> {
+    let json: JsonValue =
+        serde_json::from_str(content).map_err(|err| format!("invalid JSON: {err}"))?;
+
+    let JsonValue::Array(rows) = json else {
+        return Err("JSON file must contain an array of test cases".to_string());
+    };
+
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            json_row(row, params).map_err(|message| format!("row {}: {message}", i + 1))
+        })
+        .collect()
+}
+
+fn json_row(row: JsonValue, params: &[String]) -> Result<Row, String> {
+    match row {
+        JsonValue::Array(values) => Ok(Row {
+            name: None,
+            values: values
+                .into_iter()
+                .map(json_value)
+                .collect::<Result<_, _>>()?,
+        }),
+        JsonValue::Object(mut entries) => {
+            let mut values = Vec::with_capacity(params.len());
+            for param in params {
+                let value = entries
+                    .remove(param)
+                    .ok_or_else(|| format!("missing value for parameter `{param}`"))?;
+                values.push(json_value(value)?);
+            }
+
+            let name = match entries.remove(NAME_KEY) {
+                None => None,
+                Some(JsonValue::String(name)) => Some(name),
+                Some(_) => return Err(format!("`{NAME_KEY}` must be a string")),
+            };
+
+            if let Some(key) = entries.keys().next() {
+                return Err(format!("unexpected key `{key}`"));
+            }
+
+            Ok(Row { name, values })
+        }
+        _ => Err("test case must be an array or an object".to_string()),
+    }
+}
+
+fn json_value(value: JsonValue) -> Result<Value, String> {
+    match value {
+        JsonValue::Number(number) if number.is_f64() => {
+            Err(format!("`{number}` is not an integer"))
+        }
+        JsonValue::Number(number) => Ok(Value::Integer(number.to_string())),
+        JsonValue::Bool(value) => Ok(Value::Bool(value)),
+        JsonValue::String(value) => Ok(Value::String(value)),
+        other => Err(format!("unsupported value `{other}`")),
+    }
+}
+
+/// Parses CSV with a header row naming the parameters, every following row is a single test case.
+pub fn parse_csv(content: &str, params: &[String]) -> Result<Vec<Row>, String> {
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    let Some((_, header)) = lines.next() else {
+        return Err("CSV file must start with a header row".to_string());
+    };
+    let header = csv_fields(header).map_err(|message| format!("header: {message}"))?;
+
+    let mut columns = Vec::with_capacity(params.len());
+    for param in params {
+        let column = header
+            .iter()
+            .position(|column| column == param)
+            .ok_or_else(|| format!("header: missing column for parameter `{param}`"))?;
+        columns.push(column);
+    }
+    let name_column = header
+        .iter()
+        .position(|column| column == NAME_KEY && !params.iter().any(|param| param == NAME_KEY));
+
+    if let Some(column) = header
+        .iter()
+        .find(|column| !params.contains(column) && column.as_str() != NAME_KEY)
+    {
+        return Err(format!("header: unexpected column `{column}`"));
+    }
+
+    lines
+        .map(|(line_index, line)| {
+            let row = csv_fields(line)
+                .and_then(|fields| {
+                    if fields.len() == header.len() {
+                        Ok(fields)
+                    } else {
+                        Err(format!(
+                            "expected {} values, but got {}",
+                            header.len(),
+                            fields.len()
+                        ))
+                    }
+                })
+                .map(|mut fields| Row {
+                    name: name_column
+                        .map(|column| std::mem::take(&mut fields[column]))
+                        .filter(|name| !name.is_empty()),
+                    values: columns
+                        .iter()
+                        .map(|&column| Value::String(std::mem::take(&mut fields[column])))
+                        .collect(),
+                });
+
+            row.map_err(|message| format!("line {}: {message}", line_index + 1))
+        })
+        .collect()
+}
+
+/// Splits a CSV line into fields, supporting quoted fields with `""` as an escaped quote.
+fn csv_fields(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = vec![];
+    let mut chars = line.chars().peekable();
+
+    loop {
+        let mut field = String::new();
+
+        while chars.peek().is_some_and(|c| *c == ' ') {
+            chars.next();
+        }
+
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err("unterminated quoted field".to_string()),
+                }
+            }
+            while chars.peek().is_some_and(|c| *c != ',') {
+                if !chars.next().is_some_and(char::is_whitespace) {
+                    return Err("unexpected characters after quoted field".to_string());
+                }
+            }
+        } else {
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                field.push(c);
+            }
+            field = field.trim_end().to_string();
+        }
+
+        fields.push(field);
+
+        if chars.next().is_none() {
+            return Ok(fields);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use super::{AttributeInfo, AttributeTypeData, ErrorExt};
+use crate::{
+    args::Arguments,
+    attributes::AttributeCollector,
+    cairo_expression::CairoExpression,
+    config_statement::extend_with_config_cheatcodes,
+    types::{Number, ParseFromExpr},
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream, quote};
+use cairo_lang_parser::utils::SimpleParserDatabase;
+use num_bigint::BigInt;
+
+pub struct TimeoutCollector;
+
+impl AttributeInfo for TimeoutCollector {
+    const ATTR_NAME: &'static str = "timeout";
+}
+
+impl AttributeTypeData for TimeoutCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_timeout";
+}
+
+impl AttributeCollector for TimeoutCollector {
+    fn args_into_config_expression(
+        db: &SimpleParserDatabase,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<TokenStream, Diagnostics> {
+        let &[arg] = args.unnamed_only::<Self>()?.of_length::<1, Self>()?;
+
+        let seconds = Number::parse_from_expr::<Self>(db, arg.1, "seconds")?;
+
+        if seconds.0 <= BigInt::from(0) {
+            Err(Self::error("timeout must be greater than 0"))?;
+        }
+
+        let max = u64::MAX;
+        if seconds > Number(max.into()) {
+            Err(Self::error(format!(
+                "timeout is too large (max permissible value is {max})"
+            )))?;
+        }
+
+        let seconds = seconds.as_cairo_expression();
+
+        Ok(quote!(snforge_std::_internals::config_types::TimeoutConfig { seconds: #seconds }))
+    }
+}
+
+#[must_use]
+pub fn timeout(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<TimeoutCollector>(args, item)
+}
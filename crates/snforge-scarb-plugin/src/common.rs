@@ -2,9 +2,12 @@ use crate::{
     args::Arguments,
     attributes::{
         AttributeInfo,
+        fixture::FixtureCollector,
         fuzzer::{FuzzerCollector, FuzzerConfigCollector, wrapper::FuzzerWrapperCollector},
+        hooks::{AfterEachCollector, BeforeEachCollector},
         test::TestCollector,
         test_case::TestCaseCollector,
+        test_cases_from_file::TestCasesFromFileCollector,
     },
     parse::{parse, parse_args},
 };
@@ -90,7 +93,10 @@ pub fn has_fuzzer_attribute(db: &SimpleParserDatabase, func: &FunctionWithBody)
 }
 
 pub fn has_test_case_attribute(db: &SimpleParserDatabase, func: &FunctionWithBody) -> bool {
-    const TEST_CASE_ATTRIBUTES: [&str; 1] = [TestCaseCollector::ATTR_NAME];
+    const TEST_CASE_ATTRIBUTES: [&str; 2] = [
+        TestCaseCollector::ATTR_NAME,
+        TestCasesFromFileCollector::ATTR_NAME,
+    ];
     has_any_attribute(db, func, &TEST_CASE_ATTRIBUTES)
 }
 
@@ -98,3 +104,25 @@ pub fn has_test_attribute(db: &SimpleParserDatabase, func: &FunctionWithBody) ->
     const TEST_ATTRIBUTES: [&str; 1] = [TestCollector::ATTR_NAME];
     has_any_attribute(db, func, &TEST_ATTRIBUTES)
 }
+
+pub fn has_fixture_attribute(db: &SimpleParserDatabase, func: &FunctionWithBody) -> bool {
+    const FIXTURE_ATTRIBUTES: [&str; 1] = [FixtureCollector::ATTR_NAME];
+    has_any_attribute(db, func, &FIXTURE_ATTRIBUTES)
+}
+
+pub fn has_hook_attribute(db: &SimpleParserDatabase, func: &FunctionWithBody) -> bool {
+    const HOOK_ATTRIBUTES: [&str; 2] = [
+        BeforeEachCollector::ATTR_NAME,
+        AfterEachCollector::ATTR_NAME,
+    ];
+    has_any_attribute(db, func, &HOOK_ATTRIBUTES)
+}
+
+pub fn has_parameters(db: &SimpleParserDatabase, func: &FunctionWithBody) -> bool {
+    func.declaration(db)
+        .signature(db)
+        .parameters(db)
+        .elements(db)
+        .len()
+        != 0
+}
@@ -1,14 +1,28 @@
 use cairo_lang_macro::fingerprint;
-use std::env;
+use regex::Regex;
 use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::{env, fs, io};
 use xxhash_rust::xxh3::Xxh3;
 
+static TEST_CASES_FROM_FILE_PATH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"#\[\s*test_cases_from_file\s*\(\s*"([^"]*)""#).expect("regex should be valid")
+});
+
 /// All external inputs that influence the compilation should be added here.
 #[derive(Hash)]
 pub struct ExternalInput {
     pub forge_test_filter: Option<String>,
 }
 
+/// Data file used by `#[test_cases_from_file]`, together with its content.
+#[derive(Hash)]
+pub struct DataFile {
+    pub path: PathBuf,
+    pub content: Option<Vec<u8>>,
+}
+
 #[allow(clippy::disallowed_methods)]
 impl ExternalInput {
     pub fn get() -> Self {
@@ -16,12 +30,105 @@ impl ExternalInput {
             forge_test_filter: env::var("SNFORGE_TEST_FILTER").ok(),
         }
     }
+
+    /// Reads a data file that test cases are generated from.
+    /// `path` should already be resolved against the directory of the Cairo file using it.
+    ///
+    /// Content of this file is included in the fingerprint by [`ExternalInput::data_files`].
+    pub fn read_data_file(path: &Path) -> io::Result<String> {
+        fs::read_to_string(path)
+    }
+
+    /// Finds data files referenced by `#[test_cases_from_file]` in Cairo sources of the workspace.
+    /// Paths are resolved the same way as during expansion, relative to the Cairo file.
+    pub fn data_files() -> Vec<DataFile> {
+        let Some(root) = env::current_dir()
+            .ok()
+            .and_then(|dir| Self::workspace_root(&dir))
+        else {
+            return vec![];
+        };
+
+        let mut cairo_files = vec![];
+        Self::collect_cairo_files(&root, &mut cairo_files);
+        cairo_files.sort();
+
+        let mut data_files: Vec<_> = cairo_files
+            .iter()
+            .filter_map(|file| Some((file, fs::read_to_string(file).ok()?)))
+            .flat_map(|(file, source)| {
+                let base_dir = file.parent().map(Path::to_path_buf).unwrap_or_default();
+                TEST_CASES_FROM_FILE_PATH
+                    .captures_iter(&source)
+                    .map(|captures| base_dir.join(&captures[1]))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        data_files.sort();
+        data_files.dedup();
+
+        data_files
+            .into_iter()
+            .map(|path| DataFile {
+                content: fs::read(&path).ok(),
+                path,
+            })
+            .collect()
+    }
+
+    /// Root of the Scarb workspace that `dir` belongs to, resolved the way Scarb does it:
+    /// the closest ancestor whose `Scarb.toml` defines a `[workspace]`,
+    /// or the package containing `dir` if there is no such ancestor.
+    fn workspace_root(dir: &Path) -> Option<PathBuf> {
+        let package_root = dir
+            .ancestors()
+            .find(|ancestor| ancestor.join("Scarb.toml").is_file())?;
+
+        package_root
+            .ancestors()
+            .find(|ancestor| {
+                fs::read_to_string(ancestor.join("Scarb.toml"))
+                    .is_ok_and(|manifest| Self::defines_workspace(&manifest))
+            })
+            .or(Some(package_root))
+            .map(Path::to_path_buf)
+    }
+
+    fn defines_workspace(manifest: &str) -> bool {
+        manifest
+            .lines()
+            .map(str::trim)
+            .any(|line| line == "[workspace]" || line.starts_with("[workspace."))
+    }
+
+    fn collect_cairo_files(dir: &Path, cairo_files: &mut Vec<PathBuf>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+
+            if path.is_dir() {
+                if !name.starts_with('.') && name != "target" {
+                    Self::collect_cairo_files(&path, cairo_files);
+                }
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "cairo")
+            {
+                cairo_files.push(path);
+            }
+        }
+    }
 }
 
 /// This function implements a callback that Scarb will use to determine
 /// whether Cairo code depending on this macro should be recompiled.
 /// The callback is concerned with informing Scarb about changes to inputs that don't come from Scarb directly,
-/// like the `SNFORGE_TEST_FILTER` environmental variable.
+/// like the `SNFORGE_TEST_FILTER` environmental variable or data files of `#[test_cases_from_file]`.
 ///
 /// Warning: Removing this callback can break incremental compilation with this macro!
 #[fingerprint]
@@ -30,5 +137,67 @@ fn test_filter_fingerprint() -> u64 {
     // Thus, we cannot use the default hasher, which is rng-seeded.
     let mut hasher = Xxh3::default();
     ExternalInput::get().hash(&mut hasher);
+    ExternalInput::data_files().hash(&mut hasher);
     hasher.finish()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ExternalInput, TEST_CASES_FROM_FILE_PATH};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn finds_data_file_paths() {
+        let source = r#"
+            #[test]
+            #[test_cases_from_file("data/cases.json")]
+            fn a(x: felt252) {}
+
+            #[test_cases_from_file( "../cases.csv" )]
+            fn b(x: felt252) {}
+        "#;
+
+        let paths: Vec<_> = TEST_CASES_FROM_FILE_PATH
+            .captures_iter(source)
+            .map(|captures| captures[1].to_string())
+            .collect();
+
+        assert_eq!(paths, ["data/cases.json", "../cases.csv"]);
+    }
+
+    #[test]
+    fn workspace_root_is_scoped_to_the_enclosing_workspace() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        let workspace = root.join("workspace");
+        let member = workspace.join("crates").join("member");
+        let standalone = root.join("standalone");
+        fs::create_dir_all(member.join("src")).unwrap();
+        fs::create_dir_all(standalone.join("src")).unwrap();
+
+        // Unrelated manifest above both projects must not widen the scan
+        fs::write(root.join("Scarb.toml"), "[package]\nname = \"outer\"\n").unwrap();
+        fs::write(
+            workspace.join("Scarb.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        fs::write(member.join("Scarb.toml"), "[package]\nname = \"member\"\n").unwrap();
+        fs::write(
+            standalone.join("Scarb.toml"),
+            "[package]\nname = \"standalone\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            ExternalInput::workspace_root(&member.join("src")),
+            Some(workspace.clone())
+        );
+        assert_eq!(ExternalInput::workspace_root(&workspace), Some(workspace));
+        assert_eq!(
+            ExternalInput::workspace_root(&standalone.join("src")),
+            Some(standalone)
+        );
+    }
+}
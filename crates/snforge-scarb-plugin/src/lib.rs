@@ -3,11 +3,13 @@
 #![deny(clippy::disallowed_methods)]
 
 use attributes::fuzzer;
+use attributes::hooks::{after_each, before_each};
 use attributes::{
     available_gas::available_gas, disable_predeployed_contracts::disable_predeployed_contracts,
-    fork::fork, fuzzer::fuzzer, ignore::ignore,
-    internal_config_statement::internal_config_statement, should_panic::should_panic, test::test,
-    test_case::test_case,
+    fixture::fixture, fork::fork, fuzzer::fuzzer, ignore::ignore,
+    internal_config_statement::internal_config_statement, max_steps::max_steps, retry::retry,
+    seed::seed, setup::setup, should_panic::should_panic, tags::tags, test::test,
+    test_case::test_case, test_cases_from_file::test_cases_from_file, timeout::timeout,
 };
 use cairo_lang_macro::{
     ProcMacroResult, TokenStream, attribute_macro, derive_macro, executable_attribute, inline_macro,
@@ -31,6 +33,9 @@ mod utils;
 pub use utils::create_single_token;
 
 executable_attribute!("snforge_internal_test_executable");
+executable_attribute!("snforge_internal_fixture_executable");
+executable_attribute!("snforge_internal_before_each_executable");
+executable_attribute!("snforge_internal_after_each_executable");
 
 #[attribute_macro]
 fn __internal_config_statement(args: TokenStream, item: TokenStream) -> ProcMacroResult {
@@ -52,6 +57,11 @@ fn test_case(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     test_case(args, item)
 }
 
+#[attribute_macro]
+fn test_cases_from_file(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    test_cases_from_file(args, item)
+}
+
 #[attribute_macro]
 fn test(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     test(args, item)
@@ -82,6 +92,51 @@ fn should_panic(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     should_panic(args, item)
 }
 
+#[attribute_macro]
+fn seed(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    seed(args, item)
+}
+
+#[attribute_macro]
+fn timeout(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    timeout(args, item)
+}
+
+#[attribute_macro]
+fn max_steps(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    max_steps(args, item)
+}
+
+#[attribute_macro]
+fn retry(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    retry(args, item)
+}
+
+#[attribute_macro]
+fn tags(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    tags(args, item)
+}
+
+#[attribute_macro]
+fn fixture(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    fixture(args, item)
+}
+
+#[attribute_macro]
+fn setup(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    setup(args, item)
+}
+
+#[attribute_macro]
+fn before_each(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    before_each(args, item)
+}
+
+#[attribute_macro]
+fn after_each(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    after_each(args, item)
+}
+
 #[attribute_macro]
 fn disable_predeployed_contracts(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     disable_predeployed_contracts(args, item)
@@ -224,6 +224,22 @@ fn struct_with_nested_generic_field() {
     insta::assert_snapshot!(format_output(&result));
 }
 
+#[test]
+fn struct_with_nested_collection_fields() {
+    let item = quote!(
+        struct Matrix {
+            rows: Array<Array<u8>>,
+            weights: Span<u256>,
+        }
+    );
+
+    let result = fuzzable_derive(&item);
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
 #[test]
 fn struct_with_const_generic_param() {
     let item = quote!(
@@ -1,9 +1,18 @@
 mod available_gas;
 mod disable_predeployed_contracts;
+mod fixture;
 mod fork;
 mod fuzzer;
+mod hooks;
 mod ignore;
 mod internal_config_statement;
+mod max_steps;
+mod retry;
+mod seed;
+mod setup;
 mod should_panic;
+mod tags;
 mod test;
 mod test_case;
+mod test_cases_from_file;
+mod timeout;
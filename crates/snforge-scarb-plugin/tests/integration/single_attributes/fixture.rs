@@ -0,0 +1,95 @@
+use crate::utils::{assert_diagnostics, empty_function, format_output};
+use cairo_lang_macro::{
+    Diagnostic, ProcMacroResult, TokenStream, TokenStreamMetadata, TokenTree, quote,
+};
+use snforge_scarb_plugin::attributes::fixture::fixture;
+
+#[test]
+fn appends_executable_and_cached_result() {
+    let args = TokenStream::empty();
+
+    let result = fixture(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+fn fixture_key(result: &ProcMacroResult) -> String {
+    let output = result.token_stream.to_string();
+    let (_, key) = output
+        .split_once("fixture_result(\"")
+        .expect("Fixture should request its cached result");
+
+    key.split('"').next().unwrap().to_string()
+}
+
+#[test]
+fn identical_fixtures_in_different_modules_have_different_keys() {
+    let defined_at = |file: &str, offset: u32| {
+        let mut item =
+            empty_function().with_metadata(TokenStreamMetadata::new(file, "file_id", "2024_07"));
+        match &mut item.tokens[0] {
+            TokenTree::Ident(token) => token.span.start = offset,
+        }
+
+        fixture_key(&fixture(TokenStream::empty(), item))
+    };
+
+    let key = defined_at("src/lib.cairo", 10);
+
+    assert_eq!(key, defined_at("src/lib.cairo", 10));
+    // Inline module defined further in the same file
+    assert_ne!(key, defined_at("src/lib.cairo", 50));
+    // Module defined in another file
+    assert_ne!(key, defined_at("src/other.cairo", 10));
+}
+
+#[test]
+fn fails_with_non_empty_args() {
+    let args = quote!((123));
+
+    let result = fixture(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[fixture] does not accept any arguments",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_params() {
+    let item = quote!(
+        fn empty_fn(f: felt252) {}
+    );
+    let args = TokenStream::empty();
+
+    let result = fixture(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[fixture] function with parameters cannot be a fixture",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_test_attribute() {
+    let item = quote!(
+        #[test]
+        fn empty_fn() {}
+    );
+    let args = TokenStream::empty();
+
+    let result = fixture(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[fixture] cannot be used together with #[test]",
+        )],
+    );
+}
@@ -0,0 +1,92 @@
+use crate::utils::{assert_diagnostics, empty_function, format_output};
+use cairo_lang_macro::{Diagnostic, TokenStream, quote};
+use snforge_scarb_plugin::attributes::hooks::{after_each, before_each};
+
+#[test]
+fn before_each_appends_executable() {
+    let args = TokenStream::empty();
+
+    let result = before_each(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn after_each_appends_executable() {
+    let args = TokenStream::empty();
+
+    let result = after_each(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn fails_with_non_empty_args() {
+    let args = quote!((123));
+
+    let result = before_each(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[before_each] does not accept any arguments",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_params() {
+    let item = quote!(
+        fn empty_fn(f: felt252) {}
+    );
+    let args = TokenStream::empty();
+
+    let result = after_each(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[after_each] function with parameters cannot be a hook",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_test_attribute() {
+    let item = quote!(
+        #[test]
+        fn empty_fn() {}
+    );
+    let args = TokenStream::empty();
+
+    let result = before_each(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[before_each] cannot be used together with #[test]",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_fixture_attribute() {
+    let item = quote!(
+        #[fixture]
+        fn empty_fn() {}
+    );
+    let args = TokenStream::empty();
+
+    let result = after_each(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[after_each] cannot be used together with #[fixture]",
+        )],
+    );
+}
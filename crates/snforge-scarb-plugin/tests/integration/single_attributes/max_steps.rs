@@ -0,0 +1,70 @@
+use crate::utils::{assert_diagnostics, empty_function, format_output};
+use cairo_lang_macro::{Diagnostic, TokenStream, quote};
+use snforge_scarb_plugin::attributes::max_steps::max_steps;
+
+#[test]
+fn fails_without_args() {
+    let args = TokenStream::empty();
+
+    let result = max_steps(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[max_steps] expected arguments: 1, got: 0",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_too_many_args() {
+    let args = quote!((1000, 2000));
+
+    let result = max_steps(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[max_steps] expected arguments: 1, got: 2",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_zero() {
+    let args = quote!((0));
+
+    let result = max_steps(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[max_steps] max_steps must be greater than 0",
+        )],
+    );
+}
+
+#[test]
+fn handles_number_overflow() {
+    let args = quote!((4294967296));
+
+    let result = max_steps(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[max_steps] max_steps is too large (max permissible value is 4294967295)",
+        )],
+    );
+}
+
+#[test]
+fn works_with_number() {
+    let args = quote!((1000000));
+
+    let result = max_steps(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
@@ -0,0 +1,68 @@
+use crate::utils::{assert_diagnostics, empty_function, format_output};
+use cairo_lang_macro::{Diagnostic, TokenStream, quote};
+use snforge_scarb_plugin::attributes::retry::retry;
+
+#[test]
+fn fails_without_args() {
+    let args = TokenStream::empty();
+
+    let result = retry(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[retry] expected arguments: 1, got: 0")],
+    );
+}
+
+#[test]
+fn fails_with_too_many_args() {
+    let args = quote!((1, 2));
+
+    let result = retry(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[retry] expected arguments: 1, got: 2")],
+    );
+}
+
+#[test]
+fn handles_number_overflow() {
+    let args = quote!((4294967296));
+
+    let result = retry(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[retry] retries is too large (max permissible value is 4294967295)",
+        )],
+    );
+}
+
+#[test]
+fn works_with_number() {
+    let args = quote!((3));
+
+    let result = retry(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn is_used_once() {
+    let item = quote! {
+        #[retry(3)]
+        fn empty_fn() {}
+    };
+    let args = quote!((3));
+
+    let result = retry(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[retry] can only be used once per item")],
+    );
+}
@@ -0,0 +1,82 @@
+use crate::utils::{assert_diagnostics, empty_function, format_output};
+use cairo_lang_macro::{Diagnostic, TokenStream, quote};
+use snforge_scarb_plugin::attributes::seed::seed;
+
+#[test]
+fn fails_without_args() {
+    let args = TokenStream::empty();
+
+    let result = seed(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[seed] expected arguments: 1, got: 0")],
+    );
+}
+
+#[test]
+fn fails_with_named_args() {
+    let args = quote!((seed: 1234));
+
+    let result = seed(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[seed] can be used with unnamed arguments only",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_too_many_args() {
+    let args = quote!((1234, 5678));
+
+    let result = seed(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[seed] expected arguments: 1, got: 2")],
+    );
+}
+
+#[test]
+fn handles_number_overflow() {
+    let args = quote!((18446744073709551616));
+
+    let result = seed(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[seed] seed is too large (max permissible value is 18446744073709551615)",
+        )],
+    );
+}
+
+#[test]
+fn works_with_number() {
+    let args = quote!((1234));
+
+    let result = seed(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn is_used_once() {
+    let item = quote! {
+        #[seed(1234)]
+        fn empty_fn() {}
+    };
+    let args = quote!((1234));
+
+    let result = seed(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[seed] can only be used once per item")],
+    );
+}
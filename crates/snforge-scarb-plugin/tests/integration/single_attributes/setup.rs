@@ -0,0 +1,56 @@
+use crate::utils::{assert_diagnostics, empty_function, format_output};
+use cairo_lang_macro::{Diagnostic, TokenStream, quote};
+use snforge_scarb_plugin::attributes::setup::setup;
+
+#[test]
+fn fails_without_args() {
+    let args = TokenStream::empty();
+
+    let result = setup(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[setup] expected arguments: 1, got: 0")],
+    );
+}
+
+#[test]
+fn fails_with_non_path_arg() {
+    let args = quote!(("deployed"));
+
+    let result = setup(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[setup] <fixture> argument must be a path to a function marked with #[fixture]",
+        )],
+    );
+}
+
+#[test]
+fn works_with_path() {
+    let args = quote!((utils::deployed));
+
+    let result = setup(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn is_used_once() {
+    let item = quote! {
+        #[setup(deployed)]
+        fn empty_fn() {}
+    };
+    let args = quote!((deployed));
+
+    let result = setup(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[setup] can only be used once per item")],
+    );
+}
@@ -91,6 +91,65 @@ fn work_with_expected_mixed_tuple() {
     insta::assert_snapshot!(format_output(&result));
 }
 
+#[test]
+fn work_with_matchers_and_origin() {
+    let args = TokenStream::new(vec![TokenTree::Ident(Token::new(
+        r#"(expected: one_of(error(MyError::NotOwner), variant(MyError::Insufficient(0)), regex("out of \\d+ tokens")), contract: "Vault", function: "withdraw")"#,
+        TextSpan::call_site(),
+    ))]);
+
+    let result = should_panic(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn fails_with_nested_one_of() {
+    let args = TokenStream::new(vec![TokenTree::Ident(Token::new(
+        "(expected: one_of('a', one_of('b', 'c')))",
+        TextSpan::call_site(),
+    ))]);
+
+    let result = should_panic(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[should_panic] <expected> one_of(...) cannot be nested",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_invalid_matcher_arguments() {
+    let args = quote!((expected: error(1, 2)));
+
+    let result = should_panic(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[should_panic] <expected> error(...) expects exactly one argument, got: 2",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_invalid_regex() {
+    let args = quote!((expected: regex("(unclosed")));
+
+    let result = should_panic(args, empty_function());
+
+    assert_eq!(result.diagnostics.len(), 1);
+    assert!(result.diagnostics.iter().all(|diagnostic| {
+        diagnostic
+            .message()
+            .starts_with("#[should_panic] <expected> invalid regex:")
+    }));
+}
+
 #[test]
 fn is_used_once() {
     let item = quote!(
@@ -132,7 +191,7 @@ fn fails_with_unnamed_arg() {
     assert_diagnostics(
         &result,
         &[Diagnostic::error(
-            "#[should_panic] can be used with named arguments only [possible values: expected]. invalid arguments found: \"uwu\"",
+            "#[should_panic] can be used with named arguments only [possible values: expected, contract, function]. invalid arguments found: \"uwu\"",
         )],
     );
 }
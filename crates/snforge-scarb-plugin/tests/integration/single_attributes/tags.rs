@@ -0,0 +1,98 @@
+use crate::utils::{assert_diagnostics, empty_function, format_output};
+use cairo_lang_macro::{Diagnostic, TokenStream, quote};
+use snforge_scarb_plugin::attributes::tags::tags;
+
+#[test]
+fn fails_without_args() {
+    let args = TokenStream::empty();
+
+    let result = tags(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[tags] expected at least one tag")],
+    );
+}
+
+#[test]
+fn fails_with_named_args() {
+    let args = quote!((tag: "slow"));
+
+    let result = tags(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[tags] can be used with unnamed arguments only",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_non_string() {
+    let args = quote!((slow));
+
+    let result = tags(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[tags] <0> invalid type, should be: double quoted string",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_invalid_characters() {
+    let args = quote!(("slow & fork"));
+
+    let result = tags(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[tags] tag \"slow & fork\" must be non-empty and contain only ASCII letters, digits, `_` and `-`",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_duplicated_tag() {
+    let args = quote!(("slow", "slow"));
+
+    let result = tags(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[tags] tag \"slow\" is used more than once",
+        )],
+    );
+}
+
+#[test]
+fn works_with_strings() {
+    let args = quote!(("slow", "fork-lane"));
+
+    let result = tags(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn is_used_once() {
+    let item = quote! {
+        #[tags("slow")]
+        fn empty_fn() {}
+    };
+    let args = quote!(("slow"));
+
+    let result = tags(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("#[tags] can only be used once per item")],
+    );
+}
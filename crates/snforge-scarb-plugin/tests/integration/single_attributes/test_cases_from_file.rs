@@ -0,0 +1,93 @@
+use crate::utils::{assert_diagnostics, empty_function, format_output};
+use cairo_lang_macro::{Diagnostic, TokenStream, quote};
+use snforge_scarb_plugin::attributes::test_cases_from_file::test_cases_from_file;
+
+fn function_with_params() -> TokenStream {
+    quote!(
+        fn test_add(x: u8, y: u8, expected: u8) {}
+    )
+}
+
+#[test]
+fn works_with_json() {
+    let args = quote!(("tests/data/test_cases_from_file/additions.json"));
+
+    let result = test_cases_from_file(args, function_with_params());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn works_with_csv() {
+    let args = quote!(("tests/data/test_cases_from_file/greetings.csv"));
+    let item = quote!(
+        fn test_greet(who: ByteArray, tag: felt252, enabled: bool) {}
+    );
+
+    let result = test_cases_from_file(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn value_out_of_range() {
+    let args = quote!(("tests/data/test_cases_from_file/out_of_range.json"));
+
+    let result = test_cases_from_file(args, function_with_params());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_cases_from_file] tests/data/test_cases_from_file/out_of_range.json: row 2: parameter `x`: `300` does not fit into u8",
+        )],
+    );
+}
+
+#[test]
+fn unsupported_param_type() {
+    let args = quote!(("tests/data/test_cases_from_file/additions.json"));
+    let item = quote!(
+        fn test_add(x: Array<u8>, y: u8, expected: u8) {}
+    );
+
+    let result = test_cases_from_file(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_cases_from_file] parameter `x`: unsupported type `Array<u8>`, supported types are: felt252, bool, ByteArray, u8, u16, u32, u64, u128, u256, usize, i8, i16, i32, i64, i128",
+        )],
+    );
+}
+
+#[test]
+fn unsupported_file_format() {
+    let args = quote!(("Scarb.toml"));
+
+    let result = test_cases_from_file(args, function_with_params());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_cases_from_file] Scarb.toml: unsupported file format, expected a .json or .csv file",
+        )],
+    );
+}
+
+#[test]
+fn function_without_params() {
+    let args = quote!(("tests/data/test_cases_from_file/additions.json"));
+
+    let result = test_cases_from_file(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[test_cases_from_file] The function must have at least one parameter to use #[test_cases_from_file] attribute",
+        )],
+    );
+}
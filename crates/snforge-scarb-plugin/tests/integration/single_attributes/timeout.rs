@@ -0,0 +1,88 @@
+use crate::utils::{assert_diagnostics, empty_function, format_output};
+use cairo_lang_macro::{Diagnostic, TokenStream, quote};
+use snforge_scarb_plugin::attributes::timeout::timeout;
+
+#[test]
+fn fails_without_args() {
+    let args = TokenStream::empty();
+
+    let result = timeout(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] expected arguments: 1, got: 0",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_named_args() {
+    let args = quote!((seconds: 10));
+
+    let result = timeout(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] can be used with unnamed arguments only",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_zero() {
+    let args = quote!((0));
+
+    let result = timeout(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] timeout must be greater than 0",
+        )],
+    );
+}
+
+#[test]
+fn handles_number_overflow() {
+    let args = quote!((18446744073709551616));
+
+    let result = timeout(args, empty_function());
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] timeout is too large (max permissible value is 18446744073709551615)",
+        )],
+    );
+}
+
+#[test]
+fn works_with_number() {
+    let args = quote!((30));
+
+    let result = timeout(args, empty_function());
+
+    assert_diagnostics(&result, &[]);
+
+    insta::assert_snapshot!(format_output(&result));
+}
+
+#[test]
+fn is_used_once() {
+    let item = quote! {
+        #[timeout(30)]
+        fn empty_fn() {}
+    };
+    let args = quote!((30));
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] can only be used once per item",
+        )],
+    );
+}
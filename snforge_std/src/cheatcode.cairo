@@ -39,3 +39,11 @@ pub fn save_fuzzer_arg<T, +core::fmt::Debug<T>>(input: @T) {
     input.serialize(ref serialized);
     execute_cheatcode::<'save_fuzzer_arg'>(serialized.span());
 }
+
+// Do not use this function directly.
+// It is an internal part of the snforge fixture logic used by macros.
+pub fn fixture_result(key: ByteArray) -> Option<Span<felt252>> {
+    let mut input = array![];
+    key.serialize(ref input);
+    execute_cheatcode_and_deserialize::<'get_fixture_result'>(input.span())
+}
@@ -1,6 +1,8 @@
 use starknet::{ClassHash, ContractAddress};
 use super::cheatcode::execute_cheatcode_and_deserialize;
 pub mod block_hash;
+pub mod block_progression;
+pub mod class_replacements;
 pub mod contract_class;
 pub mod erc20;
 
@@ -9,8 +11,13 @@ pub mod execution_info;
 pub mod generate_arg;
 pub mod generate_random_felt;
 pub mod l1_handler;
+pub mod l1_messaging;
 pub mod message_to_l1;
+pub mod predeployment;
+pub mod replay;
 pub mod storage;
+pub mod storage_accesses;
+pub mod transaction;
 
 /// Enum used to specify how long the target should be cheated for.
 #[derive(Copy, Drop, Serde, PartialEq, Clone, Debug)]
@@ -87,8 +94,8 @@ pub fn start_mock_call<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: De
     execute_cheatcode_and_deserialize::<'mock_call', ()>(inputs.span());
 }
 
-/// Cancels the `mock_call` / `start_mock_call` for the function with given name and contract
-/// address.
+/// Cancels the `mock_call` / `start_mock_call` / `mock_call_when` for the function with given name
+/// and contract address.
 /// - `contract_address` - targeted contracts' address
 /// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
 /// macro)
@@ -99,6 +106,55 @@ pub fn stop_mock_call(contract_address: ContractAddress, function_selector: felt
     >(array![contract_address_felt, function_selector].span());
 }
 
+/// Specifies which calls are mocked by `mock_call_when`, based on their calldata.
+#[derive(Drop, Serde, PartialEq, Clone, Debug)]
+pub enum CalldataMatcher {
+    /// Matches calls with any calldata
+    Any,
+    /// Matches calls with exactly the given calldata
+    Exact: Array<felt252>,
+    /// Matches calls with calldata starting with the given felts
+    Prefix: Array<felt252>,
+}
+
+/// Response returned by a call mocked with `mock_call_when`.
+#[derive(Drop, Serde, PartialEq, Clone, Debug)]
+pub enum MockedResponse {
+    /// The call succeeds and returns the given (serialized) data
+    Return: Array<felt252>,
+    /// The call reverts with the given panic data
+    Revert: Array<felt252>,
+}
+
+/// Mocks calls to a `function_selector` of a contract at the given address, whose calldata is
+/// matched by `calldata`, until canceled with `stop_mock_call`.
+/// Subsequent matching calls return consecutive `responses`, the last response is returned for all
+/// calls after that.
+/// An address with no contract can be mocked as well.
+/// If more than one mock matches a call, `Exact` takes precedence over the longest `Prefix`,
+/// which takes precedence over `Any`. Mocking with a matcher that was already used replaces the
+/// previous mock. Mocks created with `mock_call_when` take precedence over `mock_call` ones.
+/// - `contract_address` - target contract address
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `calldata` - matcher of the (serialized) calldata of the mocked calls
+/// - `responses` - responses to return from the matching calls, in order
+pub fn mock_call_when(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: CalldataMatcher,
+    responses: Array<MockedResponse>,
+) {
+    assert!(responses.len() > 0, "`mock_call_when` requires at least one response");
+
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut inputs = array![contract_address_felt, function_selector];
+    calldata.serialize(ref inputs);
+    responses.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'mock_call_when', ()>(inputs.span());
+}
+
 #[derive(Drop, Serde, PartialEq, Debug)]
 pub enum ReplaceBytecodeError {
     /// Means that the contract does not exist, and thus bytecode cannot be replaced
@@ -121,3 +177,57 @@ pub fn replace_bytecode(
         'replace_bytecode',
     >(array![contract.into(), new_class.into()].span())
 }
+
+/// Upgrades a deployed contract (e.g. one from the fork) to a class of a locally compiled contract.
+/// The local contract is declared if it was not declared before. Unlike `replace_bytecode`, the
+/// class hash of the contract is changed, the same way `replace_class_syscall` would change it.
+/// - `contract` - address of the contract to upgrade
+/// - `contract_name` - name of the local contract, the same as passed to `declare`
+/// Returns `Result::Ok` with the class hash of the new class if the upgrade succeeded, and
+/// `ReplaceBytecodeError::ContractNotDeployed` if there is no contract at the given address
+pub fn upgrade_forked_contract(
+    contract: ContractAddress, contract_name: ByteArray,
+) -> Result<ClassHash, ReplaceBytecodeError> {
+    let mut inputs = array![contract.into()];
+    contract_name.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'upgrade_forked_contract'>(inputs.span())
+}
+
+/// Changes the class of the contract at the given address for a given number of calls, without
+/// changing the class hash stored in the state. The contract executes its original class again
+/// once the span passes or the cheat is canceled with `stop_cheat_class_hash`.
+/// Takes precedence over the class set with `replace_bytecode`.
+/// - `contract` - address of the cheated contract
+/// - `class_hash` - class hash of a declared class to execute the contract calls with
+/// - `span` - instance of `CheatSpan` specifying the number of calls to the contract to cheat
+/// Returns `Result::Ok` if the contract was cheated, and a `ReplaceBytecodeError` with appropriate
+/// error type otherwise
+pub fn cheat_class_hash(
+    contract: ContractAddress, class_hash: ClassHash, span: CheatSpan,
+) -> Result<(), ReplaceBytecodeError> {
+    let mut inputs = array![contract.into(), class_hash.into()];
+    span.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'cheat_class_hash'>(inputs.span())
+}
+
+/// Changes the class of the contract at the given address until the cheat is canceled with
+/// `stop_cheat_class_hash`. See `cheat_class_hash` for details.
+/// - `contract` - address of the cheated contract
+/// - `class_hash` - class hash of a declared class to execute the contract calls with
+/// Returns `Result::Ok` if the contract was cheated, and a `ReplaceBytecodeError` with appropriate
+/// error type otherwise
+pub fn start_cheat_class_hash(
+    contract: ContractAddress, class_hash: ClassHash,
+) -> Result<(), ReplaceBytecodeError> {
+    cheat_class_hash(contract, class_hash, CheatSpan::Indefinite)
+}
+
+/// Cancels the `cheat_class_hash` / `start_cheat_class_hash` for the contract at the given address.
+/// - `contract` - address of the cheated contract
+pub fn stop_cheat_class_hash(contract: ContractAddress) {
+    execute_cheatcode_and_deserialize::<
+        'stop_cheat_class_hash', (),
+    >(array![contract.into()].span());
+}
@@ -0,0 +1,49 @@
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Current block after advancing with `roll` or `warp`.
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct Block {
+    pub block_number: u64,
+    pub block_timestamp: u64,
+}
+
+/// Sets the number of seconds between consecutive blocks produced by `roll` and `warp`
+/// (6 by default).
+/// - `block_time` - number of seconds, has to be greater than 0
+pub fn set_block_time(block_time: u64) {
+    execute_cheatcode_and_deserialize::<'set_block_time', ()>(array![block_time.into()].span());
+}
+
+/// Advances the block number by `blocks` and the block timestamp to the start of the new block.
+/// Block number and timestamp are changed globally, like with `start_cheat_block_number_global`
+/// and `start_cheat_block_timestamp_global`, and hashes of the blocks left behind are returned by
+/// `get_block_hash_syscall`.
+/// - `blocks` - number of blocks to advance by
+/// Returns the new current block.
+pub fn roll(blocks: u64) -> Block {
+    execute_cheatcode_and_deserialize::<'roll'>(array![blocks.into()].span())
+}
+
+/// Advances the block timestamp by `seconds` and the block number by the number of blocks
+/// started in that time.
+/// Block number and timestamp are changed globally, like with `start_cheat_block_number_global`
+/// and `start_cheat_block_timestamp_global`, and hashes of the blocks left behind are returned by
+/// `get_block_hash_syscall`.
+/// - `seconds` - number of seconds to advance by
+/// Returns the new current block.
+pub fn warp(seconds: u64) -> Block {
+    execute_cheatcode_and_deserialize::<'warp'>(array![seconds.into()].span())
+}
+
+/// Advances the block number by `blocks` one by one, calling `hook` after each block is started.
+/// - `blocks` - number of blocks to advance by
+/// - `hook` - closure called with each new block
+pub fn roll_with_hook<F, +Drop<F>, impl func: core::ops::Fn<F, (Block,)>, +Drop<func::Output>>(
+    blocks: u64, hook: F,
+) {
+    let mut rolled = 0;
+    while rolled != blocks {
+        hook(roll(1));
+        rolled += 1;
+    }
+}
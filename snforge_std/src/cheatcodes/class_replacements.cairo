@@ -0,0 +1,93 @@
+use starknet::{ClassHash, ContractAddress};
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Creates `ClassReplacementSpy` instance that spies on all classes replaced by contracts with
+/// `replace_class_syscall`
+pub fn spy_class_replacements() -> ClassReplacementSpy {
+    execute_cheatcode_and_deserialize::<'spy_class_replacements'>(array![].span())
+}
+
+/// Replacement of a contract class done with `replace_class_syscall`
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct ClassReplacement {
+    /// Address of the contract whose class was replaced
+    pub contract_address: ContractAddress,
+    /// Class hash of the contract before the replacement
+    pub old_class_hash: ClassHash,
+    /// Class hash the contract was upgraded to
+    pub new_class_hash: ClassHash,
+}
+
+/// A class replacement spy structure allowing to get replacements done only after its creation.
+#[derive(Drop, Serde)]
+pub struct ClassReplacementSpy {
+    replacement_offset: usize,
+}
+
+pub trait ClassReplacementSpyTrait {
+    /// Gets all class replacements given [`ClassReplacementSpy`] spies for, in the order they were
+    /// done.
+    fn get_class_replacements(ref self: ClassReplacementSpy) -> Array<ClassReplacement>;
+}
+
+impl ClassReplacementSpyTraitImpl of ClassReplacementSpyTrait {
+    fn get_class_replacements(ref self: ClassReplacementSpy) -> Array<ClassReplacement> {
+        execute_cheatcode_and_deserialize::<
+            'get_class_replacements',
+        >(array![self.replacement_offset.into()].span())
+    }
+}
+
+/// Allows to assert the expected class replacements (or lack thereof),
+/// in the scope of [`ClassReplacementSpy`] structure.
+pub trait ClassReplacementSpyAssertionsTrait {
+    fn assert_replaced(ref self: ClassReplacementSpy, replacements: @Array<ClassReplacement>);
+    fn assert_not_replaced(ref self: ClassReplacementSpy, replacements: @Array<ClassReplacement>);
+}
+
+impl ClassReplacementSpyAssertionsTraitImpl of ClassReplacementSpyAssertionsTrait {
+    fn assert_replaced(ref self: ClassReplacementSpy, replacements: @Array<ClassReplacement>) {
+        let mut i = 0;
+        let done_replacements = self.get_class_replacements();
+
+        while i < replacements.len() {
+            let replacement = replacements.at(i);
+
+            if !is_replaced(@done_replacements, replacement) {
+                let contract: felt252 = (*replacement.contract_address).into();
+                panic!(
+                    "Class of contract {} was not replaced with matching class hashes", contract,
+                );
+            }
+            i += 1;
+        };
+    }
+    fn assert_not_replaced(ref self: ClassReplacementSpy, replacements: @Array<ClassReplacement>) {
+        let mut i = 0;
+        let done_replacements = self.get_class_replacements();
+
+        while i < replacements.len() {
+            let replacement = replacements.at(i);
+
+            if is_replaced(@done_replacements, replacement) {
+                let contract: felt252 = (*replacement.contract_address).into();
+                panic!("Class of contract {} was replaced with matching class hashes", contract);
+            }
+            i += 1;
+        };
+    }
+}
+
+fn is_replaced(replacements: @Array<ClassReplacement>, expected: @ClassReplacement) -> bool {
+    let mut i = 0;
+    let mut is_replaced = false;
+    while i < replacements.len() {
+        if replacements.at(i) == expected {
+            is_replaced = true;
+            break;
+        }
+
+        i += 1;
+    }
+    return is_replaced;
+}
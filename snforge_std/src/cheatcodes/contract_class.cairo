@@ -1,3 +1,4 @@
+use core::fmt::{Display, Error, Formatter};
 use starknet::{ClassHash, ContractAddress, SyscallResult};
 use crate::byte_array::byte_array_as_felt_array;
 use crate::cheatcode::execute_cheatcode_and_deserialize;
@@ -13,6 +14,48 @@ pub enum DeclareResult {
     AlreadyDeclared: ContractClass,
 }
 
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub enum DeclareError {
+    /// No contract matches the given identifier, or the given file does not exist
+    ContractNotFound: ByteArray,
+    /// Several contracts match the given identifier. Carries the identifier and the module paths
+    /// of the matching contracts
+    AmbiguousContractName: (ByteArray, Array<ByteArray>),
+    /// The provided contract class could not be parsed
+    InvalidClass: ByteArray,
+    /// The provided Sierra could not be compiled
+    CompilationFailed: ByteArray,
+}
+
+impl DisplayDeclareError of Display<DeclareError> {
+    fn fmt(self: @DeclareError, ref f: Formatter) -> Result<(), Error> {
+        match self {
+            DeclareError::ContractNotFound(identifier) => {
+                write!(f, "Failed to get contract artifact for identifier = {}.", identifier)?;
+            },
+            DeclareError::AmbiguousContractName((
+                identifier, module_paths,
+            )) => {
+                write!(
+                    f,
+                    "Multiple contracts found with identifier = {}. Found contracts at the following paths:",
+                    identifier,
+                )?;
+                for module_path in module_paths.span() {
+                    write!(f, "\n - {}", module_path)?;
+                }
+                write!(
+                    f,
+                    "\nUse a module path to disambiguate, or rename one of the contracts so that the identifier is unique.",
+                )?;
+            },
+            DeclareError::InvalidClass(message) => { Display::fmt(message, ref f)?; },
+            DeclareError::CompilationFailed(message) => { Display::fmt(message, ref f)?; },
+        }
+        Result::Ok(())
+    }
+}
+
 pub trait ContractClassTrait {
     /// Calculates an address of a contract in advance that would be returned when calling `deploy`
     /// The precalculated address is only correct for the very next deployment
@@ -110,17 +153,44 @@ impl DeclareResultImpl of DeclareResultTrait {
     }
 }
 
+pub trait DeclareOutcomeTrait {
+    /// Gets the `DeclareResult` of a successful declaration
+    /// `self` - the result of `try_declare`, `try_declare_from_file`, `declare_sierra` or
+    /// `declare_cairo0`
+    /// Returns the `DeclareResult`, panics with the description of the `DeclareError` otherwise
+    fn unwrap_declared(self: Result<DeclareResult, DeclareError>) -> DeclareResult;
+}
+
+impl DeclareOutcomeImpl of DeclareOutcomeTrait {
+    fn unwrap_declared(self: Result<DeclareResult, DeclareError>) -> DeclareResult {
+        match self {
+            Result::Ok(declare_result) => declare_result,
+            Result::Err(error) => panic!("{}", error),
+        }
+    }
+}
+
 /// Declares a contract
 /// `contract` - name of a contract as Cairo string. It is a name of the contract (part after mod
-/// keyword) e.g. "HelloStarknet"
+/// keyword) e.g. "HelloStarknet", its module path e.g. "my_package::module::HelloStarknet", or a
+/// package name followed by the contract name e.g. "my_dependency::HelloStarknet"
 /// Returns the `DeclareResult` that encapsulated possible outcomes in the enum:
 /// - `Success`: Contains the successfully declared `ContractClass`.
 /// - `AlreadyDeclared`: Contains `ContractClass` and signals that the contract has already been
 /// declared.
+/// The test fails with the description of the error if the contract could not be declared, use
+/// `try_declare` to handle the `DeclareError` instead
 pub fn declare(contract: ByteArray) -> Result<DeclareResult, Array<felt252>> {
     execute_cheatcode_and_deserialize::<'declare'>(byte_array_as_felt_array(@contract).span())
 }
 
+/// Declares a contract the same way `declare` does
+/// `contract` - name of a contract as Cairo string, see `declare`
+/// Returns the `DeclareResult`, or `DeclareError` if the contract could not be declared
+pub fn try_declare(contract: ByteArray) -> Result<DeclareResult, DeclareError> {
+    execute_cheatcode_and_deserialize::<'try_declare'>(byte_array_as_felt_array(@contract).span())
+}
+
 /// Declares a contract from a Sierra contract class JSON file
 /// `path` - path to a Sierra contract class JSON file
 /// If the file's class hash matches a package contract already loaded by `snforge`, the loaded
@@ -129,10 +199,40 @@ pub fn declare(contract: ByteArray) -> Result<DeclareResult, Array<felt252>> {
 /// - `Success`: Contains the successfully declared `ContractClass`.
 /// - `AlreadyDeclared`: Contains `ContractClass` and signals that the contract has already been
 /// declared.
+/// The test fails with the description of the error if the contract could not be declared, use
+/// `try_declare_from_file` to handle the `DeclareError` instead
 pub fn declare_from_file(path: ByteArray) -> Result<DeclareResult, Array<felt252>> {
     execute_cheatcode_and_deserialize::<'declare_from_file'>(byte_array_as_felt_array(@path).span())
 }
 
+/// Declares a contract from a Sierra contract class JSON file the same way `declare_from_file` does
+/// `path` - path to a Sierra contract class JSON file
+/// Returns the `DeclareResult`, or `DeclareError` if the contract could not be declared
+pub fn try_declare_from_file(path: ByteArray) -> Result<DeclareResult, DeclareError> {
+    execute_cheatcode_and_deserialize::<
+        'try_declare_from_file',
+    >(byte_array_as_felt_array(@path).span())
+}
+
+/// Declares a contract from a Sierra contract class JSON held in memory
+/// `sierra` - Sierra contract class JSON
+/// Returns the `DeclareResult` the same way `declare` does, or `DeclareError` if the contract
+/// could not be declared
+pub fn declare_sierra(sierra: ByteArray) -> Result<DeclareResult, DeclareError> {
+    execute_cheatcode_and_deserialize::<'declare_sierra'>(byte_array_as_felt_array(@sierra).span())
+}
+
+/// Declares a Cairo 0 contract
+/// `compiled_class` - compiled Cairo 0 contract class JSON, as produced by
+/// `starknet-compile-deprecated`
+/// Returns the `DeclareResult` the same way `declare` does, or `DeclareError` if the contract
+/// could not be declared
+pub fn declare_cairo0(compiled_class: ByteArray) -> Result<DeclareResult, DeclareError> {
+    execute_cheatcode_and_deserialize::<
+        'declare_cairo0',
+    >(byte_array_as_felt_array(@compiled_class).span())
+}
+
 /// Retrieves a class hash of a contract deployed under the given address
 /// `contract_address` - target contract address
 /// Returns the `ClassHash` under given address
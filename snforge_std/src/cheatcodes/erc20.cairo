@@ -1,5 +1,6 @@
 use snforge_std::cheatcodes::storage::{map_entry_address, store};
 use starknet::ContractAddress;
+use crate::cheatcode::execute_cheatcode_and_deserialize;
 
 const STRK_CONTRACT_ADDRESS: felt252 =
     0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d;
@@ -54,3 +55,72 @@ pub fn set_balance(target: ContractAddress, new_balance: u256, token: Token) {
     store(token.contract_address(), balance_low_address, array![new_balance.low.into()].span());
     store(token.contract_address(), balance_high_address, array![new_balance.high.into()].span());
 }
+
+/// Sets the balance of `account` in any ERC20 or ERC721 `token` to `new_balance`.
+/// The storage slot of the balance is discovered by tracing storage reads of the `balance_of`
+/// (or `balanceOf`) call, so the token can use any storage layout.
+/// - `token` - address of the token contract
+/// - `account` - address of the account, which balance you want to modify
+/// - `new_balance` - new balance value
+pub fn set_token_balance(token: ContractAddress, account: ContractAddress, new_balance: u256) {
+    let mut value = array![];
+    new_balance.serialize(ref value);
+
+    set_token_storage(
+        token,
+        array![selector!("balance_of"), selector!("balanceOf")],
+        array![account.into()],
+        value,
+    );
+}
+
+/// Sets the allowance of `spender` for tokens of `owner` in any ERC20 `token` to `new_allowance`.
+/// The storage slot of the allowance is discovered by tracing storage reads of the `allowance`
+/// call, so the token can use any storage layout.
+/// - `token` - address of the token contract
+/// - `owner` - address of the owner of the tokens
+/// - `spender` - address of the spender
+/// - `new_allowance` - new allowance value
+pub fn set_token_allowance(
+    token: ContractAddress, owner: ContractAddress, spender: ContractAddress, new_allowance: u256,
+) {
+    let mut value = array![];
+    new_allowance.serialize(ref value);
+
+    set_token_storage(
+        token, array![selector!("allowance")], array![owner.into(), spender.into()], value,
+    );
+}
+
+/// Sets the owner of an existing `token_id` in any ERC721 `token` to `new_owner`.
+/// The storage slot of the owner is discovered by tracing storage reads of the `owner_of`
+/// (or `ownerOf`) call, so the token can use any storage layout.
+/// Balances of the previous and the new owner are not modified.
+/// - `token` - address of the token contract
+/// - `token_id` - id of the token, has to be already minted
+/// - `new_owner` - address of the new owner
+pub fn set_token_owner(token: ContractAddress, token_id: u256, new_owner: ContractAddress) {
+    let mut calldata = array![];
+    token_id.serialize(ref calldata);
+
+    set_token_storage(
+        token,
+        array![selector!("owner_of"), selector!("ownerOf")],
+        calldata,
+        array![new_owner.into()],
+    );
+}
+
+fn set_token_storage(
+    token: ContractAddress,
+    getter_selectors: Array<felt252>,
+    getter_calldata: Array<felt252>,
+    value: Array<felt252>,
+) {
+    let mut inputs = array![token.into()];
+    getter_selectors.serialize(ref inputs);
+    getter_calldata.serialize(ref inputs);
+    value.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'set_token_storage', ()>(inputs.span());
+}
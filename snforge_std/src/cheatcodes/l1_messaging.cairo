@@ -0,0 +1,126 @@
+use starknet::{ContractAddress, EthAddress, SyscallResult};
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Message sent from L1 to L2 through the simulated Starknet core contract
+#[derive(Drop, Clone, Serde, Debug, PartialEq)]
+pub struct MessageToL2 {
+    /// Ethereum address of the contract that sent the message
+    pub from_address: EthAddress,
+    /// Address of the contract handling the message
+    pub to_address: ContractAddress,
+    /// Selector of the `#[l1_handler]` function handling the message
+    pub selector: felt252,
+    /// The handler function arguments
+    pub payload: Array<felt252>,
+    pub nonce: felt252,
+    /// Fee paid on L1 for handling the message
+    pub fee: u128,
+    /// Hash of the message, computed the same way as in the Starknet core contract
+    pub hash: u256,
+}
+
+#[derive(Drop, Copy, Serde, Debug, PartialEq)]
+pub enum MessageToL2Status {
+    /// Message was sent and can be executed
+    Pending,
+    /// Message was executed by its L1 handler
+    Consumed,
+    /// Cancellation of the message was started at the given timestamp, the message can still be
+    /// executed until the cancellation is completed
+    CancellationStarted: u64,
+    /// Message was cancelled and can no longer be executed
+    Cancelled,
+}
+
+/// Sends a message from L1 to L2, the way `sendMessageToL2` of the Starknet core contract does.
+/// The message is pending until it is executed with `MessageToL2Trait::execute`.
+/// `from_address` - Ethereum address of the contract sending the message
+/// `to_address` - address of the contract handling the message
+/// `selector` - selector of the `#[l1_handler]` function handling the message
+/// `payload` - the handler function arguments serialized with `Serde`
+/// `fee` - fee paid on L1 for handling the message, has to be greater than 0
+/// Returns the sent `MessageToL2` with its nonce and hash
+pub fn send_message_to_l2(
+    from_address: EthAddress,
+    to_address: ContractAddress,
+    selector: felt252,
+    payload: Span<felt252>,
+    fee: u128,
+) -> MessageToL2 {
+    let mut inputs = array![from_address.into(), to_address.into(), selector];
+    payload.serialize(ref inputs);
+    fee.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'send_message_to_l2'>(inputs.span())
+}
+
+/// Consumes a message sent from L2 to L1, the way `consumeMessageFromL2` of the Starknet core
+/// contract does. Each sent message can be consumed once.
+/// `from_address` - address of the contract that sent the message
+/// `to_address` - Ethereum address the message was sent to
+/// `payload` - payload of the message
+/// Returns the hash of the consumed message, or `Option::None` if there is no such message left to
+/// consume
+pub fn consume_message_from_l2(
+    from_address: ContractAddress, to_address: EthAddress, payload: Span<felt252>,
+) -> Option<u256> {
+    let mut inputs = array![from_address.into(), to_address.into()];
+    payload.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'consume_message_from_l2'>(inputs.span())
+}
+
+/// Changes the number of seconds after which a started cancellation of a message to L2 can be
+/// completed. Defaults to 5 days, the same as on Starknet mainnet.
+/// `cancellation_delay` - the delay in seconds
+pub fn set_message_cancellation_delay(cancellation_delay: u64) {
+    execute_cheatcode_and_deserialize::<
+        'set_message_cancellation_delay', (),
+    >(array![cancellation_delay.into()].span());
+}
+
+pub trait MessageToL2Trait {
+    /// Executes the message by calling its L1 handler, the way the sequencer does.
+    /// The message is consumed if the handler succeeds. Fails the test if the message was already
+    /// consumed or cancelled.
+    /// Returns () or panic data if the handler failed
+    fn execute(self: @MessageToL2) -> SyscallResult<()>;
+
+    /// Returns the current `MessageToL2Status` of the message
+    fn status(self: @MessageToL2) -> MessageToL2Status;
+
+    /// Starts the cancellation of a pending message, the way `startL1ToL2MessageCancellation`
+    /// does. The cancellation can be completed after the cancellation delay passes.
+    fn start_cancellation(self: @MessageToL2);
+
+    /// Completes the cancellation of the message, the way `cancelL1ToL2Message` does.
+    /// Fails the test if the cancellation was not started or the cancellation delay has not passed
+    /// yet, use `warp` to advance the block timestamp.
+    fn cancel(self: @MessageToL2);
+}
+
+impl MessageToL2Impl of MessageToL2Trait {
+    fn execute(self: @MessageToL2) -> SyscallResult<()> {
+        execute_cheatcode_and_deserialize::<'execute_message_to_l2'>(_hash_input(self))
+    }
+
+    fn status(self: @MessageToL2) -> MessageToL2Status {
+        execute_cheatcode_and_deserialize::<'get_message_to_l2_status'>(_hash_input(self))
+    }
+
+    fn start_cancellation(self: @MessageToL2) {
+        execute_cheatcode_and_deserialize::<
+            'start_message_to_l2_cancellation', (),
+        >(_hash_input(self));
+    }
+
+    fn cancel(self: @MessageToL2) {
+        execute_cheatcode_and_deserialize::<'cancel_message_to_l2', ()>(_hash_input(self));
+    }
+}
+
+fn _hash_input(message: @MessageToL2) -> Span<felt252> {
+    let mut inputs = array![];
+    message.hash.serialize(ref inputs);
+    inputs.span()
+}
@@ -0,0 +1,21 @@
+use starknet::{ClassHash, ContractAddress};
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Address of the Universal Deployer Contract, predeployed if configured with
+/// `tool.snforge.predeployment.udc`
+pub const UDC_ADDRESS: felt252 = 0x02ceed65a4bd731034c01113685c831b01c15d7d432f71afb1cf1634b53a2125;
+
+/// Account predeployed with `tool.snforge.predeployment.accounts`.
+#[derive(Drop, Copy, Serde, Debug, PartialEq)]
+pub struct PredeployedAccount {
+    pub contract_address: ContractAddress,
+    pub class_hash: ClassHash,
+    pub private_key: felt252,
+    pub public_key: felt252,
+}
+
+/// Returns accounts predeployed before running the test, in the order they are configured in
+/// `Scarb.toml`. Keys of the accounts are the same in every test run.
+pub fn predeployed_accounts() -> Array<PredeployedAccount> {
+    execute_cheatcode_and_deserialize::<'predeployed_accounts'>(array![].span())
+}
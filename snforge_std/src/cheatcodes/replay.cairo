@@ -0,0 +1,33 @@
+use starknet::SyscallResult;
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Configuration of the transaction replay.
+#[derive(Drop, Copy, Serde, Debug, PartialEq)]
+pub struct ReplayConfig {
+    /// Whether the transactions preceding the replayed one in the same block should be executed
+    /// first, so the replay sees the exact state the transaction was executed on.
+    pub replay_preceding_transactions: bool,
+    /// Whether the `__validate__` entrypoint of the accounts should be skipped, also for the
+    /// preceding transactions.
+    pub skip_validation: bool,
+}
+
+impl ReplayConfigDefault of Default<ReplayConfig> {
+    fn default() -> ReplayConfig {
+        ReplayConfig { replay_preceding_transactions: true, skip_validation: false }
+    }
+}
+
+/// Replays an on-chain transaction on top of the forked state.
+/// The test has to be forked at the block preceding the one the transaction was included in.
+/// `transaction_hash` - Hash of the `INVOKE` or `L1_HANDLER` transaction to replay
+/// `config` - Replay configuration, see `ReplayConfig`
+/// Returns the data returned by the transaction or panic data if it failed
+pub fn replay_transaction(
+    transaction_hash: felt252, config: ReplayConfig,
+) -> SyscallResult<Span<felt252>> {
+    let mut inputs = array![transaction_hash];
+    config.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'replay_transaction'>(inputs.span())
+}
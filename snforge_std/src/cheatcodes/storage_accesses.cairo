@@ -0,0 +1,60 @@
+use starknet::ContractAddress;
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Read from or write to a storage slot.
+#[derive(Drop, Clone, Serde, Debug, PartialEq)]
+pub struct StorageAccess {
+    pub key: felt252,
+    /// Value read from or written to the slot
+    pub value: felt252,
+    /// Name of the storage variable, present if the slot is the base address of a variable found
+    /// in the contract debug info
+    pub variable_name: Option<ByteArray>,
+}
+
+/// Storage accesses of a contract in the order they were executed.
+#[derive(Drop, Clone, Serde, Debug, PartialEq)]
+pub struct StorageAccesses {
+    pub reads: Array<StorageAccess>,
+    pub writes: Array<StorageAccess>,
+}
+
+/// Starts recording storage reads and writes of all contracts, dropping the ones recorded so far.
+pub fn record_storage_accesses() {
+    execute_cheatcode_and_deserialize::<'record_storage_accesses', ()>(array![].span());
+}
+
+/// Gets storage accesses of the given contract recorded since the last `record_storage_accesses`
+/// call, including the ones done in reverted calls.
+/// - `contract_address` - address of the contract whose storage was accessed
+pub fn get_storage_accesses(contract_address: ContractAddress) -> StorageAccesses {
+    execute_cheatcode_and_deserialize::<
+        'get_storage_accesses',
+    >(array![contract_address.into()].span())
+}
+
+pub trait StorageAccessesTrait {
+    /// Checks if the storage slot at `key` was read.
+    fn was_read(self: @StorageAccesses, key: felt252) -> bool;
+    /// Checks if the storage slot at `key` was written to.
+    fn was_written(self: @StorageAccesses, key: felt252) -> bool;
+}
+
+impl StorageAccessesTraitImpl of StorageAccessesTrait {
+    fn was_read(self: @StorageAccesses, key: felt252) -> bool {
+        contains_key(self.reads.span(), key)
+    }
+
+    fn was_written(self: @StorageAccesses, key: felt252) -> bool {
+        contains_key(self.writes.span(), key)
+    }
+}
+
+fn contains_key(accesses: Span<StorageAccess>, key: felt252) -> bool {
+    for access in accesses {
+        if *access.key == key {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,120 @@
+use starknet::{ClassHash, ContractAddress};
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+use super::events::Event;
+
+/// Account transaction submitted with `submit_transaction`.
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub enum AccountTransaction {
+    Invoke: InvokeTransaction,
+    DeployAccount: DeployAccountTransaction,
+    Declare: DeclareTransaction,
+}
+
+/// `INVOKE` v3 transaction.
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub struct InvokeTransaction {
+    /// Address of the account sending the transaction
+    pub sender_address: ContractAddress,
+    /// Calldata of the `__execute__` entrypoint of the account
+    pub calldata: Array<felt252>,
+    pub account_deployment_data: Array<felt252>,
+}
+
+/// `DEPLOY_ACCOUNT` v3 transaction.
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub struct DeployAccountTransaction {
+    /// Class hash of the account, has to be declared
+    pub class_hash: ClassHash,
+    pub contract_address_salt: felt252,
+    pub constructor_calldata: Array<felt252>,
+}
+
+/// `DECLARE` v3 transaction of a locally compiled contract.
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub struct DeclareTransaction {
+    /// Address of the account sending the transaction
+    pub sender_address: ContractAddress,
+    /// Name of the contract, the same as passed to `declare`
+    pub contract_name: ByteArray,
+}
+
+#[derive(Drop, Copy, Serde, Debug, PartialEq)]
+pub struct GasBounds {
+    pub max_amount: u64,
+    pub max_price_per_unit: u128,
+}
+
+#[derive(Drop, Copy, Serde, Debug, PartialEq)]
+pub struct TransactionResourceBounds {
+    pub l1_gas: GasBounds,
+    pub l2_gas: GasBounds,
+    pub l1_data_gas: GasBounds,
+}
+
+/// Parameters common to all account transactions.
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub struct TransactionParams {
+    pub signature: Array<felt252>,
+    /// Nonce of the transaction, the current nonce of the account is used if not provided
+    pub nonce: Option<felt252>,
+    /// Resource bounds of the transaction, bounds based on the current gas prices that are
+    /// sufficient for most transactions are used if not provided
+    pub resource_bounds: Option<TransactionResourceBounds>,
+    pub tip: u64,
+    pub paymaster_data: Array<felt252>,
+}
+
+impl TransactionParamsDefault of Default<TransactionParams> {
+    fn default() -> TransactionParams {
+        TransactionParams {
+            signature: array![],
+            nonce: Option::None,
+            resource_bounds: Option::None,
+            tip: 0,
+            paymaster_data: array![],
+        }
+    }
+}
+
+/// Receipt of an executed transaction.
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub struct TransactionReceipt {
+    pub transaction_hash: felt252,
+    /// Fee charged in STRK (in fri)
+    pub actual_fee: u128,
+    /// Reason of the revert if the execution of the transaction was reverted
+    pub revert_reason: Option<ByteArray>,
+    /// Events emitted by the transaction, including the fee transfer
+    pub events: Array<(ContractAddress, Event)>,
+}
+
+pub trait DeployAccountTransactionTrait {
+    /// Calculates the address the account will be deployed at, e.g. to fund it before deployment
+    fn precalculate_address(self: @DeployAccountTransaction) -> ContractAddress;
+}
+
+impl DeployAccountTransactionImpl of DeployAccountTransactionTrait {
+    fn precalculate_address(self: @DeployAccountTransaction) -> ContractAddress {
+        let mut inputs = array![];
+        self.serialize(ref inputs);
+
+        execute_cheatcode_and_deserialize::<'precalculate_account_address'>(inputs.span())
+    }
+}
+
+/// Submits an account transaction, executed the same way as on the network: the transaction is
+/// validated by the account (`__validate__`, `__validate_declare__` or `__validate_deploy__`),
+/// its nonce is checked and the fee is charged in STRK.
+/// `transaction` - transaction to submit, see `AccountTransaction`
+/// `params` - signature, nonce, resource bounds and other transaction parameters
+/// Returns the receipt of the executed transaction (also if its execution was reverted), or the
+/// reason why the transaction was rejected
+pub fn submit_transaction(
+    transaction: AccountTransaction, params: TransactionParams,
+) -> Result<TransactionReceipt, ByteArray> {
+    let mut inputs = array![];
+    transaction.serialize(ref inputs);
+    params.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'submit_transaction'>(inputs.span())
+}
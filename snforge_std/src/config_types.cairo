@@ -55,11 +55,22 @@ pub enum Expected {
     ByteArray: ByteArray,
     Array: Array<ExpectedTupleItem>,
     Any,
+    Serialized: Array<felt252>,
+    Variant: Array<felt252>,
+    Regex: ByteArray,
+}
+
+pub fn serialized<T, +Serde<T>, +Drop<T>>(value: T) -> Array<felt252> {
+    let mut data = array![];
+    value.serialize(ref data);
+    data
 }
 
 #[derive(Drop, Serde)]
 pub struct ShouldPanicConfig {
-    pub expected: Expected,
+    pub expected: Array<Expected>,
+    pub contract: Option<ByteArray>,
+    pub function: Option<ByteArray>,
 }
 
 #[derive(Drop, Serde)]
@@ -71,3 +82,33 @@ pub struct IgnoreConfig {
 pub struct PredeployedContractsConfig {
     pub is_disabled: bool,
 }
+
+#[derive(Drop, Serde)]
+pub struct SeedConfig {
+    pub seed: u64,
+}
+
+#[derive(Drop, Serde)]
+pub struct SetupConfig {
+    pub fixture: ByteArray,
+}
+
+#[derive(Drop, Serde)]
+pub struct TimeoutConfig {
+    pub seconds: u64,
+}
+
+#[derive(Drop, Serde)]
+pub struct MaxStepsConfig {
+    pub max_steps: u32,
+}
+
+#[derive(Drop, Serde)]
+pub struct RetryConfig {
+    pub retries: u32,
+}
+
+#[derive(Drop, Serde)]
+pub struct TagsConfig {
+    pub tags: Array<ByteArray>,
+}
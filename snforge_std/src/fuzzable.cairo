@@ -2,8 +2,13 @@ use core::fmt::Debug;
 use starknet::ContractAddress;
 pub use super::cheatcodes::generate_arg::generate_arg;
 
+pub mod strategies;
+
 const MAX_FELT: felt252 = 0x800000000000011000000000000000000000000000000000000000000000000;
 
+// Maximum length of generated arrays and spans
+const MAX_COLLECTION_LENGTH: u32 = 32;
+
 pub trait Fuzzable<T, +Debug<T>> {
     fn blank() -> T;
     fn generate() -> T;
@@ -118,3 +123,33 @@ pub impl FuzzableUnitType of Fuzzable<()> {
         ()
     }
 }
+
+pub impl FuzzableArray<T, +Fuzzable<T>, +Debug<T>, +Drop<T>> of Fuzzable<Array<T>> {
+    fn blank() -> Array<T> {
+        array![]
+    }
+
+    // Generates an array of length 0 to 32
+    fn generate() -> Array<T> {
+        let mut len: u32 = generate_arg(0, MAX_COLLECTION_LENGTH);
+
+        let mut result = array![];
+        while len > 0 {
+            result.append(Fuzzable::<T>::generate());
+            len = len - 1;
+        }
+
+        result
+    }
+}
+
+pub impl FuzzableSpan<T, +Fuzzable<T>, +Debug<T>, +Drop<T>> of Fuzzable<Span<T>> {
+    fn blank() -> Span<T> {
+        array![].span()
+    }
+
+    // Generates a span of length 0 to 32
+    fn generate() -> Span<T> {
+        Fuzzable::<Array<T>>::generate().span()
+    }
+}
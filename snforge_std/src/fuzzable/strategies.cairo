@@ -0,0 +1,280 @@
+use core::fmt::Debug;
+use core::num::traits::Bounded;
+use crate::cheatcode::save_fuzzer_arg;
+use super::{Fuzzable, generate_arg};
+
+// Number of values `filter` generates before giving up
+const MAX_FILTER_ATTEMPTS: u32 = 1000;
+
+/// A recipe for generating random values of type `Self::Value`.
+/// All values are generated with the `generate_arg` cheatcode.
+pub trait Strategy<S> {
+    type Value;
+    fn generate(self: @S) -> Self::Value;
+}
+
+/// Generates a value with `strategy` and reports it alongside the fuzzer arguments
+/// if the test fails
+pub fn draw<S, impl TStrategy: Strategy<S>, +Debug<TStrategy::Value>>(
+    strategy: @S,
+) -> TStrategy::Value {
+    let value = TStrategy::generate(strategy);
+    save_fuzzer_arg(@value);
+    value
+}
+
+#[derive(Copy, Drop)]
+pub struct Any<T> {}
+
+/// Generates any value of type `T` using its `Fuzzable` implementation
+pub fn any<T>() -> Any<T> {
+    Any {}
+}
+
+pub impl AnyStrategy<T, +Fuzzable<T>, +Debug<T>> of Strategy<Any<T>> {
+    type Value = T;
+
+    fn generate(self: @Any<T>) -> T {
+        Fuzzable::<T>::generate()
+    }
+}
+
+#[derive(Copy, Drop)]
+pub struct Just<T> {
+    value: T,
+}
+
+/// Always generates `value`
+pub fn just<T>(value: T) -> Just<T> {
+    Just { value }
+}
+
+pub impl JustStrategy<T, +Clone<T>> of Strategy<Just<T>> {
+    type Value = T;
+
+    fn generate(self: @Just<T>) -> T {
+        self.value.clone()
+    }
+}
+
+#[derive(Copy, Drop)]
+pub struct Range<T> {
+    min: T,
+    max: T,
+}
+
+/// Generates a value between `min` and `max` (inclusive)
+pub fn range<T, +PartialOrd<T>, +Copy<T>, +Drop<T>>(min: T, max: T) -> Range<T> {
+    assert!(min <= max, "range requires min to be less than or equal to max");
+    Range { min, max }
+}
+
+pub impl RangeStrategy<T, +RangeValue<T>, +Copy<T>, +Drop<T>> of Strategy<Range<T>> {
+    type Value = T;
+
+    fn generate(self: @Range<T>) -> T {
+        RangeValue::generate_between(*self.min, *self.max)
+    }
+}
+
+/// Types of values which can be generated with `range`
+pub trait RangeValue<T> {
+    fn generate_between(min: T, max: T) -> T;
+}
+
+mod ints {
+    use super::{RangeValue, generate_arg};
+
+    pub impl IntRangeValue<
+        T, +Into<T, felt252>, +TryInto<felt252, T>, +Copy<T>, +Drop<T>,
+    > of RangeValue<T> {
+        fn generate_between(min: T, max: T) -> T {
+            // Offset from `min` is always non-negative, even if the bounds are negative
+            let min: felt252 = min.into();
+            let max: felt252 = max.into();
+            let offset = generate_arg(0, max - min);
+            (min + offset).try_into().expect('value should be in range')
+        }
+    }
+}
+
+pub impl RangeValueU8 = ints::IntRangeValue<u8>;
+pub impl RangeValueU16 = ints::IntRangeValue<u16>;
+pub impl RangeValueU32 = ints::IntRangeValue<u32>;
+pub impl RangeValueU64 = ints::IntRangeValue<u64>;
+pub impl RangeValueU128 = ints::IntRangeValue<u128>;
+
+pub impl RangeValueI8 = ints::IntRangeValue<i8>;
+pub impl RangeValueI16 = ints::IntRangeValue<i16>;
+pub impl RangeValueI32 = ints::IntRangeValue<i32>;
+pub impl RangeValueI64 = ints::IntRangeValue<i64>;
+pub impl RangeValueI128 = ints::IntRangeValue<i128>;
+
+pub impl RangeValueU256 of RangeValue<u256> {
+    fn generate_between(min: u256, max: u256) -> u256 {
+        let width = max - min;
+        // Offsets above `width` are rejected, so that all values are equally likely
+        let offset = loop {
+            let high = generate_arg(0, width.high);
+            let low = if width.high == 0 {
+                generate_arg(0, width.low)
+            } else {
+                generate_arg(0, Bounded::<u128>::MAX)
+            };
+            let offset = u256 { low, high };
+            if offset <= width {
+                break offset;
+            }
+        };
+        min + offset
+    }
+}
+
+#[derive(Drop)]
+pub struct Map<S, F> {
+    strategy: S,
+    f: F,
+}
+
+/// Generates a value with `strategy` and transforms it with `f`
+pub fn map<S, F>(strategy: S, f: F) -> Map<S, F> {
+    Map { strategy, f }
+}
+
+pub impl MapStrategy<
+    S, F, impl TStrategy: Strategy<S>, impl TFn: core::ops::Fn<F, (TStrategy::Value,)>,
+> of Strategy<Map<S, F>> {
+    type Value = TFn::Output;
+
+    fn generate(self: @Map<S, F>) -> TFn::Output {
+        TFn::call(self.f, (TStrategy::generate(self.strategy),))
+    }
+}
+
+#[derive(Drop)]
+pub struct Filter<S, F> {
+    strategy: S,
+    predicate: F,
+}
+
+/// Generates values with `strategy` until one of them satisfies `predicate`.
+/// Panics if no matching value is found after 1000 attempts.
+pub fn filter<S, F>(strategy: S, predicate: F) -> Filter<S, F> {
+    Filter { strategy, predicate }
+}
+
+pub impl FilterStrategy<
+    S,
+    F,
+    impl TStrategy: Strategy<S>,
+    +core::ops::Fn<F, (@TStrategy::Value,)>[Output: bool],
+    +Drop<TStrategy::Value>,
+> of Strategy<Filter<S, F>> {
+    type Value = TStrategy::Value;
+
+    fn generate(self: @Filter<S, F>) -> TStrategy::Value {
+        let mut attempts = 0;
+        loop {
+            let value = TStrategy::generate(self.strategy);
+            if core::ops::Fn::call(self.predicate, (@value,)) {
+                break value;
+            }
+
+            attempts += 1;
+            assert!(
+                attempts < MAX_FILTER_ATTEMPTS,
+                "filter rejected {} generated values in a row",
+                MAX_FILTER_ATTEMPTS,
+            );
+        }
+    }
+}
+
+#[derive(Drop)]
+pub struct OneOf<S> {
+    strategies: Array<S>,
+}
+
+/// Generates a value with one of `strategies`, chosen uniformly
+pub fn one_of<S, +Drop<S>>(strategies: Array<S>) -> OneOf<S> {
+    assert!(strategies.len() > 0, "one_of requires at least one strategy");
+    OneOf { strategies }
+}
+
+pub impl OneOfStrategy<S, impl TStrategy: Strategy<S>> of Strategy<OneOf<S>> {
+    type Value = TStrategy::Value;
+
+    fn generate(self: @OneOf<S>) -> TStrategy::Value {
+        let index = generate_arg(0, self.strategies.len() - 1);
+        TStrategy::generate(self.strategies.at(index))
+    }
+}
+
+#[derive(Drop)]
+pub struct Weighted<S> {
+    choices: Array<(u32, S)>,
+    total_weight: u32,
+}
+
+/// Generates a value with one of the strategies, each chosen with probability
+/// proportional to its weight
+pub fn weighted<S, +Drop<S>>(choices: Array<(u32, S)>) -> Weighted<S> {
+    let mut total_weight = 0;
+    for (weight, _) in choices.span() {
+        total_weight += *weight;
+    }
+    assert!(total_weight > 0, "weighted requires a positive total weight");
+
+    Weighted { choices, total_weight }
+}
+
+pub impl WeightedStrategy<S, impl TStrategy: Strategy<S>> of Strategy<Weighted<S>> {
+    type Value = TStrategy::Value;
+
+    fn generate(self: @Weighted<S>) -> TStrategy::Value {
+        let mut remaining = generate_arg(0, *self.total_weight - 1);
+        let mut choices = self.choices.span();
+        loop {
+            let (weight, strategy) = choices.pop_front().unwrap();
+            if remaining < *weight {
+                break TStrategy::generate(strategy);
+            }
+            remaining -= *weight;
+        }
+    }
+}
+
+#[derive(Drop)]
+pub struct VecOf<S> {
+    strategy: S,
+    min_length: u32,
+    max_length: u32,
+}
+
+/// Generates an array of values generated with `strategy`, with length between
+/// `min_length` and `max_length` (inclusive)
+pub fn vec_of<S, +Drop<S>>(strategy: S, min_length: u32, max_length: u32) -> VecOf<S> {
+    assert!(
+        min_length <= max_length,
+        "vec_of requires min_length to be less than or equal to max_length",
+    );
+    VecOf { strategy, min_length, max_length }
+}
+
+pub impl VecOfStrategy<
+    S, impl TStrategy: Strategy<S>, +Drop<TStrategy::Value>,
+> of Strategy<VecOf<S>> {
+    type Value = Array<TStrategy::Value>;
+
+    fn generate(self: @VecOf<S>) -> Array<TStrategy::Value> {
+        let mut length: u32 = generate_arg(*self.min_length, *self.max_length);
+
+        let mut result = array![];
+        while length > 0 {
+            result.append(TStrategy::generate(self.strategy));
+            length = length - 1;
+        }
+
+        result
+    }
+}
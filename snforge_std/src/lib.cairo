@@ -7,13 +7,21 @@ pub use cheatcodes::block_hash::{
     start_cheat_block_hash, start_cheat_block_hash_global, stop_cheat_block_hash,
     stop_cheat_block_hash_global,
 };
+pub use cheatcodes::block_progression::{Block, roll, roll_with_hook, set_block_time, warp};
+pub use cheatcodes::class_replacements::{
+    ClassReplacement, ClassReplacementSpy, ClassReplacementSpyAssertionsTrait,
+    ClassReplacementSpyTrait, spy_class_replacements,
+};
 pub use cheatcodes::contract_class::{
-    ContractClass, ContractClassTrait, DeclareResult, DeclareResultTrait, get_class_hash,
+    ContractClass, ContractClassTrait, DeclareError, DeclareOutcomeTrait, DeclareResult,
+    DeclareResultTrait, get_class_hash,
 };
 
-pub use cheatcodes::contract_class::{declare, declare_from_file};
+pub use cheatcodes::contract_class::{
+    declare, declare_cairo0, declare_from_file, declare_sierra, try_declare, try_declare_from_file,
+};
 
-pub use cheatcodes::erc20::set_balance;
+pub use cheatcodes::erc20::{set_balance, set_token_allowance, set_token_balance, set_token_owner};
 pub use cheatcodes::erc20::{CustomToken, Token, TokenImpl, TokenTrait};
 
 pub use cheatcodes::events::Event;
@@ -104,16 +112,35 @@ pub use cheatcodes::generate_random_felt::generate_random_felt;
 pub use cheatcodes::l1_handler::L1Handler;
 pub use cheatcodes::l1_handler::L1HandlerTrait;
 
+pub use cheatcodes::l1_messaging::{
+    MessageToL2, MessageToL2Status, MessageToL2Trait, consume_message_from_l2, send_message_to_l2,
+    set_message_cancellation_delay,
+};
+
 pub use cheatcodes::message_to_l1::{
     MessageToL1, MessageToL1FilterTrait, MessageToL1Spy, MessageToL1SpyAssertionsTrait,
     MessageToL1SpyTrait, spy_messages_to_l1,
 };
 
+pub use cheatcodes::predeployment::{PredeployedAccount, UDC_ADDRESS, predeployed_accounts};
+
+pub use cheatcodes::replay::{ReplayConfig, replay_transaction};
+
 pub use cheatcodes::storage::store;
 pub use cheatcodes::storage::{interact_with_state, load, map_entry_address};
+pub use cheatcodes::storage_accesses::{
+    StorageAccess, StorageAccesses, StorageAccessesTrait, get_storage_accesses,
+    record_storage_accesses,
+};
+pub use cheatcodes::transaction::{
+    AccountTransaction, DeclareTransaction, DeployAccountTransaction, DeployAccountTransactionTrait,
+    GasBounds, InvokeTransaction, TransactionParams, TransactionReceipt, TransactionResourceBounds,
+    submit_transaction,
+};
 pub use cheatcodes::{
-    ReplaceBytecodeError, mock_call, replace_bytecode, start_mock_call, stop_mock_call,
-    test_address, test_selector,
+    CalldataMatcher, MockedResponse, ReplaceBytecodeError, cheat_class_hash, mock_call,
+    mock_call_when, replace_bytecode, start_cheat_class_hash, start_mock_call,
+    stop_cheat_class_hash, stop_mock_call, test_address, test_selector, upgrade_forked_contract,
 };
 
 pub mod byte_array;
@@ -136,7 +163,7 @@ pub mod trace;
 
 #[doc(hidden)]
 pub mod _internals {
-    pub use cheatcode::{is_config_run, save_fuzzer_arg};
+    pub use cheatcode::{fixture_result, is_config_run, save_fuzzer_arg};
     use super::cheatcode;
 
     pub use super::config_types;